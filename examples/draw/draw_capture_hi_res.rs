@@ -144,7 +144,8 @@ fn update(app: &App, model: &mut Model, _update: Update) {
     let mut encoder = device.create_command_encoder(&ce_desc);
     model
         .renderer
-        .render_to_texture(device, &mut encoder, draw, &model.texture);
+        .render_to_texture(device, &mut encoder, draw, &model.texture)
+        .expect("failed to render the drawing to the texture");
 
     // Take a snapshot of the texture. The capturer will do the following:
     //
@@ -13,10 +13,10 @@ struct Model {
 fn model(app: &App) -> Model {
     // Create a new window!
     app.new_window().size(512, 512).view(view).build().unwrap();
-    // Load the image from disk and upload it to a GPU texture.
+    // Load the image from disk and upload it to a GPU texture along with its mipmaps.
     let assets = app.assets_path().unwrap();
     let img_path = assets.join("images").join("nature").join("nature_1.jpg");
-    let texture = wgpu::Texture::from_path(app, img_path).unwrap();
+    let texture = app.main_window().load_texture(img_path).unwrap();
     Model { texture }
 }
 
@@ -119,7 +119,7 @@ fn crop_tiles(app: &App, model: &mut Model, win: Rect) {
             let [w, h] = model.texture.size();
             let area = geom::Rect::from_x_y_w_h(
                 map_range(model.crop_x, win.left(), win.right(), 0.0, 1.0),
-                map_range(model.crop_y, win.top(), win.bottom(), 0.0, 1.0),
+                map_range(model.crop_y, win.bottom(), win.top(), 0.0, 1.0),
                 model.tile_width / w as f32,
                 model.tile_height / h as f32,
             );
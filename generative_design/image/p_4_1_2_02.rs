@@ -67,7 +67,7 @@ fn view(app: &App, model: &Model, frame: Frame) {
 
     let area = geom::Rect::from_x_y_w_h(
         map_range(x1, win.left(), win.right(), 0.0, 1.0),
-        map_range(y1, win.top(), win.bottom(), 0.0, 1.0),
+        map_range(y1, win.bottom(), win.top(), 0.0, 1.0),
         map_range(w, 0.0, win.w(), 0.0, 1.0),
         map_range(h, 0.0, win.h(), 0.0, 1.0),
     );
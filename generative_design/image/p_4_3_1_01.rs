@@ -25,6 +25,9 @@
  * KEYS
  * 1-9                 : switch draw mode
  * s                   : save png
+ *
+ * DRAG AND DROP
+ * image file          : map the dropped image instead
  */
 use splatter::prelude::*;
 
@@ -46,6 +49,7 @@ fn model(app: &App) -> Model {
         .size(603, 873)
         .view(view)
         .key_released(key_released)
+        .dropped_file(dropped_file)
         .build()
         .unwrap();
 
@@ -228,3 +232,14 @@ fn key_released(app: &App, model: &mut Model, key: Key) {
         _ => {}
     }
 }
+
+fn dropped_file(_app: &App, model: &mut Model, path: std::path::PathBuf) {
+    match image::open(&path) {
+        // Scale the image to fit the current grid, as each pixel becomes a new element.
+        Ok(image) => {
+            let (w, h) = model.image.dimensions();
+            model.image = image.resize(w, h, image::imageops::FilterType::Triangle);
+        }
+        Err(err) => eprintln!("failed to load {}: {}", path.display(), err),
+    }
+}
@@ -158,7 +158,8 @@ fn view(app: &App, model: &Model, frame: Frame) {
             let green = c[1] as f32 / 255.0;
             let blue = c[2] as f32 / 255.0;
             let greyscale = red * 0.222 + green * 0.707 + blue * 0.071;
-            let gradient_to_index = map_range(greyscale, 0.0, 1.0, 0, model.shapes.len() - 1);
+            let gradient_to_index =
+                map_range_clamped(greyscale, 0.0, 1.0, 0, model.shapes.len() - 1);
 
             // Grid position + tile size
             let tile_width = 603.0 / w as f32;
@@ -36,7 +36,7 @@ fn main() {
 }
 
 struct Model {
-    act_random_seed: u32,
+    act_random_seed: u64,
 }
 
 fn model(app: &App) -> Model {
@@ -88,7 +88,7 @@ fn view(app: &App, model: &Model, frame: Frame) {
 }
 
 fn mouse_pressed(_app: &App, model: &mut Model, _button: MouseButton) {
-    model.act_random_seed = (random_f32() * 100000.0) as u32;
+    model.act_random_seed = (random_f32() * 100000.0) as u64;
 }
 
 fn key_pressed(app: &App, _model: &mut Model, key: Key) {
@@ -32,7 +32,7 @@
  * s                   : save png
  */
 use splatter::image;
-use splatter::noise::{Fbm, MultiFractal, NoiseFn, Perlin};
+use splatter::noise::{Noise, Perlin};
 use splatter::prelude::*;
 use splatter::winit::keyboard::NamedKey;
 
@@ -44,7 +44,7 @@ struct Model {
     octaves: usize,
     falloff: f32,
     noise_mode: u8,
-    noise_random_seed: u32,
+    noise_random_seed: u64,
     texture: wgpu::Texture,
 }
 
@@ -78,9 +78,7 @@ fn view(app: &App, model: &Model, frame: Frame) {
     frame.clear(BLACK);
 
     let win = app.window_rect();
-    let noise: Fbm<Perlin> = Fbm::new(model.noise_random_seed)
-        .set_octaves(model.octaves)
-        .set_persistence(model.falloff as f64);
+    let noise = Perlin::new(model.noise_random_seed).fbm(model.octaves, 2.0, model.falloff as f64);
 
     let noise_x_range = map_range(app.mouse.x, win.left(), win.right(), 0.0, win.w() / 10.0);
     let noise_y_range = map_range(app.mouse.y, win.top(), win.bottom(), 0.0, win.h() / 10.0);
@@ -92,7 +90,7 @@ fn view(app: &App, model: &Model, frame: Frame) {
 
         if model.noise_mode == 1 {
             noise_value = map_range(
-                noise.get([noise_x, noise_y]),
+                noise.get_2d(noise_x, noise_y),
                 1.0,
                 -1.0,
                 0.0,
@@ -100,7 +98,7 @@ fn view(app: &App, model: &Model, frame: Frame) {
             );
         } else if model.noise_mode == 2 {
             let n = map_range(
-                noise.get([noise_x, noise_y]),
+                noise.get_2d(noise_x, noise_y),
                 -1.0,
                 1.0,
                 0.0,
@@ -130,7 +128,7 @@ fn key_released(app: &App, model: &mut Model, key: Key) {
     match key {
         Key::Named(key) => {
             match key {
-                NamedKey::Space => model.noise_random_seed = (random_f32() * 100000.0) as u32,
+                NamedKey::Space => model.noise_random_seed = (random_f32() * 100000.0) as u64,
                 _ => {}
             };
         }
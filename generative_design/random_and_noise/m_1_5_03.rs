@@ -120,7 +120,7 @@ struct Model {
     agent_alpha: f32,
     stroke_width: f32,
     draw_mode: u8,
-    noise_seed: u32,
+    noise_seed: u64,
 }
 
 fn model(app: &App) -> Model {
@@ -187,7 +187,7 @@ fn key_released(app: &App, model: &mut Model, key: Key) {
     match key {
         Key::Named(key) => {
             match key {
-                NamedKey::Space => model.noise_seed = (random_f32() * 10000.0).floor() as u32,
+                NamedKey::Space => model.noise_seed = (random_f32() * 10000.0).floor() as u64,
                 _ => {}
             };
         }
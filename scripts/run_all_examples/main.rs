@@ -12,14 +12,36 @@
 //! cargo run -p run_all_examples
 //! ```
 //!
-//! TODO: Collect a map of failed examples so that they can be concisely reported at the end.
+//! Each example is run for a few seconds before being killed. An example is considered to have
+//! failed if it panics, if it fails to compile, or if it exits with an unsuccessful status before
+//! it is killed. Writing to `stderr` alone (e.g. warnings or debug output) is not a failure. All
+//! failures are collected and concisely reported at the end of the run.
 
 use anyhow::{bail, Context};
-use std::process::Stdio;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Read;
+use std::process::{ExitStatus, Stdio};
+use std::time::{Duration, Instant};
 use toml_edit::Document;
 
 type Error = anyhow::Error;
 
+/// The duration for which each example is allowed to run before being killed.
+const RUN_DURATION: Duration = Duration::from_secs(3);
+
+/// The reason an example (or a whole package) was considered to have failed.
+enum Failure {
+    /// The package's examples failed to build.
+    Build(String),
+    /// The example panicked, with the line describing the panic.
+    Panic(String),
+    /// The example failed to compile when run, with the first compiler error line.
+    Compile(String),
+    /// The example exited on its own with an unsuccessful status.
+    Exit(ExitStatus),
+}
+
 fn main() -> Result<(), Error> {
     const ALL_PACKAGES: &[&str] = &["examples", "generative_design", "nature_of_code"];
 
@@ -41,7 +63,8 @@ fn main() -> Result<(), Error> {
         .parent()
         .unwrap(); // splatter
 
-    let mut failures = Vec::new();
+    // Failed examples along with the reason for failure, keyed by package.
+    let mut failures: BTreeMap<String, Vec<(String, Failure)>> = BTreeMap::new();
     for package in packages {
         let examples_dir = workspace_manifest_dir.join(&package);
         let manifest_path = examples_dir.join("Cargo").with_extension("toml");
@@ -58,16 +81,19 @@ fn main() -> Result<(), Error> {
             .arg(&package)
             .arg("--examples")
             .output()
-            .context("failed to run `cargo build -p {package} --examples`")?;
-        if !output.stderr.is_empty() {
-            let stderr =
-                String::from_utf8(output.stderr).context("couldn't convert stderr to string")?;
-            if stderr.contains("error[E") {
-                eprintln!(
-                    "failed to build examples for package '{}':\n{}",
-                    package, stderr
-                );
-            }
+            .with_context(|| format!("failed to run `cargo build -p {package} --examples`"))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            eprintln!(
+                "failed to build examples for package '{}':\n{}",
+                package, stderr
+            );
+            let reason = first_error_line(&stderr).unwrap_or_else(|| output.status.to_string());
+            failures
+                .entry(package.clone())
+                .or_default()
+                .push(("(all examples)".to_string(), Failure::Build(reason)));
+            continue;
         }
 
         // Find the `examples` table within the `toml::Value` to find all example names.
@@ -91,30 +117,101 @@ fn main() -> Result<(), Error> {
                 .stdin(Stdio::piped())
                 .stderr(Stdio::piped())
                 .spawn()
-                .context("failed to spawn example '{name}'")?;
+                .with_context(|| format!("failed to spawn example '{name}'"))?;
 
-            // Allow each example to run for 3 secs each.
-            std::thread::sleep(std::time::Duration::from_secs(3));
+            // Drain `stderr` on another thread so that a chatty example can't block on a full pipe.
+            let mut stderr_pipe = child.stderr.take().expect("stderr was not piped");
+            let stderr_thread = std::thread::spawn(move || {
+                let mut stderr = vec![];
+                stderr_pipe.read_to_end(&mut stderr).ok();
+                stderr
+            });
 
-            // Kill the example and retrieve any output.
-            child.kill().ok();
-            let output = child
-                .wait_with_output()
-                .context("failed to wait for child process")?;
+            // Allow each example to run for `RUN_DURATION`, or until it exits on its own.
+            let start = Instant::now();
+            let mut exit_status = None;
+            while start.elapsed() < RUN_DURATION {
+                exit_status = child.try_wait().context("failed to poll child process")?;
+                if exit_status.is_some() {
+                    break;
+                }
+                std::thread::sleep(Duration::from_millis(50));
+            }
+
+            // Kill the example if it is still running. Its status is meaningless in this case.
+            if exit_status.is_none() {
+                child.kill().ok();
+                child.wait().context("failed to wait for child process")?;
+            }
+            let stderr = stderr_thread.join().unwrap_or_default();
+            let stderr = String::from_utf8_lossy(&stderr);
 
-            // If the example wrote to `stderr` it must have failed.
-            if output.status.code().unwrap_or(0) == 101 {
-                failures.push(name.to_string());
+            if let Some(failure) = detect_failure(&stderr, exit_status) {
+                eprintln!("example '{}' failed: {}", name, failure);
+                failures
+                    .entry(package.clone())
+                    .or_default()
+                    .push((name.to_string(), failure));
             }
         }
     }
+
+    if failures.is_empty() {
+        println!("All examples ran successfully.");
+        return Ok(());
+    }
     println!("Failed examples:");
-    for ex in failures.iter() {
-        println!("{ex}");
+    for (package, examples) in &failures {
+        println!("  {package}:");
+        for (name, failure) in examples {
+            println!("    {name}: {failure}");
+        }
     }
-    if failures.is_empty() {
-        Ok(())
-    } else {
-        bail!("some examples didn't build/run properly")
+    let count: usize = failures.values().map(Vec::len).sum();
+    bail!("{count} example(s) didn't build/run properly")
+}
+
+/// Determine whether an example failed given its `stderr` output and its exit status, if it
+/// exited before being killed.
+fn detect_failure(stderr: &str, exit_status: Option<ExitStatus>) -> Option<Failure> {
+    if let Some(line) = stderr.lines().find(|line| line.contains("panicked at")) {
+        return Some(Failure::Panic(line.trim().to_string()));
+    }
+    if let Some(line) = compile_error_line(stderr) {
+        return Some(Failure::Compile(line));
+    }
+    match exit_status {
+        Some(status) if !status.success() => Some(Failure::Exit(status)),
+        _ => None,
+    }
+}
+
+/// The first error within the `stderr` output of `cargo build`, e.g. `error[E0425]: ...`.
+fn first_error_line(stderr: &str) -> Option<String> {
+    stderr
+        .lines()
+        .find(|line| line.starts_with("error[") || line.starts_with("error:"))
+        .map(|line| line.trim().to_string())
+}
+
+/// The first compiler error within the `stderr` output of `cargo run`.
+///
+/// Unlike `first_error_line`, this ignores lines starting with a bare `error:` as these may be
+/// logged by the example itself at runtime.
+fn compile_error_line(stderr: &str) -> Option<String> {
+    stderr
+        .lines()
+        .find(|line| line.starts_with("error[E") || line.contains("could not compile"))
+        .map(|line| line.trim().to_string())
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Build(line) => write!(f, "build failed: {line}"),
+            Failure::Panic(line) => write!(f, "{line}"),
+            Failure::Compile(line) => write!(f, "compile failed: {line}"),
+            Failure::Exit(status) => write!(f, "exited early with {status}"),
+        }
     }
 }
@@ -0,0 +1,84 @@
+//! Measures the cost of filling the draw renderer with many small primitives.
+//!
+//! Consecutive primitives that share a pipeline, texture, sampler and scissor are coalesced into a
+//! single draw call. The number of draw calls produced by each drawing is asserted by the
+//! `draw_batching_tests`.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use splatter::draw::{Draw, Renderer, RendererBuilder};
+use splatter::headless::{self, Headless};
+use splatter::image;
+use splatter::prelude::*;
+
+const SEGMENTS: usize = 10_000;
+
+// A separate `draw.line()` per segment of a lissajous figure.
+fn lissajous_lines(draw: &Draw) {
+    let points = (0..=SEGMENTS).map(|i| {
+        let t = i as f32 / SEGMENTS as f32 * TAU;
+        pt2((t * 3.0).sin(), (t * 4.0).cos()) * 200.0
+    });
+    let points: Vec<_> = points.collect();
+    for (i, w) in points.windows(2).enumerate() {
+        draw.line()
+            .start(w[0])
+            .end(w[1])
+            .weight(1.0)
+            .hsv(i as f32 / SEGMENTS as f32, 1.0, 1.0);
+    }
+}
+
+// Textured quads with a small untextured marker drawn over each one.
+fn textured_with_markers(draw: &Draw, texture: &wgpu::Texture) {
+    for i in 0..SEGMENTS / 10 {
+        let x = (i % 40) as f32 * 10.0 - 200.0;
+        let y = (i / 40) as f32 * 10.0 - 200.0;
+        draw.texture(texture).x_y(x, y).w_h(8.0, 8.0);
+        draw.ellipse().x_y(x, y).radius(2.0).color(WHITE);
+    }
+}
+
+fn bench_fill(c: &mut Criterion, headless: &Headless, name: &str, view: impl Fn(&Draw)) {
+    let device = headless.device_queue_pair().device();
+    let size = headless.size_pixels();
+    let format = headless::Builder::TEXTURE_FORMAT;
+    let mut renderer: Renderer = RendererBuilder::new().build(device, size, 1.0, 1, format);
+    let fill = |renderer: &mut Renderer, draw: Draw| {
+        renderer.clear();
+        renderer.fill(device, &draw, 1.0, size).unwrap();
+    };
+
+    c.bench_function(name, |b| {
+        b.iter_batched(
+            || {
+                let draw = Draw::new();
+                view(&draw);
+                draw
+            },
+            |draw| fill(&mut renderer, draw),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn draw_calls(c: &mut Criterion) {
+    // Rendering requires an adapter, which may not be available, e.g. on some CI runners.
+    let headless = match splatter::headless(512, 512).msaa_samples(1).build() {
+        Ok(headless) => headless,
+        Err(err) => {
+            eprintln!("skipping draw call benchmarks: {:?}", err);
+            return;
+        }
+    };
+    let image = image::RgbaImage::from_pixel(4, 4, image::Rgba([255, 0, 0, 255]));
+    let image = image::DynamicImage::ImageRgba8(image);
+    let texture = wgpu::Texture::from_image(&**headless.device_queue_pair(), &image);
+
+    bench_fill(c, &headless, "lissajous_lines", lissajous_lines);
+    bench_fill(c, &headless, "textured_with_markers", |draw| {
+        textured_with_markers(draw, &texture)
+    });
+}
+
+criterion_group!(benches, draw_calls);
+criterion_main!(benches);
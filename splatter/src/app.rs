@@ -7,12 +7,16 @@
 //! - [**Proxy**](./struct.Proxy.html) - a handle to an **App** that may be used from a non-main
 //!   thread.
 //! - [**LoopMode**](./enum.LoopMode.html) - describes the behaviour of the application event loop.
+//! - [**TimeMode**](./enum.TimeMode.html) - describes how time advances between updates.
 
 use crate::draw;
 use crate::event::{self, Event, Key, LoopEvent, Update};
 use crate::frame::{Frame, RawFrame};
 use crate::geom;
+use crate::overlay::RenderStats;
 use crate::state;
+use crate::storage::Storage;
+use crate::text::font;
 use crate::time::DurationF64;
 use crate::wgpu;
 use crate::window::{self, Window};
@@ -21,7 +25,7 @@ use instant::Instant;
 use std::cell::{RefCell, RefMut};
 use std::collections::HashMap;
 use std::future::Future;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::sync::atomic::{self, AtomicBool};
 use std::sync::Arc;
@@ -72,6 +76,7 @@ pub struct Builder<M = (), E = Event> {
     capture_frame_timeout: Option<Option<Duration>>,
     max_capture_frame_jobs: Option<u32>,
     backends: wgpu::Backends,
+    headless_size: Option<[u32; 2]>,
 }
 
 /// A splatter `Sketch` builder.
@@ -103,6 +108,7 @@ fn default_model(_: &App) {}
 /// - The sharing of wgpu devices between windows.
 /// - A default **Draw** instance for ease of use.
 /// - A map of channels for submitting user input updates to active **Ui**s.
+/// - The offscreen **Headless** target when running headless. See `Builder::headless`.
 pub struct App {
     config: RefCell<Config>,
     default_window_size: Option<DefaultWindowSize>,
@@ -111,6 +117,8 @@ pub struct App {
     pub(crate) event_loop_window_target: Option<EventLoopWindowTarget>,
     pub(crate) event_loop_proxy: Proxy,
     pub(crate) windows: RefCell<HashMap<window::Id, Window>>,
+    /// The offscreen target to which the view is rendered in place of a window when headless.
+    headless: Option<crate::headless::Headless>,
     /// The wgpu backends to choose between.
     backends: wgpu::Backends,
     /// The main wgpu instance.
@@ -128,11 +136,22 @@ pub struct App {
     ///
     /// `down` is the set of keys that are currently pressed.
     ///
+    /// `is_down`, `pressed_this_frame` and `released_this_frame` may be used to poll individual
+    /// keys, where a "frame" spans from one update to the next.
+    ///
     /// NOTE: `down` this is tracked by the splatter `App` so issues might occur if e.g. a key is
     /// pressed while the app is in focus and then released when out of focus. Eventually we should
     /// change this to query the OS somehow, but I don't think `winit` provides a way to do this
     /// yet.
     pub keys: state::Keys,
+    /// The state of all connected gamepads, polled once per update.
+    #[cfg(feature = "gamepad")]
+    gamepads: crate::gamepad::Gamepads,
+    /// The handle to the system clipboard, opened on first use.
+    #[cfg(not(target_arch = "wasm32"))]
+    clipboard: RefCell<crate::clipboard::Clipboard>,
+    /// Compute pipelines created via `App::compute`, cached by shader.
+    pub(crate) compute_pipelines: RefCell<crate::compute::PipelineCache>,
     /// Key time measurements tracked by the App.
     ///
     /// `duration.since_start` specifies the duration since the app started running.
@@ -162,8 +181,15 @@ pub struct App {
 #[derive(Debug)]
 struct Config {
     loop_mode: LoopMode,
+    time_mode: TimeMode,
     exit_on_escape: bool,
     fullscreen_on_shortcut: bool,
+    is_paused: bool,
+    show_fps: bool,
+    // Whether or not a single update has been requested while paused.
+    step_requested: bool,
+    // Whether or not `App::quit` has been called. Only used by the headless loop.
+    quit_requested: bool,
 }
 
 // Draw state managed by the **App**.
@@ -177,7 +203,8 @@ struct DrawState {
 /// **App**'s inner event loop.
 #[derive(Clone)]
 pub struct Proxy {
-    event_loop_proxy: winit::event_loop::EventLoopProxy<()>,
+    // There is no event loop to wake when running headless.
+    event_loop_proxy: Option<winit::event_loop::EventLoopProxy<()>>,
     // Whether or not a wakeup is already queued.
     //
     // Used to avoid spuriously calling `EventLoopProxy::send_event` as this can be expensive on
@@ -188,9 +215,12 @@ pub struct Proxy {
 // State related specifically to the application loop, shared between loop modes.
 struct LoopState {
     updates_since_event: u64,
-    loop_start: Instant,
     last_update: Instant,
+    // Whether or not the loop was paused since the last update.
+    was_paused: bool,
     total_updates: u64,
+    // The moment at which the next update is due in `LoopMode::Rate`.
+    next_update: Instant,
 }
 
 /// The mode in which the **App** is currently running the event loop and emitting `Update` events.
@@ -205,8 +235,17 @@ pub enum LoopMode {
 
     /// Specifies that the application is continuously looping at a consistent rate.
     ///
-    /// **NOTE:** This currently behaves the same as `RefreshSync`. Need to upate this to handled a
-    /// fix step properly in the future. See #456.
+    /// Between updates the event loop parks the thread until the next update is due rather than
+    /// busy-waiting, making this a battery-friendly alternative to `RefreshSync` for continuous
+    /// animation. Updates are scheduled against a fixed timeline, so time spent in `update` and
+    /// `view` does not reduce the effective rate unless a single frame takes longer than
+    /// `update_interval`, in which case the late update is emitted immediately and the timeline
+    /// restarts from there.
+    ///
+    /// Window surfaces are still presented with their own present mode. With vsync enabled (the
+    /// default `Fifo` mode), presenting blocks until the next display refresh, so the effective
+    /// rate is capped at the refresh rate and rates that do not evenly divide it may judder.
+    /// Accuracy is also limited by the resolution of the OS timer, typically around a millisecond.
     Rate {
         /// The minimum interval between emitted updates.
         update_interval: Duration,
@@ -234,6 +273,26 @@ pub enum LoopMode {
     },
 }
 
+/// The mode in which the **App** advances time between `Update` events.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum TimeMode {
+    /// Time advances according to the real, wall-clock time elapsed between updates.
+    ///
+    /// This is the default and is best suited to interactive sketches.
+    #[default]
+    WallClock,
+
+    /// Time advances by exactly `1 / fps` seconds on each update, regardless of how much real
+    /// time has elapsed.
+    ///
+    /// This is useful when recording frame sequences, as the resulting animation is smooth and
+    /// reproducible regardless of how long each frame takes to render.
+    FixedStep {
+        /// The number of updates that make up a single second of app time.
+        fps: f64,
+    },
+}
+
 impl<M> Builder<M, Event>
 where
     M: 'static,
@@ -271,6 +330,7 @@ where
             max_capture_frame_jobs: None,
             capture_frame_timeout: None,
             backends: Self::DEFAULT_BACKENDS,
+            headless_size: None,
         }
     }
 
@@ -297,6 +357,7 @@ where
             max_capture_frame_jobs,
             capture_frame_timeout,
             backends,
+            headless_size,
             ..
         } = self;
         Builder {
@@ -310,7 +371,8 @@ where
             default_window_size,
             max_capture_frame_jobs,
             capture_frame_timeout,
-            backends
+            backends,
+            headless_size,
         }
     }
 }
@@ -400,6 +462,14 @@ where
         self
     }
 
+    /// Specify the initial time mode for this app.
+    ///
+    /// By default, this is `TimeMode::WallClock`.
+    pub fn time_mode(mut self, mode: TimeMode) -> Self {
+        self.config.time_mode = mode;
+        self
+    }
+
     /// The maximum number of simultaneous capture frame jobs that can be run per window before we
     /// block and wait for the existing jobs to complete.
     ///
@@ -444,6 +514,49 @@ where
         self
     }
 
+    /// Run the app without any windows or event loop, rendering the `view` to an offscreen
+    /// **Headless** target of the given size in pixels instead.
+    ///
+    /// This allows for running existing sketches in environments where no display is available,
+    /// e.g. CI or a server. The **Frame** passed to `view` behaves the same as a window's, so
+    /// `Draw::to_frame` works unchanged. The most recent frame may be read back via
+    /// `app.headless()`, e.g. within the `exit` function:
+    ///
+    /// ```no_run
+    /// use splatter::prelude::*;
+    ///
+    /// fn main() {
+    ///     splatter::app(model)
+    ///         .view(view)
+    ///         .exit(exit)
+    ///         .headless(640, 480)
+    ///         .loop_mode(LoopMode::loop_once())
+    ///         .run();
+    /// }
+    ///
+    /// fn model(_app: &App) {}
+    ///
+    /// fn view(app: &App, _model: &(), frame: Frame) {
+    ///     let draw = app.draw();
+    ///     draw.background().color(PLUM);
+    ///     draw.ellipse().color(STEELBLUE);
+    ///     draw.to_frame(app, &frame).unwrap();
+    /// }
+    ///
+    /// fn exit(app: &App, _model: ()) {
+    ///     let image = app.headless().unwrap().read_image().unwrap();
+    ///     image.save("frame.png").unwrap();
+    /// }
+    /// ```
+    ///
+    /// Updates and views are applied back to back as fast as possible, until either `App::quit`
+    /// is called or the number of updates given by `LoopMode::NTimes` has been reached. The other
+    /// loop modes and pausing have no effect, and no windows may be created.
+    pub fn headless(mut self, width: u32, height: u32) -> Self {
+        self.headless_size = Some([width, height]);
+        self
+    }
+
     /// Build and run an `App` with the specified parameters.
     ///
     /// This function will not return until the application has exited.
@@ -473,6 +586,10 @@ where
     }
 
     pub async fn run_async(self) {
+        if let Some(size) = self.headless_size {
+            return self.run_headless_async(size).await;
+        }
+
         // Start the winit window event loop.
         let event_loop = winit::event_loop::EventLoop::new().expect("couldn't create event");
 
@@ -480,7 +597,7 @@ where
         let event_loop_proxy = event_loop.create_proxy();
         let wakeup_queued = Arc::new(AtomicBool::new(false));
         let event_loop_proxy = Proxy {
-            event_loop_proxy,
+            event_loop_proxy: Some(event_loop_proxy),
             wakeup_queued,
         };
 
@@ -500,6 +617,7 @@ where
             max_capture_frame_jobs,
             capture_frame_timeout,
             self.backends,
+            None,
         );
 
         // Create the default window if necessary
@@ -531,6 +649,47 @@ where
             self.exit,
         );
     }
+
+    // Run the app without a window or event loop. See `Builder::headless`.
+    async fn run_headless_async(self, [width, height]: [u32; 2]) {
+        let headless = crate::headless::Builder::new(width, height)
+            .backends(self.backends)
+            .build_async()
+            .await
+            .expect("could not build headless target");
+        let event_loop_proxy = Proxy {
+            event_loop_proxy: None,
+            wakeup_queued: Arc::new(AtomicBool::new(false)),
+        };
+        let max_capture_frame_jobs = self
+            .max_capture_frame_jobs
+            .unwrap_or(num_cpus::get() as u32);
+        let capture_frame_timeout = self
+            .capture_frame_timeout
+            .unwrap_or(Some(Self::DEFAULT_CAPTURE_FRAME_TIMEOUT));
+        let app = App::new(
+            self.config,
+            event_loop_proxy,
+            None,
+            None,
+            max_capture_frame_jobs,
+            capture_frame_timeout,
+            self.backends,
+            Some(headless),
+        );
+
+        // Call the user's model function.
+        let model = Pin::from((self.model)(&app)).await;
+
+        run_headless_loop(
+            app,
+            model,
+            self.event,
+            self.update,
+            self.default_view,
+            self.exit,
+        );
+    }
 }
 
 impl<E> SketchBuilder<E>
@@ -545,12 +704,28 @@ where
         self
     }
 
+    /// Specify the initial time mode for this sketch.
+    ///
+    /// This method delegates to `Builder::time_mode`.
+    pub fn time_mode(mut self, mode: TimeMode) -> Self {
+        self.builder = self.builder.time_mode(mode);
+        self
+    }
+
     /// The size of the sketch window.
     pub fn size(mut self, width: u32, height: u32) -> Self {
         self.builder = self.builder.size(width, height);
         self
     }
 
+    /// Run the sketch without a window, rendering to an offscreen target of the given size.
+    ///
+    /// This method delegates to `Builder::headless`.
+    pub fn headless(mut self, width: u32, height: u32) -> Self {
+        self.builder = self.builder.headless(width, height);
+        self
+    }
+
     /// Build and run a `Sketch` with the specified parameters.
     ///
     /// This calls `App::run` internally. See that method for details!
@@ -598,6 +773,8 @@ impl LoopMode {
     }
 
     /// Specify the **Rate** mode with the given frames-per-second.
+    ///
+    /// **Panics** if `fps` is not greater than `0.0`.
     pub fn rate_fps(fps: f64) -> Self {
         let update_interval = update_interval(fps);
         LoopMode::Rate { update_interval }
@@ -627,15 +804,34 @@ impl Default for LoopMode {
     }
 }
 
+impl TimeMode {
+    /// Specify the **FixedStep** mode with the given number of updates per second.
+    ///
+    /// **Panics** if `fps` is not greater than `0.0`.
+    pub fn fixed_step(fps: f64) -> Self {
+        assert!(
+            fps > 0.0,
+            "`TimeMode::FixedStep` requires an `fps` greater than 0"
+        );
+        TimeMode::FixedStep { fps }
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         let loop_mode = Default::default();
+        let time_mode = Default::default();
         let exit_on_escape = App::DEFAULT_EXIT_ON_ESCAPE;
         let fullscreen_on_shortcut = App::DEFAULT_FULLSCREEN_ON_SHORTCUT;
         Config {
             loop_mode,
+            time_mode,
             exit_on_escape,
             fullscreen_on_shortcut,
+            is_paused: false,
+            show_fps: false,
+            step_requested: false,
+            quit_requested: false,
         }
     }
 }
@@ -646,6 +842,7 @@ impl App {
     pub const DEFAULT_FULLSCREEN_ON_SHORTCUT: bool = true;
 
     // Create a new `App`.
+    #[allow(clippy::too_many_arguments)]
     fn new(
         config: Config,
         event_loop_proxy: Proxy,
@@ -654,6 +851,7 @@ impl App {
         max_capture_frame_jobs: u32,
         capture_frame_timeout: Option<Duration>,
         backends: wgpu::Backends,
+        headless: Option<crate::headless::Headless>,
     ) -> Self {
         let instance = wgpu::Instance::new(InstanceDescriptor {
             backends,
@@ -668,6 +866,8 @@ impl App {
         let focused_window = RefCell::new(None);
         let mouse = state::Mouse::new();
         let keys = state::Keys::default();
+        #[cfg(feature = "gamepad")]
+        let gamepads = crate::gamepad::Gamepads::new();
         let duration = state::Time::default();
         let time = duration.since_start.secs() as _;
         App {
@@ -681,10 +881,16 @@ impl App {
             instance,
             adapters,
             windows,
+            headless,
             config,
             draw_state,
             mouse,
             keys,
+            #[cfg(feature = "gamepad")]
+            gamepads,
+            #[cfg(not(target_arch = "wasm32"))]
+            clipboard: Default::default(),
+            compute_pipelines: Default::default(),
             duration,
             time,
         }
@@ -747,6 +953,25 @@ impl App {
         find_project_path()
     }
 
+    /// Load the font at the given `.ttf` or `.otf` file path.
+    ///
+    /// The returned **font::Id** may be used to select the font for text, e.g.
+    /// `draw.text("hello").font(font_id)`. The font's glyphs are cached by the draw renderer
+    /// under this same **Id**.
+    pub fn load_font<P>(&self, path: P) -> Result<font::Id, font::Error>
+    where
+        P: AsRef<Path>,
+    {
+        font::from_file(path).map(font::register)
+    }
+
+    /// Load a font from the bytes of a `.ttf` or `.otf` file.
+    ///
+    /// See the `load_font` method for details.
+    pub fn load_font_from_bytes(&self, bytes: Vec<u8>) -> Result<font::Id, font::Error> {
+        font::from_bytes(bytes).map(font::register)
+    }
+
     /// Begin building a new window.
     pub fn new_window(&self) -> window::Builder {
         let builder = window::Builder::new(self);
@@ -760,6 +985,23 @@ impl App {
             .capture_frame_timeout(self.capture_frame_timeout)
     }
 
+    /// Create a new offscreen texture of the given size in pixels and format, to which a **Draw**
+    /// may be rendered via `Draw::to_texture`.
+    ///
+    /// The target is created on the device of the main window and may be sampled by drawings to
+    /// any window sharing that device, e.g. `draw.texture(&target)`.
+    ///
+    /// **Panics** if there is no window to provide the device.
+    pub fn new_texture_target(
+        &self,
+        width: u32,
+        height: u32,
+        format: wgpu::TextureFormat,
+    ) -> draw::TextureTarget {
+        let device_queue_pair = self.main_window().device_queue_pair().clone();
+        draw::TextureTarget::new(device_queue_pair, [width, height], format)
+    }
+
     /// The number of windows currently in the application.
     pub fn window_count(&self) -> usize {
         self.windows.borrow().len()
@@ -793,11 +1035,30 @@ impl App {
 
     /// Return the **Rect** for the currently focused window.
     ///
-    /// The **Rect** coords are described in "points" (pixels divided by the hidpi factor).
+    /// The **Rect** coords are described in "points" (pixels divided by the hidpi factor). When
+    /// running headless, this is the rect of the headless target.
     ///
     /// **Panics** if there are no windows or if no window is in focus.
     pub fn window_rect(&self) -> geom::Rect<f32> {
-        self.main_window().rect()
+        match self.headless {
+            Some(ref headless) => headless.rect(),
+            None => self.main_window().rect(),
+        }
+    }
+
+    /// The scale factor of the currently focused window, i.e. the number of physical pixels per
+    /// point.
+    ///
+    /// Multiply a size in points, e.g. from `window_rect`, by this factor to convert it to pixels,
+    /// or divide a size in pixels, e.g. from `Window::rect_pixels`, to convert it to points.
+    ///
+    /// **Panics** if there are no windows or if no window is in focus. When running headless, this
+    /// is always `1.0`.
+    pub fn scale_factor(&self) -> f32 {
+        match self.headless {
+            Some(_) => 1.0,
+            None => self.main_window().scale_factor(),
+        }
     }
 
     /// A reference to the window currently in focus.
@@ -813,6 +1074,21 @@ impl App {
             .expect("no window for focused id")
     }
 
+    /// The offscreen target to which the view is rendered, if the app is running headless.
+    ///
+    /// See `Builder::headless`.
+    pub fn headless(&self) -> Option<&crate::headless::Headless> {
+        self.headless.as_ref()
+    }
+
+    // The device and queue of the main window, or of the headless target when running headless.
+    pub(crate) fn main_device_queue_pair(&self) -> Arc<wgpu::DeviceQueuePair> {
+        match self.headless {
+            Some(ref headless) => headless.device_queue_pair().clone(),
+            None => self.main_window().device_queue_pair().clone(),
+        }
+    }
+
     /// Return the wgpu `Backends` in use.
     pub fn backends(&self) -> wgpu::Backends {
         self.backends
@@ -890,6 +1166,225 @@ impl App {
         self.config.borrow_mut().loop_mode = mode;
     }
 
+    /// Returns the **App**'s current **TimeMode**.
+    ///
+    /// The default time mode is `TimeMode::WallClock`.
+    pub fn time_mode(&self) -> TimeMode {
+        self.config.borrow().time_mode
+    }
+
+    /// Sets the time mode of the **App**.
+    ///
+    /// With `TimeMode::FixedStep { fps }`, `Update::since_start` and `Update::since_last` (along
+    /// with `app.duration` and `app.time`) advance by exactly `1 / fps` seconds on each update,
+    /// independent of real elapsed time. This is useful for rendering smooth frame sequences.
+    ///
+    /// Note: The new mode takes effect from the next update. Switching modes continues on from
+    /// the current `since_start` rather than jumping.
+    pub fn set_time_mode(&self, mode: TimeMode) {
+        self.config.borrow_mut().time_mode = mode;
+    }
+
+    /// Specify whether or not to draw a performance overlay in the top left corner of each window.
+    ///
+    /// The overlay shows the most recent and average frame time and frame rate. For windows drawn
+    /// via `Draw::to_frame`, it also shows the number of draw calls and primitives of the most
+    /// recent drawing. The overlay is drawn after the window's `view` function so that it is
+    /// always visible, but is not included in captured frames.
+    ///
+    /// This applies to all windows, including those created later, replacing any setting applied
+    /// to individual windows via `Window::set_show_fps`.
+    pub fn set_show_fps(&self, show: bool) {
+        self.config.borrow_mut().show_fps = show;
+        for window in self.windows.borrow().values() {
+            window.show_fps.set(None);
+        }
+    }
+
+    /// Pause the **App**, freezing time at the most recent update.
+    ///
+    /// While paused, no `Update`s are emitted and `app.time`, `app.duration` and `Update` stop
+    /// advancing, so each redraw shows the same static frame. Window and input events are still
+    /// delivered to the `event` function. Use `step` to advance a single update at a time.
+    pub fn pause(&self) {
+        self.config.borrow_mut().is_paused = true;
+    }
+
+    /// Resume the **App** after a call to `pause`.
+    ///
+    /// Time resumes from where it was paused rather than jumping ahead by the duration of the
+    /// pause.
+    pub fn resume(&self) {
+        let mut config = self.config.borrow_mut();
+        config.is_paused = false;
+        config.step_requested = false;
+    }
+
+    /// Whether or not the **App** is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.config.borrow().is_paused
+    }
+
+    /// While paused, advance by exactly one `update` and `view` cycle.
+    ///
+    /// Time advances by a single increment: one `1 / fps` step in `TimeMode::FixedStep`, or the
+    /// interval of the last update before the pause in `TimeMode::WallClock`. Multiple calls
+    /// before the next update result in a single step. Has no effect while not paused.
+    pub fn step(&self) {
+        let mut config = self.config.borrow_mut();
+        if config.is_paused {
+            config.step_requested = true;
+        }
+    }
+
+    /// Open a stream that calls `callback` with each buffer of interleaved samples captured by
+    /// the audio input device described by `config`.
+    ///
+    /// The callback is called on the audio thread, where it must not block or allocate. Move an
+    /// `audio::Feed` into the callback to analyse the input on the main thread. The stream runs
+    /// until the returned **Stream** is dropped, so it should typically be stored in the model.
+    ///
+    /// Requires the `audio` feature.
+    ///
+    /// ```no_run
+    /// use splatter::audio;
+    /// use splatter::prelude::*;
+    ///
+    /// struct Model {
+    ///     _stream: audio::Stream,
+    ///     analyzer: audio::Analyzer,
+    /// }
+    ///
+    /// fn model(app: &App) -> Model {
+    ///     let (mut feed, analyzer) = audio::analyzer(1024);
+    ///     let config = audio::Config::default();
+    ///     let stream = app
+    ///         .audio_input(&config, move |buffer, info| {
+    ///             feed.push(buffer, info.channels);
+    ///         })
+    ///         .unwrap();
+    ///     Model { _stream: stream, analyzer }
+    /// }
+    ///
+    /// fn update(_app: &App, model: &mut Model, _update: Update) {
+    ///     let rms = model.analyzer.update().rms;
+    ///     println!("{}", rms);
+    /// }
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "audio")]
+    pub fn audio_input<F>(
+        &self,
+        config: &crate::audio::Config,
+        callback: F,
+    ) -> Result<crate::audio::Stream, crate::audio::StreamError>
+    where
+        F: 'static + Send + FnMut(&[f32], crate::audio::StreamInfo),
+    {
+        crate::audio::input(config, callback)
+    }
+
+    /// Open a stream that calls `callback` to fill each buffer of interleaved samples played by
+    /// the audio output device described by `config`.
+    ///
+    /// The callback is called on the audio thread, where it must not block or allocate. The
+    /// stream runs until the returned **Stream** is dropped, so it should typically be stored in
+    /// the model.
+    ///
+    /// Requires the `audio` feature.
+    #[cfg(feature = "audio")]
+    pub fn audio_output<F>(
+        &self,
+        config: &crate::audio::Config,
+        callback: F,
+    ) -> Result<crate::audio::Stream, crate::audio::StreamError>
+    where
+        F: 'static + Send + FnMut(&mut [f32], crate::audio::StreamInfo),
+    {
+        crate::audio::output(config, callback)
+    }
+
+    /// The names of the MIDI input ports available on the system.
+    ///
+    /// Requires the `midi` feature.
+    #[cfg(feature = "midi")]
+    pub fn midi_ports(&self) -> Vec<String> {
+        crate::midi::ports()
+    }
+
+    /// Connect to the MIDI input port with the given name, calling `callback` with each message
+    /// received on the port.
+    ///
+    /// The callback is called on the MIDI input thread. Move a `midi::Sender` into the callback
+    /// to handle the messages on the main thread within `update`. The connection remains open
+    /// until the returned **Input** is dropped, so it should typically be stored in the model.
+    ///
+    /// Requires the `midi` feature.
+    #[cfg(feature = "midi")]
+    pub fn midi_input<F>(
+        &self,
+        port: &str,
+        callback: F,
+    ) -> Result<crate::midi::Input, crate::midi::InputError>
+    where
+        F: 'static + Send + FnMut(crate::midi::MidiMessage),
+    {
+        crate::midi::input(port, callback)
+    }
+
+    /// The state of all connected gamepads as of the most recent update.
+    ///
+    /// Gamepads are polled at the start of each update, before the `event` and `update`
+    /// functions are called. Each change is also emitted to the `event` function as an
+    /// `Event::Gamepad`.
+    ///
+    /// Requires the `gamepad` feature.
+    #[cfg(feature = "gamepad")]
+    pub fn gamepads(&self) -> &crate::gamepad::Gamepads {
+        &self.gamepads
+    }
+
+    /// Access to the system clipboard for copying and pasting text and images.
+    ///
+    /// See the `clipboard` module for details. Not available on the web.
+    ///
+    /// **Panics** if the clipboard is already borrowed, e.g. if called while a previously returned
+    /// handle is still alive.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn clipboard(&self) -> RefMut<crate::clipboard::Clipboard> {
+        self.clipboard.borrow_mut()
+    }
+
+    /// The number of seconds since the app started running, as of the most recent update.
+    ///
+    /// This is the same value as the `app.time` field and is backed by the same clock that feeds
+    /// the `Update` passed to the `update` function. It is available anywhere the **App** is,
+    /// including in `view`-only sketches.
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// The number of seconds between the two most recent updates.
+    ///
+    /// This is equivalent to `Update::since_last` in seconds and respects the current
+    /// **TimeMode**.
+    pub fn dt(&self) -> f32 {
+        self.duration.since_prev_update.secs() as f32
+    }
+
+    /// Seed the RNG used by the `random`, `random_f32`, `random_f64`, `random_range` and
+    /// `random_ascii` helper functions.
+    ///
+    /// By default these helpers draw from a non-deterministic thread-local RNG. Once a seed is
+    /// set, they instead draw from a seeded PRNG, producing the same sequence of values every
+    /// time the program is run. Combined with a fixed number of updates, e.g.
+    /// `LoopMode::loop_ntimes`, this allows a sketch to produce identical frames run-to-run.
+    ///
+    /// The seeded RNG is local to the main thread. See `splatter::rand::set_seed` for details.
+    pub fn set_random_seed(&self, seed: u64) {
+        crate::rand::set_seed(seed);
+    }
+
     /// A handle to the **App** that can be shared across threads.
     ///
     /// This can be used to "wake up" the **App**'s inner event loop.
@@ -897,6 +1392,13 @@ impl App {
         self.event_loop_proxy.clone()
     }
 
+    /// Begin running the given WGSL compute shader on the device of the main window.
+    ///
+    /// See the `compute` module for details.
+    pub fn compute<'a>(&'a self, source: &'a str) -> crate::compute::Builder<'a> {
+        crate::compute::Builder::new(self, source)
+    }
+
     /// Produce the **App**'s **Draw** API for drawing geometry and text with colors and textures.
     ///
     /// **Note:** You can also create your own **Draw** instances via `Draw::new()`! This method
@@ -908,10 +1410,35 @@ impl App {
         draw.clone()
     }
 
+    /// The ID of the pickable drawing beneath the given point within the given window, if any.
+    ///
+    /// The `point` is described in the window's coordinate space, e.g. `app.mouse.position()`.
+    /// IDs are read from the pick buffer rendered during the window's most recent `Draw::to_frame`
+    /// call, so only drawings made via `Draw::pickable` are considered.
+    ///
+    /// This blocks the current thread until the GPU has finished reading the ID.
+    pub fn pick_at(&self, window_id: window::Id, point: geom::Point2) -> Option<u64> {
+        let window = self.window(window_id)?;
+        let renderers = self.draw_state.renderers.borrow();
+        let renderer = renderers.get(&window_id)?.borrow();
+        let rect = window.rect();
+        let scale_factor = window.scale_factor();
+        let x = (point.x - rect.left()) * scale_factor;
+        let y = (rect.top() - point.y) * scale_factor;
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+        let pixel = [x as u32, y as u32];
+        renderer.pick_at(window.device(), window.queue(), pixel)
+    }
+
     /// The number of times the focused window's **view** function has been called since the start
     /// of the program.
     pub fn elapsed_frames(&self) -> u64 {
-        self.main_window().frame_count
+        match self.headless {
+            Some(ref headless) => headless.elapsed_frames(),
+            None => self.main_window().frame_count,
+        }
     }
 
     /// The number of frames that can currently be displayed a second
@@ -919,6 +1446,15 @@ impl App {
         self.duration.updates_per_second()
     }
 
+    /// A keyed store for persisting settings between runs of the app.
+    ///
+    /// Values are stored within the platform's configuration directory for the app, named after
+    /// the executable. See the `storage` module for details.
+    pub fn storage(&self) -> Storage {
+        let name = self.exe_name().unwrap_or_else(|_| "splatter".to_string());
+        Storage::new(&name)
+    }
+
     /// The name of the splatter executable that is currently running.
     pub fn exe_name(&self) -> std::io::Result<String> {
         let string = std::env::current_exe()?
@@ -929,9 +1465,29 @@ impl App {
         Ok(string)
     }
 
+    /// Block and wait for the active capture frame jobs of all windows to complete.
+    ///
+    /// This is useful to call before exiting the application, e.g. within the `exit` function, to
+    /// ensure that no captured frames are lost. Returns the first timeout error encountered, if
+    /// any.
+    pub fn await_capture_frame_jobs(
+        &self,
+    ) -> Result<(), wgpu::TextureCapturerAwaitWorkerTimeout<()>> {
+        let windows = self.windows.borrow();
+        let mut result = Ok(());
+        for window in windows.values() {
+            let window_result = window.await_capture_frame_jobs();
+            if result.is_ok() {
+                result = window_result;
+            }
+        }
+        result
+    }
+
     /// Quits the currently running application.
     pub fn quit(&self) {
         self.windows.borrow_mut().clear();
+        self.config.borrow_mut().quit_requested = true;
     }
 }
 
@@ -947,9 +1503,15 @@ impl Proxy {
     /// immediately set the flag to false afterwards. This makes it safe to call the `wakeup`
     /// method as frequently as necessary across methods without causing any underlying OS methods
     /// to be called more than necessary.
+    ///
+    /// This does nothing when the app is running headless.
     pub fn wakeup(&self) -> Result<(), winit::event_loop::EventLoopClosed<()>> {
+        let event_loop_proxy = match self.event_loop_proxy {
+            Some(ref proxy) => proxy,
+            None => return Ok(()),
+        };
         if !self.wakeup_queued.load(atomic::Ordering::SeqCst) {
-            self.event_loop_proxy.send_event(())?;
+            event_loop_proxy.send_event(())?;
             self.wakeup_queued.store(true, atomic::Ordering::SeqCst);
         }
         Ok(())
@@ -959,9 +1521,15 @@ impl Proxy {
 impl draw::Draw {
     /// Render the **Draw**'s inner list of commands to the texture associated with the **Frame**.
     ///
-    /// The **App** stores a unique render.
+    /// The **App** stores a unique render. When running headless, frames are rendered to the
+    /// **App**'s **Headless** target.
     pub fn to_frame(&self, app: &App, frame: &Frame) -> Result<(), draw::renderer::DrawError> {
         let window_id = frame.window_id();
+        if let Some(headless) = app.headless().filter(|h| h.id() == window_id) {
+            let mouse = app.mouse.position();
+            headless.renderer.borrow_mut().set_shader_inputs(app.time, mouse);
+            return self.to_headless_frame(headless, frame);
+        }
         let window = app
             .window(window_id)
             .expect("no window to draw to for `Draw`'s window_id");
@@ -988,8 +1556,8 @@ impl draw::Draw {
 
         let scale_factor = window.tracked_state.scale_factor as _;
         let mut renderer = renderer.borrow_mut();
-        renderer.render_to_frame(window.device(), self, scale_factor, frame);
-        Ok(())
+        renderer.set_shader_inputs(app.time, app.mouse.position());
+        renderer.render_to_frame(window.device(), self, scale_factor, frame)
     }
 }
 
@@ -998,7 +1566,8 @@ impl<'a> wgpu::WithDeviceQueuePair for &'a crate::app::App {
     where
         F: FnOnce(&wgpu::Device, &wgpu::Queue) -> O,
     {
-        self.main_window().with_device_queue_pair(f)
+        let device_queue_pair = self.main_device_queue_pair();
+        f(device_queue_pair.device(), device_queue_pair.queue())
     }
 }
 
@@ -1100,9 +1669,10 @@ fn run_loop<M, E>(
     // Keep track of state related to the loop mode itself.
     let mut loop_state = LoopState {
         updates_since_event: 0,
-        loop_start,
         last_update: loop_start,
+        was_paused: false,
         total_updates: 0,
+        next_update: loop_start,
     };
 
     // Run the event loop.
@@ -1121,21 +1691,38 @@ fn run_loop<M, E>(
                 if let Some(model) = model.as_mut() {
                     let loop_mode = app.loop_mode();
                     let now = Instant::now();
-                    let mut do_update = |loop_state: &mut LoopState| {
-                        apply_update(&mut app, model, event_fn, update_fn, loop_state, now);
+                    let is_paused = app.is_paused();
+                    let mut do_update = |app: &mut App, loop_state: &mut LoopState| {
+                        apply_update(app, model, event_fn, update_fn, loop_state, now);
                     };
                     match loop_mode {
+                        // While paused, only updates requested via `App::step` are applied.
+                        _ if is_paused => {
+                            loop_state.was_paused = true;
+                            let step_requested =
+                                std::mem::take(&mut app.config.borrow_mut().step_requested);
+                            if step_requested {
+                                do_update(&mut app, &mut loop_state);
+                            }
+                        }
                         LoopMode::NTimes { number_of_updates }
                             if loop_state.total_updates >= number_of_updates as u64 => {}
                         // Sometimes winit interrupts ControlFlow::Wait for no good reason, so we
                         // make sure that there were some events in order to do an update when
                         // LoopMode::Wait is used.
                         LoopMode::Wait if loop_state.updates_since_event > 0 => {}
+                        // Events may wake the loop before the next update is due.
+                        LoopMode::Rate { .. } if now < loop_state.next_update => {}
+                        LoopMode::Rate { update_interval } => {
+                            do_update(&mut app, &mut loop_state);
+                            loop_state.next_update =
+                                next_rate_update(loop_state.next_update, update_interval, now);
+                        }
                         // TODO: Consider allowing for a custom number of updates like so:
                         // LoopMode::Wait { updates_before_waiting } =>
                         //     if loop_state.updates_since_event > updates_before_waiting as u64 => {}
                         _ => {
-                            do_update(&mut loop_state);
+                            do_update(&mut app, &mut loop_state);
                         },
                     }
                 }
@@ -1320,18 +1907,59 @@ fn run_loop<M, E>(
                                 },
                             }
 
-                            // Queue has been submitted by now, time to present.
-                            surface_tex.present();
-
                             // Release immutable lock
                             drop(windows);
 
-                            // Increment the window's frame count.
                             let mut windows = app.windows.borrow_mut();
                             let window = windows
                                 .get_mut(&window_id)
                                 .expect("no window for redraw request ID");
 
+                            // Draw the FPS overlay over the user's view, if enabled.
+                            let show_fps = window
+                                .show_fps
+                                .get()
+                                .unwrap_or(app.config.borrow().show_fps);
+                            if show_fps {
+                                let stats = app.draw_state.renderers.borrow().get(&window_id).map(
+                                    |renderer| {
+                                        let renderer = renderer.borrow();
+                                        RenderStats {
+                                            draw_calls: renderer.draw_call_count(),
+                                            primitives: renderer.primitive_count(),
+                                        }
+                                    },
+                                );
+                                let device_queue_pair = window.device_queue_pair.clone();
+                                let device = device_queue_pair.device();
+                                let size: [u32; 2] = window.tracked_state.physical_size.into();
+                                let scale_factor = window.tracked_state.scale_factor as f32;
+                                let format = window.surface_conf.format;
+                                let overlay =
+                                    window.fps_overlay.get_or_insert_with(Default::default);
+                                overlay.record_frame(Instant::now());
+                                let ce_desc = wgpu::CommandEncoderDescriptor {
+                                    label: Some("splatter_fps_overlay"),
+                                };
+                                let mut encoder = device.create_command_encoder(&ce_desc);
+                                overlay.encode(
+                                    device,
+                                    &mut encoder,
+                                    surface_texture,
+                                    format,
+                                    size,
+                                    scale_factor,
+                                    stats,
+                                );
+                                device_queue_pair.queue().submit(Some(encoder.finish()));
+                            } else {
+                                window.fps_overlay = None;
+                            }
+
+                            // Queue has been submitted by now, time to present.
+                            surface_tex.present();
+
+                            // Increment the window's frame count.
                             // Assume invalidated window was cleared above before `view()`
                             window.is_invalidated = false;
                             window.frame_count += 1;
@@ -1356,12 +1984,14 @@ fn run_loop<M, E>(
             .unwrap()
             .as_ref()
             .set_control_flow(match loop_mode {
+                _ if app.is_paused() => ControlFlow::Wait,
                 LoopMode::Wait => ControlFlow::Wait,
                 LoopMode::NTimes { number_of_updates }
                     if loop_state.total_updates >= number_of_updates as u64 =>
                 {
                     ControlFlow::Wait
                 }
+                LoopMode::Rate { .. } => ControlFlow::WaitUntil(loop_state.next_update),
                 _ => ControlFlow::Poll,
             });
 
@@ -1391,6 +2021,60 @@ fn run_loop<M, E>(
     // }
 }
 
+// The application loop used when running headless.
+//
+// Without windows there are no events to wait on or surfaces to synchronise with, so updates and
+// views are simply applied back to back until the app quits or the `NTimes` limit is reached.
+fn run_headless_loop<M, E>(
+    mut app: App,
+    mut model: M,
+    event_fn: Option<EventFn<M, E>>,
+    update_fn: Option<UpdateFn<M>>,
+    default_view: Option<View<M>>,
+    exit_fn: Option<ExitFn<M>>,
+) where
+    M: 'static,
+    E: LoopEvent,
+{
+    let loop_start = Instant::now();
+    let mut loop_state = LoopState {
+        updates_since_event: 0,
+        last_update: loop_start,
+        was_paused: false,
+        total_updates: 0,
+        next_update: loop_start,
+    };
+    loop {
+        if app.config.borrow().quit_requested {
+            break;
+        }
+        if let LoopMode::NTimes { number_of_updates } = app.loop_mode() {
+            if loop_state.total_updates >= number_of_updates as u64 {
+                break;
+            }
+        }
+        let now = Instant::now();
+        apply_update(
+            &mut app,
+            &mut model,
+            event_fn,
+            update_fn,
+            &mut loop_state,
+            now,
+        );
+        let headless = app.headless().expect("headless loop requires a headless target");
+        let frame = headless.frame();
+        match default_view {
+            Some(View::Sketch(view)) => view(&app, frame),
+            Some(View::WithModel(view)) => view(&app, &model, frame),
+            None => frame.submit(),
+        }
+    }
+    if let Some(exit_fn) = exit_fn {
+        exit_fn(&app, model);
+    }
+}
+
 // Apply an update to the model via the user's function and update the app and loop state
 // accordingly.
 fn apply_update<M, E>(
@@ -1404,9 +2088,18 @@ fn apply_update<M, E>(
     M: 'static,
     E: LoopEvent,
 {
-    // Update the app's durations.
-    let since_last = now.duration_since(loop_state.last_update);
-    let since_start = now.duration_since(loop_state.loop_start);
+    // Update the app's durations. Time spent paused is skipped, with steps and the first update
+    // after resuming advancing by the same interval as the update before the pause.
+    let since_last = match app.time_mode() {
+        TimeMode::WallClock if loop_state.was_paused => match app.duration.since_prev_update {
+            Duration::ZERO => update_interval(LoopMode::DEFAULT_RATE_FPS),
+            since_prev_update => since_prev_update,
+        },
+        TimeMode::WallClock => now.duration_since(loop_state.last_update),
+        TimeMode::FixedStep { fps } => update_interval(fps),
+    };
+    let since_start = app.duration.since_start + since_last;
+    loop_state.was_paused = app.is_paused();
     app.duration.since_prev_update = since_last;
     app.duration.since_start = since_start;
     app.time = since_start.secs() as _;
@@ -1414,6 +2107,15 @@ fn apply_update<M, E>(
         since_start,
         since_last,
     };
+    // Gamepad input is applied before the update so that its state is current within `update`.
+    #[cfg(feature = "gamepad")]
+    for gamepad_event in app.gamepads.poll() {
+        if let Some(event_fn) = event_fn {
+            if let Some(event) = E::from_gamepad_event(gamepad_event) {
+                event_fn(app, model, event);
+            }
+        }
+    }
     // User event function.
     if let Some(event_fn) = event_fn {
         let event = E::from(update);
@@ -1423,6 +2125,9 @@ fn apply_update<M, E>(
     if let Some(update_fn) = update_fn {
         update_fn(app, model, update);
     }
+    // Presses and releases are only reported for the update following the event.
+    app.keys.next_frame();
+    app.mouse.next_frame();
     loop_state.last_update = now;
     loop_state.total_updates += 1;
     loop_state.updates_since_event += 1;
@@ -1433,6 +2138,19 @@ fn apply_update<M, E>(
     }
 }
 
+// The moment at which the update following one due at `due` should occur in `LoopMode::Rate`.
+//
+// Updates stay on a fixed timeline to account for time spent updating and rendering, unless the
+// loop has fallen an entire interval behind, in which case the timeline restarts from `now`.
+fn next_rate_update(due: Instant, interval: Duration, now: Instant) -> Instant {
+    let next = due + interval;
+    if next <= now {
+        now + interval
+    } else {
+        next
+    }
+}
+
 // Whether or not the given event should toggle fullscreen.
 fn should_toggle_fullscreen(
     winit_event: &winit::event::WindowEvent,
@@ -1527,11 +2245,7 @@ where
                     if app.fullscreen_on_shortcut()
                         && should_toggle_fullscreen(event, &app.keys.mods)
                     {
-                        if win.is_fullscreen() {
-                            win.set_fullscreen(false);
-                        } else {
-                            win.set_fullscreen(true);
-                        }
+                        win.toggle_fullscreen();
                     }
 
                     let sf = win.tracked_state.scale_factor;
@@ -1576,12 +2290,25 @@ where
                     app.mouse.window = Some(window_id);
                 }
 
+                winit::event::WindowEvent::MouseWheel { delta, .. } => {
+                    let delta = match *delta {
+                        event::MouseScrollDelta::LineDelta(x, y) => {
+                            geom::vec2(x, y) * state::mouse::SCROLL_LINE_HEIGHT
+                        }
+                        event::MouseScrollDelta::PixelDelta(position) => {
+                            let (x, y) = position.to_logical::<f32>(scale_factor).into();
+                            geom::vec2(x, y)
+                        }
+                    };
+                    app.mouse.scroll(delta);
+                }
+
                 winit::event::WindowEvent::KeyboardInput { event, .. } => match event.state {
                     event::ElementState::Pressed => {
-                        app.keys.down.keys.insert(event.logical_key.clone());
+                        app.keys.press(event.logical_key.clone());
                     }
                     event::ElementState::Released => {
-                        app.keys.down.keys.remove(&event.logical_key);
+                        app.keys.release(&event.logical_key);
                     }
                 },
 
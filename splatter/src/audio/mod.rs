@@ -0,0 +1,234 @@
+//! Items for sketches that react to sound.
+//!
+//! Audio arrives on a real-time audio thread where blocking on a lock or allocating memory can
+//! cause audible glitches. The **Feed** and **Analyzer** pair returned by `analyzer` allow the
+//! audio thread to hand interleaved samples to the main thread via a lock-free ring, where the
+//! **Analyzer** may be updated once per frame to produce an **Analysis** of the most recent sound.
+//!
+//! ```
+//! let (mut feed, mut analyzer) = splatter::audio::analyzer(1024);
+//!
+//! // Within the audio callback, with two interleaved channels.
+//! let buffer = [0.25, 0.25, -0.5, -0.5];
+//! feed.push(&buffer, 2);
+//!
+//! // Within `update` or `view` on the main thread.
+//! let analysis = analyzer.update();
+//! assert!(analysis.rms > 0.0);
+//! ```
+//!
+//! With the `audio` feature enabled, `App::audio_input` and `App::audio_output` open streams on
+//! the system's audio devices via [cpal](https://docs.rs/cpal), whose callbacks may push to a
+//! **Feed** directly.
+
+pub mod ring;
+#[cfg(feature = "audio")]
+mod stream;
+
+#[cfg(feature = "audio")]
+pub use self::stream::{input, output, Config, Stream, StreamError, StreamInfo};
+
+use std::f32::consts::PI;
+
+/// Feeds samples from the audio thread to an **Analyzer**.
+///
+/// Pushing never blocks or allocates. If the **Analyzer** falls behind, the newest samples are
+/// dropped until there is room.
+#[derive(Debug)]
+pub struct Feed {
+    producer: ring::Producer,
+}
+
+/// Produces an **Analysis** of the samples most recently pushed to its **Feed**.
+#[derive(Debug)]
+pub struct Analyzer {
+    consumer: ring::Consumer,
+    // The most recent `fft_size` mono samples, where `window_start` is the oldest.
+    window: Vec<f32>,
+    window_start: usize,
+    // A buffer for draining the ring.
+    drained: Vec<f32>,
+    // Re-usable buffers for the FFT.
+    fft_re: Vec<f32>,
+    fft_im: Vec<f32>,
+    analysis: Analysis,
+}
+
+/// An analysis of the most recent `fft_size` mono samples.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Analysis {
+    /// The root mean square amplitude.
+    pub rms: f32,
+    /// The greatest absolute amplitude.
+    pub peak: f32,
+    /// The magnitude of each frequency bin from 0 Hz up to the Nyquist frequency.
+    ///
+    /// There are `fft_size / 2` bins, where bin `i` is centred on `i * sample_rate / fft_size`
+    /// Hz. Magnitudes are normalised so that a full-scale sine wave produces a magnitude of `1.0`.
+    pub bins: Vec<f32>,
+}
+
+/// Create a connected **Feed** and **Analyzer** that analyse the most recent `fft_size` samples.
+///
+/// The **Feed** should be moved to the audio thread while the **Analyzer** remains on the main
+/// thread, e.g. within the model.
+///
+/// **Panics** if `fft_size` is not a power of two.
+pub fn analyzer(fft_size: usize) -> (Feed, Analyzer) {
+    assert!(
+        fft_size.is_power_of_two(),
+        "the `fft_size` must be a power of two"
+    );
+    // Leave room for several frames worth of audio between updates.
+    let (producer, consumer) = ring::ring(fft_size * 8);
+    let feed = Feed { producer };
+    let analyzer = Analyzer {
+        consumer,
+        window: vec![0.0; fft_size],
+        window_start: 0,
+        drained: vec![0.0; fft_size],
+        fft_re: vec![0.0; fft_size],
+        fft_im: vec![0.0; fft_size],
+        analysis: Analysis {
+            bins: vec![0.0; fft_size / 2],
+            ..Default::default()
+        },
+    };
+    (feed, analyzer)
+}
+
+impl Feed {
+    /// Push a buffer of interleaved samples with the given number of channels.
+    ///
+    /// Channels are mixed down to mono. Returns the number of frames pushed.
+    pub fn push(&mut self, buffer: &[f32], channels: usize) -> usize {
+        let channels = channels.max(1);
+        let mut pushed = 0;
+        for frame in buffer.chunks_exact(channels) {
+            let mono = frame.iter().sum::<f32>() / channels as f32;
+            if !self.producer.push(mono) {
+                break;
+            }
+            pushed += 1;
+        }
+        pushed
+    }
+}
+
+impl Analyzer {
+    /// The number of samples analysed.
+    pub fn fft_size(&self) -> usize {
+        self.window.len()
+    }
+
+    /// The most recent analysis, as of the last call to `update`.
+    pub fn analysis(&self) -> &Analysis {
+        &self.analysis
+    }
+
+    /// Drain all samples pushed since the last update and analyse the most recent `fft_size`.
+    ///
+    /// This never blocks on the audio thread and performs no allocation.
+    pub fn update(&mut self) -> &Analysis {
+        loop {
+            let n = self.consumer.pop_slice(&mut self.drained);
+            if n == 0 {
+                break;
+            }
+            for &sample in &self.drained[..n] {
+                self.window[self.window_start] = sample;
+                self.window_start = (self.window_start + 1) % self.window.len();
+            }
+        }
+        self.analyse();
+        &self.analysis
+    }
+
+    fn analyse(&mut self) {
+        let n = self.window.len();
+        let samples = self.window[self.window_start..]
+            .iter()
+            .chain(&self.window[..self.window_start]);
+
+        // Apply a Hann window to reduce spectral leakage.
+        let mut sum_squares = 0.0;
+        let mut peak: f32 = 0.0;
+        for (i, &sample) in samples.enumerate() {
+            sum_squares += sample * sample;
+            peak = peak.max(sample.abs());
+            let hann = 0.5 - 0.5 * (2.0 * PI * i as f32 / n as f32).cos();
+            self.fft_re[i] = sample * hann;
+            self.fft_im[i] = 0.0;
+        }
+        self.analysis.rms = (sum_squares / n as f32).sqrt();
+        self.analysis.peak = peak;
+
+        fft(&mut self.fft_re, &mut self.fft_im);
+        // The Hann window halves the amplitude and only half of the energy is in the positive
+        // frequencies, so scale by `4 / n` to normalise.
+        let scale = 4.0 / n as f32;
+        for (i, bin) in self.analysis.bins.iter_mut().enumerate() {
+            let (re, im) = (self.fft_re[i], self.fft_im[i]);
+            *bin = (re * re + im * im).sqrt() * scale;
+        }
+    }
+}
+
+// An in-place, iterative radix-2 Cooley-Tukey FFT. The length must be a power of two.
+fn fft(re: &mut [f32], im: &mut [f32]) {
+    let n = re.len();
+    if n < 2 {
+        return;
+    }
+
+    // Bit-reversal permutation.
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    // Butterflies.
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let (b_re, b_im) = (re[b] * cos - im[b] * sin, re[b] * sin + im[b] * cos);
+                re[b] = re[a] - b_re;
+                im[b] = im[a] - b_im;
+                re[a] += b_re;
+                im[a] += b_im;
+            }
+        }
+        len *= 2;
+    }
+}
+
+#[test]
+fn test_analyzer_sine() {
+    let (fft_size, sample_rate) = (1024, 44_100.0);
+    let hz = 48.0 * sample_rate / fft_size as f32;
+    let (mut feed, mut analyzer) = analyzer(fft_size);
+    // A stereo sine wave centred on bin 48.
+    let buffer: Vec<f32> = (0..fft_size * 2)
+        .flat_map(|i| {
+            let s = (2.0 * PI * hz * i as f32 / sample_rate).sin();
+            [s, s]
+        })
+        .collect();
+    assert_eq!(feed.push(&buffer, 2), fft_size * 2);
+    let analysis = analyzer.update();
+    assert!((analysis.rms - 0.5f32.sqrt()).abs() < 0.01);
+    assert!((analysis.peak - 1.0).abs() < 0.01);
+    let loudest = (0..analysis.bins.len())
+        .max_by(|&a, &b| analysis.bins[a].total_cmp(&analysis.bins[b]))
+        .unwrap();
+    assert_eq!(loudest, 48);
+    assert!((analysis.bins[48] - 1.0).abs() < 0.01);
+}
@@ -0,0 +1,132 @@
+//! A lock-free, single-producer, single-consumer ring buffer of samples.
+//!
+//! Neither end ever blocks or allocates, making the ring suitable for handing samples from a
+//! real-time audio thread to the main thread without risking glitches.
+
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// The writing end of a ring, typically owned by the audio thread.
+#[derive(Debug)]
+pub struct Producer {
+    shared: Arc<Shared>,
+}
+
+/// The reading end of a ring, typically owned by the main thread.
+#[derive(Debug)]
+pub struct Consumer {
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    // Samples are stored as their bit representation so that they may be shared without locks.
+    samples: Box<[AtomicU32]>,
+    // The total number of samples written. Only modified by the producer.
+    written: AtomicUsize,
+    // The total number of samples read. Only modified by the consumer.
+    read: AtomicUsize,
+}
+
+/// Create a ring with room for `capacity` samples, returning its producer and consumer.
+///
+/// **Panics** if `capacity` is `0`.
+pub fn ring(capacity: usize) -> (Producer, Consumer) {
+    assert!(capacity > 0, "a ring requires a non-zero capacity");
+    let samples = (0..capacity).map(|_| AtomicU32::new(0)).collect();
+    let shared = Arc::new(Shared {
+        samples,
+        written: AtomicUsize::new(0),
+        read: AtomicUsize::new(0),
+    });
+    let producer = Producer {
+        shared: shared.clone(),
+    };
+    let consumer = Consumer { shared };
+    (producer, consumer)
+}
+
+impl Shared {
+    fn capacity(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+impl Producer {
+    /// Push as many of the given samples as there is room for, returning the number pushed.
+    ///
+    /// Samples that do not fit are dropped rather than waiting for the consumer.
+    pub fn push_slice(&mut self, samples: &[f32]) -> usize {
+        let shared = &*self.shared;
+        let written = shared.written.load(Ordering::Relaxed);
+        let read = shared.read.load(Ordering::Acquire);
+        let free = shared.capacity() - written.wrapping_sub(read);
+        let n = free.min(samples.len());
+        for (i, sample) in samples[..n].iter().enumerate() {
+            let ix = written.wrapping_add(i) % shared.capacity();
+            shared.samples[ix].store(sample.to_bits(), Ordering::Relaxed);
+        }
+        shared
+            .written
+            .store(written.wrapping_add(n), Ordering::Release);
+        n
+    }
+
+    /// Push a single sample, returning `false` if the ring is full.
+    pub fn push(&mut self, sample: f32) -> bool {
+        self.push_slice(&[sample]) == 1
+    }
+}
+
+impl Consumer {
+    /// The number of samples that are ready to be read.
+    pub fn len(&self) -> usize {
+        let shared = &*self.shared;
+        let read = shared.read.load(Ordering::Relaxed);
+        shared.written.load(Ordering::Acquire).wrapping_sub(read)
+    }
+
+    /// Whether or not there are no samples ready to be read.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pop samples into the given buffer, returning the number of samples popped.
+    pub fn pop_slice(&mut self, buffer: &mut [f32]) -> usize {
+        let shared = &*self.shared;
+        let read = shared.read.load(Ordering::Relaxed);
+        let written = shared.written.load(Ordering::Acquire);
+        let n = written.wrapping_sub(read).min(buffer.len());
+        for (i, sample) in buffer[..n].iter_mut().enumerate() {
+            let ix = read.wrapping_add(i) % shared.capacity();
+            *sample = f32::from_bits(shared.samples[ix].load(Ordering::Relaxed));
+        }
+        shared.read.store(read.wrapping_add(n), Ordering::Release);
+        n
+    }
+}
+
+#[test]
+fn test_ring_across_threads() {
+    let (mut producer, mut consumer) = ring(64);
+    let count = 10_000;
+    let handle = std::thread::spawn(move || {
+        let mut next = 0;
+        while next < count {
+            if producer.push(next as f32) {
+                next += 1;
+            }
+        }
+    });
+    let mut expected = 0;
+    let mut buffer = [0.0; 16];
+    while expected < count {
+        let n = consumer.pop_slice(&mut buffer);
+        for &sample in &buffer[..n] {
+            assert_eq!(sample, expected as f32);
+            expected += 1;
+        }
+    }
+    handle.join().unwrap();
+    assert!(consumer.is_empty());
+}
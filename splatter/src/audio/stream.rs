@@ -0,0 +1,292 @@
+//! Audio input and output streams built on [cpal](https://docs.rs/cpal).
+//!
+//! This module requires the `audio` feature.
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{FromSample, SampleFormat, SizedSample};
+use std::fmt;
+
+/// Describes the device and format with which to open an audio stream.
+///
+/// Any field left as `None` uses the default of the device.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    /// The name of the device to open, or `None` for the host's default device.
+    pub device: Option<String>,
+    /// The number of frames per second.
+    pub sample_rate: Option<u32>,
+    /// The number of interleaved channels within each buffer.
+    pub channels: Option<u16>,
+}
+
+/// The format of the buffers delivered to a stream's callback.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StreamInfo {
+    /// The number of frames per second.
+    pub sample_rate: u32,
+    /// The number of interleaved channels within each buffer.
+    pub channels: usize,
+}
+
+/// A running audio stream.
+///
+/// The stream's callback is called on the audio thread until the **Stream** is dropped.
+pub struct Stream {
+    stream: cpal::Stream,
+    info: StreamInfo,
+}
+
+/// Errors that might occur while opening an audio stream.
+#[derive(Debug)]
+pub enum StreamError {
+    /// The requested device, or a default device, could not be found.
+    NoDevice,
+    /// The host's devices could not be enumerated.
+    Devices(cpal::DevicesError),
+    /// The default format of the device could not be determined.
+    DefaultConfig(cpal::DefaultStreamConfigError),
+    /// The device's default sample format cannot be converted to and from `f32`.
+    UnsupportedSampleFormat(SampleFormat),
+    /// The stream could not be built with the requested format.
+    Build(cpal::BuildStreamError),
+    /// The stream was built, but could not be started.
+    Play(cpal::PlayStreamError),
+}
+
+/// Open a stream that calls `callback` with each buffer of interleaved samples captured by the
+/// input device described by `config`.
+///
+/// The callback is called on the audio thread, where it must not block or allocate. A **Feed**
+/// may be moved into the callback to hand samples to an **Analyzer** on the main thread.
+///
+/// Samples are captured in the device's default sample format and converted to `f32`.
+///
+/// See `App::audio_input`.
+pub fn input<F>(config: &Config, mut callback: F) -> Result<Stream, StreamError>
+where
+    F: 'static + Send + FnMut(&[f32], StreamInfo),
+{
+    let host = cpal::default_host();
+    let device = match config.device {
+        None => host.default_input_device(),
+        Some(ref name) => find_device(host.input_devices()?, name),
+    };
+    let device = device.ok_or(StreamError::NoDevice)?;
+    let default = device.default_input_config()?;
+    let sample_format = default.sample_format();
+    let stream_config = stream_config(config, default);
+    let info = stream_info(&stream_config);
+    let callback = move |buffer: &[f32]| callback(buffer, info);
+    let stream = match sample_format {
+        SampleFormat::I8 => build_input_stream::<i8, _>(&device, &stream_config, callback),
+        SampleFormat::I16 => build_input_stream::<i16, _>(&device, &stream_config, callback),
+        SampleFormat::I32 => build_input_stream::<i32, _>(&device, &stream_config, callback),
+        SampleFormat::I64 => build_input_stream::<i64, _>(&device, &stream_config, callback),
+        SampleFormat::U8 => build_input_stream::<u8, _>(&device, &stream_config, callback),
+        SampleFormat::U16 => build_input_stream::<u16, _>(&device, &stream_config, callback),
+        SampleFormat::U32 => build_input_stream::<u32, _>(&device, &stream_config, callback),
+        SampleFormat::U64 => build_input_stream::<u64, _>(&device, &stream_config, callback),
+        SampleFormat::F32 => build_input_stream::<f32, _>(&device, &stream_config, callback),
+        SampleFormat::F64 => build_input_stream::<f64, _>(&device, &stream_config, callback),
+        format => return Err(StreamError::UnsupportedSampleFormat(format)),
+    }?;
+    stream.play()?;
+    Ok(Stream { stream, info })
+}
+
+/// Open a stream that calls `callback` to fill each buffer of interleaved samples played by the
+/// output device described by `config`.
+///
+/// The callback is called on the audio thread, where it must not block or allocate. A **Feed**
+/// may be moved into the callback to analyse the output on the main thread.
+///
+/// Samples are converted from `f32` to the device's default sample format.
+///
+/// See `App::audio_output`.
+pub fn output<F>(config: &Config, mut callback: F) -> Result<Stream, StreamError>
+where
+    F: 'static + Send + FnMut(&mut [f32], StreamInfo),
+{
+    let host = cpal::default_host();
+    let device = match config.device {
+        None => host.default_output_device(),
+        Some(ref name) => find_device(host.output_devices()?, name),
+    };
+    let device = device.ok_or(StreamError::NoDevice)?;
+    let default = device.default_output_config()?;
+    let sample_format = default.sample_format();
+    let stream_config = stream_config(config, default);
+    let info = stream_info(&stream_config);
+    let callback = move |buffer: &mut [f32]| callback(buffer, info);
+    let stream = match sample_format {
+        SampleFormat::I8 => build_output_stream::<i8, _>(&device, &stream_config, callback),
+        SampleFormat::I16 => build_output_stream::<i16, _>(&device, &stream_config, callback),
+        SampleFormat::I32 => build_output_stream::<i32, _>(&device, &stream_config, callback),
+        SampleFormat::I64 => build_output_stream::<i64, _>(&device, &stream_config, callback),
+        SampleFormat::U8 => build_output_stream::<u8, _>(&device, &stream_config, callback),
+        SampleFormat::U16 => build_output_stream::<u16, _>(&device, &stream_config, callback),
+        SampleFormat::U32 => build_output_stream::<u32, _>(&device, &stream_config, callback),
+        SampleFormat::U64 => build_output_stream::<u64, _>(&device, &stream_config, callback),
+        SampleFormat::F32 => build_output_stream::<f32, _>(&device, &stream_config, callback),
+        SampleFormat::F64 => build_output_stream::<f64, _>(&device, &stream_config, callback),
+        format => return Err(StreamError::UnsupportedSampleFormat(format)),
+    }?;
+    stream.play()?;
+    Ok(Stream { stream, info })
+}
+
+impl Stream {
+    /// The format of the buffers delivered to the stream's callback.
+    pub fn info(&self) -> StreamInfo {
+        self.info
+    }
+
+    /// Resume calling the stream's callback after a call to `pause`.
+    ///
+    /// Streams begin playing as soon as they are opened.
+    pub fn play(&self) -> Result<(), cpal::PlayStreamError> {
+        self.stream.play()
+    }
+
+    /// Stop calling the stream's callback until `play` is called.
+    pub fn pause(&self) -> Result<(), cpal::PauseStreamError> {
+        self.stream.pause()
+    }
+}
+
+// Build an input stream of samples of type `T`, converting each buffer to `f32` for the callback.
+//
+// The converted samples are written to a buffer that is only reallocated when the device delivers
+// a larger buffer than any before it.
+fn build_input_stream<T, F>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    mut callback: F,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+    F: 'static + Send + FnMut(&[f32]),
+{
+    let mut samples: Vec<f32> = vec![];
+    device.build_input_stream(
+        config,
+        move |buffer: &[T], _: &cpal::InputCallbackInfo| {
+            samples.clear();
+            samples.extend(buffer.iter().map(|&sample| f32::from_sample_(sample)));
+            callback(&samples);
+        },
+        report_error,
+        None,
+    )
+}
+
+// Build an output stream of samples of type `T`, converting each buffer filled by the callback
+// from `f32`.
+//
+// The callback fills a buffer that is only reallocated when the device requests a larger buffer
+// than any before it.
+fn build_output_stream<T, F>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    mut callback: F,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: SizedSample + FromSample<f32>,
+    F: 'static + Send + FnMut(&mut [f32]),
+{
+    let mut samples: Vec<f32> = vec![];
+    device.build_output_stream(
+        config,
+        move |buffer: &mut [T], _: &cpal::OutputCallbackInfo| {
+            samples.clear();
+            samples.resize(buffer.len(), 0.0);
+            callback(&mut samples);
+            for (out, &sample) in buffer.iter_mut().zip(&samples) {
+                *out = T::from_sample_(sample);
+            }
+        },
+        report_error,
+        None,
+    )
+}
+
+// The first of the given devices with the given name.
+fn find_device<I>(mut devices: I, name: &str) -> Option<cpal::Device>
+where
+    I: Iterator<Item = cpal::Device>,
+{
+    devices.find(|device| device.name().map(|n| n == name).unwrap_or(false))
+}
+
+// Apply the user's config to the default config of the device.
+fn stream_config(config: &Config, default: cpal::SupportedStreamConfig) -> cpal::StreamConfig {
+    let mut stream_config = default.config();
+    if let Some(sample_rate) = config.sample_rate {
+        stream_config.sample_rate = cpal::SampleRate(sample_rate);
+    }
+    if let Some(channels) = config.channels {
+        stream_config.channels = channels;
+    }
+    stream_config
+}
+
+fn stream_info(config: &cpal::StreamConfig) -> StreamInfo {
+    StreamInfo {
+        sample_rate: config.sample_rate.0,
+        channels: config.channels as usize,
+    }
+}
+
+// Errors that occur once the stream is running are reported rather than delivered to the user.
+fn report_error(err: cpal::StreamError) {
+    // TODO: Log errors, don't print to stderr.
+    eprintln!("an error occurred on the audio stream: {}", err);
+}
+
+impl fmt::Debug for Stream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Stream").field("info", &self.info).finish()
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            StreamError::NoDevice => write!(f, "no audio device found"),
+            StreamError::Devices(ref err) => err.fmt(f),
+            StreamError::DefaultConfig(ref err) => err.fmt(f),
+            StreamError::UnsupportedSampleFormat(format) => {
+                write!(f, "unsupported sample format: {}", format)
+            }
+            StreamError::Build(ref err) => err.fmt(f),
+            StreamError::Play(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<cpal::DevicesError> for StreamError {
+    fn from(err: cpal::DevicesError) -> Self {
+        StreamError::Devices(err)
+    }
+}
+
+impl From<cpal::DefaultStreamConfigError> for StreamError {
+    fn from(err: cpal::DefaultStreamConfigError) -> Self {
+        StreamError::DefaultConfig(err)
+    }
+}
+
+impl From<cpal::BuildStreamError> for StreamError {
+    fn from(err: cpal::BuildStreamError) -> Self {
+        StreamError::Build(err)
+    }
+}
+
+impl From<cpal::PlayStreamError> for StreamError {
+    fn from(err: cpal::PlayStreamError) -> Self {
+        StreamError::Play(err)
+    }
+}
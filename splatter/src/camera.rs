@@ -0,0 +1,209 @@
+//! Cameras for viewing 3D drawings with perspective.
+//!
+//! A camera may be applied to a **Draw** via `draw.camera(&camera)`, after which all drawings
+//! made via the returned **Draw** are described in world space and projected onto the window.
+
+use crate::geom::{Point3, Rect, Vec2, Vec3};
+use crate::glam::Mat4;
+use crate::state::mouse::{Button, Mouse};
+
+/// A perspective camera that orbits around a `target` point.
+///
+/// The position of the camera is described by its `yaw` about the *y* axis, its `pitch` above
+/// the *xz* plane and its `distance` from the `target`. With a `yaw` and `pitch` of `0.0` the
+/// camera looks down the negative *z* axis, matching the default 2D view.
+///
+/// ```
+/// # use splatter::prelude::*;
+/// let camera = OrbitCamera::new().yaw(0.5).pitch(0.25).distance(800.0);
+/// let draw = Draw::new();
+/// draw.camera(&camera).rect().w_h(100.0, 100.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OrbitCamera {
+    /// The rotation of the camera about the *y* axis through the `target` in radians.
+    pub yaw: f32,
+    /// The elevation of the camera above the *xz* plane through the `target` in radians.
+    pub pitch: f32,
+    /// The distance from the camera to the `target`.
+    pub distance: f32,
+    /// The point at which the camera looks.
+    pub target: Point3,
+    /// The vertical field of view in radians.
+    pub fov_y: f32,
+    /// The distance to the near clipping plane.
+    pub near: f32,
+    /// The distance to the far clipping plane.
+    pub far: f32,
+}
+
+impl OrbitCamera {
+    /// The default distance from the target, at which a point on the target plane appears at
+    /// roughly the same size as in the default 2D view within a window 600 points high.
+    pub const DEFAULT_DISTANCE: f32 = 724.0;
+    /// The default vertical field of view in radians (45 degrees).
+    pub const DEFAULT_FOV_Y: f32 = std::f32::consts::FRAC_PI_4;
+    /// The default distance to the near clipping plane.
+    pub const DEFAULT_NEAR: f32 = 1.0;
+    /// The default distance to the far clipping plane.
+    pub const DEFAULT_FAR: f32 = 10_000.0;
+    /// The pitch is clamped to within this many radians of straight up or down.
+    pub const PITCH_LIMIT: f32 = std::f32::consts::FRAC_PI_2 - 0.001;
+    /// The radians of yaw and pitch per point dragged via `update_from_mouse`.
+    pub const ORBIT_PER_POINT: f32 = 0.01;
+    /// The fraction of the distance zoomed per point scrolled via `update_from_mouse`.
+    pub const ZOOM_PER_POINT: f32 = 0.002;
+
+    /// A camera looking at the origin from the default distance along the positive *z* axis.
+    pub fn new() -> Self {
+        OrbitCamera {
+            yaw: 0.0,
+            pitch: 0.0,
+            distance: Self::DEFAULT_DISTANCE,
+            target: Point3::ZERO,
+            fov_y: Self::DEFAULT_FOV_Y,
+            near: Self::DEFAULT_NEAR,
+            far: Self::DEFAULT_FAR,
+        }
+    }
+
+    /// Specify the rotation about the *y* axis in radians.
+    pub fn yaw(mut self, yaw: f32) -> Self {
+        self.yaw = yaw;
+        self
+    }
+
+    /// Specify the elevation above the *xz* plane in radians.
+    pub fn pitch(mut self, pitch: f32) -> Self {
+        self.pitch = pitch.clamp(-Self::PITCH_LIMIT, Self::PITCH_LIMIT);
+        self
+    }
+
+    /// Specify the distance from the camera to the target.
+    pub fn distance(mut self, distance: f32) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    /// Specify the point at which the camera looks.
+    pub fn target(mut self, target: Point3) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Specify the vertical field of view in radians.
+    pub fn fov_y(mut self, fov_y: f32) -> Self {
+        self.fov_y = fov_y;
+        self
+    }
+
+    /// Specify the distances to the near and far clipping planes.
+    pub fn near_far(mut self, near: f32, far: f32) -> Self {
+        self.near = near;
+        self.far = far;
+        self
+    }
+
+    /// The position of the camera.
+    pub fn eye(&self) -> Point3 {
+        let (yaw_sin, yaw_cos) = self.yaw.sin_cos();
+        let (pitch_sin, pitch_cos) = self.pitch.sin_cos();
+        let dir = Vec3::new(pitch_cos * yaw_sin, pitch_sin, pitch_cos * yaw_cos);
+        self.target + dir * self.distance
+    }
+
+    /// The matrix transforming world space into the space of the camera.
+    pub fn view_matrix(&self) -> Mat4 {
+        Mat4::look_at_rh(self.eye(), self.target, Vec3::Y)
+    }
+
+    /// The perspective projection for a viewport with the given width / height ratio.
+    ///
+    /// Depth is mapped to the range `0.0..=1.0`.
+    pub fn projection_matrix(&self, aspect: f32) -> Mat4 {
+        Mat4::perspective_rh(self.fov_y, aspect, self.near, self.far)
+    }
+
+    /// The combined view and projection for a viewport with the given width / height ratio.
+    pub fn view_projection_matrix(&self, aspect: f32) -> Mat4 {
+        self.projection_matrix(aspect) * self.view_matrix()
+    }
+
+    /// The transform from world space to the point space of a **Draw** with the given viewport.
+    ///
+    /// This is the transform applied by `draw.camera(..)`, with depth mapped onto the range of
+    /// *z* values visible to the draw renderer.
+    pub fn draw_transform(&self, viewport: Rect) -> Mat4 {
+        let (w, h) = viewport.w_h();
+        let far = w.max(h);
+        let aspect = if h == 0.0 { 1.0 } else { w / h };
+        // The draw renderer maps `z` within `far..-far` to depth `0.0..1.0`.
+        let ndc_to_points = Mat4::from_translation(Vec3::new(viewport.x(), viewport.y(), far))
+            * Mat4::from_scale(Vec3::new(w / 2.0, h / 2.0, -2.0 * far));
+        ndc_to_points * self.view_projection_matrix(aspect)
+    }
+
+    /// Rotate the camera about the target by the given yaw and pitch in radians.
+    pub fn orbit(&mut self, yaw: f32, pitch: f32) {
+        self.yaw += yaw;
+        self.pitch = (self.pitch + pitch).clamp(-Self::PITCH_LIMIT, Self::PITCH_LIMIT);
+    }
+
+    /// Move the target and camera within the camera's view plane.
+    ///
+    /// The delta is described in world units along the camera's right and up axes.
+    pub fn pan(&mut self, delta: Vec2) {
+        let forward = (self.target - self.eye()).normalize_or_zero();
+        let right = forward.cross(Vec3::Y).normalize_or_zero();
+        let up = right.cross(forward);
+        self.target += right * delta.x + up * delta.y;
+    }
+
+    /// Scale the distance to the target by the given factor.
+    ///
+    /// Factors less than `1.0` move the camera closer to the target.
+    pub fn zoom(&mut self, factor: f32) {
+        self.distance = (self.distance * factor).max(self.near);
+    }
+
+    /// Orbit, pan and zoom the camera from the mouse state tracked by the **App**.
+    ///
+    /// - Dragging with the left button orbits around the target.
+    /// - Dragging with the right button pans the target.
+    /// - Scrolling zooms towards or away from the target.
+    ///
+    /// Call this once per update, e.g. `model.camera.update_from_mouse(&app.mouse)`.
+    pub fn update_from_mouse(&mut self, mouse: &Mouse) {
+        let orbit = mouse.drag_delta(Button::Left) * Self::ORBIT_PER_POINT;
+        self.orbit(-orbit.x, -orbit.y);
+        // Pan so that points on the target plane follow the mouse.
+        let points_per_unit = Self::DEFAULT_DISTANCE / self.distance;
+        self.pan(-mouse.drag_delta(Button::Right) / points_per_unit);
+        let scroll = mouse.scroll_delta().y;
+        self.zoom((1.0 - scroll * Self::ZOOM_PER_POINT).max(0.1));
+    }
+}
+
+impl Default for OrbitCamera {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn test_default_camera_matches_2d_view() {
+    use crate::geom::pt3;
+    let camera = OrbitCamera::new();
+    let viewport = Rect::from_w_h(800.0, 600.0);
+    let transform = camera.draw_transform(viewport);
+    let p = transform.project_point3(pt3(100.0, 50.0, 0.0));
+    assert!(
+        (p.x - 100.0).abs() < 1.0 && (p.y - 50.0).abs() < 1.0,
+        "{:?}",
+        p
+    );
+    // Nearer points project to a greater `z`, i.e. a lesser depth.
+    let near = transform.project_point3(pt3(0.0, 0.0, 100.0));
+    let far = transform.project_point3(pt3(0.0, 0.0, -100.0));
+    assert!(near.z > p.z && p.z > far.z);
+}
@@ -0,0 +1,75 @@
+//! Access to the system clipboard for copying and pasting text and images.
+//!
+//! The clipboard is accessed via `App::clipboard`. For example, a sketch might copy its current
+//! frame to the clipboard on a key press via `Window::capture_frame_to` and `set_image`.
+//!
+//! Not available on the web.
+
+pub use arboard::Error;
+
+/// A handle to the system clipboard.
+///
+/// The connection to the system clipboard is opened on first use and kept open for the lifetime
+/// of the **App**. On some platforms (e.g. X11) the copied contents are only available while the
+/// connection remains open, unless a clipboard manager is running.
+#[derive(Default)]
+pub struct Clipboard {
+    // Opened lazily so that sketches that do not use the clipboard do not connect to it.
+    system: Option<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    /// The text currently on the clipboard, if any.
+    pub fn get_text(&mut self) -> Option<String> {
+        self.system().ok()?.get_text().ok()
+    }
+
+    /// Place the given text on the clipboard.
+    pub fn set_text<T>(&mut self, text: T) -> Result<(), Error>
+    where
+        T: Into<String>,
+    {
+        self.system()?.set_text(text.into())
+    }
+
+    /// The image currently on the clipboard, if any.
+    ///
+    /// Returns the non-premultiplied RGBA8 pixel data along with the image's width and height.
+    pub fn get_image(&mut self) -> Option<(Vec<u8>, u32, u32)> {
+        let image = self.system().ok()?.get_image().ok()?;
+        let (w, h) = (image.width as u32, image.height as u32);
+        Some((image.bytes.into_owned(), w, h))
+    }
+
+    /// Place the given image on the clipboard.
+    ///
+    /// `rgba` must contain the non-premultiplied RGBA8 pixel data of the image, row by row, with
+    /// a length of `width * height * 4`.
+    pub fn set_image(&mut self, rgba: Vec<u8>, width: u32, height: u32) -> Result<(), Error> {
+        if rgba.len() != width as usize * height as usize * 4 {
+            return Err(Error::ConversionFailure);
+        }
+        let image = arboard::ImageData {
+            width: width as usize,
+            height: height as usize,
+            bytes: rgba.into(),
+        };
+        self.system()?.set_image(image)
+    }
+
+    // Open the connection to the system clipboard if it is not already open.
+    fn system(&mut self) -> Result<&mut arboard::Clipboard, Error> {
+        if self.system.is_none() {
+            self.system = Some(arboard::Clipboard::new()?);
+        }
+        Ok(self.system.as_mut().expect("clipboard was just opened"))
+    }
+}
+
+impl std::fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Clipboard")
+            .field("is_open", &self.system.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,318 @@
+//! A simple API for running WGSL compute shaders, e.g. to simulate GPU particle systems.
+//!
+//! A compute shader is run via `App::compute`, which creates the compute pipeline, uploads the
+//! given data to storage buffers, dispatches the shader and returns the resulting buffers:
+//!
+//! ```no_run
+//! # use splatter::prelude::*;
+//! const SHADER: &str = r#"
+//! @group(0) @binding(0)
+//! var<storage, read_write> values: array<f32>;
+//!
+//! @compute @workgroup_size(64)
+//! fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+//!     if id.x < arrayLength(&values) {
+//!         values[id.x] = values[id.x] * 2.0;
+//!     }
+//! }
+//! "#;
+//!
+//! fn update(app: &App, values: &mut Vec<f32>, _update: Update) {
+//!     let workgroups = (values.len() as u32 + 63) / 64;
+//!     let output = app
+//!         .compute(SHADER)
+//!         .buffer("values", values)
+//!         .dispatch(workgroups, 1, 1);
+//!     *values = output.read("values").unwrap();
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Each buffer is bound as a `var<storage, read_write>` at `@group(0)`, with bindings numbered in
+//! the order in which the buffers were specified. The shader's entry point is `main` by default.
+//!
+//! The values read back from a buffer may then be used to draw the results, e.g. as the transforms
+//! of `Drawing::instances`. Alternatively, `Output::buffer` provides access to the storage buffers
+//! for use within custom render pipelines.
+//!
+//! The compute pipeline is cached per device by the hash of the shader source, entry point and
+//! number of buffers, so that running the same shader each frame only creates the buffers anew.
+
+use crate::wgpu::{self, util::DeviceExt, BufferInitDescriptor};
+use crate::App;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Weak};
+
+/// A builder for dispatching a compute shader, produced via `App::compute`.
+pub struct Builder<'a> {
+    app: &'a App,
+    source: &'a str,
+    entry_point: &'a str,
+    buffers: Vec<(String, Vec<u8>)>,
+}
+
+/// The storage buffers resulting from a compute shader dispatch.
+pub struct Output {
+    device_queue_pair: Arc<wgpu::DeviceQueuePair>,
+    buffers: Vec<OutputBuffer>,
+}
+
+// A storage buffer along with its name and the size of its data in bytes.
+struct OutputBuffer {
+    name: String,
+    buffer: wgpu::Buffer,
+    len: usize,
+}
+
+/// Compute pipelines that have been created by `App::compute`, cached for reuse.
+///
+/// Pipelines are cached per device, as a pipeline may only be used with the device that created
+/// it.
+#[derive(Default)]
+pub(crate) struct PipelineCache {
+    devices: Vec<DevicePipelines>,
+}
+
+// The pipelines created for a single device.
+struct DevicePipelines {
+    device_queue_pair: Weak<wgpu::DeviceQueuePair>,
+    pipelines: HashMap<PipelineId, Pipeline>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct PipelineId {
+    shader_hash: u64,
+    buffer_count: usize,
+}
+
+struct Pipeline {
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline: wgpu::ComputePipeline,
+}
+
+impl<'a> Builder<'a> {
+    /// The name of the default entry point within the shader.
+    pub const DEFAULT_ENTRY_POINT: &'static str = "main";
+
+    pub(crate) fn new(app: &'a App, source: &'a str) -> Self {
+        Builder {
+            app,
+            source,
+            entry_point: Self::DEFAULT_ENTRY_POINT,
+            buffers: vec![],
+        }
+    }
+
+    /// Specify the name of the shader's entry point. By default, this is `main`.
+    pub fn entry_point(mut self, entry_point: &'a str) -> Self {
+        self.entry_point = entry_point;
+        self
+    }
+
+    /// Upload the given data to a storage buffer bound at the next binding of `@group(0)`.
+    ///
+    /// The buffer may be accessed by name via the `Output` of the dispatch. The layout of `T` must
+    /// match the layout of the corresponding array element type within the shader.
+    ///
+    /// **Panics** if `data` is empty, as empty storage buffers may not be bound.
+    pub fn buffer<T>(mut self, name: &str, data: &[T]) -> Self
+    where
+        T: bytemuck::Pod,
+    {
+        assert!(!data.is_empty(), "compute buffer \"{}\" is empty", name);
+        let bytes = bytemuck::cast_slice(data);
+        self.buffers.push((name.to_string(), bytes.to_vec()));
+        self
+    }
+
+    /// Dispatch the given number of workgroups along each dimension.
+    ///
+    /// The compute pass is submitted immediately on the device of the main window, or of the
+    /// headless target when running headless. The returned `Output` provides access to the
+    /// resulting storage buffers.
+    pub fn dispatch(self, x: u32, y: u32, z: u32) -> Output {
+        let Builder {
+            app,
+            source,
+            entry_point,
+            buffers,
+        } = self;
+        let device_queue_pair = app.main_device_queue_pair();
+        let device = device_queue_pair.device();
+
+        // Retrieve the cached pipeline or create it.
+        let id = PipelineId {
+            shader_hash: shader_hash(source, entry_point),
+            buffer_count: buffers.len(),
+        };
+        let mut cache = app.compute_pipelines.borrow_mut();
+        let pipeline = cache
+            .pipelines(&device_queue_pair)
+            .entry(id)
+            .or_insert_with(|| Pipeline::new(device, source, entry_point, buffers.len()));
+
+        // Upload the data.
+        let usage = wgpu::BufferUsages::STORAGE
+            | wgpu::BufferUsages::COPY_SRC
+            | wgpu::BufferUsages::COPY_DST;
+        let buffers: Vec<_> = buffers
+            .into_iter()
+            .map(|(name, bytes)| {
+                let buffer = device.create_buffer_init(&BufferInitDescriptor {
+                    label: Some("splatter_compute_buffer"),
+                    contents: &bytes,
+                    usage,
+                });
+                let len = bytes.len();
+                OutputBuffer { name, buffer, len }
+            })
+            .collect();
+        let bind_group = buffers
+            .iter()
+            .fold(wgpu::BindGroupBuilder::new(), |builder, b| {
+                builder.buffer_bytes(&b.buffer, 0, None)
+            })
+            .build(device, &pipeline.bind_group_layout);
+
+        // Encode and submit the compute pass.
+        let desc = wgpu::CommandEncoderDescriptor {
+            label: Some("splatter_compute"),
+        };
+        let mut encoder = device.create_command_encoder(&desc);
+        {
+            let pass_desc = wgpu::ComputePassDescriptor {
+                label: Some("splatter_compute_pass"),
+            };
+            let mut pass = encoder.begin_compute_pass(&pass_desc);
+            pass.set_pipeline(&pipeline.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(x, y, z);
+        }
+        device_queue_pair
+            .queue()
+            .submit(std::iter::once(encoder.finish()));
+        drop(cache);
+
+        Output {
+            device_queue_pair,
+            buffers,
+        }
+    }
+}
+
+impl Output {
+    /// The storage buffer with the given name, if any.
+    pub fn buffer(&self, name: &str) -> Option<&wgpu::Buffer> {
+        self.find(name).map(|b| &b.buffer)
+    }
+
+    /// Read the contents of the storage buffer with the given name back to the CPU.
+    ///
+    /// This blocks until the compute pass has completed. Returns `None` if there is no buffer with
+    /// the given name or if the buffer could not be read.
+    pub fn read<T>(&self, name: &str) -> Option<Vec<T>>
+    where
+        T: bytemuck::Pod,
+    {
+        let OutputBuffer { buffer, len, .. } = self.find(name)?;
+        let device = self.device_queue_pair.device();
+        let read_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("splatter_compute_read_buffer"),
+            size: buffer.size(),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let desc = wgpu::CommandEncoderDescriptor {
+            label: Some("splatter_compute_read"),
+        };
+        let mut encoder = device.create_command_encoder(&desc);
+        encoder.copy_buffer_to_buffer(buffer, 0, &read_buffer, 0, buffer.size());
+        let queue = self.device_queue_pair.queue();
+        queue.submit(std::iter::once(encoder.finish()));
+
+        // The buffer is only mapped once the device is polled.
+        let slice = read_buffer.slice(..);
+        let (tx, rx) = futures::channel::oneshot::channel();
+        slice.map_async(wgpu::MapMode::Read, |res| {
+            tx.send(res).expect("failed to send map_async result");
+        });
+        device.poll(wgpu::Maintain::Wait);
+        futures::executor::block_on(rx).ok()?.ok()?;
+        let bytes = slice.get_mapped_range();
+        let size = std::mem::size_of::<T>();
+        let values = bytes[..*len]
+            .chunks_exact(size)
+            .map(bytemuck::pod_read_unaligned)
+            .collect();
+        Some(values)
+    }
+
+    fn find(&self, name: &str) -> Option<&OutputBuffer> {
+        self.buffers.iter().find(|b| b.name == name)
+    }
+}
+
+impl PipelineCache {
+    // The pipelines created for the given device, forgetting those of devices that were dropped.
+    fn pipelines(
+        &mut self,
+        device_queue_pair: &Arc<wgpu::DeviceQueuePair>,
+    ) -> &mut HashMap<PipelineId, Pipeline> {
+        self.devices
+            .retain(|d| d.device_queue_pair.strong_count() > 0);
+        let weak = Arc::downgrade(device_queue_pair);
+        let ix = match self
+            .devices
+            .iter()
+            .position(|d| d.device_queue_pair.ptr_eq(&weak))
+        {
+            Some(ix) => ix,
+            None => {
+                self.devices.push(DevicePipelines {
+                    device_queue_pair: weak,
+                    pipelines: HashMap::new(),
+                });
+                self.devices.len() - 1
+            }
+        };
+        &mut self.devices[ix].pipelines
+    }
+}
+
+impl Pipeline {
+    fn new(device: &wgpu::Device, source: &str, entry_point: &str, buffer_count: usize) -> Self {
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("splatter_compute_shader"),
+            source: wgpu::ShaderSource::Wgsl(source.into()),
+        });
+        let bind_group_layout = (0..buffer_count)
+            .fold(wgpu::BindGroupLayoutBuilder::new(), |builder, _| {
+                builder.storage_buffer(wgpu::ShaderStages::COMPUTE, false, false)
+            })
+            .build(device);
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("splatter_compute_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("splatter_compute_pipeline"),
+            layout: Some(&layout),
+            module: &module,
+            entry_point,
+        });
+        Pipeline {
+            bind_group_layout,
+            pipeline,
+        }
+    }
+}
+
+// Hash the shader source along with the entry point in order to identify the pipeline.
+fn shader_hash(source: &str, entry_point: &str) -> u64 {
+    let mut s = std::collections::hash_map::DefaultHasher::new();
+    source.hash(&mut s);
+    entry_point.hash(&mut s);
+    s.finish()
+}
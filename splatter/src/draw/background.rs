@@ -1,12 +1,35 @@
 use crate::color::{self, IntoLinSrgba, Srgb, Srgba};
-use crate::draw::properties::ColorScalar;
+use crate::draw::primitive;
+use crate::draw::properties::{ColorScalar, SetDimensions};
 use crate::draw::Draw;
+use crate::geom;
+use crate::glam::Vec2;
+use crate::wgpu;
 
 /// A type used to update the background colour.
 pub struct Background<'a> {
     draw: &'a Draw,
 }
 
+/// Describes how a background image is fit to the frame when their aspect ratios differ.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Fit {
+    /// Scale the image to cover the whole frame, cropping the edges of the image that overflow.
+    #[default]
+    Cover,
+    /// Scale the image to fit entirely within the frame, leaving the remaining area uncovered.
+    Contain,
+    /// Stretch the image to the bounds of the frame, ignoring its aspect ratio.
+    Stretch,
+}
+
+/// An image drawn across the frame before all other primitives.
+#[derive(Clone, Debug)]
+pub(crate) struct Image {
+    texture_view: wgpu::TextureView,
+    fit: Fit,
+}
+
 /// Begin coloring the background.
 pub fn new<'a>(draw: &'a Draw) -> Background<'a> {
     Background { draw }
@@ -28,6 +51,34 @@ impl<'a> Background<'a> {
         self
     }
 
+    /// Draw the given texture across the frame before all other drawings.
+    ///
+    /// By default the image covers the whole frame while preserving its aspect ratio. Use the
+    /// `fit` method to specify how the image should be fit to the frame.
+    ///
+    /// The image is drawn in addition to any background color, so a color may be used to clear
+    /// the area left uncovered by `Fit::Contain`.
+    pub fn image(self, view: &dyn wgpu::ToTextureView) -> Self {
+        if let Ok(mut state) = self.draw.state.try_borrow_mut() {
+            let texture_view = view.to_texture_view();
+            let fit = Fit::default();
+            state.background_image = Some(Image { texture_view, fit });
+        }
+        self
+    }
+
+    /// Specify how the background image is fit to the frame, e.g. `background::Fit::Contain`.
+    ///
+    /// Has no effect if no image has been specified via the `image` method.
+    pub fn fit(self, fit: Fit) -> Self {
+        if let Ok(mut state) = self.draw.state.try_borrow_mut() {
+            if let Some(image) = state.background_image.as_mut() {
+                image.fit = fit;
+            }
+        }
+        self
+    }
+
     /// Specify the color via red, green and blue channels.
     pub fn rgb(self, r: ColorScalar, g: ColorScalar, b: ColorScalar) -> Self {
         self.color(Srgb::new(r, g, b))
@@ -94,3 +145,49 @@ impl<'a> Background<'a> {
         self.color(color::Hsva::new(hue, s, v, a))
     }
 }
+
+impl Image {
+    // Produce the texture primitive that fits the image to the given frame rect.
+    pub(crate) fn primitive(&self, frame: geom::Rect) -> primitive::Texture {
+        let [w, h] = self.texture_view.size();
+        let size = Vec2::new(w as f32, h as f32);
+        let (wh, area) = fit(self.fit, size, frame.wh());
+        primitive::Texture::new(&self.texture_view)
+            .w_h(wh.x, wh.y)
+            .area(area)
+    }
+}
+
+// The dimensions of the drawn image and the area of the texture to draw for the given fit.
+fn fit(fit: Fit, texture: Vec2, frame: Vec2) -> (Vec2, geom::Rect) {
+    let full = geom::Rect::from_x_y_w_h(0.5, 0.5, 1.0, 1.0);
+    if texture.min_element() <= 0.0 || frame.min_element() <= 0.0 {
+        return (frame, full);
+    }
+    match fit {
+        Fit::Stretch => (frame, full),
+        Fit::Contain => {
+            let scale = (frame / texture).min_element();
+            (texture * scale, full)
+        }
+        Fit::Cover => {
+            let scale = (frame / texture).max_element();
+            let area = frame / (texture * scale);
+            (frame, geom::Rect::from_x_y_w_h(0.5, 0.5, area.x, area.y))
+        }
+    }
+}
+
+#[test]
+fn test_fit() {
+    let texture = Vec2::new(200.0, 100.0);
+    let frame = Vec2::new(100.0, 100.0);
+    let full = geom::Rect::from_x_y_w_h(0.5, 0.5, 1.0, 1.0);
+    assert_eq!(fit(Fit::Stretch, texture, frame), (frame, full));
+    assert_eq!(
+        fit(Fit::Contain, texture, frame),
+        (Vec2::new(100.0, 50.0), full)
+    );
+    let cover = geom::Rect::from_x_y_w_h(0.5, 0.5, 0.5, 1.0);
+    assert_eq!(fit(Fit::Cover, texture, frame), (frame, cover));
+}
@@ -1,12 +1,12 @@
 use crate::color::IntoLinSrgba;
 use crate::draw::mesh::vertex::{Color, TexCoords};
-use crate::draw::primitive::Primitive;
+use crate::draw::primitive::{Instances, Primitive};
 use crate::draw::properties::{
     ColorScalar, SetColor, SetDimensions, SetFill, SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Draw};
 use crate::geom::{Point2, Point3};
-use crate::glam::{Quat, Vec2, Vec3};
+use crate::glam::{Mat4, Quat, Vec2, Vec3};
 use lyon::path::PathEvent;
 use lyon::tessellation::{FillOptions, LineCap, LineJoin, StrokeOptions};
 use std::marker::PhantomData;
@@ -45,6 +45,8 @@ pub struct DrawingContext<'a> {
     pub path_points_colored_buffer: &'a mut Vec<(Point2, Color)>,
     /// A re-usable buffer for collecting textured polyline points.
     pub path_points_textured_buffer: &'a mut Vec<(Point2, TexCoords)>,
+    /// A re-usable buffer for collecting textured and colored polyline points.
+    pub path_points_textured_colored_buffer: &'a mut Vec<(Point2, Color, TexCoords)>,
     /// A re-usable buffer for collecting text.
     pub text_buffer: &'a mut String,
 }
@@ -77,6 +79,7 @@ impl<'a> DrawingContext<'a> {
             ref mut path_event_buffer,
             ref mut path_points_colored_buffer,
             ref mut path_points_textured_buffer,
+            ref mut path_points_textured_colored_buffer,
             ref mut text_buffer,
         } = *state;
         DrawingContext {
@@ -84,6 +87,7 @@ impl<'a> DrawingContext<'a> {
             path_event_buffer: path_event_buffer,
             path_points_colored_buffer: path_points_colored_buffer,
             path_points_textured_buffer: path_points_textured_buffer,
+            path_points_textured_colored_buffer: path_points_textured_colored_buffer,
             text_buffer: text_buffer,
         }
     }
@@ -108,6 +112,58 @@ impl<'a, T> Drawing<'a, T> {
         self.finish_inner()
     }
 
+    /// Specify the layer within which the primitive is drawn.
+    ///
+    /// Primitives are drawn in order of their layer, from lowest to highest, such that
+    /// primitives within higher layers appear over those within lower layers. Primitives within
+    /// the same layer are drawn in the order in which they were submitted. By default, all
+    /// primitives are drawn within layer `0`.
+    ///
+    /// Note that layers only determine the order in which primitives are drawn. Geometry with a
+    /// greater *z* position still occludes geometry with a lesser *z* position.
+    ///
+    /// ```
+    /// # use splatter::prelude::*;
+    /// let draw = Draw::new();
+    /// // Drawn over the rect despite being submitted first.
+    /// draw.ellipse().radius(20.0).color(RED).layer(1);
+    /// draw.rect().w_h(100.0, 100.0).color(WHITE);
+    /// ```
+    pub fn layer(self, layer: i32) -> Self {
+        if let Ok(mut state) = self.draw.state.try_borrow_mut() {
+            match layer {
+                0 => state.layers.remove(&self.index),
+                _ => state.layers.insert(self.index, layer),
+            };
+        }
+        self
+    }
+
+    /// Draw the primitive once for each of the given transforms.
+    ///
+    /// The primitive is only tessellated once and all instances are submitted to the GPU with a
+    /// single instanced draw call, making this far cheaper than drawing the same primitive many
+    /// times. Each transform is applied to the primitive before the transform of the **Draw**.
+    ///
+    /// Use `colors` on the resulting drawing to tint each instance.
+    ///
+    /// ```
+    /// # use splatter::prelude::*;
+    /// let draw = Draw::new();
+    /// let transforms = (0..100).map(|i| Mat4::from_translation(vec3(i as f32 * 10.0, 0.0, 0.0)));
+    /// draw.rect()
+    ///     .w_h(8.0, 8.0)
+    ///     .color(WHITE)
+    ///     .instances(transforms)
+    ///     .colors((0..100).map(|i| hsl(i as f32 / 100.0, 0.5, 0.5)));
+    /// ```
+    pub fn instances<I>(self, transforms: I) -> Drawing<'a, Instances>
+    where
+        I: IntoIterator<Item = Mat4>,
+    {
+        self.map_primitive(|prim| Instances::new(prim, transforms).into())
+    }
+
     // Map the given function onto the primitive stored within **Draw** at `index`.
     //
     // The functionn is only applied if the node has not yet been **Drawn**.
@@ -160,6 +216,20 @@ impl<'a, T> Drawing<'a, T> {
         }
     }
 
+    // Apply the given function to a reference to the primitive stored within **Draw** at `index`
+    // along with the intermediary state, returning the result.
+    //
+    // Returns `None` if the primitive has already been drawn or the state is already borrowed.
+    pub(crate) fn with_primitive_and_state<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&Primitive, &draw::IntermediaryState) -> R,
+    {
+        let state = self.draw.state.try_borrow().ok()?;
+        let primitive = state.drawing.get(&self.index)?;
+        let intermediary_state = state.intermediary_state.try_borrow().ok()?;
+        Some(f(primitive, &intermediary_state))
+    }
+
     /// Apply the given function to the type stored within **Draw**.
     ///
     /// The function is only applied if the node has not yet been **Drawn**.
@@ -6,6 +6,7 @@
 //! Lyon tessellators assume `f32` data, so we do the same in the following implementations.
 
 use crate::draw;
+use crate::draw::properties::Gradient;
 use crate::geom::Point2;
 use crate::glam::Mat4;
 use lyon::tessellation::geometry_builder::{
@@ -29,6 +30,7 @@ pub struct MeshBuilder<'a, A> {
 pub struct SingleColor(draw::mesh::vertex::Color);
 pub struct ColorPerPoint;
 pub struct TexCoordsPerPoint;
+pub struct ColorAndTexCoordsPerPoint;
 
 impl<'a, A> MeshBuilder<'a, A> {
     /// Begin extending the mesh.
@@ -68,6 +70,14 @@ impl<'a> MeshBuilder<'a, TexCoordsPerPoint> {
     }
 }
 
+impl<'a> MeshBuilder<'a, ColorAndTexCoordsPerPoint> {
+    /// Begin extending a mesh where the path interpolates both a unique color and unique texture
+    /// coordinates per point.
+    pub fn color_and_tex_coords_per_point(mesh: &'a mut draw::Mesh, transform: Mat4) -> Self {
+        Self::new(mesh, transform, ColorAndTexCoordsPerPoint)
+    }
+}
+
 impl<'a, A> GeometryBuilder for MeshBuilder<'a, A> {
     fn begin_geometry(&mut self) {
         self.begin_vertex_count = self.mesh.points().len() as u32;
@@ -107,7 +117,7 @@ impl<'a> FillGeometryBuilder for MeshBuilder<'a, SingleColor> {
 
         // Construct and insert the point
         let p = Point2::new(position.x, position.y).extend(0.0);
-        let point = self.transform.transform_point3(p);
+        let point = self.transform.project_point3(p);
         let SingleColor(color) = self.attributes;
         let tex_coords = draw::mesh::vertex::default_tex_coords();
         let vertex = draw::mesh::vertex::new(point, color, tex_coords);
@@ -130,7 +140,7 @@ impl<'a> StrokeGeometryBuilder for MeshBuilder<'a, SingleColor> {
 
         // Construct and insert the point
         let p = Point2::new(position.x, position.y).extend(0.0);
-        let point = self.transform.transform_point3(p);
+        let point = self.transform.project_point3(p);
         let SingleColor(color) = self.attributes;
         let tex_coords = draw::mesh::vertex::default_tex_coords();
         let vertex = draw::mesh::vertex::new(point, color, tex_coords);
@@ -153,7 +163,7 @@ impl<'a> FillGeometryBuilder for MeshBuilder<'a, ColorPerPoint> {
 
         // Construct and insert the point
         let p = Point2::new(position.x, position.y).extend(0.0);
-        let point = self.transform.transform_point3(p);
+        let point = self.transform.project_point3(p);
         let col = vertex.interpolated_attributes();
         let color: draw::mesh::vertex::Color = (col[0], col[1], col[2], col[3]).into();
         let tex_coords = draw::mesh::vertex::default_tex_coords();
@@ -177,7 +187,7 @@ impl<'a> StrokeGeometryBuilder for MeshBuilder<'a, ColorPerPoint> {
 
         // Construct and insert the point
         let p = Point2::new(position.x, position.y).extend(0.0);
-        let point = self.transform.transform_point3(p);
+        let point = self.transform.project_point3(p);
         let col = vertex.interpolated_attributes();
         let color: draw::mesh::vertex::Color = (col[0], col[1], col[2], col[3]).into();
         let tex_coords = draw::mesh::vertex::default_tex_coords();
@@ -201,7 +211,7 @@ impl<'a> FillGeometryBuilder for MeshBuilder<'a, TexCoordsPerPoint> {
 
         // Construct and insert the point
         let p = Point2::new(position.x, position.y).extend(0.0);
-        let point = self.transform.transform_point3(p);
+        let point = self.transform.project_point3(p);
         let tc = vertex.interpolated_attributes();
         let tex_coords: draw::mesh::vertex::TexCoords = (tc[0], tc[1]).into();
         let color = draw::mesh::vertex::DEFAULT_VERTEX_COLOR;
@@ -225,7 +235,7 @@ impl<'a> StrokeGeometryBuilder for MeshBuilder<'a, TexCoordsPerPoint> {
 
         // Construct and insert the point
         let p = Point2::new(position.x, position.y).extend(0.0);
-        let point = self.transform.transform_point3(p);
+        let point = self.transform.project_point3(p);
         let tc = vertex.interpolated_attributes();
         let tex_coords: draw::mesh::vertex::TexCoords = (tc[0], tc[1]).into();
         let color = draw::mesh::vertex::DEFAULT_VERTEX_COLOR;
@@ -236,3 +246,184 @@ impl<'a> StrokeGeometryBuilder for MeshBuilder<'a, TexCoordsPerPoint> {
         Ok(id)
     }
 }
+
+impl<'a> FillGeometryBuilder for MeshBuilder<'a, ColorAndTexCoordsPerPoint> {
+    fn add_fill_vertex(
+        &mut self,
+        mut vertex: FillVertex,
+    ) -> Result<VertexId, GeometryBuilderError> {
+        // Retrieve the index.
+        let id = VertexId::from_usize(self.mesh.points().len());
+
+        let position = vertex.position();
+
+        // Construct and insert the point
+        let p = Point2::new(position.x, position.y).extend(0.0);
+        let point = self.transform.project_point3(p);
+        let attrs = vertex.interpolated_attributes();
+        let color: draw::mesh::vertex::Color = (attrs[0], attrs[1], attrs[2], attrs[3]).into();
+        let tex_coords: draw::mesh::vertex::TexCoords = (attrs[4], attrs[5]).into();
+        let vertex = draw::mesh::vertex::new(point, color, tex_coords);
+        self.mesh.push_vertex(vertex);
+
+        // Return the index.
+        Ok(id)
+    }
+}
+
+impl<'a> StrokeGeometryBuilder for MeshBuilder<'a, ColorAndTexCoordsPerPoint> {
+    fn add_stroke_vertex(
+        &mut self,
+        mut vertex: StrokeVertex,
+    ) -> Result<VertexId, GeometryBuilderError> {
+        // Retrieve the index.
+        let id = VertexId::from_usize(self.mesh.points().len());
+
+        let position = vertex.position();
+
+        // Construct and insert the point
+        let p = Point2::new(position.x, position.y).extend(0.0);
+        let point = self.transform.project_point3(p);
+        let attrs = vertex.interpolated_attributes();
+        let color: draw::mesh::vertex::Color = (attrs[0], attrs[1], attrs[2], attrs[3]).into();
+        let tex_coords: draw::mesh::vertex::TexCoords = (attrs[4], attrs[5]).into();
+        let vertex = draw::mesh::vertex::new(point, color, tex_coords);
+        self.mesh.push_vertex(vertex);
+
+        // Return the index.
+        Ok(id)
+    }
+}
+
+/// Extends a mesh with tessellated geometry colored by a **Gradient**.
+///
+/// The gradient is sampled at each vertex and interpolated across each triangle. As tessellation
+/// alone often produces too few vertices to represent a gradient (e.g. a filled rectangle has only
+/// four), tessellated triangles are buffered and uniformly subdivided upon `end_geometry`.
+pub struct GradientMeshBuilder<'a> {
+    /// The mesh that is to be extended.
+    mesh: &'a mut draw::Mesh,
+    /// Transform matrix that also integrates position and orientation here.
+    transform: Mat4,
+    /// The gradient, described in the space of the untransformed points.
+    gradient: &'a Gradient,
+    /// The untransformed points produced by the tessellator.
+    points: Vec<Point2>,
+    /// The triangles produced by the tessellator, indexing into `points`.
+    triangles: Vec<[usize; 3]>,
+}
+
+impl<'a> GradientMeshBuilder<'a> {
+    /// The number of segments per gradient extent targeted when subdividing triangle edges.
+    pub const SEGMENTS_PER_EXTENT: f32 = 16.0;
+    /// The maximum number of segments into which any triangle edge is subdivided.
+    pub const MAX_SEGMENTS: usize = 16;
+
+    /// Begin extending a mesh where each vertex is colored by the given gradient.
+    pub fn new(mesh: &'a mut draw::Mesh, transform: Mat4, gradient: &'a Gradient) -> Self {
+        GradientMeshBuilder {
+            mesh,
+            transform,
+            gradient,
+            points: vec![],
+            triangles: vec![],
+        }
+    }
+
+    // Buffer a tessellated point, returning its index.
+    fn push_point(&mut self, position: lyon::math::Point) -> VertexId {
+        let id = VertexId::from_usize(self.points.len());
+        self.points.push(Point2::new(position.x, position.y));
+        id
+    }
+
+    // Push a vertex to the mesh at the given untransformed point, colored by the gradient.
+    fn push_vertex(&mut self, p: Point2) {
+        let color = self.gradient.color_at(p);
+        let point = self.transform.project_point3(p.extend(0.0));
+        let tex_coords = draw::mesh::vertex::default_tex_coords();
+        let vertex = draw::mesh::vertex::new(point, color, tex_coords);
+        self.mesh.push_vertex(vertex);
+    }
+}
+
+impl<'a> GeometryBuilder for GradientMeshBuilder<'a> {
+    fn begin_geometry(&mut self) {
+        self.points.clear();
+        self.triangles.clear();
+    }
+
+    fn end_geometry(&mut self) {
+        // Use the same number of segments for every edge so that shared edges are split at the
+        // same points, avoiding cracks between neighbouring triangles.
+        let points = &self.points;
+        let max_edge = self
+            .triangles
+            .iter()
+            .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+            .map(|(i, j)| points[i].distance(points[j]))
+            .fold(0.0, f32::max);
+        let segment_len = self.gradient.extent() / Self::SEGMENTS_PER_EXTENT;
+        let n = match segment_len > 0.0 {
+            true => ((max_edge / segment_len).ceil() as usize).clamp(1, Self::MAX_SEGMENTS),
+            false => 1,
+        };
+
+        let triangles = std::mem::take(&mut self.triangles);
+        for &[a, b, c] in &triangles {
+            let (pa, pb, pc) = (self.points[a], self.points[b], self.points[c]);
+
+            // Push the vertices of a triangular grid, row by row.
+            let start = self.mesh.points().len();
+            for i in 0..=n {
+                for j in 0..=n - i {
+                    let k = n - i - j;
+                    let p = (pa * k as f32 + pb * i as f32 + pc * j as f32) / n as f32;
+                    self.push_vertex(p);
+                }
+            }
+
+            // Push the indices of the grid's triangles.
+            let index =
+                |i: usize, j: usize| (start + i * (n + 1) - i * i.saturating_sub(1) / 2 + j) as u32;
+            for i in 0..n {
+                for j in 0..n - i {
+                    self.mesh.push_index(index(i, j));
+                    self.mesh.push_index(index(i + 1, j));
+                    self.mesh.push_index(index(i, j + 1));
+                    if j + 1 < n - i {
+                        self.mesh.push_index(index(i + 1, j));
+                        self.mesh.push_index(index(i + 1, j + 1));
+                        self.mesh.push_index(index(i, j + 1));
+                    }
+                }
+            }
+        }
+        self.triangles = triangles;
+    }
+
+    fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
+        self.triangles
+            .push([a.to_usize(), b.to_usize(), c.to_usize()]);
+    }
+
+    fn abort_geometry(&mut self) {
+        self.points.clear();
+        self.triangles.clear();
+    }
+}
+
+impl<'a> FillGeometryBuilder for GradientMeshBuilder<'a> {
+    fn add_fill_vertex(&mut self, vertex: FillVertex) -> Result<VertexId, GeometryBuilderError> {
+        Ok(self.push_point(vertex.position()))
+    }
+}
+
+impl<'a> StrokeGeometryBuilder for GradientMeshBuilder<'a> {
+    fn add_stroke_vertex(
+        &mut self,
+        vertex: StrokeVertex,
+    ) -> Result<VertexId, GeometryBuilderError> {
+        Ok(self.push_point(vertex.position()))
+    }
+}
@@ -7,7 +7,7 @@ use std::ops::{Deref, DerefMut};
 pub mod builder;
 pub mod vertex;
 
-pub use self::builder::MeshBuilder;
+pub use self::builder::{GradientMeshBuilder, MeshBuilder};
 pub use self::vertex::Vertex;
 
 pub type Points = Vec<vertex::Point>;
@@ -201,6 +201,9 @@ impl mesh::GetVertex<u32> for Mesh {
     fn get_vertex(&self, index: u32) -> Option<Self::Vertex> {
         mesh::WithTexCoords::get_vertex(&self.mesh, index)
     }
+    fn get_vertex_checked(&self, index: u32) -> Result<Self::Vertex, Option<mesh::OutOfBounds>> {
+        mesh::WithTexCoords::get_vertex_checked(&self.mesh, index)
+    }
 }
 
 impl mesh::Points for Mesh {
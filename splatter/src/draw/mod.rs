@@ -2,6 +2,8 @@
 //!
 //! See the [**Draw** type](./struct.Draw.html) for more details.
 
+use crate::camera::OrbitCamera;
+use crate::color::conv::IntoLinSrgba;
 use crate::geom::{self, Point2};
 use crate::glam::{vec3, EulerRot, Mat4, Quat, Vec2, Vec3};
 use crate::math::{deg_to_rad, turns_to_rad};
@@ -17,7 +19,10 @@ pub use self::drawing::{Drawing, DrawingContext};
 use self::mesh::vertex::{Color, TexCoords};
 pub use self::mesh::Mesh;
 use self::primitive::Primitive;
+use self::properties::ColorScalar;
+pub use self::record::DrawCommands;
 pub use self::renderer::{Builder as RendererBuilder, Renderer};
+pub use self::target::TextureTarget;
 pub use self::theme::Theme;
 
 pub mod background;
@@ -25,7 +30,10 @@ mod drawing;
 pub mod mesh;
 pub mod primitive;
 pub mod properties;
+pub mod record;
 pub mod renderer;
+mod svg;
+pub mod target;
 pub mod theme;
 
 /// A simple API for drawing 2D and 3D graphics.
@@ -40,6 +48,11 @@ pub mod theme;
 /// a new **Draw** instance where all drawings are translated along the *x* axis by the given
 /// amount.
 ///
+/// These transformations nest, forming a retained transform stack. E.g. in
+/// `let group = draw.xy(pos).rotate(angle).scale(2.0);`, every drawing made via `group` is first
+/// scaled, then rotated and finally translated to `pos`, while drawings made via `draw` remain
+/// unaffected. This makes it easy to draw hierarchical scenes, such as a rotating group of shapes.
+///
 /// See the [draw](https://github.com/splatter-org/splatter/blob/master/examples) examples for a
 /// variety of demonstrations of how the **Draw** type can be used!
 #[derive(Clone, Debug)]
@@ -62,12 +75,16 @@ pub struct Draw {
 #[derive(Clone, Debug, PartialEq)]
 pub struct Context {
     pub transform: Mat4,
+    /// A camera through which 3D drawings are projected onto the window, if any.
+    pub camera: Option<OrbitCamera>,
     pub blend: wgpu::BlendState,
     pub scissor: Scissor,
     // TODO: Consider changing `PolygonMode` (added as of wgpu 0.7) rather than `PrimitiveTopology`
     // here.
     pub topology: wgpu::PrimitiveTopology,
     pub sampler: wgpu::SamplerDescriptor<'static>,
+    /// The ID written to the pick buffer by drawings, if any. See `Draw::pickable`.
+    pub pick_id: Option<u64>,
 }
 
 /// Commands generated by drawings.
@@ -93,6 +110,12 @@ pub enum Scissor {
     NoOverlap,
 }
 
+/// Types that may be used to specify the blending of a **Draw** via `Draw::blend`.
+pub trait SetBlend {
+    /// Apply self to the given blend state.
+    fn set_blend(self, blend: &mut wgpu::BlendState);
+}
+
 /// The inner state of the **Draw** type.
 ///
 /// The **Draw** type stores its **State** behind a **RefCell** - a type used for moving mutability
@@ -106,6 +129,8 @@ pub struct State {
     last_draw_context: Option<Context>,
     /// If `Some`, the **Draw** should first clear the frame's texture with the given color.
     background_color: Option<properties::LinSrgba>,
+    /// If `Some`, the **Draw** should draw the given image before all other primitives.
+    background_image: Option<background::Image>,
     /// Primitives that are in the process of being drawn.
     ///
     /// Keys are indices into the `draw_commands` Vec.
@@ -114,6 +139,11 @@ pub struct State {
     ///
     /// An element may be `None` if it is a primitive in the process of being drawn.
     draw_commands: Vec<Option<DrawCommand>>,
+    /// The layers assigned to primitives via `Drawing::layer`.
+    ///
+    /// Keys are indices into the `draw_commands` Vec. Primitives without an entry are drawn
+    /// within layer `0`.
+    layers: HashMap<usize, i32>,
     /// State made accessible via the `DrawingContext`.
     intermediary_state: RefCell<IntermediaryState>,
     /// The theme containing default values.
@@ -121,6 +151,12 @@ pub struct State {
 }
 
 /// State made accessible via the `DrawingContext`.
+///
+/// Paths, meshes and text append their data to these buffers as they are drawn, with primitives
+/// referring to their data by range. The buffers are only read once the primitives are rendered,
+/// so they grow until the **Draw** is reset. Resetting truncates each buffer without freeing its
+/// allocation, so once a sketch reaches a steady number of drawings per frame no further
+/// allocations are required.
 #[derive(Clone, Debug)]
 pub struct IntermediaryState {
     /// Buffers of vertex data that may be re-used for paths, meshes, etc between view calls.
@@ -131,27 +167,41 @@ pub struct IntermediaryState {
     path_points_colored_buffer: Vec<(Point2, Color)>,
     /// A re-usable buffer for collecting textured polyline points.
     path_points_textured_buffer: Vec<(Point2, TexCoords)>,
+    /// A re-usable buffer for collecting textured and colored polyline points.
+    path_points_textured_colored_buffer: Vec<(Point2, Color, TexCoords)>,
     /// A buffer containing all text.
     text_buffer: String,
 }
 
 impl IntermediaryState {
+    /// Clear all buffers while retaining their capacity for reuse.
     pub fn reset(&mut self) {
         self.intermediary_mesh.clear();
         self.path_event_buffer.clear();
         self.path_points_colored_buffer.clear();
         self.path_points_textured_buffer.clear();
+        self.path_points_textured_colored_buffer.clear();
         self.text_buffer.clear();
     }
+
+    /// Reserve capacity for at least the given number of additional elements within each of the
+    /// path buffers.
+    pub fn reserve_path_buffers(&mut self, events: usize, colored: usize, textured: usize) {
+        self.path_event_buffer.reserve(events);
+        self.path_points_colored_buffer.reserve(colored);
+        self.path_points_textured_buffer.reserve(textured);
+    }
 }
 
 impl State {
     // Resets all state within the `Draw` instance.
     fn reset(&mut self) {
         self.background_color = None;
+        self.background_image = None;
         self.last_draw_context = None;
         self.drawing.clear();
         self.draw_commands.clear();
+        self.layers.clear();
         self.intermediary_state.borrow_mut().reset();
     }
 
@@ -171,6 +221,52 @@ impl State {
         }
     }
 
+    // Produce the given draw commands with primitives stably sorted by their layer.
+    //
+    // Context commands are re-inserted wherever the context changes between the sorted
+    // primitives.
+    fn sort_by_layer<I>(&self, cmds: I) -> Vec<DrawCommand>
+    where
+        I: IntoIterator<Item = (usize, DrawCommand)>,
+    {
+        let mut ctxt = Context::default();
+        let mut prims = vec![];
+        for (index, cmd) in cmds {
+            match cmd {
+                DrawCommand::Context(c) => ctxt = c,
+                DrawCommand::Primitive(prim) => {
+                    let layer = self.layers.get(&index).copied().unwrap_or(0);
+                    prims.push((layer, ctxt.clone(), prim));
+                }
+            }
+        }
+        prims.sort_by_key(|&(layer, _, _)| layer);
+        let mut last_ctxt = Context::default();
+        let mut sorted = Vec::with_capacity(prims.len());
+        for (_, ctxt, prim) in prims {
+            if ctxt != last_ctxt {
+                sorted.push(DrawCommand::Context(ctxt.clone()));
+                last_ctxt = ctxt;
+            }
+            sorted.push(DrawCommand::Primitive(prim));
+        }
+        sorted
+    }
+
+    // The draw commands in the order in which they should be drawn.
+    //
+    // This is the order of submission unless any primitives have been assigned a layer.
+    pub(crate) fn ordered_commands(&self) -> Vec<DrawCommand> {
+        let cmds = self.draw_commands.iter().cloned();
+        let cmds = cmds
+            .enumerate()
+            .filter_map(|(i, cmd)| cmd.map(|cmd| (i, cmd)));
+        match self.layers.is_empty() {
+            true => cmds.map(|(_, cmd)| cmd).collect(),
+            false => self.sort_by_layer(cmds),
+        }
+    }
+
     // Insert the draw primitive command at the given index.
     fn insert_draw_command(&mut self, index: usize, prim: Primitive) {
         if let Some(elem) = self.draw_commands.get_mut(index) {
@@ -188,10 +284,34 @@ impl Draw {
     }
 
     /// Resets all state within the `Draw` instance.
+    ///
+    /// The buffers used to collect path, mesh and text data are truncated rather than freed, so
+    /// their capacity is reused by the drawings that follow. `App::draw` resets its **Draw**
+    /// each time it is called, i.e. once per frame for most sketches. A **Draw** created via
+    /// `Draw::new` must be reset manually between frames, otherwise these buffers continue to
+    /// grow.
     pub fn reset(&self) {
         self.state.borrow_mut().reset();
     }
 
+    /// Reserve capacity within the buffers used to collect the data of paths.
+    ///
+    /// - `events` is the number of additional path events, e.g. the points of polylines and
+    ///   polygons along with the begin and end events of each path.
+    /// - `colored` is the number of additional points of paths drawn via `points_colored`.
+    /// - `textured` is the number of additional points of paths drawn via `points_textured`.
+    ///
+    /// The buffers keep their capacity when the **Draw** is reset, so reserving up front avoids
+    /// repeated reallocation during the first frames of a sketch that draws many paths per frame.
+    /// See `Draw::reset` for details on when the buffers are truncated.
+    pub fn reserve_path_buffers(&self, events: usize, colored: usize, textured: usize) {
+        self.state
+            .borrow()
+            .intermediary_state
+            .borrow_mut()
+            .reserve_path_buffers(events, colored, textured);
+    }
+
     // Context changes.
 
     /// Produce a new **Draw** instance transformed by the given transform matrix.
@@ -204,6 +324,20 @@ impl Draw {
         self.context(context)
     }
 
+    /// View all drawings made via the returned **Draw** through the given camera.
+    ///
+    /// Drawings are described in world space and projected onto the window with perspective,
+    /// with nearer geometry occluding further geometry. Transforms applied before or after the
+    /// camera both apply in world space.
+    ///
+    /// Note that geometry is not clipped against the camera's near plane, so drawings should be
+    /// kept in front of the camera.
+    pub fn camera(&self, camera: &OrbitCamera) -> Self {
+        let mut context = self.context.clone();
+        context.camera = Some(*camera);
+        self.context(context)
+    }
+
     /// Translate the position of the origin by the given translation vector.
     pub fn translate(&self, v: Vec3) -> Self {
         self.transform(Mat4::from_translation(v))
@@ -395,9 +529,33 @@ impl Draw {
         self.context(context)
     }
 
-    /// Short-hand for `color_blend`, the common use-case.
-    pub fn blend(&self, blend_descriptor: wgpu::BlendComponent) -> Self {
-        self.color_blend(blend_descriptor)
+    /// Produce a new **Draw** instance that will draw with the given blending.
+    ///
+    /// Accepts either a **BlendMode** (e.g. `BlendMode::Additive`), which describes the blending
+    /// of both color and alpha, or a color **BlendComponent**, in which case this is short-hand
+    /// for `color_blend`. Primitives drawn with differing blending are rendered in separate
+    /// batches.
+    pub fn blend<B>(&self, blend: B) -> Self
+    where
+        B: SetBlend,
+    {
+        let mut context = self.context.clone();
+        blend.set_blend(&mut context.blend);
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance whose drawings write the given `id` to the pick buffer.
+    ///
+    /// When rendered to a window, the geometry of each pickable drawing is also rendered to an
+    /// offscreen ID texture, allowing the drawing beneath a point to be retrieved afterwards via
+    /// `App::pick_at`. Picking is based on geometry alone, so transparent areas of textures and
+    /// the spaces within glyphs are pickable too.
+    ///
+    /// The pick buffer is only rendered while at least one pickable drawing is submitted.
+    pub fn pickable(&self, id: u64) -> Self {
+        let mut context = self.context.clone();
+        context.pick_id = Some(id);
+        self.context(context)
     }
 
     /// Produce a new **Draw** instance that will be cropped to the given rectangle.
@@ -417,6 +575,31 @@ impl Draw {
         self.context(context)
     }
 
+    /// Produce a new **Draw** instance whose drawings are clipped to the given rectangle.
+    ///
+    /// Unlike `scissor`, the given `rect` is described in the current coordinate space of the
+    /// **Draw** and so is affected by any translation or scaling applied via `transform`,
+    /// `translate`, `scale`, etc. This makes it easy to clip drawing to a sub-region of a layout.
+    /// If the current transform includes a rotation, the drawing is clipped to the axis-aligned
+    /// bounds of the transformed rectangle.
+    ///
+    /// Clips nest: clipping an instance that is already clipped results in the overlap of the two.
+    /// Clipping only applies to drawings made via the returned instance (and instances produced
+    /// from it), so the original **Draw** remains unclipped.
+    pub fn clip(&self, rect: geom::Rect<f32>) -> Self {
+        let mut corners = rect.corners_iter().map(|[x, y]| {
+            self.context
+                .transform
+                .transform_point3(vec3(x, y, 0.0))
+                .truncate()
+        });
+        let first = corners.next().expect("a rect always has four corners");
+        let bounds = corners.fold(geom::Rect::from_corners(first, first), |bounds, p| {
+            bounds.stretch_to_point(p.into())
+        });
+        self.scissor(bounds)
+    }
+
     /// Produce a new **Draw** instance.
     ///
     /// All drawing that occurs on the new instance will be rendered as a "wireframe" between all
@@ -478,7 +661,8 @@ impl Draw {
 
     // Primitives.
 
-    /// Specify a color with which the background should be cleared.
+    /// Specify a color with which the background should be cleared, or an image to draw beneath
+    /// all other drawings.
     pub fn background(&self) -> Background {
         background::new(self)
     }
@@ -538,11 +722,30 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Begin drawing a **Star**.
+    ///
+    /// ```
+    /// # use splatter::prelude::*;
+    /// let draw = Draw::new();
+    /// draw.star().points(5).inner_radius(20.0).outer_radius(50.0).color(GOLD);
+    /// ```
+    pub fn star(&self) -> Drawing<'_, primitive::Star> {
+        self.a(Default::default())
+    }
+
     /// Begin drawing a **Triangle**.
     pub fn tri(&self) -> Drawing<primitive::Tri> {
         self.a(Default::default())
     }
 
+    /// Begin drawing a regular polygon with the given number of sides.
+    ///
+    /// The polygon is inscribed within its width and height, with its first vertex at `0.0`
+    /// radians. `sides` is clamped to a minimum of `3`.
+    pub fn ngon(&self, sides: u32) -> Drawing<'_, primitive::Ngon> {
+        self.a(primitive::Ngon::new(sides))
+    }
+
     /// Begin drawing a **Polygon**.
     pub fn polygon(&self) -> Drawing<primitive::PolygonInit> {
         self.a(Default::default())
@@ -553,6 +756,52 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Draw a dot with the given radius at each of the given points.
+    ///
+    /// All dots are built into a single **Mesh**, making this far cheaper than drawing an ellipse
+    /// per point for large point clouds. Each dot is a small regular polygon, indistinguishable
+    /// from a circle at the radii typical of point clouds.
+    ///
+    /// ```
+    /// # use splatter::prelude::*;
+    /// let draw = Draw::new();
+    /// let points = (0..1000).map(|i| pt2(i as f32, (i as f32 * 0.1).sin() * 50.0));
+    /// draw.points(points, 1.5).color(WHITE);
+    /// ```
+    pub fn points<I>(&self, points: I, radius: f32) -> Drawing<primitive::Mesh>
+    where
+        I: IntoIterator,
+        I::Item: Into<Point2>,
+    {
+        let offsets = dot_offsets(radius);
+        let points: Vec<Point2> = points.into_iter().map(Into::into).collect();
+        let vertices = points
+            .iter()
+            .flat_map(|&p| offsets.iter().map(move |&o| (p + o).extend(0.0)));
+        self.mesh().indexed(vertices, dot_indices(points.len()))
+    }
+
+    /// Draw a dot with the given radius at each of the given points, each with its own color.
+    ///
+    /// See `points` for details.
+    pub fn points_colored<I, P, C>(&self, points: I, radius: f32) -> Drawing<primitive::Mesh>
+    where
+        I: IntoIterator<Item = (P, C)>,
+        P: Into<Point2>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        let offsets = dot_offsets(radius);
+        let points: Vec<(Point2, Color)> = points
+            .into_iter()
+            .map(|(p, c)| (p.into(), c.into_lin_srgba()))
+            .collect();
+        let vertices = points
+            .iter()
+            .flat_map(|&(p, c)| offsets.iter().map(move |&o| ((p + o).extend(0.0), c)));
+        self.mesh()
+            .indexed_colored(vertices, dot_indices(points.len()))
+    }
+
     /// Begin drawing a **Polyline**.
     ///
     /// Note that this is simply short-hand for `draw.path().stroke()`
@@ -576,6 +825,107 @@ impl Draw {
         self.a(primitive::Texture::new(view))
     }
 
+    /// Begin drawing a layer of the given texture array.
+    ///
+    /// The layer is specified via `array_layer`, e.g. `draw.texture_array(&array).array_layer(i)`.
+    /// The whole array is bound at once, so drawings of differing layers of the same array may be
+    /// drawn together within a single draw call. This is useful for sprite atlases with many
+    /// layers.
+    ///
+    /// The texture must be two-dimensional. A texture with a single layer is drawn as an array
+    /// with one layer.
+    pub fn texture_array(&self, array: &wgpu::Texture) -> Drawing<primitive::Texture> {
+        let view = array
+            .view()
+            .dimension(wgpu::TextureViewDimension::D2Array)
+            .build();
+        self.a(primitive::Texture::new(&view))
+    }
+
+    /// Begin drawing the frame of the given sprite sheet shown at the given time in seconds.
+    ///
+    /// The drawing is sized to a single frame of the sheet by default. This is short-hand for
+    /// drawing the sheet's texture with its `area` set to `sheet.frame_at(secs)`.
+    pub fn sprite(
+        &self,
+        sheet: &crate::sprite::SpriteSheet,
+        secs: f32,
+    ) -> Drawing<primitive::Texture> {
+        let size = sheet.frame_size();
+        self.texture(sheet.texture_view())
+            .area(sheet.frame_at(secs))
+            .w_h(size.x, size.y)
+    }
+
+    /// Begin drawing a rect with the given WGSL fragment shader.
+    ///
+    /// By default, the rect covers the whole frame. The shader must declare a `@fragment fn
+    /// main` returning the color at `@location(0)`, and may accept any subset of the `color`,
+    /// `tex_coords` and `mode` vertex outputs at locations `0`, `1` and `2` respectively. The
+    /// texture coordinates range from `[0.0, 0.0]` at the top left of the rect to `[1.0, 1.0]` at
+    /// the bottom right.
+    ///
+    /// The following uniform block is made available to the shader:
+    ///
+    /// ```wgsl
+    /// struct Inputs {
+    ///     resolution: vec2<f32>,
+    ///     mouse: vec2<f32>,
+    ///     time: f32,
+    /// };
+    /// @group(1) @binding(0)
+    /// var<uniform> inputs: Inputs;
+    /// ```
+    ///
+    /// where `resolution` is the size of the frame in pixels. When drawing via `to_frame`, `time`
+    /// and `mouse` are `app.time` and `app.mouse.position()` unless specified via the `time` and
+    /// `mouse` methods, otherwise they default to zero. Data provided via `uniforms` may be
+    /// accessed by declaring a uniform variable at `@group(1) @binding(1)`.
+    ///
+    /// The shader is compiled once and cached by the renderer for as long as it continues to be
+    /// drawn each frame.
+    pub fn shader(&self, source: &str) -> Drawing<primitive::Shader> {
+        self.a(primitive::Shader::new(source))
+    }
+
+    /// Tessellate the drawings made via the given function once, producing a **Baked** drawing
+    /// that may be redrawn each frame via `Draw::baked` without re-tessellating.
+    ///
+    /// The **Draw** passed to the function shares the theme of this **Draw**, but begins with
+    /// the default context. Transforms applied within the function are baked into the vertices,
+    /// while other context such as a camera, blend mode or scissor is ignored and should instead
+    /// be applied to the **Draw** via which the baked drawing is drawn. Text is not yet supported
+    /// and is omitted from the baked drawing.
+    ///
+    /// ```
+    /// # use splatter::prelude::*;
+    /// let draw = Draw::new();
+    /// let background = draw.bake(|draw| {
+    ///     let points = (0..100).map(|i| pt2(i as f32, (i as f32 * 0.1).sin() * 50.0));
+    ///     draw.polyline().weight(2.0).points(points);
+    /// });
+    /// draw.rotate(0.5).baked(&background);
+    /// ```
+    pub fn bake<F>(&self, f: F) -> primitive::Baked
+    where
+        F: FnOnce(&Draw),
+    {
+        let draw = Draw::new();
+        draw.state.borrow_mut().theme = self.state.borrow().theme.clone();
+        f(&draw);
+        primitive::Baked::new(&draw)
+    }
+
+    /// Draw a **Baked** drawing produced by `Draw::bake`.
+    ///
+    /// The baked vertices are transformed by the context of this **Draw** without being
+    /// re-tessellated.
+    pub fn baked(&self, baked: &primitive::Baked) {
+        for part in baked.parts() {
+            self.a(part);
+        }
+    }
+
     /// Finish any drawings-in-progress and produce an iterator draining the inner draw commands
     /// and yielding them by value.
     pub fn drain_commands(&self) -> impl Iterator<Item = DrawCommand> {
@@ -583,15 +933,84 @@ impl Draw {
         let cmds = {
             let mut state = self.state.borrow_mut();
             let empty = Vec::with_capacity(state.draw_commands.len());
-            std::mem::replace(&mut state.draw_commands, empty)
+            let cmds = std::mem::replace(&mut state.draw_commands, empty);
+            let cmds = cmds.into_iter().enumerate();
+            let cmds = cmds.filter_map(|(i, cmd)| cmd.map(|cmd| (i, cmd)));
+            if state.layers.is_empty() {
+                cmds.map(|(_, cmd)| cmd).collect()
+            } else {
+                let sorted = state.sort_by_layer(cmds);
+                state.layers.clear();
+                sorted
+            }
         };
-        cmds.into_iter().filter_map(|opt| opt)
+        cmds.into_iter()
     }
 
     /// Drain any remaining `drawing`s and convert them to draw commands.
     pub fn finish_remaining_drawings(&self) {
         self.state.borrow_mut().finish_remaining_drawings()
     }
+
+    /// Snapshot the primitives drawn so far in a serializable form.
+    ///
+    /// Unlike rendering to a frame, this leaves the draw commands intact, so a `view` may record
+    /// its drawing before rendering it as usual. See **DrawCommands** for what is recorded.
+    ///
+    /// ```
+    /// # use splatter::prelude::*;
+    /// let draw = Draw::new();
+    /// draw.ellipse().w_h(20.0, 20.0).color(STEELBLUE);
+    /// let expected = draw.record();
+    ///
+    /// let other = Draw::new();
+    /// other.ellipse().w_h(20.0, 20.0).color(STEELBLUE);
+    /// assert_eq!(other.record(), expected);
+    /// ```
+    pub fn record(&self) -> DrawCommands {
+        record::record(self)
+    }
+
+    /// Draw the primitives of the given **DrawCommands** produced by `Draw::record`.
+    ///
+    /// Each recorded primitive is drawn as a colored mesh via the context of this **Draw**, and
+    /// the recorded background color (if any) is applied.
+    pub fn replay(&self, commands: &DrawCommands) {
+        record::replay(self, commands)
+    }
+
+    /// Produce an SVG document from the primitives drawn so far.
+    ///
+    /// The given `view` describes the region of the **Draw**'s coordinate space covered by the
+    /// document, e.g. `app.window_rect()`. Paths and polygons are written from their original
+    /// path events, preserving curves rather than the triangles produced by tessellation.
+    ///
+    /// Unlike rendering to a frame, this leaves the draw commands intact. Arrows, meshes, text and
+    /// textures are not yet supported and are omitted from the document. Per-vertex colors are
+    /// replaced by the primitive's color.
+    pub fn to_svg(&self, view: geom::Rect) -> String {
+        self.finish_remaining_drawings();
+        let state = self.state.borrow();
+        svg::document(&state, view)
+    }
+}
+
+impl SetBlend for wgpu::BlendComponent {
+    fn set_blend(self, blend: &mut wgpu::BlendState) {
+        blend.color = self;
+    }
+}
+
+impl SetBlend for wgpu::BlendState {
+    fn set_blend(self, blend: &mut wgpu::BlendState) {
+        *blend = self;
+    }
+}
+
+impl SetBlend for wgpu::blend::BlendMode {
+    fn set_blend(self, blend: &mut wgpu::BlendState) {
+        *blend = self.blend_state();
+    }
 }
 
 impl Default for IntermediaryState {
@@ -600,12 +1019,14 @@ impl Default for IntermediaryState {
         let path_event_buffer = Default::default();
         let path_points_colored_buffer = Default::default();
         let path_points_textured_buffer = Default::default();
+        let path_points_textured_colored_buffer = Default::default();
         let text_buffer = Default::default();
         IntermediaryState {
             intermediary_mesh,
             path_event_buffer,
             path_points_colored_buffer,
             path_points_textured_buffer,
+            path_points_textured_colored_buffer,
             text_buffer,
         }
     }
@@ -615,17 +1036,21 @@ impl Default for State {
     fn default() -> Self {
         let last_draw_context = None;
         let background_color = Default::default();
+        let background_image = None;
         let draw_commands = Default::default();
         let drawing = Default::default();
+        let layers = Default::default();
         let intermediary_state = RefCell::new(Default::default());
         let theme = Default::default();
         State {
             last_draw_context,
             draw_commands,
+            layers,
             drawing,
             intermediary_state,
             theme,
             background_color,
+            background_image,
         }
     }
 }
@@ -642,6 +1067,7 @@ impl Default for Context {
     fn default() -> Self {
         Self {
             transform: Mat4::IDENTITY,
+            camera: None,
             blend: wgpu::BlendState {
                 color: wgpu::RenderPipelineBuilder::DEFAULT_COLOR_BLEND,
                 alpha: wgpu::RenderPipelineBuilder::DEFAULT_ALPHA_BLEND,
@@ -649,6 +1075,107 @@ impl Default for Context {
             scissor: Scissor::Full,
             topology: wgpu::RenderPipelineBuilder::DEFAULT_PRIMITIVE_TOPOLOGY,
             sampler: wgpu::SamplerBuilder::new().into_descriptor(),
+            pick_id: None,
         }
     }
 }
+
+// The number of vertices in each of the dots drawn by `Draw::points`.
+const DOT_RESOLUTION: usize = 8;
+
+// The offset of each vertex of a dot with the given radius from its center.
+fn dot_offsets(radius: f32) -> [Vec2; DOT_RESOLUTION] {
+    let mut offsets = [Vec2::ZERO; DOT_RESOLUTION];
+    for (i, offset) in offsets.iter_mut().enumerate() {
+        let radians = i as f32 * std::f32::consts::TAU / DOT_RESOLUTION as f32;
+        *offset = Vec2::new(radians.cos(), radians.sin()) * radius;
+    }
+    offsets
+}
+
+// The indices triangulating the given number of dots as triangle fans.
+fn dot_indices(count: usize) -> impl Iterator<Item = usize> {
+    (0..count).flat_map(|dot| {
+        let start = dot * DOT_RESOLUTION;
+        (1..DOT_RESOLUTION - 1).flat_map(move |i| [start, start + i, start + i + 1])
+    })
+}
+
+#[test]
+fn test_nested_clip_intersects() {
+    let draw = Draw::new();
+    let clipped = draw
+        .clip(geom::Rect::from_w_h(100.0, 100.0))
+        .translate(vec3(50.0, 0.0, 0.0))
+        .clip(geom::Rect::from_w_h(40.0, 200.0));
+    let expected = geom::Rect::from_corner_points([30.0, -50.0], [50.0, 50.0]);
+    assert_eq!(clipped.context.scissor, Scissor::Rect(expected));
+    assert_eq!(draw.context.scissor, Scissor::Full);
+}
+
+#[test]
+fn test_nested_transforms_compose() {
+    let draw = Draw::new();
+    let group = draw
+        .translate(vec3(100.0, 0.0, 0.0))
+        .rotate(std::f32::consts::FRAC_PI_2)
+        .scale(2.0);
+    let p = group
+        .context
+        .transform
+        .transform_point3(vec3(1.0, 0.0, 0.0));
+    assert!(p.abs_diff_eq(vec3(100.0, 2.0, 0.0), 1e-4));
+    assert_eq!(draw.context.transform, Mat4::IDENTITY);
+}
+
+#[test]
+fn test_layers_sort_primitives_stably() {
+    let draw = Draw::new();
+    draw.ellipse().layer(1);
+    draw.x(10.0).rect();
+    draw.tri().layer(-1);
+    draw.line().layer(1);
+    let cmds: Vec<_> = draw.drain_commands().collect();
+    let kinds: Vec<_> = cmds
+        .iter()
+        .map(|cmd| match cmd {
+            DrawCommand::Context(ctxt) => match ctxt.transform == Mat4::IDENTITY {
+                true => "identity",
+                false => "translated",
+            },
+            DrawCommand::Primitive(Primitive::Tri(_)) => "tri",
+            DrawCommand::Primitive(Primitive::Rect(_)) => "rect",
+            DrawCommand::Primitive(Primitive::Ellipse(_)) => "ellipse",
+            DrawCommand::Primitive(Primitive::Line(_)) => "line",
+            DrawCommand::Primitive(_) => "other",
+        })
+        .collect();
+    let expected = ["tri", "translated", "rect", "identity", "ellipse", "line"];
+    assert_eq!(kinds, expected);
+}
+
+#[test]
+fn test_reset_retains_path_buffer_capacity() {
+    let draw = Draw::new();
+    draw.reserve_path_buffers(256, 64, 32);
+    draw.polyline()
+        .points((0..100).map(|i| geom::pt2(i as f32, 0.0)));
+    draw.reset();
+    let state = draw.state.borrow();
+    let intermediary_state = state.intermediary_state.borrow();
+    assert!(intermediary_state.path_event_buffer.is_empty());
+    assert!(intermediary_state.path_event_buffer.capacity() >= 256);
+    assert!(intermediary_state.path_points_colored_buffer.capacity() >= 64);
+    assert!(intermediary_state.path_points_textured_buffer.capacity() >= 32);
+}
+
+#[test]
+fn test_dot_indices() {
+    let indices: Vec<_> = dot_indices(2).collect();
+    assert_eq!(indices.len(), 2 * (DOT_RESOLUTION - 2) * 3);
+    assert_eq!(&indices[..6], &[0, 1, 2, 0, 2, 3]);
+    let second = (DOT_RESOLUTION - 2) * 3;
+    let start = DOT_RESOLUTION;
+    assert_eq!(&indices[second..second + 3], &[start, start + 1, start + 2]);
+    assert_eq!(*indices.iter().max().unwrap(), 2 * DOT_RESOLUTION - 1);
+}
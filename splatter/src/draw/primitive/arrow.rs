@@ -17,12 +17,18 @@ pub struct Arrow {
     line: Line,
     head_length: Option<f32>,
     head_width: Option<f32>,
+    head_stroked: bool,
 }
 
 /// The drawing context for a line.
 pub type DrawingArrow<'a> = Drawing<'a, Arrow>;
 
 impl Arrow {
+    /// The maximum length of the arrow head as a fraction of the length of the whole arrow.
+    ///
+    /// This ensures that very short arrows still show a portion of their shaft.
+    pub const MAX_HEAD_LENGTH_FRACTION: f32 = 0.5;
+
     /// Short-hand for the `stroke_weight` method.
     pub fn weight(self, weight: f32) -> Self {
         self.map_line(|l| l.weight(weight))
@@ -52,7 +58,8 @@ impl Arrow {
     ///
     /// By default, this is equal to `weight * 4.0`.
     ///
-    /// This value will be clamped to the length of the line itself.
+    /// This value will be clamped to `MAX_HEAD_LENGTH_FRACTION` of the length of the arrow, in
+    /// which case the head width is scaled down by the same amount to preserve its shape.
     pub fn head_length(mut self, length: f32) -> Self {
         self.head_length = Some(length);
         self
@@ -66,6 +73,18 @@ impl Arrow {
         self
     }
 
+    /// Fill the arrow head. This is the default.
+    pub fn head_filled(mut self) -> Self {
+        self.head_stroked = false;
+        self
+    }
+
+    /// Outline the arrow head using the same stroke options as the shaft, rather than filling it.
+    pub fn head_stroked(mut self) -> Self {
+        self.head_stroked = true;
+        self
+    }
+
     // Map the inner `PathStroke<S>` using the given function.
     fn map_line<F>(self, map: F) -> Self
     where
@@ -75,12 +94,14 @@ impl Arrow {
             line,
             head_length,
             head_width,
+            head_stroked,
         } = self;
         let line = map(line);
         Arrow {
             line,
             head_length,
             head_width,
+            head_stroked,
         }
     }
 }
@@ -115,7 +136,8 @@ impl<'a> DrawingArrow<'a> {
     ///
     /// By default, this is equal to `weight * 4.0`.
     ///
-    /// This value will be clamped to the length of the line itself.
+    /// This value will be clamped to `MAX_HEAD_LENGTH_FRACTION` of the length of the arrow, in
+    /// which case the head width is scaled down by the same amount to preserve its shape.
     pub fn head_length(self, length: f32) -> Self {
         self.map_ty(|ty| ty.head_length(length))
     }
@@ -126,6 +148,16 @@ impl<'a> DrawingArrow<'a> {
     pub fn head_width(self, width: f32) -> Self {
         self.map_ty(|ty| ty.head_width(width))
     }
+
+    /// Fill the arrow head. This is the default.
+    pub fn head_filled(self) -> Self {
+        self.map_ty(|ty| ty.head_filled())
+    }
+
+    /// Outline the arrow head using the same stroke options as the shaft, rather than filling it.
+    pub fn head_stroked(self) -> Self {
+        self.map_ty(|ty| ty.head_stroked())
+    }
 }
 
 impl SetStroke for Arrow {
@@ -177,6 +209,7 @@ impl draw::renderer::RenderPrimitive for Arrow {
             line,
             head_length,
             head_width,
+            head_stroked,
         } = self;
         let start = line.start.unwrap_or(pt2(0.0, 0.0));
         let end = line.end.unwrap_or(pt2(0.0, 0.0));
@@ -191,7 +224,11 @@ impl draw::renderer::RenderPrimitive for Arrow {
         let head_length = head_length.unwrap_or(line_w_4);
         let line_dir = end - start;
         let line_dir_len = line_dir.length();
-        let tri_len = head_length.min(line_dir_len);
+        let tri_len = head_length.min(line_dir_len * Arrow::MAX_HEAD_LENGTH_FRACTION);
+        let head_width = match head_length > tri_len {
+            true => head_width * tri_len / head_length,
+            false => head_width,
+        };
         let tri_dir_norm = line_dir.normalize() * tri_len;
         let tri_start = end - tri_dir_norm;
         let tri_end = end;
@@ -214,11 +251,15 @@ impl draw::renderer::RenderPrimitive for Arrow {
         let tri_points = tri_points.iter().cloned().map(|p| p.to_array().into());
         let close_tri = true;
         let tri_events = lyon::path::iterator::FromPolyline::new(close_tri, tri_points);
+        let tri_options = match head_stroked {
+            true => path::Options::Stroke(line.path.opts),
+            false => path::Options::Fill(Default::default()),
+        };
         path::render_path_events(
             tri_events,
             line.path.color,
             transform,
-            path::Options::Fill(Default::default()),
+            tri_options,
             &ctxt.theme,
             &draw::theme::Primitive::Arrow,
             &mut ctxt.fill_tessellator,
@@ -254,10 +295,12 @@ impl Default for Arrow {
         let line = Default::default();
         let head_length = Default::default();
         let head_width = Default::default();
+        let head_stroked = false;
         Arrow {
             line,
             head_length,
             head_width,
+            head_stroked,
         }
     }
 }
@@ -0,0 +1,142 @@
+use crate::draw::primitive::shader::Material;
+use crate::draw::primitive::Primitive;
+use crate::draw::renderer::{PrimitiveRender, RenderContext, RenderPrimitive, VertexMode};
+use crate::draw::{self, Draw};
+use crate::wgpu;
+use std::sync::Arc;
+
+/// A drawing that has been tessellated once via `Draw::bake`.
+///
+/// Redrawing a **Baked** drawing via `Draw::baked` only transforms and copies its vertices,
+/// skipping tessellation entirely. This is useful for static geometry that is redrawn every
+/// frame, e.g. a complex background. Cloning a **Baked** drawing is cheap.
+#[derive(Clone, Debug)]
+pub struct Baked {
+    parts: Arc<Vec<Part>>,
+}
+
+/// A single part of a **Baked** drawing, as submitted to the **Draw** by `Draw::baked`.
+#[derive(Clone, Debug)]
+pub struct BakedPart {
+    baked: Baked,
+    index: usize,
+}
+
+// A run of consecutive baked primitives that share a vertex mode, texture, texture layer and
+// address mode.
+//
+// Primitives drawn with a user shader are always baked into their own part.
+#[derive(Debug)]
+struct Part {
+    mesh: draw::Mesh,
+    vertex_mode: VertexMode,
+    texture_view: Option<wgpu::TextureView>,
+    texture_layer: u32,
+    address_mode: Option<wgpu::AddressMode>,
+    shader: Option<Material>,
+}
+
+impl Baked {
+    /// Tessellate and drain the drawings of the given **Draw**.
+    pub(crate) fn new(draw: &Draw) -> Self {
+        let mut parts: Vec<Part> = vec![];
+        draw::renderer::tessellate(draw, |mesh, render| {
+            let PrimitiveRender {
+                texture_view,
+                vertex_mode,
+                texture_layer,
+                address_mode,
+                shader,
+            } = render;
+            let view_id = texture_view.as_ref().map(|view| view.id());
+            match parts.last_mut() {
+                Some(part)
+                    if part.vertex_mode == vertex_mode
+                        && part.texture_view.as_ref().map(|view| view.id()) == view_id
+                        && part.texture_layer == texture_layer
+                        && part.address_mode == address_mode
+                        && part.shader.is_none()
+                        && shader.is_none() =>
+                {
+                    extend(&mut part.mesh, mesh);
+                }
+                _ => parts.push(Part {
+                    mesh: mesh.clone(),
+                    vertex_mode,
+                    texture_view,
+                    texture_layer,
+                    address_mode,
+                    shader,
+                }),
+            }
+        });
+        let parts = Arc::new(parts);
+        Baked { parts }
+    }
+
+    /// The total number of vertices within the baked drawing.
+    pub fn vertex_count(&self) -> usize {
+        self.parts.iter().map(|part| part.mesh.vertex_count()).sum()
+    }
+
+    /// Produce a primitive for each part of the baked drawing.
+    pub(crate) fn parts(&self) -> impl Iterator<Item = BakedPart> + '_ {
+        (0..self.parts.len()).map(move |index| BakedPart {
+            baked: self.clone(),
+            index,
+        })
+    }
+}
+
+impl RenderPrimitive for BakedPart {
+    fn render_primitive(self, ctxt: RenderContext, mesh: &mut draw::Mesh) -> PrimitiveRender {
+        let part = &self.baked.parts[self.index];
+        let transform = *ctxt.transform;
+        let offset = mesh.raw_vertex_count() as u32;
+        let points = part.mesh.points().iter();
+        let colors = part.mesh.colors().iter();
+        let tex_coords = part.mesh.tex_coords().iter();
+        let vertices = points
+            .zip(colors)
+            .zip(tex_coords)
+            .map(|((&p, &c), &t)| draw::mesh::vertex::new(transform.project_point3(p), c, t));
+        let indices = part.mesh.indices().iter().map(|i| offset + i);
+        mesh.extend(vertices, indices);
+        PrimitiveRender {
+            texture_view: part.texture_view.clone(),
+            vertex_mode: part.vertex_mode,
+            texture_layer: part.texture_layer,
+            address_mode: part.address_mode,
+            shader: part.shader.clone(),
+        }
+    }
+}
+
+impl From<BakedPart> for Primitive {
+    fn from(prim: BakedPart) -> Self {
+        Primitive::Baked(prim)
+    }
+}
+
+impl From<Primitive> for Option<BakedPart> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Baked(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
+
+// Extend the mesh with the vertices and indices of the given mesh.
+fn extend(mesh: &mut draw::Mesh, other: &draw::Mesh) {
+    let offset = mesh.raw_vertex_count() as u32;
+    let points = other.points().iter();
+    let colors = other.colors().iter();
+    let tex_coords = other.tex_coords().iter();
+    let vertices = points
+        .zip(colors)
+        .zip(tex_coords)
+        .map(|((&p, &c), &t)| draw::mesh::vertex::new(p, c, t));
+    let indices = other.indices().iter().map(|i| offset + i);
+    mesh.extend(vertices, indices);
+}
@@ -8,23 +8,40 @@ use crate::draw::properties::{
 };
 use crate::draw::Drawing;
 use crate::geom;
-use crate::glam::Vec2;
+use crate::glam::{Mat4, Vec2, Vec3};
 use lyon::tessellation::StrokeOptions;
 
 /// Properties related to drawing an **Ellipse**.
 #[derive(Clone, Debug, Default)]
 pub struct Ellipse {
     dimensions: spatial::dimension::Properties,
-    resolution: Option<f32>,
+    resolution: Option<Resolution>,
     polygon: PolygonInit,
 }
 
+// The way in which the number of sides of an **Ellipse** is determined.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Resolution {
+    // A fixed number of sides.
+    Sides(f32),
+    // The number of sides required for edges of roughly the given length on screen.
+    Auto { edge_length: f32 },
+}
+
 /// The drawing context for an ellipse.
 pub type DrawingEllipse<'a> = Drawing<'a, Ellipse>;
 
 // Ellipse-specific methods.
 
 impl Ellipse {
+    /// The default target length of each edge in points when automatically determining the
+    /// resolution.
+    pub const DEFAULT_EDGE_LENGTH: f32 = 4.0;
+    /// The minimum number of sides used when automatically determining the resolution.
+    pub const MIN_AUTO_RESOLUTION: f32 = 8.0;
+    /// The maximum number of sides used when automatically determining the resolution.
+    pub const MAX_AUTO_RESOLUTION: f32 = 1024.0;
+
     /// Stroke the outline with the given color.
     pub fn stroke<C>(self, color: C) -> Self
     where
@@ -41,14 +58,56 @@ impl Ellipse {
 
     /// The number of sides used to draw the ellipse.
     ///
-    /// By default, ellipse does not use a resolution, but rather uses a stroke tolerance to
-    /// determine how many vertices to use during tessellation.
+    /// By default, the number of sides is determined automatically from the size of the ellipse
+    /// on screen. See `auto_resolution`.
     pub fn resolution(mut self, resolution: f32) -> Self {
-        self.resolution = Some(resolution);
+        self.resolution = Some(Resolution::Sides(resolution));
+        self
+    }
+
+    /// Determine the number of sides from the radius of the ellipse on screen, such that each
+    /// edge is roughly `DEFAULT_EDGE_LENGTH` points long.
+    ///
+    /// The radius on screen accounts for the transform of the **Draw**, so scaled up ellipses
+    /// remain smooth while small ellipses use fewer triangles. The number of sides is clamped to
+    /// the range `MIN_AUTO_RESOLUTION..=MAX_AUTO_RESOLUTION`. This is the default.
+    pub fn auto_resolution(self) -> Self {
+        self.edge_length(Self::DEFAULT_EDGE_LENGTH)
+    }
+
+    /// Determine the number of sides automatically as in `auto_resolution`, but targeting edges
+    /// of the given length in points.
+    pub fn edge_length(mut self, edge_length: f32) -> Self {
+        self.resolution = Some(Resolution::Auto { edge_length });
         self
     }
 }
 
+impl Resolution {
+    // The number of sides for an ellipse with the given radii under the given transform.
+    fn sides(self, radii: Vec2, transform: &Mat4) -> f32 {
+        match self {
+            Resolution::Sides(sides) => sides,
+            Resolution::Auto { edge_length } => {
+                let centre = transform.project_point3(Vec3::ZERO);
+                let rx = transform.project_point3(Vec3::new(radii.x, 0.0, 0.0)) - centre;
+                let ry = transform.project_point3(Vec3::new(0.0, radii.y, 0.0)) - centre;
+                let radius = rx.truncate().length().max(ry.truncate().length());
+                let sides = (std::f32::consts::TAU * radius / edge_length).ceil();
+                sides.clamp(Ellipse::MIN_AUTO_RESOLUTION, Ellipse::MAX_AUTO_RESOLUTION)
+            }
+        }
+    }
+}
+
+impl Default for Resolution {
+    fn default() -> Self {
+        Resolution::Auto {
+            edge_length: Ellipse::DEFAULT_EDGE_LENGTH,
+        }
+    }
+}
+
 // Trait implementations.
 
 impl draw::renderer::RenderPrimitive for Ellipse {
@@ -72,43 +131,52 @@ impl draw::renderer::RenderPrimitive for Ellipse {
 
         let w = maybe_x.map(f32::abs).unwrap_or(100.0);
         let h = maybe_y.map(f32::abs).unwrap_or(100.0);
-        match resolution {
-            None => {
-                // Determine the transform to apply to all points.
-                let radii = lyon::math::vector(w * 0.5, h * 0.5);
-                if radii.square_length() > 0.0 {
-                    let centre = lyon::math::point(0.0, 0.0);
-                    let mut builder = lyon::path::Path::svg_builder();
-                    let sweep_angle = lyon::math::Angle::radians(std::f32::consts::PI * 2.0);
-                    let x_rotation = lyon::math::Angle::radians(0.0);
-                    let start = lyon::math::point(w * 0.5, 0.0);
-                    builder.move_to(start);
-                    builder.arc(centre, radii, sweep_angle, x_rotation);
-                    let path = builder.build();
-                    polygon::render_events_themed(
-                        polygon.opts,
-                        || (&path).into_iter(),
-                        ctxt,
-                        &draw::theme::Primitive::Ellipse,
-                        mesh,
-                    );
-                }
+        if w > 0.0 && h > 0.0 {
+            let radii = Vec2::new(w * 0.5, h * 0.5);
+            let resolution = resolution.unwrap_or_default().sides(radii, ctxt.transform);
+            let rect = geom::Rect::from_w_h(w, h);
+            let ellipse = geom::Ellipse::new(rect, resolution);
+            let points = ellipse.circumference().map(Vec2::from);
+            polygon::render_points_themed(
+                polygon.opts,
+                points,
+                ctxt,
+                &draw::theme::Primitive::Ellipse,
+                mesh,
+            );
+        }
+
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+impl draw::svg::SvgPrimitive for Ellipse {
+    fn svg_primitive(&self, ctxt: &draw::svg::SvgContext, svg: &mut String) {
+        let w = self.dimensions.x.map(f32::abs).unwrap_or(100.0);
+        let h = self.dimensions.y.map(f32::abs).unwrap_or(100.0);
+        let (name, geometry) = match self.resolution {
+            None | Some(Resolution::Auto { .. }) if w == h => {
+                ("circle", format!(r#"r="{}""#, w * 0.5))
+            }
+            None | Some(Resolution::Auto { .. }) => {
+                ("ellipse", format!(r#"rx="{}" ry="{}""#, w * 0.5, h * 0.5))
             }
-            Some(resolution) => {
+            Some(Resolution::Sides(resolution)) => {
                 let rect = geom::Rect::from_w_h(w, h);
                 let ellipse = geom::Ellipse::new(rect, resolution);
                 let points = ellipse.circumference().map(Vec2::from);
-                polygon::render_points_themed(
-                    polygon.opts,
-                    points,
-                    ctxt,
-                    &draw::theme::Primitive::Ellipse,
-                    mesh,
-                );
+                let d = draw::svg::points_data(points, true);
+                ("path", format!(r#"d="{}""#, d))
             }
-        }
-
-        draw::renderer::PrimitiveRender::default()
+        };
+        draw::svg::write_polygon(
+            svg,
+            ctxt,
+            &self.polygon.opts,
+            &draw::theme::Primitive::Ellipse,
+            name,
+            &geometry,
+        );
     }
 }
 
@@ -185,4 +253,39 @@ impl<'a> DrawingEllipse<'a> {
     pub fn resolution(self, resolution: f32) -> Self {
         self.map_ty(|ty| ty.resolution(resolution))
     }
+
+    /// Determine the number of sides from the radius of the ellipse on screen.
+    ///
+    /// See `Ellipse::auto_resolution` for details. This is the default.
+    pub fn auto_resolution(self) -> Self {
+        self.map_ty(|ty| ty.auto_resolution())
+    }
+
+    /// Determine the number of sides automatically, targeting edges of the given length in
+    /// points.
+    pub fn edge_length(self, edge_length: f32) -> Self {
+        self.map_ty(|ty| ty.edge_length(edge_length))
+    }
+}
+
+#[test]
+fn test_auto_resolution_scales_with_screen_radius() {
+    let auto = Resolution::default();
+    let radii = Vec2::new(100.0, 50.0);
+    let sides = auto.sides(radii, &Mat4::IDENTITY);
+    assert_eq!(
+        sides,
+        (std::f32::consts::TAU * 100.0 / Ellipse::DEFAULT_EDGE_LENGTH).ceil()
+    );
+    // Scaling up on screen increases the number of sides, up to the maximum.
+    let scaled = auto.sides(radii, &Mat4::from_scale(Vec3::splat(2.0)));
+    assert!(scaled > sides);
+    let huge = auto.sides(radii, &Mat4::from_scale(Vec3::splat(100.0)));
+    assert_eq!(huge, Ellipse::MAX_AUTO_RESOLUTION);
+    // Tiny ellipses use the minimum.
+    let tiny = auto.sides(Vec2::new(0.5, 0.5), &Mat4::IDENTITY);
+    assert_eq!(tiny, Ellipse::MIN_AUTO_RESOLUTION);
+    // A fixed resolution ignores the transform.
+    let fixed = Resolution::Sides(5.0).sides(radii, &Mat4::from_scale(Vec3::splat(2.0)));
+    assert_eq!(fixed, 5.0);
 }
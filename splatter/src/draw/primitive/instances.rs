@@ -0,0 +1,102 @@
+use crate::color::conv::IntoLinSrgba;
+use crate::draw::mesh::vertex::DEFAULT_VERTEX_COLOR;
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::{ColorScalar, LinSrgba};
+use crate::draw::Drawing;
+use crate::glam::Mat4;
+
+/// A primitive drawn once for each of a list of **Instance**s.
+///
+/// The base primitive is only tessellated once. Each instance applies its own transform and color
+/// to the resulting mesh on the GPU, so that all instances are submitted with a single draw call.
+/// When drawing via a camera, the instances are instead transformed on the CPU.
+///
+/// Instance colors are not applied to textured primitives.
+#[derive(Clone, Debug)]
+pub struct Instances {
+    base: Box<Primitive>,
+    instances: Vec<Instance>,
+}
+
+/// The transform and color applied to a single instance of an **Instances** primitive.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Instance {
+    /// Applied to the base primitive prior to the transform of the **Draw** instance.
+    pub transform: Mat4,
+    /// Multiplied with the colors of the base primitive.
+    pub color: LinSrgba,
+}
+
+/// The drawing context for **Instances**.
+pub type DrawingInstances<'a> = Drawing<'a, Instances>;
+
+impl Instances {
+    /// Instance the given primitive once for each of the given transforms.
+    pub fn new<I>(base: Primitive, transforms: I) -> Self
+    where
+        I: IntoIterator<Item = Mat4>,
+    {
+        let base = Box::new(base);
+        let instances = transforms.into_iter().map(Instance::new).collect();
+        Instances { base, instances }
+    }
+
+    /// Specify the color of each instance, in the same order as the transforms.
+    ///
+    /// The colors are multiplied with the colors of the base primitive, so a base primitive drawn
+    /// in white takes on the color of each instance. Instances without a corresponding color are
+    /// left unchanged.
+    pub fn colors<I, C>(mut self, colors: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        for (instance, color) in self.instances.iter_mut().zip(colors) {
+            instance.color = color.into_lin_srgba();
+        }
+        self
+    }
+
+    /// The base primitive along with the instances to draw.
+    pub(crate) fn into_parts(self) -> (Primitive, Vec<Instance>) {
+        (*self.base, self.instances)
+    }
+}
+
+impl Instance {
+    /// An instance with the given transform and the base primitive's colors.
+    pub fn new(transform: Mat4) -> Self {
+        let color = DEFAULT_VERTEX_COLOR;
+        Instance { transform, color }
+    }
+}
+
+impl<'a> DrawingInstances<'a> {
+    /// Specify the color of each instance, in the same order as the transforms.
+    ///
+    /// The colors are multiplied with the colors of the base primitive, so a base primitive drawn
+    /// in white takes on the color of each instance. Instances without a corresponding color are
+    /// left unchanged.
+    pub fn colors<I, C>(self, colors: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.colors(colors))
+    }
+}
+
+impl From<Instances> for Primitive {
+    fn from(prim: Instances) -> Self {
+        Primitive::Instances(prim)
+    }
+}
+
+impl From<Primitive> for Option<Instances> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Instances(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
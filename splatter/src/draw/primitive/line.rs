@@ -1,21 +1,26 @@
-use crate::color::LinSrgba;
+use crate::color::{IntoLinSrgba, LinSrgba};
 use crate::draw::primitive::path;
 use crate::draw::primitive::{PathStroke, Primitive};
 use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{ColorScalar, SetColor, SetOrientation, SetPosition, SetStroke};
 use crate::draw::{self, Drawing};
 use crate::geom::{pt2, Point2};
+use crate::glam::{Mat4, Vec2};
 use lyon::tessellation::StrokeOptions;
 
 /// A path containing only two points - a start and end.
 ///
 /// The usage of this type is almost identical to `PathStroke` but provides `start`, `end` and
-/// `points(a, b)` methods.
+/// `points(a, b)` methods, along with `start_color` and `end_color` for gradient lines and
+/// `pixel_perfect` for antialiased hairlines.
 #[derive(Clone, Debug, Default)]
 pub struct Line {
     pub path: PathStroke,
     pub start: Option<Point2>,
     pub end: Option<Point2>,
+    pub start_color: Option<LinSrgba>,
+    pub end_color: Option<LinSrgba>,
+    pub pixel_perfect: bool,
 }
 
 /// The drawing context for a line.
@@ -49,14 +54,48 @@ impl Line {
         self.start(start).end(end)
     }
 
+    /// Specify the color at the start of the line.
+    ///
+    /// When either `start_color` or `end_color` is specified, the line is drawn as a gradient
+    /// from the start color to the end color. The color at the other end falls back to the color
+    /// of the line.
+    pub fn start_color<C>(mut self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.start_color = Some(color.into_lin_srgba());
+        self
+    }
+
+    /// Specify the color at the end of the line.
+    ///
+    /// See `start_color` for details.
+    pub fn end_color<C>(mut self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.end_color = Some(color.into_lin_srgba());
+        self
+    }
+
+    /// Draw the line as an antialiased hairline exactly one physical pixel wide.
+    ///
+    /// Rather than tessellating a stroke, the line is built from a thin strip whose edges fade
+    /// out over a single pixel, producing clean hairlines regardless of MSAA. The stroke weight,
+    /// caps and joins are ignored. The pixel width is determined from the scale factor of the
+    /// output, so this assumes that the line is drawn without a 3D camera or projection.
+    pub fn pixel_perfect(mut self) -> Self {
+        self.pixel_perfect = true;
+        self
+    }
+
     // Map the inner `PathStroke<S>` using the given function.
-    fn map_path<F>(self, map: F) -> Self
+    fn map_path<F>(mut self, map: F) -> Self
     where
         F: FnOnce(PathStroke) -> PathStroke,
     {
-        let Line { path, start, end } = self;
-        let path = map(path);
-        Line { path, start, end }
+        self.path = map(self.path);
+        self
     }
 }
 
@@ -85,6 +124,35 @@ impl<'a> DrawingLine<'a> {
     pub fn points(self, start: Point2, end: Point2) -> Self {
         self.map_ty(|ty| ty.points(start, end))
     }
+
+    /// Specify the color at the start of the line.
+    ///
+    /// When either `start_color` or `end_color` is specified, the line is drawn as a gradient
+    /// from the start color to the end color. The color at the other end falls back to the color
+    /// of the line.
+    pub fn start_color<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.start_color(color))
+    }
+
+    /// Specify the color at the end of the line.
+    ///
+    /// See `start_color` for details.
+    pub fn end_color<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.end_color(color))
+    }
+
+    /// Draw the line as an antialiased hairline exactly one physical pixel wide.
+    ///
+    /// See `Line::pixel_perfect` for details.
+    pub fn pixel_perfect(self) -> Self {
+        self.map_ty(|ty| ty.pixel_perfect())
+    }
 }
 
 impl SetStroke for Line {
@@ -132,22 +200,56 @@ impl draw::renderer::RenderPrimitive for Line {
         mut ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Line { path, start, end } = self;
+        let Line {
+            path,
+            start,
+            end,
+            start_color,
+            end_color,
+            pixel_perfect,
+        } = self;
         let start = start.unwrap_or(pt2(0.0, 0.0));
         let end = end.unwrap_or(pt2(0.0, 0.0));
         if start == end {
             return draw::renderer::PrimitiveRender::default();
         }
         let close = false;
-        let points = [start, end];
-        let points = points.iter().cloned().map(|p| p.to_array().into());
-        let events = lyon::path::iterator::FromPolyline::new(close, points);
 
         // Determine the transform to apply to all points.
         let global_transform = *ctxt.transform;
         let local_transform = path.position.transform() * path.orientation.transform();
         let transform = global_transform * local_transform;
 
+        let color = path
+            .color
+            .unwrap_or_else(|| ctxt.theme.stroke_lin_srgba(&draw::theme::Primitive::Line));
+
+        if pixel_perfect {
+            let colors = [start_color.unwrap_or(color), end_color.unwrap_or(color)];
+            let pixel = 1.0 / ctxt.output_attachment_scale_factor;
+            render_hairline([start, end], colors, transform, pixel, mesh);
+            return draw::renderer::PrimitiveRender::default();
+        }
+
+        // A color at either end draws the line as a gradient, interpolating a color per point.
+        if start_color.is_some() || end_color.is_some() {
+            let start_color = start_color.unwrap_or(color);
+            let end_color = end_color.unwrap_or(color);
+            path::render_path_points_colored(
+                [(start, start_color), (end, end_color)],
+                close,
+                transform,
+                path::Options::Stroke(path.opts),
+                &mut ctxt.fill_tessellator,
+                &mut ctxt.stroke_tessellator,
+                mesh,
+            );
+            return draw::renderer::PrimitiveRender::default();
+        }
+
+        let points = [start, end];
+        let points = points.iter().cloned().map(|p| p.to_array().into());
+        let events = lyon::path::iterator::FromPolyline::new(close, points);
         path::render_path_events(
             events,
             path.color,
@@ -163,3 +265,75 @@ impl draw::renderer::RenderPrimitive for Line {
         draw::renderer::PrimitiveRender::default()
     }
 }
+
+// Extend the mesh with a hairline between the given points, one pixel wide in output space.
+//
+// Each end has a vertex of full opacity on the line along with a fully transparent vertex one
+// pixel to either side, so that the coverage fades out linearly across the edges.
+fn render_hairline(
+    points: [Point2; 2],
+    colors: [LinSrgba; 2],
+    transform: Mat4,
+    pixel: f32,
+    mesh: &mut draw::Mesh,
+) {
+    let [a, b] = points.map(|p| transform.project_point3(p.extend(0.0)));
+    let normal = (b - a).truncate().perp().normalize_or_zero() * pixel;
+    if normal == Vec2::ZERO {
+        return;
+    }
+    let normal = normal.extend(0.0);
+    let start_index = mesh.points().len() as u32;
+    for (&point, &color) in [a, b].iter().zip(&colors) {
+        let transparent = LinSrgba {
+            alpha: 0.0,
+            ..color
+        };
+        let vertices = [
+            (point - normal, transparent),
+            (point, color),
+            (point + normal, transparent),
+        ];
+        for (point, color) in vertices {
+            let tex_coords = draw::mesh::vertex::default_tex_coords();
+            mesh.push_vertex(draw::mesh::vertex::new(point, color, tex_coords));
+        }
+    }
+    for [i, j, k] in [[0, 1, 4], [0, 4, 3], [1, 2, 5], [1, 5, 4]] {
+        mesh.push_index(start_index + i);
+        mesh.push_index(start_index + j);
+        mesh.push_index(start_index + k);
+    }
+}
+
+impl draw::svg::SvgPrimitive for Line {
+    fn svg_primitive(&self, ctxt: &draw::svg::SvgContext, svg: &mut String) {
+        let Line {
+            path,
+            start,
+            end,
+            start_color,
+            ..
+        } = self;
+        let start = start.unwrap_or(pt2(0.0, 0.0));
+        let end = end.unwrap_or(pt2(0.0, 0.0));
+        if start == end {
+            return;
+        }
+        let geometry = format!(
+            r#"x1="{}" y1="{}" x2="{}" y2="{}""#,
+            start.x, start.y, end.x, end.y,
+        );
+        let local_transform = path.position.transform() * path.orientation.transform();
+        let transform = *ctxt.transform * local_transform;
+        // Gradient lines are written with their start color.
+        let color = start_color
+            .or(path.color)
+            .unwrap_or_else(|| ctxt.theme.stroke_lin_srgba(&draw::theme::Primitive::Line));
+        let paint = draw::svg::Paint {
+            fill: None,
+            stroke: Some((color, &path.opts)),
+        };
+        draw::svg::write_element(svg, "line", &geometry, &transform, &paint);
+    }
+}
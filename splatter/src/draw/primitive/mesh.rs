@@ -5,7 +5,9 @@ use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition};
 use crate::draw::{self, Drawing};
 use crate::geom;
+use crate::mesh::Channel;
 use crate::wgpu;
+use std::convert::TryInto;
 use std::ops;
 
 /// The mesh type prior to being initialised with vertices or indices.
@@ -287,6 +289,58 @@ impl Vertexless {
         mesh
     }
 
+    /// Describe the mesh with the points and indices of the given **splatter_mesh** mesh.
+    ///
+    /// This method assumes that the entire mesh should be coloured with a single colour. Any
+    /// other channels of the given mesh are ignored.
+    pub fn indexed_mesh<M>(self, inner_mesh: &mut draw::Mesh, mesh: &M) -> Mesh
+    where
+        M: crate::mesh::Points + crate::mesh::Indices,
+        M::Point: Clone + Into<Point>,
+        M::Index: Clone + TryInto<usize>,
+    {
+        let points = mesh.points().iter().cloned();
+        self.indexed(inner_mesh, points, mesh_indices(mesh))
+    }
+
+    /// Describe the mesh with the points, colors and indices of the given **splatter_mesh**
+    /// mesh.
+    ///
+    /// Any other channels of the given mesh, e.g. normals, are ignored.
+    pub fn indexed_mesh_colored<M>(self, inner_mesh: &mut draw::Mesh, mesh: &M) -> Mesh
+    where
+        M: crate::mesh::Points + crate::mesh::Colors + crate::mesh::Indices,
+        M::Point: Clone + Into<Point>,
+        M::Color: Clone + IntoLinSrgba<ColorScalar>,
+        M::Index: Clone + TryInto<usize>,
+    {
+        let points = mesh.points().iter().cloned();
+        let colors = mesh.colors().iter().cloned();
+        self.indexed_colored(inner_mesh, points.zip(colors), mesh_indices(mesh))
+    }
+
+    /// Describe the mesh with the points, texture coordinates and indices of the given
+    /// **splatter_mesh** mesh.
+    ///
+    /// Any other channels of the given mesh, e.g. normals, are ignored.
+    pub fn indexed_mesh_textured<M>(
+        self,
+        inner_mesh: &mut draw::Mesh,
+        texture_view: &dyn wgpu::ToTextureView,
+        mesh: &M,
+    ) -> Mesh
+    where
+        M: crate::mesh::Points + crate::mesh::TexCoords + crate::mesh::Indices,
+        M::Point: Clone + Into<Point>,
+        M::TexCoord: Clone + Into<TexCoords>,
+        M::Index: Clone + TryInto<usize>,
+    {
+        let points = mesh.points().iter().cloned();
+        let tex_coords = mesh.tex_coords().iter().cloned();
+        let points = points.zip(tex_coords);
+        self.indexed_textured(inner_mesh, texture_view, points, mesh_indices(mesh))
+    }
+
     fn indexed_inner<V, I>(
         self,
         inner_mesh: &mut draw::Mesh,
@@ -302,7 +356,8 @@ impl Vertexless {
         let v_start = inner_mesh.points().len();
         let i_start = inner_mesh.indices().len();
         inner_mesh.extend_vertices(vertices);
-        inner_mesh.extend_indices(indices.into_iter().map(|ix| ix as u32));
+        // Indices are relative to the given vertices, so offset them to where they now begin.
+        inner_mesh.extend_indices(indices.into_iter().map(|ix| (v_start + ix) as u32));
         let v_end = inner_mesh.points().len();
         let i_end = inner_mesh.indices().len();
         Mesh::new(v_start..v_end, i_start..i_end, vertex_mode, texture_view)
@@ -479,6 +534,66 @@ impl<'a> Drawing<'a, Vertexless> {
     {
         self.map_ty_with_context(|ty, ctxt| ty.indexed_textured(ctxt.mesh, view, points, indices))
     }
+
+    /// Describe the mesh with the points and indices of the given **splatter_mesh** mesh.
+    ///
+    /// This method assumes that the entire mesh should be coloured with a single colour. Any
+    /// other channels of the given mesh are ignored.
+    pub fn indexed_mesh<M>(self, mesh: &M) -> DrawingMesh<'a>
+    where
+        M: crate::mesh::Points + crate::mesh::Indices,
+        M::Point: Clone + Into<Point>,
+        M::Index: Clone + TryInto<usize>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.indexed_mesh(ctxt.mesh, mesh))
+    }
+
+    /// Describe the mesh with the points, colors and indices of the given **splatter_mesh**
+    /// mesh.
+    ///
+    /// Any other channels of the given mesh, e.g. normals, are ignored.
+    ///
+    /// ```
+    /// # use splatter::prelude::*;
+    /// use splatter::mesh;
+    ///
+    /// let points = vec![vec3(0.0, 0.0, 0.0), vec3(100.0, 0.0, 0.0), vec3(0.0, 100.0, 0.0)];
+    /// let colors = vec![RED, GREEN, BLUE];
+    /// let indices: Vec<u32> = vec![0, 1, 2];
+    /// let mesh = mesh::from_points(points);
+    /// let mesh = mesh::with_colors(mesh, colors);
+    /// let mesh = mesh::with_indices(mesh, indices);
+    ///
+    /// let draw = Draw::new();
+    /// draw.mesh().indexed_mesh_colored(&mesh);
+    /// ```
+    pub fn indexed_mesh_colored<M>(self, mesh: &M) -> DrawingMesh<'a>
+    where
+        M: crate::mesh::Points + crate::mesh::Colors + crate::mesh::Indices,
+        M::Point: Clone + Into<Point>,
+        M::Color: Clone + IntoLinSrgba<ColorScalar>,
+        M::Index: Clone + TryInto<usize>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.indexed_mesh_colored(ctxt.mesh, mesh))
+    }
+
+    /// Describe the mesh with the points, texture coordinates and indices of the given
+    /// **splatter_mesh** mesh.
+    ///
+    /// Any other channels of the given mesh, e.g. normals, are ignored.
+    pub fn indexed_mesh_textured<M>(
+        self,
+        view: &dyn wgpu::ToTextureView,
+        mesh: &M,
+    ) -> DrawingMesh<'a>
+    where
+        M: crate::mesh::Points + crate::mesh::TexCoords + crate::mesh::Indices,
+        M::Point: Clone + Into<Point>,
+        M::TexCoord: Clone + Into<TexCoords>,
+        M::Index: Clone + TryInto<usize>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.indexed_mesh_textured(ctxt.mesh, view, mesh))
+    }
 }
 
 impl draw::renderer::RenderPrimitive for Mesh {
@@ -510,7 +625,7 @@ impl draw::renderer::RenderPrimitive for Mesh {
             .map(|i| new_mesh_vertex_start + i - old_mesh_vertex_start);
 
         // A small function for transforming a point via the transform matrix.
-        let transform_point = |p: geom::Point3| -> geom::Point3 { transform.transform_point3(p) };
+        let transform_point = |p: geom::Point3| -> geom::Point3 { transform.project_point3(p) };
 
         // Color the vertices based on whether or not we should fill, then extend the mesh!
         match fill_color {
@@ -540,6 +655,9 @@ impl draw::renderer::RenderPrimitive for Mesh {
         draw::renderer::PrimitiveRender {
             texture_view,
             vertex_mode,
+            texture_layer: 0,
+            address_mode: None,
+            shader: None,
         }
     }
 }
@@ -614,3 +732,52 @@ impl Into<Option<Mesh>> for Primitive {
         }
     }
 }
+
+// The indices of the given mesh as `usize`s.
+fn mesh_indices<M>(mesh: &M) -> impl Iterator<Item = usize> + '_
+where
+    M: crate::mesh::Indices,
+    M::Index: Clone + TryInto<usize>,
+{
+    mesh.indices().iter().map(|i| match i.clone().try_into() {
+        Ok(i) => i,
+        Err(_) => panic!("mesh index does not fit within a `usize`"),
+    })
+}
+
+#[test]
+fn test_multiple_indexed_meshes() {
+    use crate::color::{BLUE, GREEN, RED, WHITE};
+    use crate::glam::vec3;
+    use crate::mesh;
+
+    // Two quads with indices relative to their own points.
+    let quad = |x: f32| {
+        vec![
+            vec3(x, 0.0, 0.0),
+            vec3(x + 10.0, 0.0, 0.0),
+            vec3(x + 10.0, 10.0, 0.0),
+            vec3(x, 10.0, 0.0),
+        ]
+    };
+    let indices: Vec<u32> = vec![0, 1, 2, 0, 2, 3];
+    let a = mesh::with_indices(mesh::from_points(quad(0.0)), indices.clone());
+    let b = mesh::from_points(quad(20.0));
+    let b = mesh::with_colors(b, vec![RED, GREEN, BLUE, WHITE]);
+    let b = mesh::with_indices(b, indices.clone());
+
+    let mut inner_mesh = draw::Mesh::default();
+    let first = Vertexless.indexed_mesh(&mut inner_mesh, &a);
+    let second = Vertexless.indexed_mesh_colored(&mut inner_mesh, &b);
+
+    // Each mesh should index its own quad regardless of the meshes described before it.
+    for (prim, points) in [(first, quad(0.0)), (second, quad(20.0))] {
+        let v_start = prim.vertex_range.start;
+        let prim_indices: Vec<_> = inner_mesh.indices()[prim.index_range]
+            .iter()
+            .map(|&i| i - v_start as u32)
+            .collect();
+        assert_eq!(prim_indices, indices);
+        assert_eq!(&inner_mesh.points()[prim.vertex_range], &points[..]);
+    }
+}
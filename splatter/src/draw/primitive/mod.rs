@@ -1,23 +1,33 @@
 pub mod arrow;
+pub mod baked;
 pub mod ellipse;
+pub mod instances;
 pub mod line;
 pub mod mesh;
+pub mod ngon;
 pub mod path;
 pub mod polygon;
 pub mod quad;
 pub mod rect;
+pub mod shader;
+pub mod star;
 pub mod text;
 pub mod texture;
 pub mod tri;
 
 pub use self::arrow::Arrow;
+pub use self::baked::{Baked, BakedPart};
 pub use self::ellipse::Ellipse;
+pub use self::instances::Instances;
 pub use self::line::Line;
 pub use self::mesh::Mesh;
+pub use self::ngon::Ngon;
 pub use self::path::{Path, PathFill, PathInit, PathStroke};
 pub use self::polygon::{Polygon, PolygonInit};
 pub use self::quad::Quad;
 pub use self::rect::Rect;
+pub use self::shader::Shader;
+pub use self::star::Star;
 pub use self::text::Text;
 pub use self::texture::Texture;
 pub use self::tri::Tri;
@@ -30,10 +40,13 @@ pub use self::tri::Tri;
 #[derive(Clone, Debug)]
 pub enum Primitive {
     Arrow(Arrow),
+    Baked(BakedPart),
     Ellipse(Ellipse),
+    Instances(Instances),
     Line(Line),
     MeshVertexless(mesh::Vertexless),
     Mesh(Mesh),
+    Ngon(Ngon),
     PathInit(PathInit),
     PathFill(PathFill),
     PathStroke(PathStroke),
@@ -42,6 +55,8 @@ pub enum Primitive {
     Polygon(Polygon),
     Quad(Quad),
     Rect(Rect),
+    Shader(Shader),
+    Star(Star),
     Text(Text),
     Texture(Texture),
     Tri(Tri),
@@ -0,0 +1,207 @@
+use crate::color::conv::IntoLinSrgba;
+use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{pt2, Point2};
+use lyon::tessellation::StrokeOptions;
+
+/// Properties related to drawing a regular polygon with any number of sides.
+#[derive(Clone, Debug)]
+pub struct Ngon {
+    sides: u32,
+    dimensions: dimension::Properties,
+    polygon: PolygonInit,
+}
+
+/// The drawing context for an **Ngon**.
+pub type DrawingNgon<'a> = Drawing<'a, Ngon>;
+
+// Ngon-specific methods.
+
+impl Ngon {
+    /// The minimum number of sides of an **Ngon**.
+    pub const MIN_SIDES: u32 = 3;
+
+    /// A regular polygon with the given number of sides.
+    ///
+    /// `sides` is clamped to a minimum of `MIN_SIDES`.
+    pub fn new(sides: u32) -> Self {
+        let dimensions = Default::default();
+        let polygon = Default::default();
+        let ngon = Ngon {
+            sides: Self::MIN_SIDES,
+            dimensions,
+            polygon,
+        };
+        ngon.sides(sides)
+    }
+
+    /// Stroke the outline with the given color.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.stroke_color(color)
+    }
+
+    /// Specify the number of sides.
+    ///
+    /// `sides` is clamped to a minimum of `MIN_SIDES`.
+    pub fn sides(mut self, sides: u32) -> Self {
+        self.sides = sides.max(Self::MIN_SIDES);
+        self
+    }
+
+    /// Specify the width and height of the **Ngon** via the radius of its circumscribed circle.
+    pub fn radius(self, radius: f32) -> Self {
+        let side = radius * 2.0;
+        self.w_h(side, side)
+    }
+
+    // The vertices of the polygon, inscribed within its dimensions and starting at `0.0`
+    // radians.
+    fn points(&self) -> impl Clone + Iterator<Item = Point2> {
+        let w = self.dimensions.x.map(f32::abs).unwrap_or(100.0);
+        let h = self.dimensions.y.map(f32::abs).unwrap_or(100.0);
+        let sides = self.sides;
+        (0..sides).map(move |i| {
+            let radians = i as f32 / sides as f32 * std::f32::consts::TAU;
+            let (sin, cos) = radians.sin_cos();
+            pt2(cos * w * 0.5, sin * h * 0.5)
+        })
+    }
+}
+
+// Drawing methods.
+
+impl<'a> DrawingNgon<'a> {
+    /// Stroke the outline with the given color.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.stroke(color))
+    }
+
+    /// Specify the number of sides.
+    ///
+    /// `sides` is clamped to a minimum of `Ngon::MIN_SIDES`.
+    pub fn sides(self, sides: u32) -> Self {
+        self.map_ty(|ty| ty.sides(sides))
+    }
+
+    /// Specify the width and height of the **Ngon** via the radius of its circumscribed circle.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+}
+
+// Trait implementations.
+
+impl draw::renderer::RenderPrimitive for Ngon {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let points = self.points();
+        polygon::render_points_themed(
+            self.polygon.opts,
+            points,
+            ctxt,
+            &draw::theme::Primitive::Ngon,
+            mesh,
+        );
+
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+impl draw::svg::SvgPrimitive for Ngon {
+    fn svg_primitive(&self, ctxt: &draw::svg::SvgContext, svg: &mut String) {
+        let d = draw::svg::points_data(self.points(), true);
+        draw::svg::write_polygon(
+            svg,
+            ctxt,
+            &self.polygon.opts,
+            &draw::theme::Primitive::Ngon,
+            "path",
+            &format!(r#"d="{}""#, d),
+        );
+    }
+}
+
+impl Default for Ngon {
+    fn default() -> Self {
+        Ngon::new(6)
+    }
+}
+
+impl SetOrientation for Ngon {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Ngon {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetDimensions for Ngon {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor<ColorScalar> for Ngon {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Ngon {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Ngon {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Ngon> for Primitive {
+    fn from(prim: Ngon) -> Self {
+        Primitive::Ngon(prim)
+    }
+}
+
+impl From<Primitive> for Option<Ngon> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Ngon(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_ngon_points() {
+    let ngon = Ngon::new(4).w_h(20.0, 10.0);
+    let points: Vec<_> = ngon.points().collect();
+    assert_eq!(points.len(), 4);
+    assert_eq!(points[0], pt2(10.0, 0.0));
+    assert!((points[1] - pt2(0.0, 5.0)).length() < 1e-5);
+    // Fewer than three sides are clamped.
+    assert_eq!(Ngon::new(1).points().count(), 3);
+    assert_eq!(Ngon::new(8).sides(2).points().count(), 3);
+}
@@ -4,7 +4,7 @@ use crate::draw::mesh::vertex::{Color, TexCoords};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{
-    ColorScalar, SetColor, SetFill, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Gradient, SetColor, SetFill, SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing, DrawingContext};
 use crate::geom::Point2;
@@ -35,6 +35,11 @@ pub(crate) enum PathEventSource {
         range: std::ops::Range<usize>,
         close: bool,
     },
+    /// Generate events from the `path_points_textured_colored_buffer`.
+    TexturedColoredPoints {
+        range: std::ops::Range<usize>,
+        close: bool,
+    },
 }
 
 pub(crate) enum PathEventSourceIter<'a> {
@@ -47,6 +52,10 @@ pub(crate) enum PathEventSourceIter<'a> {
         points: &'a mut dyn Iterator<Item = (Point2, TexCoords)>,
         close: bool,
     },
+    TexturedColoredPoints {
+        points: &'a mut dyn Iterator<Item = (Point2, Color, TexCoords)>,
+        close: bool,
+    },
 }
 
 /// The beginning of the path building process, prior to choosing the tessellation mode (fill or
@@ -58,6 +67,7 @@ pub struct PathInit;
 #[derive(Clone, Debug, Default)]
 pub struct PathOptions<T> {
     pub(crate) opts: T,
+    pub(crate) close: bool,
     pub(crate) color: Option<LinSrgba>,
     pub(crate) position: position::Properties,
     pub(crate) orientation: orientation::Properties,
@@ -135,11 +145,22 @@ impl<T> PathOptions<T> {
         let color = Default::default();
         PathOptions {
             opts,
+            close: false,
             orientation,
             position,
             color,
         }
     }
+
+    /// Specify whether or not the path should be closed from the end point back to the start point
+    /// when submitted via the `points`, `points_colored`, `points_textured` or
+    /// `points_textured_colored` methods.
+    ///
+    /// By default, paths are not closed.
+    pub fn closed(mut self, close: bool) -> Self {
+        self.close = close;
+        self
+    }
 }
 
 impl PathFill {
@@ -204,7 +225,8 @@ where
         I: IntoIterator,
         I::Item: Into<Point2>,
     {
-        self.points_inner(ctxt, false, points)
+        let close = self.close;
+        self.points_inner(ctxt, close, points)
     }
 
     /// Consumes an iterator of points and converts them to an iterator yielding path events.
@@ -225,7 +247,8 @@ where
         P: Into<Point2>,
         C: IntoLinSrgba<ColorScalar>,
     {
-        self.points_colored_inner(ctxt, false, points)
+        let close = self.close;
+        self.points_colored_inner(ctxt, close, points)
     }
 
     /// Submit path events as a polyline of colored points.
@@ -250,7 +273,9 @@ where
         P: Into<Point2>,
         TC: Into<TexCoords>,
     {
-        self.points_textured_inner(ctxt, texture_view.to_texture_view(), false, points)
+        let view = texture_view.to_texture_view();
+        let close = self.close;
+        self.points_textured_inner(ctxt, view, close, points)
     }
 
     /// Submit path events as a polyline of textured points.
@@ -268,6 +293,43 @@ where
         self.points_textured_inner(ctxt, texture_view.to_texture_view(), true, points)
     }
 
+    /// Submit path events as a polyline of textured points, each tinted by its color.
+    ///
+    /// The color of each point is multiplied with the color sampled from the texture.
+    pub fn points_textured_colored<I, P, C, TC>(
+        self,
+        ctxt: DrawingContext,
+        texture_view: &dyn wgpu::ToTextureView,
+        points: I,
+    ) -> Path
+    where
+        I: IntoIterator<Item = (P, C, TC)>,
+        P: Into<Point2>,
+        C: IntoLinSrgba<ColorScalar>,
+        TC: Into<TexCoords>,
+    {
+        let view = texture_view.to_texture_view();
+        let close = self.close;
+        self.points_textured_colored_inner(ctxt, view, close, points)
+    }
+
+    /// Submit path events as a polyline of textured points, each tinted by its color.
+    pub fn points_textured_colored_closed<I, P, C, TC>(
+        self,
+        ctxt: DrawingContext,
+        texture_view: &dyn wgpu::ToTextureView,
+        points: I,
+    ) -> Path
+    where
+        I: IntoIterator<Item = (P, C, TC)>,
+        P: Into<Point2>,
+        C: IntoLinSrgba<ColorScalar>,
+        TC: Into<TexCoords>,
+    {
+        let view = texture_view.to_texture_view();
+        self.points_textured_colored_inner(ctxt, view, true, points)
+    }
+
     // Consumes an iterator of points and converts them to an iterator yielding events.
     fn points_inner<I>(self, ctxt: DrawingContext, close: bool, points: I) -> Path
     where
@@ -349,6 +411,45 @@ where
             Some(texture_view),
         )
     }
+
+    // Consumes an iterator of textured, colored points and buffers them for rendering.
+    fn points_textured_colored_inner<I, P, C, TC>(
+        self,
+        ctxt: DrawingContext,
+        texture_view: wgpu::TextureView,
+        close: bool,
+        points: I,
+    ) -> Path
+    where
+        I: IntoIterator<Item = (P, C, TC)>,
+        P: Into<Point2>,
+        C: IntoLinSrgba<ColorScalar>,
+        TC: Into<TexCoords>,
+    {
+        let DrawingContext {
+            path_points_textured_colored_buffer,
+            ..
+        } = ctxt;
+        let start = path_points_textured_colored_buffer.len();
+        let points = points
+            .into_iter()
+            .map(|(p, c, tc)| (p.into(), c.into_lin_srgba(), tc.into()));
+        path_points_textured_colored_buffer.extend(points);
+        let end = path_points_textured_colored_buffer.len();
+        let path_event_src = PathEventSource::TexturedColoredPoints {
+            range: start..end,
+            close,
+        };
+        Path::new(
+            self.position,
+            self.orientation,
+            self.color,
+            path_event_src,
+            self.opts.into_options(),
+            draw::renderer::VertexMode::TextureColor,
+            Some(texture_view),
+        )
+    }
 }
 
 pub(crate) fn render_path_events<I>(
@@ -381,6 +482,29 @@ pub(crate) fn render_path_events<I>(
     }
 }
 
+pub(crate) fn render_path_events_gradient<I>(
+    events: I,
+    gradient: &Gradient,
+    transform: Mat4,
+    options: Options,
+    fill_tessellator: &mut lyon::tessellation::FillTessellator,
+    stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+    mesh: &mut draw::Mesh,
+) where
+    I: IntoIterator<Item = lyon::path::PathEvent>,
+{
+    let mut mesh_builder = draw::mesh::GradientMeshBuilder::new(mesh, transform, gradient);
+    let res = match options {
+        Options::Fill(options) => fill_tessellator.tessellate(events, &options, &mut mesh_builder),
+        Options::Stroke(options) => {
+            stroke_tessellator.tessellate(events, &options, &mut mesh_builder)
+        }
+    };
+    if let Err(err) = res {
+        eprintln!("failed to tessellate path: {:?}", err);
+    }
+}
+
 pub(crate) fn render_path_points_colored<I>(
     points_colored: I,
     close: bool,
@@ -459,6 +583,45 @@ pub(crate) fn render_path_points_textured<I>(
     }
 }
 
+pub(crate) fn render_path_points_textured_colored<I>(
+    points_textured_colored: I,
+    close: bool,
+    transform: Mat4,
+    options: Options,
+    fill_tessellator: &mut lyon::tessellation::FillTessellator,
+    stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+    mesh: &mut draw::Mesh,
+) where
+    I: IntoIterator<Item = (Point2, Color, TexCoords)>,
+{
+    let path = match points_textured_colored_to_lyon_path(points_textured_colored, close) {
+        None => return,
+        Some(p) => p,
+    };
+
+    // Extend the mesh with the built path.
+    let mut mesh_builder = draw::mesh::MeshBuilder::color_and_tex_coords_per_point(mesh, transform);
+    let res = match options {
+        Options::Fill(options) => fill_tessellator.tessellate_with_ids(
+            path.id_iter(),
+            &path,
+            Some(&path),
+            &options,
+            &mut mesh_builder,
+        ),
+        Options::Stroke(options) => stroke_tessellator.tessellate_with_ids(
+            path.id_iter(),
+            &path,
+            Some(&path),
+            &options,
+            &mut mesh_builder,
+        ),
+    };
+    if let Err(err) = res {
+        eprintln!("failed to tessellate path: {:?}", err);
+    }
+}
+
 pub(crate) fn render_path_source(
     // TODO:
     path_src: PathEventSourceIter,
@@ -501,6 +664,17 @@ pub(crate) fn render_path_source(
             stroke_tessellator,
             mesh,
         ),
+        PathEventSourceIter::TexturedColoredPoints { points, close } => {
+            render_path_points_textured_colored(
+                points,
+                close,
+                transform,
+                options,
+                fill_tessellator,
+                stroke_tessellator,
+                mesh,
+            )
+        }
     }
 }
 
@@ -581,24 +755,90 @@ impl draw::renderer::RenderPrimitive for Path {
                     &mut ctxt.stroke_tessellator,
                 );
             }
+            PathEventSource::TexturedColoredPoints { range, close } => {
+                let mut points_textured_colored = ctxt.path_points_textured_colored_buffer[range]
+                    .iter()
+                    .cloned();
+                let src = PathEventSourceIter::TexturedColoredPoints {
+                    points: &mut points_textured_colored,
+                    close,
+                };
+                render(
+                    src,
+                    &ctxt.theme,
+                    &mut ctxt.fill_tessellator,
+                    &mut ctxt.stroke_tessellator,
+                );
+            }
         }
 
         draw::renderer::PrimitiveRender {
             texture_view,
             vertex_mode,
+            texture_layer: 0,
+            address_mode: None,
+            shader: None,
         }
     }
 }
 
+/// Produce the SVG path data for the given path event source.
+pub(crate) fn svg_path_data(path_src: &PathEventSource, ctxt: &draw::svg::SvgContext) -> String {
+    match *path_src {
+        PathEventSource::Buffered(ref range) => {
+            draw::svg::path_data(ctxt.path_event_buffer[range.clone()].iter().cloned())
+        }
+        PathEventSource::ColoredPoints { ref range, close } => {
+            let points = ctxt.path_points_colored_buffer[range.clone()].iter();
+            draw::svg::points_data(points.map(|&(p, _)| p), close)
+        }
+        PathEventSource::TexturedPoints { ref range, close } => {
+            let points = ctxt.path_points_textured_buffer[range.clone()].iter();
+            draw::svg::points_data(points.map(|&(p, _)| p), close)
+        }
+        PathEventSource::TexturedColoredPoints { ref range, close } => {
+            let points = ctxt.path_points_textured_colored_buffer[range.clone()].iter();
+            draw::svg::points_data(points.map(|&(p, _, _)| p), close)
+        }
+    }
+}
+
+impl draw::svg::SvgPrimitive for Path {
+    fn svg_primitive(&self, ctxt: &draw::svg::SvgContext, svg: &mut String) {
+        let d = svg_path_data(&self.path_event_src, ctxt);
+        let local_transform = self.position.transform() * self.orientation.transform();
+        let transform = *ctxt.transform * local_transform;
+        let theme_prim = &draw::theme::Primitive::Path;
+        let paint = match self.options {
+            Options::Fill(ref opts) => {
+                let color = self
+                    .color
+                    .unwrap_or_else(|| ctxt.theme.fill_lin_srgba(theme_prim));
+                draw::svg::Paint {
+                    fill: Some((color, opts.fill_rule)),
+                    stroke: None,
+                }
+            }
+            Options::Stroke(ref opts) => {
+                let color = self
+                    .color
+                    .unwrap_or_else(|| ctxt.theme.stroke_lin_srgba(theme_prim));
+                draw::svg::Paint {
+                    fill: None,
+                    stroke: Some((color, opts)),
+                }
+            }
+        };
+        let geometry = format!(r#"d="{}""#, d);
+        draw::svg::write_element(svg, "path", &geometry, &transform, &paint);
+    }
+}
+
 /// Create a lyon path for the given iterator of colored points.
 pub fn points_colored_to_lyon_path<I>(points_colored: I, close: bool) -> Option<lyon::path::Path>
 where
     I: IntoIterator<Item = (Point2, Color)>,
 {
-    // FIXME: debugging
-    let points_colored = points_colored.into_iter().collect::<Vec<_>>();
-    eprintln!("points_colored: {points_colored:?}");
-
     // Build a path with a color attribute for each channel.
     let channels = draw::mesh::vertex::COLOR_CHANNEL_COUNT;
     let mut path_builder = lyon::path::Path::builder_with_attributes(channels);
@@ -609,9 +849,6 @@ where
     let p = first_point.to_array().into();
     let (r, g, b, a) = first_color.into();
 
-    // FIXME: Debugging
-    eprintln!("point: {p:?}, attrs: {attrs:?}", attrs = [r, g, b, a]);
-
     path_builder.begin(p, &[r, g, b, a]);
 
     // Add the lines, keeping track of the last
@@ -658,6 +895,41 @@ where
     Some(path_builder.build())
 }
 
+/// Create a lyon path for the given iterator of textured, colored points.
+pub fn points_textured_colored_to_lyon_path<I>(
+    points_textured_colored: I,
+    close: bool,
+) -> Option<lyon::path::Path>
+where
+    I: IntoIterator<Item = (Point2, Color, TexCoords)>,
+{
+    // Build a path with an attribute for each color channel followed by each texture coordinate.
+    let channels = draw::mesh::vertex::COLOR_CHANNEL_COUNT + 2;
+    let mut path_builder = lyon::path::Path::builder_with_attributes(channels);
+
+    // Begin the path.
+    let mut iter = points_textured_colored.into_iter();
+    let (first_point, first_color, first_tex_coords) = iter.next()?;
+    let p = first_point.to_array().into();
+    let (r, g, b, a) = first_color.into();
+    let (tc_x, tc_y) = first_tex_coords.into();
+    path_builder.begin(p, &[r, g, b, a, tc_x, tc_y]);
+
+    // Add the lines, keeping track of the last
+    for (point, color, tex_coords) in iter {
+        let p = point.to_array().into();
+        let (r, g, b, a) = color.into();
+        let (tc_x, tc_y) = tex_coords.into();
+        path_builder.line_to(p, &[r, g, b, a, tc_x, tc_y]);
+    }
+
+    // End the path, closing if necessary.
+    path_builder.end(close);
+
+    // Build it!
+    Some(path_builder.build())
+}
+
 impl Path {
     // Initialise a new `Path` with its ranges into the intermediary mesh, ready for drawing.
     fn new(
@@ -729,6 +1001,24 @@ where
     PathOptions<T>: Into<Primitive>,
     Primitive: Into<Option<PathOptions<T>>>,
 {
+    /// Specify whether or not the path should be closed from the end point back to the start point
+    /// when submitted via the `points`, `points_colored`, `points_textured` or
+    /// `points_textured_colored` methods.
+    ///
+    /// This is an alternative to the `*_closed` variants of these methods, e.g. the following are
+    /// equivalent:
+    ///
+    /// ```
+    /// # use splatter::prelude::*;
+    /// let draw = Draw::new();
+    /// let points = [pt2(0.0, 0.0), pt2(100.0, 0.0), pt2(50.0, 80.0)];
+    /// draw.polyline().closed(true).points(points);
+    /// draw.polyline().points_closed(points);
+    /// ```
+    pub fn closed(self, close: bool) -> Self {
+        self.map_ty(|ty| ty.closed(close))
+    }
+
     /// Submit the path events to be tessellated.
     pub fn events<I>(self, events: I) -> DrawingPath<'a>
     where
@@ -808,6 +1098,40 @@ where
     {
         self.map_ty_with_context(|ty, ctxt| ty.points_textured_closed(ctxt, view, points))
     }
+
+    /// Submit path events as a polyline of textured points, each tinted by its color.
+    ///
+    /// The color of each point is multiplied with the color sampled from the texture.
+    pub fn points_textured_colored<I, P, C, TC>(
+        self,
+        view: &dyn wgpu::ToTextureView,
+        points: I,
+    ) -> DrawingPath<'a>
+    where
+        I: IntoIterator<Item = (P, C, TC)>,
+        P: Into<Point2>,
+        C: IntoLinSrgba<ColorScalar>,
+        TC: Into<TexCoords>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.points_textured_colored(ctxt, view, points))
+    }
+
+    /// Submit path events as a polyline of textured points, each tinted by its color.
+    ///
+    /// The path with automatically close from the end point to the start point.
+    pub fn points_textured_colored_closed<I, P, C, TC>(
+        self,
+        view: &dyn wgpu::ToTextureView,
+        points: I,
+    ) -> DrawingPath<'a>
+    where
+        I: IntoIterator<Item = (P, C, TC)>,
+        P: Into<Point2>,
+        C: IntoLinSrgba<ColorScalar>,
+        TC: Into<TexCoords>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.points_textured_colored_closed(ctxt, view, points))
+    }
 }
 
 impl SetFill for PathFill {
@@ -822,6 +1146,20 @@ impl SetStroke for PathStroke {
     }
 }
 
+// Allows for specifying stroke options after the points of a polyline have been submitted.
+impl SetStroke for Path {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        // As with `Option<StrokeOptions>`, switch to stroke tessellation if not already stroked.
+        if let Options::Fill(_) = self.options {
+            self.options = Options::Stroke(Default::default());
+        }
+        match self.options {
+            Options::Stroke(ref mut opts) => opts,
+            Options::Fill(_) => unreachable!(),
+        }
+    }
+}
+
 impl TessellationOptions for FillOptions {
     type Tessellator = FillTessellator;
     fn into_options(self) -> Options {
@@ -931,3 +1269,15 @@ impl Into<Option<Path>> for Primitive {
         }
     }
 }
+
+#[test]
+fn test_polyline_stroke_options_after_points() {
+    let draw = draw::Draw::new();
+    draw.polyline()
+        .weight(20.0)
+        .points(vec![[0.0, 0.0], [10.0, 10.0], [20.0, 0.0]])
+        .caps_round()
+        .join_round();
+    let svg = draw.to_svg(crate::geom::Rect::from_w_h(100.0, 100.0));
+    assert!(svg.contains(r#"stroke-width="20" stroke-linecap="round" stroke-linejoin="round""#));
+}
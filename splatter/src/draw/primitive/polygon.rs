@@ -5,10 +5,12 @@ use crate::draw::primitive::path::{self, PathEventSource};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{
-    ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Gradient, LinSrgba, SetColor, SetOrientation, SetPosition, SetStroke, StrokeAlign,
+    StrokeDash,
 };
 use crate::draw::{self, Drawing};
 use crate::geom::Point2;
+use crate::glam::Vec2;
 use crate::wgpu;
 use lyon::path::PathEvent;
 use lyon::tessellation::StrokeOptions;
@@ -36,6 +38,37 @@ pub trait SetPolygon: Sized {
         self
     }
 
+    /// Specify whether the stroke sits inside, centered on or outside the outline of the shape.
+    ///
+    /// By default, the stroke is centered on the outline. Only supported by shapes described by a
+    /// closed sequence of points, i.e. ellipses, rects without rounded corners, quads, tris, ngons
+    /// and stars. Other polygons are always stroked along the center of their outline.
+    fn stroke_align(mut self, align: StrokeAlign) -> Self {
+        self.polygon_options_mut().stroke_align = align;
+        self
+    }
+
+    /// Stroke the outline with a repeating pattern of dashes of the given length separated by gaps
+    /// of the given length.
+    ///
+    /// Only supported by the same shapes as `stroke_align`.
+    fn stroke_dash(mut self, dash: f32, gap: f32) -> Self {
+        self.polygon_options_mut().stroke_dash = Some(StrokeDash { dash, gap });
+        self
+    }
+
+    /// Fill the polygon with the given linear or radial gradient rather than a single color.
+    ///
+    /// The gradient is described in the local coordinate space of the polygon, i.e. prior to
+    /// applying its position and orientation.
+    fn gradient<G>(mut self, gradient: G) -> Self
+    where
+        G: Into<Gradient>,
+    {
+        self.polygon_options_mut().gradient = Some(gradient.into());
+        self
+    }
+
     /// Specify the whole set of polygon options.
     fn polygon_options(mut self, opts: PolygonOptions) -> Self {
         *self.polygon_options_mut() = opts;
@@ -58,6 +91,9 @@ pub struct PolygonOptions {
     pub stroke_color: Option<LinSrgba>,
     pub color: Option<LinSrgba>,
     pub stroke: Option<StrokeOptions>,
+    pub stroke_align: StrokeAlign,
+    pub stroke_dash: Option<StrokeDash>,
+    pub gradient: Option<Gradient>,
 }
 
 /// A polygon with vertices already submitted.
@@ -177,12 +213,29 @@ impl PolygonInit {
 pub fn render_events_themed<F, I>(
     opts: PolygonOptions,
     events: F,
-    mut ctxt: draw::renderer::RenderContext,
+    ctxt: draw::renderer::RenderContext,
+    theme_primitive: &draw::theme::Primitive,
+    mesh: &mut draw::Mesh,
+) where
+    F: Fn() -> I,
+    I: Iterator<Item = lyon::path::PathEvent>,
+{
+    render_outline_events_themed(opts, &events, &events, ctxt, theme_primitive, mesh);
+}
+
+// Fill the events produced by `fill_events` and stroke the events produced by `stroke_events`.
+fn render_outline_events_themed<F, I, G, J>(
+    opts: PolygonOptions,
+    fill_events: F,
+    stroke_events: G,
+    ctxt: draw::renderer::RenderContext,
     theme_primitive: &draw::theme::Primitive,
     mesh: &mut draw::Mesh,
 ) where
     F: Fn() -> I,
     I: Iterator<Item = lyon::path::PathEvent>,
+    G: FnOnce() -> J,
+    J: Iterator<Item = lyon::path::PathEvent>,
 {
     let PolygonOptions {
         position,
@@ -191,6 +244,8 @@ pub fn render_events_themed<F, I>(
         stroke_color,
         color,
         stroke,
+        gradient,
+        ..
     } = opts;
 
     // Determine the transform to apply to all points.
@@ -198,48 +253,49 @@ pub fn render_events_themed<F, I>(
     let local_transform = position.transform() * orientation.transform();
     let transform = global_transform * local_transform;
 
-    // A function for rendering the path.
-    let mut render =
-        |opts: path::Options,
-         color: Option<LinSrgba>,
-         theme: &draw::Theme,
-         fill_tessellator: &mut lyon::tessellation::FillTessellator,
-         stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
-            path::render_path_events(
-                events(),
-                color,
-                transform,
-                opts,
-                theme,
-                theme_primitive,
-                fill_tessellator,
-                stroke_tessellator,
-                mesh,
-            )
-        };
+    // A gradient replaces the single color fill.
+    let gradient = gradient.filter(|_| !no_fill);
+    if let Some(ref gradient) = gradient {
+        path::render_path_events_gradient(
+            fill_events(),
+            gradient,
+            transform,
+            path::Options::Fill(lyon::tessellation::FillOptions::default()),
+            ctxt.fill_tessellator,
+            ctxt.stroke_tessellator,
+            mesh,
+        );
+    }
 
     // Do the fill tessellation first.
-    if !no_fill {
+    if !no_fill && gradient.is_none() {
         let opts = path::Options::Fill(lyon::tessellation::FillOptions::default());
-        render(
-            opts,
+        path::render_path_events(
+            fill_events(),
             color,
-            &ctxt.theme,
-            &mut ctxt.fill_tessellator,
-            &mut ctxt.stroke_tessellator,
+            transform,
+            opts,
+            ctxt.theme,
+            theme_primitive,
+            ctxt.fill_tessellator,
+            ctxt.stroke_tessellator,
+            mesh,
         );
     }
 
     // Do the stroke tessellation on top.
     if let Some(stroke_opts) = stroke {
         let opts = path::Options::Stroke(stroke_opts);
-        let color = stroke_color;
-        render(
+        path::render_path_events(
+            stroke_events(),
+            stroke_color,
+            transform,
             opts,
-            color,
-            &ctxt.theme,
-            &mut ctxt.fill_tessellator,
-            &mut ctxt.stroke_tessellator,
+            ctxt.theme,
+            theme_primitive,
+            ctxt.fill_tessellator,
+            ctxt.stroke_tessellator,
+            mesh,
         );
     }
 }
@@ -253,15 +309,143 @@ pub fn render_points_themed<I>(
 ) where
     I: Clone + Iterator<Item = Point2>,
 {
-    render_events_themed(
+    let events =
+        || lyon::path::iterator::FromPolyline::closed(points.clone().map(|p| p.to_array().into()));
+    let is_aligned = opts.stroke_align != StrokeAlign::Center;
+    let outline = match opts.stroke {
+        Some(ref stroke) if is_aligned || opts.stroke_dash.is_some() => {
+            let mut outline: Vec<Point2> = points.clone().collect();
+            let offset = match opts.stroke_align {
+                StrokeAlign::Inner => -stroke.line_width * 0.5,
+                StrokeAlign::Center => 0.0,
+                StrokeAlign::Outer => stroke.line_width * 0.5,
+            };
+            if offset != 0.0 {
+                outline = offset_closed_polyline(&outline, offset);
+            }
+            outline
+        }
+        _ => return render_events_themed(opts, events, ctxt, theme_primitive, mesh),
+    };
+    let stroke_events = match opts.stroke_dash {
+        Some(dash) => dash_closed_polyline(&outline, dash),
+        None => {
+            let points = outline.iter().map(|p| p.to_array().into());
+            lyon::path::iterator::FromPolyline::closed(points).collect()
+        }
+    };
+    render_outline_events_themed(
         opts,
-        || lyon::path::iterator::FromPolyline::closed(points.clone().map(|p| p.to_array().into())),
+        events,
+        || stroke_events.into_iter(),
         ctxt,
         theme_primitive,
         mesh,
     );
 }
 
+// Offset each edge of the closed polyline outward by the given distance, or inward if negative.
+//
+// Vertices are moved along the bisector of their adjacent edges so that each edge remains
+// parallel to the original. The miter at sharp corners is limited to four times the offset.
+fn offset_closed_polyline(points: &[Point2], offset: f32) -> Vec<Point2> {
+    let mut points = points.to_vec();
+    points.dedup();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if points.len() < 3 {
+        return points;
+    }
+    // Positive for counter-clockwise outlines, in which case the outward normal of each edge is
+    // to its right.
+    let area: f32 = (0..points.len())
+        .map(|i| points[i].perp_dot(points[(i + 1) % points.len()]))
+        .sum();
+    let offset = if area < 0.0 { -offset } else { offset };
+    let normal = |a: Point2, b: Point2| {
+        let d = (b - a).normalize_or_zero();
+        Vec2::new(d.y, -d.x)
+    };
+    (0..points.len())
+        .map(|i| {
+            let prev = points[(i + points.len() - 1) % points.len()];
+            let p = points[i];
+            let next = points[(i + 1) % points.len()];
+            let n_prev = normal(prev, p);
+            let n_next = normal(p, next);
+            let bisector = (n_prev + n_next).normalize_or_zero();
+            let cos_half_angle = bisector.dot(n_next).max(0.25);
+            p + bisector * (offset / cos_half_angle)
+        })
+        .collect()
+}
+
+// The path events describing a dashed stroke along the closed polyline.
+fn dash_closed_polyline(points: &[Point2], dash: StrokeDash) -> Vec<PathEvent> {
+    let to_point = |p: Point2| lyon::math::point(p.x, p.y);
+    if dash.dash <= 0.0 || dash.gap <= 0.0 || points.len() < 2 {
+        let points = points.iter().map(|&p| to_point(p));
+        return lyon::path::iterator::FromPolyline::closed(points).collect();
+    }
+    let mut events = vec![];
+    let mut is_dash = true;
+    let mut remaining = dash.dash;
+    let mut first = points[0];
+    let mut last = first;
+    events.push(PathEvent::Begin {
+        at: to_point(first),
+    });
+    let edges = points.iter().zip(points.iter().cycle().skip(1));
+    for (&a, &b) in edges {
+        let mut length = a.distance(b);
+        while length > 0.0 {
+            let step = remaining.min(length);
+            let to = last.lerp(b, step / length);
+            if is_dash {
+                events.push(PathEvent::Line {
+                    from: to_point(last),
+                    to: to_point(to),
+                });
+            }
+            last = to;
+            length -= step;
+            remaining -= step;
+            if remaining <= 0.0 {
+                if is_dash {
+                    events.push(PathEvent::End {
+                        last: to_point(last),
+                        first: to_point(first),
+                        close: false,
+                    });
+                    remaining = dash.gap;
+                } else {
+                    first = last;
+                    events.push(PathEvent::Begin {
+                        at: to_point(first),
+                    });
+                    remaining = dash.dash;
+                }
+                is_dash = !is_dash;
+            }
+        }
+    }
+    // Finish the trailing dash, or discard it if it was only just begun.
+    if is_dash {
+        match events.last() {
+            Some(PathEvent::Begin { .. }) => {
+                events.pop();
+            }
+            _ => events.push(PathEvent::End {
+                last: to_point(last),
+                first: to_point(first),
+                close: false,
+            }),
+        }
+    }
+    events
+}
+
 impl Polygon {
     pub(crate) fn render_themed(
         self,
@@ -279,6 +463,8 @@ impl Polygon {
                     stroke_color,
                     color,
                     stroke,
+                    gradient,
+                    ..
                 },
             texture_view,
         } = self;
@@ -288,6 +474,7 @@ impl Polygon {
             path_event_buffer,
             path_points_colored_buffer,
             path_points_textured_buffer,
+            path_points_textured_colored_buffer,
             transform,
             theme,
             ..
@@ -298,6 +485,37 @@ impl Polygon {
         let local_transform = position.transform() * orientation.transform();
         let transform = global_transform * local_transform;
 
+        // A gradient replaces the fill color of the path, or the colors of colored points.
+        // Textured points are always filled with their texture.
+        let gradient = gradient.filter(|_| !no_fill);
+        let gradient_events = match (&gradient, &path_event_src) {
+            (None, _)
+            | (_, PathEventSource::TexturedPoints { .. })
+            | (_, PathEventSource::TexturedColoredPoints { .. }) => None,
+            (Some(_), PathEventSource::Buffered(range)) => {
+                let mut builder = lyon::path::Path::builder();
+                for event in &path_event_buffer[range.clone()] {
+                    builder.path_event(*event);
+                }
+                Some(builder.build())
+            }
+            (Some(_), PathEventSource::ColoredPoints { range, close }) => {
+                let points_colored = path_points_colored_buffer[range.clone()].iter().cloned();
+                path::points_colored_to_lyon_path(points_colored, *close)
+            }
+        };
+        if let (Some(gradient), Some(events)) = (&gradient, &gradient_events) {
+            path::render_path_events_gradient(
+                events.iter(),
+                gradient,
+                transform,
+                path::Options::Fill(lyon::tessellation::FillOptions::default()),
+                fill_tessellator,
+                stroke_tessellator,
+                mesh,
+            );
+        }
+
         // A function for rendering the path.
         let mut render =
             |src: path::PathEventSourceIter,
@@ -320,7 +538,7 @@ impl Polygon {
             };
 
         // Do the fill tessellation first.
-        if !no_fill {
+        if !no_fill && gradient_events.is_none() {
             let opts = path::Options::Fill(lyon::tessellation::FillOptions::default());
             match path_event_src {
                 PathEventSource::Buffered(ref range) => {
@@ -367,6 +585,23 @@ impl Polygon {
                         stroke_tessellator,
                     );
                 }
+                PathEventSource::TexturedColoredPoints { ref range, close } => {
+                    let mut points = path_points_textured_colored_buffer[range.clone()]
+                        .iter()
+                        .cloned();
+                    let src = path::PathEventSourceIter::TexturedColoredPoints {
+                        points: &mut points,
+                        close,
+                    };
+                    render(
+                        src,
+                        opts,
+                        color,
+                        theme,
+                        fill_tessellator,
+                        stroke_tessellator,
+                    );
+                }
             }
         }
 
@@ -421,15 +656,27 @@ impl Polygon {
                         stroke_tessellator,
                     );
                 }
+                PathEventSource::TexturedColoredPoints { range, close } => {
+                    let mut points = path_points_textured_colored_buffer[range].iter().cloned();
+                    let src = path::PathEventSourceIter::TexturedColoredPoints {
+                        points: &mut points,
+                        close,
+                    };
+                    render(
+                        src,
+                        opts,
+                        stroke_color,
+                        theme,
+                        fill_tessellator,
+                        stroke_tessellator,
+                    );
+                }
             }
         }
 
         match texture_view {
             None => draw::renderer::PrimitiveRender::default(),
-            Some(texture_view) => draw::renderer::PrimitiveRender {
-                texture_view: Some(texture_view),
-                vertex_mode: draw::renderer::VertexMode::Texture,
-            },
+            Some(texture_view) => draw::renderer::PrimitiveRender::texture(texture_view),
         }
     }
 }
@@ -444,6 +691,20 @@ impl draw::renderer::RenderPrimitive for Polygon {
     }
 }
 
+impl draw::svg::SvgPrimitive for Polygon {
+    fn svg_primitive(&self, ctxt: &draw::svg::SvgContext, svg: &mut String) {
+        let d = path::svg_path_data(&self.path_event_src, ctxt);
+        draw::svg::write_polygon(
+            svg,
+            ctxt,
+            &self.opts,
+            &draw::theme::Primitive::Polygon,
+            "path",
+            &format!(r#"d="{}""#, d),
+        );
+    }
+}
+
 impl<'a, T> Drawing<'a, T>
 where
     T: SetPolygon + Into<Primitive>,
@@ -465,6 +726,32 @@ where
         self.map_ty(|ty| ty.stroke_color(color))
     }
 
+    /// Specify whether the stroke sits inside, centered on or outside the outline of the shape.
+    ///
+    /// See `SetPolygon::stroke_align` for details.
+    pub fn stroke_align(self, align: StrokeAlign) -> Self {
+        self.map_ty(|ty| ty.stroke_align(align))
+    }
+
+    /// Stroke the outline with a repeating pattern of dashes of the given length separated by gaps
+    /// of the given length.
+    ///
+    /// See `SetPolygon::stroke_dash` for details.
+    pub fn stroke_dash(self, dash: f32, gap: f32) -> Self {
+        self.map_ty(|ty| ty.stroke_dash(dash, gap))
+    }
+
+    /// Fill the polygon with the given linear or radial gradient rather than a single color.
+    ///
+    /// The gradient is described in the local coordinate space of the polygon, i.e. prior to
+    /// applying its position and orientation.
+    pub fn gradient<G>(self, gradient: G) -> Self
+    where
+        G: Into<Gradient>,
+    {
+        self.map_ty(|ty| ty.gradient(gradient))
+    }
+
     /// Specify the whole set of polygon options.
     pub fn polygon_options(self, opts: PolygonOptions) -> Self {
         self.map_ty(|ty| ty.polygon_options(opts))
@@ -605,3 +892,52 @@ impl Into<Option<Polygon>> for Primitive {
         }
     }
 }
+
+#[test]
+fn test_offset_closed_polyline() {
+    let ccw = [
+        Point2::new(0.0, 0.0),
+        Point2::new(10.0, 0.0),
+        Point2::new(10.0, 10.0),
+        Point2::new(0.0, 10.0),
+    ];
+    let outer = offset_closed_polyline(&ccw, 1.0);
+    let expected = [[-1.0, -1.0], [11.0, -1.0], [11.0, 11.0], [-1.0, 11.0]];
+    for (p, e) in outer.iter().zip(&expected) {
+        assert!(p.distance(Point2::from(*e)) < 1e-5);
+    }
+    // Clockwise outlines are offset in the same direction.
+    let cw: Vec<_> = ccw.iter().rev().cloned().collect();
+    let inner = offset_closed_polyline(&cw, -1.0);
+    assert!(inner[0].distance(Point2::new(1.0, 9.0)) < 1e-5);
+}
+
+#[test]
+fn test_dash_closed_polyline() {
+    let square = [
+        Point2::new(0.0, 0.0),
+        Point2::new(10.0, 0.0),
+        Point2::new(10.0, 10.0),
+        Point2::new(0.0, 10.0),
+    ];
+    let dash = StrokeDash {
+        dash: 5.0,
+        gap: 5.0,
+    };
+    let events = dash_closed_polyline(&square, dash);
+    let begins = events
+        .iter()
+        .filter(|e| matches!(e, PathEvent::Begin { .. }))
+        .count();
+    let ends = events
+        .iter()
+        .filter(|e| matches!(e, PathEvent::End { close: false, .. }))
+        .count();
+    assert_eq!((begins, ends), (4, 4));
+    match events[1] {
+        PathEvent::Line { from, to } => {
+            assert_eq!((from.x, from.y, to.x, to.y), (0.0, 0.0, 5.0, 0.0));
+        }
+        _ => panic!("expected a line"),
+    }
+}
@@ -44,23 +44,11 @@ impl Quad {
         self.quad = geom::Quad([a, b, c, d]);
         self
     }
-}
-
-// Trait implementations.
-impl draw::renderer::RenderPrimitive for Quad {
-    fn render_primitive(
-        self,
-        ctxt: draw::renderer::RenderContext,
-        mesh: &mut draw::Mesh,
-    ) -> draw::renderer::PrimitiveRender {
-        let Quad {
-            mut quad,
-            polygon,
-            dimensions,
-        } = self;
 
-        // If dimensions were specified, scale the points to those dimensions.
-        let (maybe_x, maybe_y, _maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
+    // The quad's points, scaled to the specified dimensions if any.
+    fn scaled_quad(&self) -> geom::Quad<Point2> {
+        let mut quad = self.quad;
+        let (maybe_x, maybe_y) = (self.dimensions.x, self.dimensions.y);
         if maybe_x.is_some() || maybe_y.is_some() {
             let cuboid = quad.bounding_rect();
             let centroid = quad.centroid();
@@ -75,6 +63,19 @@ impl draw::renderer::RenderPrimitive for Quad {
             let new_d = translate(d);
             quad = geom::Quad([new_a, new_b, new_c, new_d]);
         }
+        quad
+    }
+}
+
+// Trait implementations.
+impl draw::renderer::RenderPrimitive for Quad {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let quad = self.scaled_quad();
+        let Quad { polygon, .. } = self;
 
         let points = quad.vertices();
         polygon::render_points_themed(
@@ -89,6 +90,20 @@ impl draw::renderer::RenderPrimitive for Quad {
     }
 }
 
+impl draw::svg::SvgPrimitive for Quad {
+    fn svg_primitive(&self, ctxt: &draw::svg::SvgContext, svg: &mut String) {
+        let d = draw::svg::points_data(self.scaled_quad().vertices(), true);
+        draw::svg::write_polygon(
+            svg,
+            ctxt,
+            &self.polygon.opts,
+            &draw::theme::Primitive::Quad,
+            "path",
+            &format!(r#"d="{}""#, d),
+        );
+    }
+}
+
 impl From<geom::Quad<Point2>> for Quad {
     fn from(quad: geom::Quad<Point2>) -> Self {
         let polygon = Default::default();
@@ -8,6 +8,8 @@ use crate::draw::properties::{
 use crate::draw::{self, Drawing};
 use crate::geom;
 use crate::glam::Vec2;
+use lyon::path::builder::BorderRadii;
+use lyon::path::Winding;
 use lyon::tessellation::StrokeOptions;
 
 /// Properties related to drawing a **Rect**.
@@ -15,6 +17,7 @@ use lyon::tessellation::StrokeOptions;
 pub struct Rect {
     dimensions: dimension::Properties,
     polygon: PolygonInit,
+    corner_radii: [f32; 4],
 }
 
 /// The drawing context for a Rect.
@@ -30,6 +33,28 @@ impl Rect {
     {
         self.stroke_color(color)
     }
+
+    /// Round each of the corners of the rectangle with the given radius.
+    ///
+    /// Radii that are too large to fit within the rectangle are scaled down so that the rounded
+    /// corners never overlap. A radius of at least half of the shorter side produces a capsule.
+    pub fn corner_radius(self, radius: f32) -> Self {
+        self.corner_radii(radius, radius, radius, radius)
+    }
+
+    /// Round the corners of the rectangle with the given radius for each corner.
+    ///
+    /// As with `corner_radius`, radii are scaled down uniformly if adjacent corners would overlap.
+    pub fn corner_radii(
+        mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> Self {
+        self.corner_radii = [top_left, top_right, bottom_right, bottom_left];
+        self
+    }
 }
 
 impl<'a> DrawingRect<'a> {
@@ -40,6 +65,56 @@ impl<'a> DrawingRect<'a> {
     {
         self.map_ty(|ty| ty.stroke(color))
     }
+
+    /// Round each of the corners of the rectangle with the given radius.
+    ///
+    /// Radii that are too large to fit within the rectangle are scaled down so that the rounded
+    /// corners never overlap. A radius of at least half of the shorter side produces a capsule.
+    pub fn corner_radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.corner_radius(radius))
+    }
+
+    /// Round the corners of the rectangle with the given radius for each corner.
+    ///
+    /// As with `corner_radius`, radii are scaled down uniformly if adjacent corners would overlap.
+    pub fn corner_radii(
+        self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> Self {
+        self.map_ty(|ty| ty.corner_radii(top_left, top_right, bottom_right, bottom_left))
+    }
+}
+
+// Scale the given radii down uniformly so that adjacent rounded corners never overlap.
+fn clamp_corner_radii(w: f32, h: f32, radii: [f32; 4]) -> [f32; 4] {
+    let [tl, tr, br, bl] = radii.map(f32::abs);
+    let sides = [(w, tl + tr), (w, bl + br), (h, tl + bl), (h, tr + br)];
+    let scale = sides
+        .iter()
+        .filter(|&&(_, sum)| sum > 0.0)
+        .fold(1.0f32, |scale, &(side, sum)| scale.min(side / sum));
+    [tl, tr, br, bl].map(|r| r * scale)
+}
+
+// Produce the path of a rectangle centred on the origin with the given corner radii.
+fn rounded_rect_path(w: f32, h: f32, radii: [f32; 4]) -> lyon::path::Path {
+    let (w, h) = (w.abs(), h.abs());
+    let [tl, tr, br, bl] = clamp_corner_radii(w, h, radii);
+    let min = lyon::math::point(-w * 0.5, -h * 0.5);
+    let max = lyon::math::point(w * 0.5, h * 0.5);
+    // lyon considers the minimum *y* to be the top, whereas *y* points upwards for **Draw**.
+    let radii = BorderRadii {
+        top_left: bl,
+        top_right: br,
+        bottom_left: tl,
+        bottom_right: tr,
+    };
+    let mut builder = lyon::path::Path::builder();
+    builder.add_rounded_rectangle(&lyon::math::Box2D::new(min, max), &radii, Winding::Positive);
+    builder.build()
 }
 
 impl draw::renderer::RenderPrimitive for Rect {
@@ -51,6 +126,7 @@ impl draw::renderer::RenderPrimitive for Rect {
         let Rect {
             polygon,
             dimensions,
+            corner_radii,
         } = self;
 
         // If dimensions were specified, scale the points to those dimensions.
@@ -61,17 +137,62 @@ impl draw::renderer::RenderPrimitive for Rect {
         );
         let w = maybe_x.unwrap_or(100.0);
         let h = maybe_y.unwrap_or(100.0);
-        let rect = geom::Rect::from_wh([w, h].into());
-        let points = rect.corners().vertices().map(Vec2::from);
-        polygon::render_points_themed(
-            polygon.opts,
-            points,
+        if corner_radii.iter().any(|&r| r != 0.0) {
+            let path = rounded_rect_path(w, h, corner_radii);
+            polygon::render_events_themed(
+                polygon.opts,
+                || path.iter(),
+                ctxt,
+                &draw::theme::Primitive::Rect,
+                mesh,
+            );
+        } else {
+            let rect = geom::Rect::from_wh([w, h].into());
+            let points = rect.corners().vertices().map(Vec2::from);
+            polygon::render_points_themed(
+                polygon.opts,
+                points,
+                ctxt,
+                &draw::theme::Primitive::Rect,
+                mesh,
+            );
+        }
+
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+impl draw::svg::SvgPrimitive for Rect {
+    fn svg_primitive(&self, ctxt: &draw::svg::SvgContext, svg: &mut String) {
+        let w = self.dimensions.x.map(f32::abs).unwrap_or(100.0);
+        let h = self.dimensions.y.map(f32::abs).unwrap_or(100.0);
+        let (name, geometry) = match self.corner_radii.iter().any(|&r| r != 0.0) {
+            true => {
+                let path = rounded_rect_path(w, h, self.corner_radii);
+                (
+                    "path",
+                    format!(r#"d="{}""#, draw::svg::path_data(path.iter())),
+                )
+            }
+            false => {
+                let geometry = format!(
+                    r#"x="{}" y="{}" width="{}" height="{}""#,
+                    -w * 0.5,
+                    -h * 0.5,
+                    w,
+                    h,
+                );
+                ("rect", geometry)
+            }
+        };
+        draw::svg::write_polygon(
+            svg,
             ctxt,
+            &self.polygon.opts,
             &draw::theme::Primitive::Rect,
-            mesh,
+            name,
+            &geometry,
         );
-
-        draw::renderer::PrimitiveRender::default()
     }
 }
 
@@ -86,9 +207,11 @@ impl Default for Rect {
     fn default() -> Self {
         let dimensions = <_>::default();
         let polygon = <_>::default();
+        let corner_radii = [0.0; 4];
         Rect {
             dimensions,
             polygon,
+            corner_radii,
         }
     }
 }
@@ -145,3 +268,18 @@ impl Into<Option<Rect>> for Primitive {
         }
     }
 }
+
+#[test]
+fn test_clamp_corner_radii() {
+    assert_eq!(clamp_corner_radii(20.0, 10.0, [2.0; 4]), [2.0; 4]);
+    // Radii larger than half of the shorter side produce a capsule.
+    assert_eq!(clamp_corner_radii(20.0, 10.0, [100.0; 4]), [5.0; 4]);
+    assert_eq!(
+        clamp_corner_radii(10.0, 10.0, [10.0, 0.0, 5.0, 0.0]),
+        [10.0, 0.0, 5.0, 0.0]
+    );
+    assert_eq!(
+        clamp_corner_radii(10.0, 10.0, [15.0, 5.0, 0.0, 0.0]),
+        [7.5, 2.5, 0.0, 0.0]
+    );
+}
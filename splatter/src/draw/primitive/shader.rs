@@ -0,0 +1,227 @@
+use crate::draw::primitive::path;
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{SetDimensions, SetOrientation, SetPosition};
+use crate::draw::{self, Drawing};
+use crate::geom;
+use crate::glam::Vec2;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Properties related to drawing a rect with a user-provided fragment shader.
+#[derive(Clone, Debug)]
+pub struct Shader {
+    spatial: spatial::Properties,
+    material: Material,
+}
+
+/// A user-provided WGSL fragment shader along with the inputs with which it is drawn.
+///
+/// Materials with the same source share the same compiled pipeline within the renderer.
+#[derive(Clone, Debug)]
+pub struct Material {
+    source: Arc<str>,
+    id: u64,
+    time: Option<f32>,
+    mouse: Option<Vec2>,
+    uniforms: Vec<u8>,
+}
+
+/// The drawing context for a **Shader**.
+pub type DrawingShader<'a> = Drawing<'a, Shader>;
+
+// Trait implementations.
+
+impl Shader {
+    pub(crate) fn new(source: &str) -> Self {
+        let spatial = Default::default();
+        let material = Material::new(source);
+        Self { spatial, material }
+    }
+
+    /// The number of seconds provided to the shader via `inputs.time`.
+    ///
+    /// By default, this is `app.time` when drawing via `Draw::to_frame`.
+    pub fn time(mut self, seconds: f32) -> Self {
+        self.material.time = Some(seconds);
+        self
+    }
+
+    /// The position of the mouse provided to the shader via `inputs.mouse`.
+    ///
+    /// By default, this is `app.mouse.position()` when drawing via `Draw::to_frame`.
+    pub fn mouse(mut self, position: Vec2) -> Self {
+        self.material.mouse = Some(position);
+        self
+    }
+
+    /// Provide the given data to the shader via the uniform buffer bound at `@group(1)
+    /// @binding(1)`.
+    ///
+    /// The type should be `#[repr(C)]` and match the memory layout of the corresponding uniform
+    /// struct declared within the shader, taking WGSL's alignment rules into account. Any padding
+    /// required by those rules must be declared as explicit fields in order to implement `Pod`.
+    pub fn uniforms<T>(mut self, uniforms: &T) -> Self
+    where
+        T: bytemuck::Pod,
+    {
+        self.material.uniforms = bytemuck::bytes_of(uniforms).to_vec();
+        self
+    }
+}
+
+impl<'a> DrawingShader<'a> {
+    /// The number of seconds provided to the shader via `inputs.time`.
+    ///
+    /// By default, this is `app.time` when drawing via `Draw::to_frame`.
+    pub fn time(self, seconds: f32) -> Self {
+        self.map_ty(|ty| ty.time(seconds))
+    }
+
+    /// The position of the mouse provided to the shader via `inputs.mouse`.
+    ///
+    /// By default, this is `app.mouse.position()` when drawing via `Draw::to_frame`.
+    pub fn mouse(self, position: Vec2) -> Self {
+        self.map_ty(|ty| ty.mouse(position))
+    }
+
+    /// Provide the given data to the shader via the uniform buffer bound at `@group(1)
+    /// @binding(1)`.
+    ///
+    /// The type should be `#[repr(C)]` and match the memory layout of the corresponding uniform
+    /// struct declared within the shader, taking WGSL's alignment rules into account. Any padding
+    /// required by those rules must be declared as explicit fields in order to implement `Pod`.
+    pub fn uniforms<T>(self, uniforms: &T) -> Self
+    where
+        T: bytemuck::Pod,
+    {
+        self.map_ty(|ty| ty.uniforms(uniforms))
+    }
+}
+
+impl Material {
+    fn new(source: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        source.hash(&mut hasher);
+        Material {
+            source: source.into(),
+            id: hasher.finish(),
+            time: None,
+            mouse: None,
+            uniforms: vec![],
+        }
+    }
+
+    /// The WGSL source of the fragment shader.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// A hash of the shader source, used to identify its compiled pipeline.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// The number of seconds provided to the shader, if specified by the user.
+    ///
+    /// Otherwise, the renderer provides its own time.
+    pub fn time(&self) -> Option<f32> {
+        self.time
+    }
+
+    /// The position of the mouse provided to the shader, if specified by the user.
+    ///
+    /// Otherwise, the renderer provides its own mouse position.
+    pub fn mouse(&self) -> Option<Vec2> {
+        self.mouse
+    }
+
+    /// The bytes of the user's uniform data.
+    pub fn uniforms(&self) -> &[u8] {
+        &self.uniforms
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Shader {
+    fn render_primitive(
+        self,
+        mut ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let Shader { spatial, material } = self;
+        let spatial::Properties {
+            dimensions,
+            position,
+            orientation,
+        } = spatial;
+
+        // Cover the whole output attachment unless dimensions were specified.
+        let w = dimensions.x.unwrap_or(ctxt.output_attachment_size.x);
+        let h = dimensions.y.unwrap_or(ctxt.output_attachment_size.y);
+        let rect = geom::Rect::from_w_h(w, h);
+
+        // Determine the transform to apply to all points.
+        let global_transform = *ctxt.transform;
+        let local_transform = position.transform() * orientation.transform();
+        let transform = global_transform * local_transform;
+
+        // Texture coordinates have their origin in the top left, with *v* pointing down.
+        let tex_coords = geom::Rect {
+            x: geom::Range::new(0.0, 1.0),
+            y: geom::Range::new(1.0, 0.0),
+        };
+        let points_textured = rect
+            .corners()
+            .vertices()
+            .map(Vec2::from)
+            .zip(tex_coords.corners().vertices().map(Vec2::from));
+
+        path::render_path_points_textured(
+            points_textured,
+            true,
+            transform,
+            path::Options::Fill(Default::default()),
+            &mut ctxt.fill_tessellator,
+            &mut ctxt.stroke_tessellator,
+            mesh,
+        );
+
+        draw::renderer::PrimitiveRender::shader(material)
+    }
+}
+
+impl SetOrientation for Shader {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for Shader {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for Shader {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Shader> for Primitive {
+    fn from(prim: Shader) -> Self {
+        Primitive::Shader(prim)
+    }
+}
+
+impl Into<Option<Shader>> for Primitive {
+    fn into(self) -> Option<Shader> {
+        match self {
+            Primitive::Shader(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
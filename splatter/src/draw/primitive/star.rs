@@ -0,0 +1,249 @@
+use crate::color::conv::IntoLinSrgba;
+use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition, SetStroke,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{pt2, Point2};
+use lyon::tessellation::StrokeOptions;
+
+/// Properties related to drawing a **Star**, a polygon alternating between an inner and an outer
+/// radius.
+#[derive(Clone, Debug)]
+pub struct Star {
+    points: u32,
+    outer_radius: f32,
+    inner: Inner,
+    polygon: PolygonInit,
+}
+
+// The way in which the inner radius of a **Star** is described.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Inner {
+    Radius(f32),
+    Ratio(f32),
+}
+
+/// The drawing context for a **Star**.
+pub type DrawingStar<'a> = Drawing<'a, Star>;
+
+// Star-specific methods.
+
+impl Star {
+    /// The minimum number of points of a **Star**.
+    pub const MIN_POINTS: u32 = 2;
+    /// The default number of points.
+    pub const DEFAULT_POINTS: u32 = 5;
+    /// The default outer radius.
+    pub const DEFAULT_OUTER_RADIUS: f32 = 50.0;
+    /// The default ratio of the inner radius to the outer radius.
+    pub const DEFAULT_INNER_RATIO: f32 = 0.5;
+
+    /// Stroke the outline with the given color.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.stroke_color(color)
+    }
+
+    /// Specify the number of points.
+    ///
+    /// `points` is clamped to a minimum of `MIN_POINTS`.
+    pub fn points(mut self, points: u32) -> Self {
+        self.points = points.max(Self::MIN_POINTS);
+        self
+    }
+
+    /// Specify the distance from the centre to the tip of each point.
+    pub fn outer_radius(mut self, radius: f32) -> Self {
+        self.outer_radius = radius;
+        self
+    }
+
+    /// Specify the distance from the centre to the vertices between each point.
+    pub fn inner_radius(mut self, radius: f32) -> Self {
+        self.inner = Inner::Radius(radius);
+        self
+    }
+
+    /// Specify the outer radius, with the inner radius described via `inner_ratio`.
+    ///
+    /// This is short-hand for `outer_radius`.
+    pub fn radius(self, radius: f32) -> Self {
+        self.outer_radius(radius)
+    }
+
+    /// Specify the inner radius as a ratio of the outer radius.
+    pub fn inner_ratio(mut self, ratio: f32) -> Self {
+        self.inner = Inner::Ratio(ratio);
+        self
+    }
+
+    // The vertices of the star, alternating between the outer and inner radii and starting with
+    // a point at `0.0` radians.
+    fn vertices(&self) -> impl Clone + Iterator<Item = Point2> {
+        let outer = self.outer_radius;
+        let inner = match self.inner {
+            Inner::Radius(radius) => radius,
+            Inner::Ratio(ratio) => outer * ratio,
+        };
+        let vertices = self.points * 2;
+        (0..vertices).map(move |i| {
+            let radians = i as f32 / vertices as f32 * std::f32::consts::TAU;
+            let radius = if i % 2 == 0 { outer } else { inner };
+            let (sin, cos) = radians.sin_cos();
+            pt2(cos * radius, sin * radius)
+        })
+    }
+}
+
+// Drawing methods.
+
+impl<'a> DrawingStar<'a> {
+    /// Stroke the outline with the given color.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.stroke(color))
+    }
+
+    /// Specify the number of points.
+    ///
+    /// `points` is clamped to a minimum of `Star::MIN_POINTS`.
+    pub fn points(self, points: u32) -> Self {
+        self.map_ty(|ty| ty.points(points))
+    }
+
+    /// Specify the distance from the centre to the tip of each point.
+    pub fn outer_radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.outer_radius(radius))
+    }
+
+    /// Specify the distance from the centre to the vertices between each point.
+    pub fn inner_radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.inner_radius(radius))
+    }
+
+    /// Specify the outer radius, with the inner radius described via `inner_ratio`.
+    ///
+    /// This is short-hand for `outer_radius`.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+
+    /// Specify the inner radius as a ratio of the outer radius.
+    pub fn inner_ratio(self, ratio: f32) -> Self {
+        self.map_ty(|ty| ty.inner_ratio(ratio))
+    }
+}
+
+// Trait implementations.
+
+impl draw::renderer::RenderPrimitive for Star {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let vertices = self.vertices();
+        polygon::render_points_themed(
+            self.polygon.opts,
+            vertices,
+            ctxt,
+            &draw::theme::Primitive::Star,
+            mesh,
+        );
+
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+impl draw::svg::SvgPrimitive for Star {
+    fn svg_primitive(&self, ctxt: &draw::svg::SvgContext, svg: &mut String) {
+        let d = draw::svg::points_data(self.vertices(), true);
+        draw::svg::write_polygon(
+            svg,
+            ctxt,
+            &self.polygon.opts,
+            &draw::theme::Primitive::Star,
+            "path",
+            &format!(r#"d="{}""#, d),
+        );
+    }
+}
+
+impl Default for Star {
+    fn default() -> Self {
+        Star {
+            points: Self::DEFAULT_POINTS,
+            outer_radius: Self::DEFAULT_OUTER_RADIUS,
+            inner: Inner::Ratio(Self::DEFAULT_INNER_RATIO),
+            polygon: Default::default(),
+        }
+    }
+}
+
+impl SetOrientation for Star {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Star {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetColor<ColorScalar> for Star {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Star {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Star {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Star> for Primitive {
+    fn from(prim: Star) -> Self {
+        Primitive::Star(prim)
+    }
+}
+
+impl From<Primitive> for Option<Star> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Star(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_star_vertices() {
+    let star = Star::default().points(4).radius(10.0).inner_ratio(0.25);
+    let vertices: Vec<_> = star.vertices().collect();
+    assert_eq!(vertices.len(), 8);
+    assert_eq!(vertices[0], pt2(10.0, 0.0));
+    assert!((vertices[1].length() - 2.5).abs() < 1e-5);
+    assert!((vertices[2] - pt2(0.0, 10.0)).length() < 1e-5);
+    // An explicit inner radius takes precedence over the default ratio.
+    let star = Star::default().outer_radius(10.0).inner_radius(3.0);
+    assert!((star.vertices().nth(1).unwrap().length() - 3.0).abs() < 1e-5);
+    assert_eq!(Star::default().points(0).vertices().count(), 4);
+}
@@ -7,7 +7,8 @@ use crate::draw::properties::{
 };
 use crate::draw::{self, theme, Drawing};
 use crate::geom::{self, Point2};
-use crate::text::{self, Align, Font, FontSize, Justify, Layout, Scalar, Wrap};
+use crate::text::font::IntoFont;
+use crate::text::{self, Align, FontSize, Justify, Layout, Scalar, Wrap};
 
 /// Properties related to drawing the **Text** primitive.
 #[derive(Clone, Debug)]
@@ -88,7 +89,12 @@ impl Text {
     }
 
     /// A method for specifying the `Font` used for displaying the `Text`.
-    pub fn font(self, font: Font) -> Self {
+    ///
+    /// The font may be given directly or via the `font::Id` returned by `App::load_font`.
+    pub fn font<F>(self, font: F) -> Self
+    where
+        F: IntoFont,
+    {
         self.map_layout(|l| l.font(font))
     }
 
@@ -156,6 +162,47 @@ impl Text {
         self.style.glyph_colors = colors;
         self
     }
+
+    /// Lay out the text within its layout rect, centred on the origin.
+    ///
+    /// The layout rect is described by the dimensions of the **Text**, defaulting to 200x200. The
+    /// given `text_buffer` must be that of the **Draw** instance with which the text was drawn.
+    pub(crate) fn build_text<'b>(&self, text_buffer: &'b str) -> text::Text<'b> {
+        let w = self.spatial.dimensions.x.unwrap_or(200.0);
+        let h = self.spatial.dimensions.y.unwrap_or(200.0);
+        let rect = geom::Rect::from_wh([w, h].into());
+        let layout = self.style.layout.clone().build();
+        text::text(&text_buffer[self.text.clone()])
+            .layout(&layout)
+            .build(rect)
+    }
+
+    /// The bounding rect of the laid out text after applying its position and orientation.
+    pub(crate) fn bounds(&self, text_buffer: &str) -> geom::Rect {
+        let text = self.build_text(text_buffer);
+        self.transform_rect(text.bounding_rect())
+    }
+
+    /// The rect of each laid out glyph after applying the position and orientation of the text.
+    pub(crate) fn glyph_rects(&self, text_buffer: &str) -> Vec<geom::Rect> {
+        let text = self.build_text(text_buffer);
+        text.glyphs()
+            .map(|(_, rect)| self.transform_rect(rect))
+            .collect()
+    }
+
+    // The axis-aligned bounds of the given rect after applying the position and orientation.
+    fn transform_rect(&self, rect: geom::Rect) -> geom::Rect {
+        let transform = self.spatial.position.transform() * self.spatial.orientation.transform();
+        let mut corners = rect.corners_iter().map(|[x, y]| {
+            let p = transform.transform_point3([x, y, 0.0].into());
+            Point2::new(p.x, p.y)
+        });
+        let first = corners.next().expect("a rect always has four corners");
+        corners.fold(geom::Rect::from_corners(first, first), |bounds, p| {
+            bounds.stretch_to_point(p.into())
+        })
+    }
 }
 
 impl<'a> DrawingText<'a> {
@@ -180,7 +227,14 @@ impl<'a> DrawingText<'a> {
     }
 
     /// A method for specifying the `Font` used for displaying the `Text`.
-    pub fn font(self, font: text::Font) -> Self {
+    ///
+    /// The font may be given directly or via the `font::Id` returned by `App::load_font`. If no
+    /// font was loaded with the given `Id`, the default font is used. Glyphs that are missing from
+    /// the selected font are drawn with the default font.
+    pub fn font<F>(self, font: F) -> Self
+    where
+        F: IntoFont,
+    {
         self.map_ty(|ty| ty.font(font))
     }
 
@@ -241,6 +295,35 @@ impl<'a> DrawingText<'a> {
         self.map_ty(|ty| ty.layout(layout))
     }
 
+    /// The bounding rect of the text as it will be laid out and drawn.
+    ///
+    /// This accounts for the font size, line spacing, wrapping width and justification, along
+    /// with the position of the text. If the text is rotated, this is the axis-aligned bounds of
+    /// the rotated rect. The rect is described in the coordinate space of the **Draw** instance.
+    pub fn bounds(&self) -> geom::Rect {
+        self.query(|text, text_buffer| text.bounds(text_buffer))
+    }
+
+    /// The rect of each glyph, typically one per character, as it will be laid out and drawn.
+    ///
+    /// Glyphs are ordered line by line. Like `bounds`, each rect accounts for the full layout and
+    /// the position of the text and is described in the coordinate space of the **Draw** instance.
+    pub fn glyph_rects(&self) -> Vec<geom::Rect> {
+        self.query(|text, text_buffer| text.glyph_rects(text_buffer))
+    }
+
+    // Apply the given query to the **Text** along with the text buffer of the **Draw**.
+    fn query<F, R>(&self, query: F) -> R
+    where
+        F: FnOnce(&Text, &str) -> R,
+    {
+        self.with_primitive_and_state(|prim, state| match prim {
+            Primitive::Text(text) => query(text, &state.text_buffer),
+            _ => panic!("expected `Text` but primitive contained different type"),
+        })
+        .expect("failed to access the `Text` primitive")
+    }
+
     /// Set a color for each glyph, which is typically one character.
     /// Colors unspecified glyphs using the drawing color.
     /// NOTE: Sometimes, a glyph can represent multiple characters,
@@ -265,43 +348,54 @@ impl draw::renderer::RenderPrimitive for Text {
         ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Text {
-            spatial,
-            style,
-            text,
-        } = self;
+        assert!(
+            self.spatial.dimensions.z.is_none(),
+            "z dimension support for text is unimplemented"
+        );
+        let text = self.build_text(ctxt.text_buffer);
+        let Text { spatial, style, .. } = self;
         let Style {
             color,
             glyph_colors,
-            layout,
+            ..
         } = style;
-        let layout = layout.build();
-        let (maybe_x, maybe_y, maybe_z) = (
-            spatial.dimensions.x,
-            spatial.dimensions.y,
-            spatial.dimensions.z,
-        );
-        assert!(
-            maybe_z.is_none(),
-            "z dimension support for text is unimplemented"
-        );
-        let w = maybe_x.unwrap_or(200.0);
-        let h = maybe_y.unwrap_or(200.0);
-        let rect: geom::Rect = geom::Rect::from_wh([w, h].into());
         let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme::Primitive::Text));
 
-        let text_str = &ctxt.text_buffer[text.clone()];
-        let text = text::text(text_str).layout(&layout).build(rect);
-
-        // Queue the glyphs to be cached
+        // Position the glyphs, substituting those missing from the font with the fallback font.
         let font_id = text::font::id(text.font());
+        let mut fallback = None;
+        let chars = text.lines().flat_map(str::chars);
         let positioned_glyphs: Vec<_> = text
             .rt_glyphs(
                 ctxt.output_attachment_size,
                 ctxt.output_attachment_scale_factor,
             )
+            .zip(chars)
+            .map(|(glyph, ch)| {
+                if glyph.id() != text::GlyphId(0) {
+                    return (font_id, glyph);
+                }
+                let fallback = fallback.get_or_insert_with(|| {
+                    text::font::fallback().map(|font| (text::font::id(&font), font))
+                });
+                match fallback {
+                    Some((fallback_id, fallback_font)) if *fallback_id != font_id => {
+                        let fallback_glyph = fallback_font
+                            .glyph(ch)
+                            .scaled(glyph.scale())
+                            .positioned(glyph.position());
+                        match fallback_glyph.id() != text::GlyphId(0) {
+                            true => (*fallback_id, fallback_glyph),
+                            false => (font_id, glyph),
+                        }
+                    }
+                    _ => (font_id, glyph),
+                }
+            })
             .collect();
-        for glyph in positioned_glyphs.iter() {
+
+        // Queue the glyphs to be cached
+        for (font_id, glyph) in positioned_glyphs.iter() {
             ctxt.glyph_cache.queue_glyph(font_id.index(), glyph.clone());
         }
 
@@ -369,14 +463,14 @@ impl draw::renderer::RenderPrimitive for Text {
             .chain(std::iter::repeat(&color));
 
         // Extend the mesh with a rect for each displayed glyph.
-        for (g, g_color) in positioned_glyphs.iter().zip(glyph_colors_iter) {
-            if let Ok(Some((uv_rect, screen_rect))) = ctxt.glyph_cache.rect_for(font_id.index(), &g)
+        for ((font_id, g), g_color) in positioned_glyphs.iter().zip(glyph_colors_iter) {
+            if let Ok(Some((uv_rect, screen_rect))) = ctxt.glyph_cache.rect_for(font_id.index(), g)
             {
                 let rect = to_splatter_rect(screen_rect);
 
                 // Create a mesh-compatible vertex from the position and tex_coords.
                 let v = |p: Point2, tex_coords: [f32; 2]| -> draw::mesh::Vertex {
-                    let p = transform.transform_point3([p.x, p.y, 0.0].into());
+                    let p = transform.project_point3([p.x, p.y, 0.0].into());
                     let point = draw::mesh::vertex::Point::from(p);
                     draw::mesh::vertex::new(point, g_color.to_owned(), tex_coords.into())
                 };
@@ -456,3 +550,32 @@ impl Into<Option<Text>> for Primitive {
         }
     }
 }
+
+#[test]
+fn test_text_bounds_and_glyph_rects() {
+    let draw = draw::Draw::new();
+    let text = draw
+        .text("hello world")
+        .font_size(20)
+        .w_h(300.0, 100.0)
+        .right_justify()
+        .x_y(50.0, -20.0);
+    let bounds = text.bounds();
+    let glyph_rects = text.glyph_rects();
+    assert_eq!(glyph_rects.len(), "hello world".len());
+    assert!(bounds.w() > 0.0 && bounds.h() > 0.0);
+    // Right justified within the layout rect `[-100, 200]` along x.
+    assert!((bounds.right() - 200.0).abs() < 2.0);
+    assert!(bounds.left() > -100.0);
+    for r in &glyph_rects {
+        assert!(r.left() >= bounds.left() - 1.0 && r.right() <= bounds.right() + 1.0);
+    }
+
+    // Wrapping within a narrower width produces more lines and so taller bounds.
+    let wrapped = draw
+        .text("hello world")
+        .font_size(20)
+        .w_h(60.0, 100.0)
+        .x_y(50.0, -20.0);
+    assert!(wrapped.bounds().h() > bounds.h());
+}
@@ -13,6 +13,9 @@ pub struct Texture {
     texture_view: wgpu::TextureView,
     spatial: spatial::Properties,
     area: geom::Rect,
+    wrap: Option<wgpu::AddressMode>,
+    tile: Vec2,
+    layer: u32,
 }
 
 /// The drawing context for a Rect.
@@ -40,6 +43,9 @@ impl Texture {
             texture_view,
             spatial,
             area,
+            wrap: None,
+            tile: Vec2::ONE,
+            layer: 0,
         }
     }
 }
@@ -58,6 +64,41 @@ impl Texture {
         self.area = rect;
         self
     }
+
+    /// Specify how texture coordinates that fall outside of the texture are sampled.
+    ///
+    /// By default, the address mode of the **Draw**'s sampler is used.
+    pub fn wrap(mut self, address_mode: wgpu::AddressMode) -> Self {
+        self.wrap = Some(address_mode);
+        self
+    }
+
+    /// Repeat the texture `x` times horizontally and `y` times vertically across the drawn rect.
+    ///
+    /// Tiling extends the texture coordinates beyond the chosen `area`, so sampling beyond the
+    /// edges of the texture is determined by the `wrap` address mode. If no `wrap` mode was
+    /// specified, `AddressMode::Repeat` is used. Note that repetition always occurs across the
+    /// whole texture, so only an `area` spanning the full texture tiles seamlessly.
+    ///
+    /// By default, the texture is drawn once, i.e. `tile(1.0, 1.0)`.
+    pub fn tile(mut self, x: f32, y: f32) -> Self {
+        self.tile = Vec2::new(x, y);
+        self
+    }
+
+    /// Specify the layer to draw when drawing a texture array via `Draw::texture_array`.
+    ///
+    /// Drawings of different layers of the same texture array are batched together, so switching
+    /// between the layers of e.g. a sprite atlas does not require rebinding the texture. This has
+    /// no effect for textures that are not arrays.
+    ///
+    /// Note that this is distinct from `Drawing::layer`, which orders drawings by depth.
+    ///
+    /// By default, the first layer is drawn.
+    pub fn array_layer(mut self, layer: u32) -> Self {
+        self.layer = layer;
+        self
+    }
 }
 
 impl<'a> DrawingTexture<'a> {
@@ -73,6 +114,38 @@ impl<'a> DrawingTexture<'a> {
     pub fn area(self, rect: geom::Rect) -> Self {
         self.map_ty(|ty| ty.area(rect))
     }
+
+    /// Specify how texture coordinates that fall outside of the texture are sampled.
+    ///
+    /// By default, the address mode of the **Draw**'s sampler is used.
+    pub fn wrap(self, address_mode: wgpu::AddressMode) -> Self {
+        self.map_ty(|ty| ty.wrap(address_mode))
+    }
+
+    /// Repeat the texture `x` times horizontally and `y` times vertically across the drawn rect.
+    ///
+    /// Tiling extends the texture coordinates beyond the chosen `area`, so sampling beyond the
+    /// edges of the texture is determined by the `wrap` address mode. If no `wrap` mode was
+    /// specified, `AddressMode::Repeat` is used. Note that repetition always occurs across the
+    /// whole texture, so only an `area` spanning the full texture tiles seamlessly.
+    ///
+    /// By default, the texture is drawn once, i.e. `tile(1.0, 1.0)`.
+    pub fn tile(self, x: f32, y: f32) -> Self {
+        self.map_ty(|ty| ty.tile(x, y))
+    }
+
+    /// Specify the layer to draw when drawing a texture array via `Draw::texture_array`.
+    ///
+    /// Drawings of different layers of the same texture array are batched together, so switching
+    /// between the layers of e.g. a sprite atlas does not require rebinding the texture. This has
+    /// no effect for textures that are not arrays.
+    ///
+    /// Note that this is distinct from `Drawing::layer`, which orders drawings by depth.
+    ///
+    /// By default, the first layer is drawn.
+    pub fn array_layer(self, layer: u32) -> Self {
+        self.map_ty(|ty| ty.array_layer(layer))
+    }
 }
 
 impl draw::renderer::RenderPrimitive for Texture {
@@ -85,6 +158,9 @@ impl draw::renderer::RenderPrimitive for Texture {
             texture_view,
             spatial,
             area,
+            wrap,
+            tile,
+            layer,
         } = self;
         let spatial::Properties {
             dimensions,
@@ -107,12 +183,35 @@ impl draw::renderer::RenderPrimitive for Texture {
         let local_transform = position.transform() * orientation.transform();
         let transform = global_transform * local_transform;
 
+        // Stretch the area of the texture from its starting corner to produce the tiling.
+        let stretch = |range: geom::Range, n: f32| geom::Range {
+            start: range.start,
+            end: range.start + (range.end - range.start) * n,
+        };
+        let area = geom::Rect {
+            x: stretch(area.x, tile.x),
+            y: stretch(area.y, tile.y),
+        };
+
+        // Texture coordinates of the GPU have their origin in the top left, with *v* pointing down.
+        let tex_coords = geom::Rect {
+            x: area.x,
+            y: geom::Range {
+                start: 1.0 - area.y.start,
+                end: 1.0 - area.y.end,
+            },
+        };
+        let wrap = match tile == Vec2::ONE {
+            true => wrap,
+            false => Some(wrap.unwrap_or(wgpu::AddressMode::Repeat)),
+        };
+
         // Create an iterator yielding texture points.
         let points_textured = rect
             .corners()
             .vertices()
             .map(Vec2::from)
-            .zip(area.invert_y().corners().vertices().map(Vec2::from));
+            .zip(tex_coords.corners().vertices().map(Vec2::from));
 
         path::render_path_points_textured(
             points_textured,
@@ -124,7 +223,11 @@ impl draw::renderer::RenderPrimitive for Texture {
             mesh,
         );
 
-        draw::renderer::PrimitiveRender::texture(texture_view)
+        let render = draw::renderer::PrimitiveRender::texture(texture_view).texture_layer(layer);
+        match wrap {
+            None => render,
+            Some(address_mode) => render.address_mode(address_mode),
+        }
     }
 }
 
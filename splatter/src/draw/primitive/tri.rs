@@ -43,6 +43,26 @@ impl Tri {
         self.tri = geom::Tri([a, b, c]);
         self
     }
+
+    // The triangle's points, scaled to the specified dimensions if any.
+    fn scaled_tri(&self) -> geom::Tri<Point2> {
+        let mut tri = self.tri;
+        let (maybe_x, maybe_y) = (self.dimensions.x, self.dimensions.y);
+        if maybe_x.is_some() || maybe_y.is_some() {
+            let cuboid = tri.bounding_rect();
+            let centroid = tri.centroid();
+            let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
+            let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
+            let scale = vec2(x_scale, y_scale);
+            let (a, b, c) = tri.into();
+            let translate = |v: Point2| centroid + ((v - centroid) * scale);
+            let new_a = translate(a);
+            let new_b = translate(b);
+            let new_c = translate(c);
+            tri = geom::Tri([new_a, new_b, new_c]);
+        }
+        tri
+    }
 }
 
 // Drawing methods.
@@ -73,26 +93,8 @@ impl draw::renderer::RenderPrimitive for Tri {
         ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Tri {
-            mut tri,
-            dimensions,
-            polygon,
-        } = self;
-        let (maybe_x, maybe_y, _maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
-        // If dimensions were specified, scale the points to those dimensions.
-        if maybe_x.is_some() || maybe_y.is_some() {
-            let cuboid = tri.bounding_rect();
-            let centroid = tri.centroid();
-            let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
-            let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
-            let scale = vec2(x_scale, y_scale);
-            let (a, b, c) = tri.into();
-            let translate = |v: Point2| centroid + ((v - centroid) * scale);
-            let new_a = translate(a);
-            let new_b = translate(b);
-            let new_c = translate(c);
-            tri = geom::Tri([new_a, new_b, new_c]);
-        }
+        let tri = self.scaled_tri();
+        let Tri { polygon, .. } = self;
         let points = tri.vertices();
         polygon::render_points_themed(
             polygon.opts,
@@ -106,6 +108,20 @@ impl draw::renderer::RenderPrimitive for Tri {
     }
 }
 
+impl draw::svg::SvgPrimitive for Tri {
+    fn svg_primitive(&self, ctxt: &draw::svg::SvgContext, svg: &mut String) {
+        let d = draw::svg::points_data(self.scaled_tri().vertices(), true);
+        draw::svg::write_polygon(
+            svg,
+            ctxt,
+            &self.polygon.opts,
+            &draw::theme::Primitive::Tri,
+            "path",
+            &format!(r#"d="{}""#, d),
+        );
+    }
+}
+
 impl From<geom::Tri<Point2>> for Tri {
     fn from(tri: geom::Tri<Point2>) -> Self {
         let dimensions = <_>::default();
@@ -0,0 +1,173 @@
+//! Linear and radial gradients that may be used to fill polygon primitives.
+
+use crate::color::{self, IntoLinSrgba};
+use crate::draw::properties::{ColorScalar, LinSrgba};
+use crate::geom::Point2;
+
+/// A gradient with which a primitive may be filled.
+///
+/// Gradients are described in the local coordinate space of the primitive, i.e. prior to
+/// applying its position and orientation. E.g. for a rectangle, `[0.0, 0.0]` is its centre.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Gradient {
+    Linear(LinearGradient),
+    Radial(RadialGradient),
+}
+
+/// A gradient that varies along the axis from `start` to `end`.
+///
+/// Each point is colored according to its projection onto the axis, where `start` is at offset
+/// `0.0` and `end` is at offset `1.0`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearGradient {
+    pub start: Point2,
+    pub end: Point2,
+    /// Offsets along the axis and their associated colors, sorted by offset.
+    pub stops: Vec<(f32, LinSrgba)>,
+}
+
+/// A gradient that varies with the distance from `center`.
+///
+/// The `center` is at offset `0.0` and the circumference at `radius` is at offset `1.0`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RadialGradient {
+    pub center: Point2,
+    pub radius: f32,
+    /// Offsets from the center and their associated colors, sorted by offset.
+    pub stops: Vec<(f32, LinSrgba)>,
+}
+
+impl Gradient {
+    /// The color of the gradient at the given point.
+    ///
+    /// Points before the first stop or after the last stop take the color of that stop. If the
+    /// gradient has no stops, the result is fully transparent.
+    pub fn color_at(&self, point: Point2) -> LinSrgba {
+        let (offset, stops) = match self {
+            Gradient::Linear(linear) => (linear.offset(point), &linear.stops),
+            Gradient::Radial(radial) => (radial.offset(point), &radial.stops),
+        };
+        sample_stops(stops, offset)
+    }
+
+    /// The distance over which the gradient varies from offset `0.0` to `1.0`.
+    pub fn extent(&self) -> f32 {
+        match self {
+            Gradient::Linear(linear) => linear.start.distance(linear.end),
+            Gradient::Radial(radial) => radial.radius.abs(),
+        }
+    }
+}
+
+impl LinearGradient {
+    /// A linear gradient along the axis from `start` to `end` with no stops.
+    pub fn new(start: Point2, end: Point2) -> Self {
+        let stops = vec![];
+        LinearGradient { start, end, stops }
+    }
+
+    /// Add a stop with the given color at the given offset along the axis.
+    pub fn stop<C>(mut self, offset: f32, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        insert_stop(&mut self.stops, offset, color.into_lin_srgba());
+        self
+    }
+
+    /// The offset of the given point's projection onto the gradient axis.
+    pub fn offset(&self, point: Point2) -> f32 {
+        let axis = self.end - self.start;
+        let len_squared = axis.length_squared();
+        if len_squared == 0.0 {
+            return 0.0;
+        }
+        (point - self.start).dot(axis) / len_squared
+    }
+}
+
+impl RadialGradient {
+    /// A radial gradient about `center` with the given `radius` and no stops.
+    pub fn new(center: Point2, radius: f32) -> Self {
+        let stops = vec![];
+        RadialGradient {
+            center,
+            radius,
+            stops,
+        }
+    }
+
+    /// Add a stop with the given color at the given offset from the center.
+    pub fn stop<C>(mut self, offset: f32, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        insert_stop(&mut self.stops, offset, color.into_lin_srgba());
+        self
+    }
+
+    /// The offset of the given point based on its distance from the center.
+    pub fn offset(&self, point: Point2) -> f32 {
+        if self.radius == 0.0 {
+            return 0.0;
+        }
+        point.distance(self.center) / self.radius.abs()
+    }
+}
+
+impl From<LinearGradient> for Gradient {
+    fn from(gradient: LinearGradient) -> Self {
+        Gradient::Linear(gradient)
+    }
+}
+
+impl From<RadialGradient> for Gradient {
+    fn from(gradient: RadialGradient) -> Self {
+        Gradient::Radial(gradient)
+    }
+}
+
+// Insert the stop, keeping the stops sorted by offset.
+fn insert_stop(stops: &mut Vec<(f32, LinSrgba)>, offset: f32, color: LinSrgba) {
+    let ix = stops
+        .iter()
+        .position(|&(o, _)| o > offset)
+        .unwrap_or(stops.len());
+    stops.insert(ix, (offset, color));
+}
+
+// Interpolate the color between the two stops nearest to the given offset.
+fn sample_stops(stops: &[(f32, LinSrgba)], offset: f32) -> LinSrgba {
+    match stops.is_empty() {
+        true => LinSrgba::new(0.0, 0.0, 0.0, 0.0),
+        false => color::gradient(stops, offset),
+    }
+}
+
+#[test]
+fn test_gradient_color_at() {
+    use crate::geom::pt2;
+    let red = LinSrgba::new(1.0, 0.0, 0.0, 1.0);
+    let blue = LinSrgba::new(0.0, 0.0, 1.0, 1.0);
+    let linear: Gradient = LinearGradient::new(pt2(-10.0, 0.0), pt2(10.0, 0.0))
+        .stop(1.0, blue)
+        .stop(0.0, red)
+        .into();
+    assert_eq!(linear.color_at(pt2(-20.0, 5.0)), red);
+    assert_eq!(
+        linear.color_at(pt2(0.0, 5.0)),
+        LinSrgba::new(0.5, 0.0, 0.5, 1.0)
+    );
+    assert_eq!(linear.color_at(pt2(20.0, -5.0)), blue);
+    let radial: Gradient = RadialGradient::new(pt2(0.0, 0.0), 10.0)
+        .stop(0.0, red)
+        .stop(0.5, blue)
+        .into();
+    assert_eq!(radial.color_at(pt2(0.0, 0.0)), red);
+    assert_eq!(
+        radial.color_at(pt2(0.0, -2.5)),
+        LinSrgba::new(0.5, 0.0, 0.5, 1.0)
+    );
+    assert_eq!(radial.color_at(pt2(8.0, 0.0)), blue);
+    assert_eq!(radial.extent(), 10.0);
+}
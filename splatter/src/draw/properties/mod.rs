@@ -8,15 +8,17 @@
 
 pub mod color;
 pub mod fill;
+pub mod gradient;
 pub mod spatial;
 pub mod stroke;
 
 pub use self::color::SetColor;
 pub use self::fill::SetFill;
+pub use self::gradient::{Gradient, LinearGradient, RadialGradient};
 pub use self::spatial::dimension::SetDimensions;
 pub use self::spatial::orientation::SetOrientation;
 pub use self::spatial::position::SetPosition;
-pub use self::stroke::SetStroke;
+pub use self::stroke::{SetStroke, StrokeAlign, StrokeDash};
 
 /// The scalar type used for the color channel values.
 pub type ColorScalar = crate::color::DefaultScalar;
@@ -1,5 +1,26 @@
 use lyon::tessellation::{LineCap, LineJoin, StrokeOptions};
 
+/// Where the stroke of a closed shape sits relative to the shape's outline.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum StrokeAlign {
+    /// The stroke lies entirely inside the outline.
+    Inner,
+    /// The stroke is centered on the outline, extending half of its weight to either side.
+    #[default]
+    Center,
+    /// The stroke lies entirely outside the outline.
+    Outer,
+}
+
+/// A repeating pattern of dashes along the stroke of a closed shape.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StrokeDash {
+    /// The length of each dash.
+    pub dash: f32,
+    /// The length of the gap between each dash.
+    pub gap: f32,
+}
+
 /// Nodes that support stroke tessellation.
 ///
 /// This trait allows the `Drawing` context to automatically provide an implementation of the
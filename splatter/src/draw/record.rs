@@ -0,0 +1,148 @@
+//! Snapshots of the geometry submitted to a **Draw**, for replay and comparison.
+//!
+//! See `Draw::record` and `Draw::replay`.
+
+use crate::color::{lin_srgba, LinSrgba};
+use crate::draw::{self, renderer::VertexMode, Draw};
+use crate::glam::Vec3;
+use serde_derive::{Deserialize, Serialize};
+
+/// A serializable snapshot of the primitives submitted to a **Draw**, produced by `Draw::record`.
+///
+/// Each primitive is recorded as the triangles produced by tessellating it, with the transform of
+/// its context already applied to its points. Comparing two snapshots allows for asserting that a
+/// sketch produced the expected geometry without comparing pixels.
+///
+/// Text, textures and shaders are not recorded. Text is omitted entirely, while textured and
+/// shaded primitives are recorded by their geometry alone.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DrawCommands {
+    /// The color with which the **Draw** clears the frame, if any.
+    pub background_color: Option<[f32; 4]>,
+    /// The tessellated primitives in the order in which they are drawn.
+    pub primitives: Vec<RecordedPrimitive>,
+}
+
+/// The tessellated geometry of a single primitive within **DrawCommands**.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordedPrimitive {
+    /// The position of each vertex after transformation.
+    pub points: Vec<[f32; 3]>,
+    /// The linear sRGBA color of each vertex.
+    pub colors: Vec<[f32; 4]>,
+    /// The texture coordinates of each vertex.
+    pub tex_coords: Vec<[f32; 2]>,
+    /// Each trio of indices describes a single triangle.
+    pub indices: Vec<u32>,
+    /// Whether or not the primitive's vertices are colored by a texture.
+    pub is_textured: bool,
+}
+
+impl DrawCommands {
+    /// The total number of vertices across all recorded primitives.
+    pub fn vertex_count(&self) -> usize {
+        self.primitives.iter().map(|prim| prim.points.len()).sum()
+    }
+
+    /// Whether or not both snapshots describe the same geometry, allowing each point, color and
+    /// texture coordinate component to differ by up to `epsilon`.
+    ///
+    /// This is useful for comparing snapshots produced on different platforms, where tessellation
+    /// may produce slightly different floating point results.
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        let eq = |a: &[f32], b: &[f32]| {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| (a - b).abs() <= epsilon)
+        };
+        let background_eq = match (self.background_color, other.background_color) {
+            (None, None) => true,
+            (Some(a), Some(b)) => eq(&a, &b),
+            _ => false,
+        };
+        background_eq
+            && self.primitives.len() == other.primitives.len()
+            && self.primitives.iter().zip(&other.primitives).all(|(a, b)| {
+                a.indices == b.indices
+                    && a.is_textured == b.is_textured
+                    && eq(a.points.concat().as_slice(), b.points.concat().as_slice())
+                    && eq(a.colors.concat().as_slice(), b.colors.concat().as_slice())
+                    && eq(
+                        a.tex_coords.concat().as_slice(),
+                        b.tex_coords.concat().as_slice(),
+                    )
+            })
+    }
+}
+
+/// Snapshot the primitives submitted to the given **Draw** so far without draining them.
+pub(crate) fn record(draw: &Draw) -> DrawCommands {
+    draw.finish_remaining_drawings();
+    let state = draw.state.borrow();
+    let background_color = state.background_color.map(color_to_array);
+    let mut primitives = vec![];
+    draw::renderer::tessellate_commands(&state, state.ordered_commands(), |mesh, render| {
+        primitives.push(RecordedPrimitive {
+            points: mesh.points().iter().map(|p| p.to_array()).collect(),
+            colors: mesh.colors().iter().map(|&c| color_to_array(c)).collect(),
+            tex_coords: mesh.tex_coords().iter().map(|t| t.to_array()).collect(),
+            indices: mesh.indices().to_vec(),
+            is_textured: matches!(
+                render.vertex_mode,
+                VertexMode::Texture | VertexMode::TextureColor
+            ),
+        });
+    });
+    DrawCommands {
+        background_color,
+        primitives,
+    }
+}
+
+/// Submit the recorded primitives to the given **Draw** as colored meshes.
+pub(crate) fn replay(draw: &Draw, commands: &DrawCommands) {
+    if let Some([r, g, b, a]) = commands.background_color {
+        draw.background().color(lin_srgba(r, g, b, a));
+    }
+    for prim in &commands.primitives {
+        let points = prim.points.iter().zip(&prim.colors);
+        let points = points.map(|(&p, &[r, g, b, a])| (Vec3::from(p), lin_srgba(r, g, b, a)));
+        let indices = prim.indices.iter().map(|&i| i as usize);
+        draw.mesh().indexed_colored(points, indices);
+    }
+}
+
+fn color_to_array(color: LinSrgba) -> [f32; 4] {
+    [color.red, color.green, color.blue, color.alpha]
+}
+
+#[test]
+fn test_record_replay() {
+    use crate::color::RED;
+
+    let draw = Draw::new();
+    draw.background().color(RED);
+    draw.rect().w_h(20.0, 10.0).color(RED);
+    draw.tri().color(RED);
+    let commands = draw.record();
+    assert_eq!(commands.primitives.len(), 2);
+    assert!(commands.background_color.is_some());
+
+    // Recording does not drain the draw.
+    assert_eq!(draw.record(), commands);
+
+    // Replaying reproduces the same geometry.
+    let replayed = Draw::new();
+    replayed.replay(&commands);
+    let replayed = replayed.record();
+    assert_eq!(replayed.background_color, commands.background_color);
+    assert_eq!(replayed.primitives.len(), commands.primitives.len());
+    for (a, b) in replayed.primitives.iter().zip(&commands.primitives) {
+        assert_eq!(a.points, b.points);
+        assert_eq!(a.colors, b.colors);
+        assert_eq!(a.indices, b.indices);
+    }
+
+    // Snapshots survive serialization.
+    let json = serde_json::to_string(&commands).unwrap();
+    let deserialized: DrawCommands = serde_json::from_str(&json).unwrap();
+    assert!(deserialized.approx_eq(&commands, 0.0));
+}
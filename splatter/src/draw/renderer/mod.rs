@@ -8,12 +8,17 @@ use crate::text;
 use crate::wgpu;
 use lyon::path::PathEvent;
 use lyon::tessellation::{FillTessellator, StrokeTessellator};
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use wgpu::util::{BufferInitDescriptor, DeviceExt};
 
+use self::pick::PickBuffer;
+
+mod pick;
+
 /// Draw API primitives that may be rendered via the **Renderer** type.
 pub trait RenderPrimitive {
     /// Render self into the given mesh.
@@ -30,6 +35,17 @@ pub struct PrimitiveRender {
     pub texture_view: Option<wgpu::TextureView>,
     /// The way in which vertices should be coloured in the fragment shader.
     pub vertex_mode: VertexMode,
+    /// The layer sampled by the primitive's vertices if the `texture_view` is of a texture array.
+    ///
+    /// Primitives that sample different layers of the same texture array may be drawn together
+    /// without switching bind groups.
+    pub texture_layer: u32,
+    /// If `Some`, overrides the *u* and *v* address modes of the **Draw**'s sampler for this
+    /// primitive.
+    pub address_mode: Option<wgpu::AddressMode>,
+    /// If `Some`, the primitive is drawn with the given user-provided fragment shader rather than
+    /// the default pipeline.
+    pub shader: Option<draw::primitive::shader::Material>,
 }
 
 /// The context provided to primitives to assist with the rendering process.
@@ -39,6 +55,7 @@ pub struct RenderContext<'a> {
     pub path_event_buffer: &'a [PathEvent],
     pub path_points_colored_buffer: &'a [(Point2, Color)],
     pub path_points_textured_buffer: &'a [(Point2, TexCoords)],
+    pub path_points_textured_colored_buffer: &'a [(Point2, Color, TexCoords)],
     pub text_buffer: &'a str,
     pub theme: &'a draw::Theme,
     pub glyph_cache: &'a mut GlyphCache,
@@ -69,6 +86,8 @@ pub enum VertexMode {
     ///
     /// Uses the color values, but multiplies the alpha by the glyph cache texture's red value.
     Text = 2,
+    /// Use the texture color multiplied by the color values.
+    TextureColor = 3,
 }
 
 /// A helper type aimed at simplifying the rendering of splatter primitives via wgpu.
@@ -77,6 +96,8 @@ pub struct Renderer {
     glyph_cache: GlyphCache,
     vs_mod: wgpu::ShaderModule,
     fs_mod: wgpu::ShaderModule,
+    // The fragment shader used for primitives that sample a texture array.
+    fs_array_mod: wgpu::ShaderModule,
     // One pipeline per unique Pipeline ID (combination of blend, topology and component type).
     pipelines: HashMap<PipelineId, wgpu::RenderPipeline>,
     glyph_cache_texture: wgpu::Texture,
@@ -89,7 +110,7 @@ pub struct Renderer {
     text_bind_group_layout: wgpu::BindGroupLayout,
     text_bind_group: wgpu::BindGroup,
     texture_samplers: HashMap<SamplerId, wgpu::Sampler>,
-    texture_bind_group_layouts: HashMap<wgpu::TextureSampleType, wgpu::BindGroupLayout>,
+    texture_bind_group_layouts: HashMap<TextureBindGroupLayoutId, wgpu::BindGroupLayout>,
     texture_bind_groups: HashMap<BindGroupId, wgpu::BindGroup>,
     output_color_format: wgpu::TextureFormat,
     sample_count: u32,
@@ -97,7 +118,39 @@ pub struct Renderer {
     render_commands: Vec<RenderCommand>,
     mesh: draw::Mesh,
     vertex_mode_buffer: Vec<VertexMode>,
+    // The texture array layer of each vertex.
+    texture_layer_buffer: Vec<u32>,
+    // The transform and color of each instance, where the first is used for non-instanced draws.
+    instances: Vec<InstanceData>,
     uniform_buffer: wgpu::Buffer,
+    // The frame whose depth is currently stored within the depth texture, if any.
+    depth_frame: Option<DepthFrame>,
+    // The layout of the bind group containing the inputs and uniforms of user shaders.
+    shader_bind_group_layout: wgpu::BindGroupLayout,
+    // The vertex and fragment shader modules for each unique user shader source.
+    shader_modules: HashMap<ShaderId, (wgpu::ShaderModule, wgpu::ShaderModule)>,
+    // One pipeline per unique user shader source, blend and topology.
+    shader_pipelines: HashMap<ShaderPipelineId, wgpu::RenderPipeline>,
+    // The bind group for each user shader primitive drawn during the current frame.
+    shader_bind_groups: Vec<wgpu::BindGroup>,
+    // The time and mouse position provided to user shaders that do not specify their own.
+    shader_time: f32,
+    shader_mouse: Vec2,
+    // The IDs of pickable drawings during the current frame, indexed by `SetPickId` commands.
+    pick_ids: Vec<u64>,
+    // The IDs of pickable drawings rendered to the target, if any were drawn.
+    pick_buffer: Option<PickBuffer>,
+    // The number of primitives submitted during the most recent `fill`.
+    primitive_count: usize,
+}
+
+// Identifies the depth contents of a frame, i.e. the window and frame number along with the
+// number of times depth has been cleared within the frame.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct DepthFrame {
+    window_id: crate::window::Id,
+    nth: u64,
+    depth_clears: u32,
 }
 
 /// A type aimed at simplifying construction of a `draw::Renderer`.
@@ -118,24 +171,73 @@ enum RenderCommand {
     SetBindGroup(BindGroupId),
     /// Set the rectangular scissor.
     SetScissor(Scissor),
-    /// Draw the given vertex range.
+    /// Change to the pipeline of a user shader.
+    SetShaderPipeline(ShaderPipelineId),
+    /// Change to the bind group of the user shader primitive at the given index.
+    SetShaderBindGroup(usize),
+    /// Change to the pickable ID at the given index, or stop writing to the pick buffer.
+    SetPickId(Option<usize>),
+    /// Draw the given vertex range once for each instance in the given range.
     DrawIndexed {
         start_vertex: i32,
         index_range: std::ops::Range<u32>,
+        instance_range: std::ops::Range<u32>,
     },
 }
 
+/// The per-instance data uploaded to the GPU.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct InstanceData {
+    /// Transforms the vertices of the instance in "logical pixel coordinate space".
+    transform: [[f32; 4]; 4],
+    /// Multiplied with the color of each vertex.
+    color: [f32; 4],
+}
+
+impl InstanceData {
+    /// The instance used for all non-instanced draws.
+    const IDENTITY: Self = InstanceData {
+        transform: Mat4::IDENTITY.to_cols_array_2d(),
+        color: [1.0; 4],
+    };
+
+    /// The range of instances used for all non-instanced draws.
+    const IDENTITY_RANGE: std::ops::Range<u32> = 0..1;
+
+    /// The vertex attributes of the instance buffer.
+    const ATTRIBUTES: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
+        4 => Float32x4,
+        5 => Float32x4,
+        6 => Float32x4,
+        7 => Float32x4,
+        8 => Float32x4,
+    ];
+
+    fn new(transform: Mat4, color: Color) -> Self {
+        let transform = transform.to_cols_array_2d();
+        let color = [color.red, color.green, color.blue, color.alpha];
+        InstanceData { transform, color }
+    }
+}
+
 /// The position and dimensions of the scissor.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Scissor {
     left: u32,
-    bottom: u32,
+    top: u32,
     width: u32,
     height: u32,
 }
 
+/// An error that may occur while rendering a **Draw**.
 #[derive(Debug)]
-pub struct DrawError;
+pub enum DrawError {
+    /// The WGSL source of a `Draw::shader` drawing is invalid.
+    ///
+    /// Contains the error formatted along with the offending source.
+    Shader(String),
+}
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -150,11 +252,27 @@ struct Uniforms {
     proj: Mat4,
 }
 
+/// The standard inputs provided to user shaders.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct ShaderInputs {
+    /// The size of the output attachment in pixels.
+    resolution: [f32; 2],
+    /// The position of the mouse, either specified by the user or tracked by the renderer.
+    mouse: [f32; 2],
+    /// The time in seconds, either specified by the user or tracked by the renderer.
+    time: f32,
+    /// Pads the struct to the 16 byte alignment of uniform buffers.
+    _padding: [f32; 3],
+}
+
 type SamplerId = u64;
 type BindGroupId = (SamplerId, wgpu::TextureViewId);
+type TextureBindGroupLayoutId = (wgpu::TextureSampleType, wgpu::TextureViewDimension);
 type BlendId = u64;
 type ColorId = BlendId;
 type AlphaId = BlendId;
+type ShaderId = u64;
 
 /// Each of the properties that indicate a unique pipeline.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
@@ -163,6 +281,23 @@ struct PipelineId {
     alpha_id: AlphaId,
     topology: wgpu::PrimitiveTopology,
     texture_sample_type: wgpu::TextureSampleType,
+    texture_view_dimension: wgpu::TextureViewDimension,
+}
+
+/// Each of the properties that indicate a unique user shader pipeline.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+struct ShaderPipelineId {
+    shader_id: ShaderId,
+    color_id: ColorId,
+    alpha_id: AlphaId,
+    topology: wgpu::PrimitiveTopology,
+}
+
+impl PipelineId {
+    // Pipelines that sample the same kind of texture share a texture bind group layout.
+    fn texture_bind_group_layout_id(&self) -> TextureBindGroupLayoutId {
+        (self.texture_sample_type, self.texture_view_dimension)
+    }
 }
 
 impl Default for PrimitiveRender {
@@ -175,13 +310,17 @@ impl RenderPrimitive for draw::Primitive {
     fn render_primitive(self, ctxt: RenderContext, mesh: &mut draw::Mesh) -> PrimitiveRender {
         match self {
             draw::Primitive::Arrow(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Baked(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Mesh(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Ngon(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Path(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Polygon(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Tri(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Ellipse(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Quad(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Rect(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Shader(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Star(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Line(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Text(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Texture(prim) => prim.render_primitive(ctxt, mesh),
@@ -206,6 +345,9 @@ impl PrimitiveRender {
         PrimitiveRender {
             texture_view: None,
             vertex_mode,
+            texture_layer: 0,
+            address_mode: None,
+            shader: None,
         }
     }
 
@@ -217,9 +359,32 @@ impl PrimitiveRender {
         PrimitiveRender {
             vertex_mode: VertexMode::Texture,
             texture_view: Some(texture_view),
+            texture_layer: 0,
+            address_mode: None,
+            shader: None,
+        }
+    }
+
+    /// Draw the primitive with the given user-provided fragment shader.
+    pub fn shader(material: draw::primitive::shader::Material) -> Self {
+        PrimitiveRender {
+            shader: Some(material),
+            ..Self::color()
         }
     }
 
+    /// Specify the layer to sample if the primitive's texture view is of a texture array.
+    pub fn texture_layer(mut self, layer: u32) -> Self {
+        self.texture_layer = layer;
+        self
+    }
+
+    /// Override the *u* and *v* address modes of the sampler used for the primitive.
+    pub fn address_mode(mut self, address_mode: wgpu::AddressMode) -> Self {
+        self.address_mode = Some(address_mode);
+        self
+    }
+
     pub fn text() -> Self {
         Self::vertex_mode(VertexMode::Text)
     }
@@ -387,8 +552,10 @@ impl Renderer {
         // Load shader modules.
         let vs_desc = wgpu::include_wgsl!("shaders/vs.wgsl");
         let fs_desc = wgpu::include_wgsl!("shaders/fs.wgsl");
+        let fs_array_desc = wgpu::include_wgsl!("shaders/fs_array.wgsl");
         let vs_mod = device.create_shader_module(vs_desc);
         let fs_mod = device.create_shader_module(fs_desc);
+        let fs_array_mod = device.create_shader_module(fs_array_desc);
 
         // Create the glyph cache texture.
         let text_sampler_desc = wgpu::SamplerBuilder::new().into_descriptor();
@@ -429,6 +596,9 @@ impl Renderer {
         let uniform_bind_group =
             create_uniform_bind_group(device, &uniform_bind_group_layout, &uniform_buffer);
 
+        // Bind group layout for the inputs and uniforms of user shaders.
+        let shader_bind_group_layout = create_shader_bind_group_layout(device);
+
         // Bind group for text.
         let text_bind_group_layout = create_text_bind_group_layout(device, text_sampler_filtering);
         let text_bind_group = create_text_bind_group(
@@ -454,10 +624,12 @@ impl Renderer {
         let render_commands = vec![];
         let mesh = Default::default();
         let vertex_mode_buffer = vec![];
+        let texture_layer_buffer = vec![];
 
         Self {
             vs_mod,
             fs_mod,
+            fs_array_mod,
             glyph_cache,
             glyph_cache_texture,
             depth_texture,
@@ -478,28 +650,75 @@ impl Renderer {
             render_commands,
             mesh,
             vertex_mode_buffer,
+            texture_layer_buffer,
+            instances: vec![InstanceData::IDENTITY],
             uniform_buffer,
+            depth_frame: None,
+            shader_bind_group_layout,
+            shader_modules: Default::default(),
+            shader_pipelines: Default::default(),
+            shader_bind_groups: vec![],
+            shader_time: 0.0,
+            shader_mouse: Vec2::ZERO,
+            pick_ids: vec![],
+            pick_buffer: None,
+            primitive_count: 0,
         }
     }
 
+    /// The number of draw calls produced by the most recent `fill`.
+    ///
+    /// Consecutive primitives that share a pipeline, texture, sampler and scissor are coalesced
+    /// into a single draw call.
+    pub fn draw_call_count(&self) -> usize {
+        self.render_commands
+            .iter()
+            .filter(|cmd| matches!(cmd, RenderCommand::DrawIndexed { .. }))
+            .count()
+    }
+
+    /// The number of primitives drawn by the most recent `fill`.
+    pub fn primitive_count(&self) -> usize {
+        self.primitive_count
+    }
+
+    /// Specify the time and mouse position provided to user shaders via `inputs.time` and
+    /// `inputs.mouse`, unless specified for the shader via `Shader::time` or `Shader::mouse`.
+    ///
+    /// When drawing to a **Frame** via `Draw::to_frame`, these are updated from the **App**
+    /// before each render. By default, both are zero.
+    pub fn set_shader_inputs(&mut self, time: f32, mouse: Vec2) {
+        self.shader_time = time;
+        self.shader_mouse = mouse;
+    }
+
     /// Clear all pending render commands vertex data.
     pub fn clear(&mut self) {
         self.render_commands.clear();
         self.mesh.clear();
         self.vertex_mode_buffer.clear();
+        self.texture_layer_buffer.clear();
+        self.instances.clear();
+        self.instances.push(InstanceData::IDENTITY);
+        self.shader_bind_groups.clear();
+        self.pick_ids.clear();
+        self.primitive_count = 0;
     }
 
     /// Generate a list of `RenderCommand`s from the given **Draw** instance and prepare any
     /// necessary vertex data.
     ///
     /// Note that the given **Draw** instance will be *drained* of its commands.
+    ///
+    /// Returns an error if the WGSL source of a `Draw::shader` drawing is invalid, in which case
+    /// nothing will be drawn.
     pub fn fill(
         &mut self,
         device: &wgpu::Device,
         draw: &draw::Draw,
         scale_factor: f32,
         output_attachment_size: [u32; 2],
-    ) {
+    ) -> Result<(), DrawError> {
         // Pushes a draw command and updates the `curr_start_index`.
         //
         // Returns `true` if the command was added, `false` if there was nothing to
@@ -507,6 +726,7 @@ impl Renderer {
         fn push_draw_cmd(
             curr_start_index: &mut u32,
             end_index: u32,
+            instance_range: std::ops::Range<u32>,
             render_commands: &mut Vec<RenderCommand>,
         ) -> bool {
             let index_range = *curr_start_index..end_index;
@@ -516,6 +736,7 @@ impl Renderer {
                 let cmd = RenderCommand::DrawIndexed {
                     start_vertex,
                     index_range,
+                    instance_range,
                 };
                 render_commands.push(cmd);
                 true
@@ -531,12 +752,35 @@ impl Renderer {
         let pt_to_px = |s: f32| (s * scale_factor).round() as u32;
         let full_rect = Rect::from_w_h(px_to_pt(w_px), px_to_pt(h_px));
 
+        // Scissor coordinates have their origin at the top-left of the target with *y* pointing down.
         let window_to_scissor = |v: Vec2| -> [u32; 2] {
             let x = map_range(v.x, full_rect.left(), full_rect.right(), 0u32, w_px);
-            let y = map_range(v.y, full_rect.bottom(), full_rect.top(), 0u32, h_px);
+            let y = map_range(v.y, full_rect.top(), full_rect.bottom(), 0u32, h_px);
             [x, y]
         };
 
+        // Produce the scissor for the given context's scissor.
+        let to_scissor = |scissor: draw::Scissor| -> Scissor {
+            let rect = match scissor {
+                draw::Scissor::Full => full_rect,
+                draw::Scissor::Rect(rect) => full_rect
+                    .overlap(rect)
+                    .unwrap_or(geom::Rect::from_w_h(0.0, 0.0)),
+                draw::Scissor::NoOverlap => geom::Rect::from_w_h(0.0, 0.0),
+            };
+            let [left, top] = window_to_scissor(rect.top_left());
+            let (width, height) = rect.w_h();
+            // Rounding must not push the scissor beyond the bounds of the target.
+            let width = pt_to_px(width).min(w_px.saturating_sub(left));
+            let height = pt_to_px(height).min(h_px.saturating_sub(top));
+            Scissor {
+                left,
+                top,
+                width,
+                height,
+            }
+        };
+
         // TODO: Store these in `Renderer`.
         let mut fill_tessellator = FillTessellator::new();
         let mut stroke_tessellator = StrokeTessellator::new();
@@ -547,42 +791,112 @@ impl Renderer {
         let mut curr_start_index = 0;
         let mut new_tex_views = HashMap::new();
         let mut new_tex_sampler_combos = HashMap::new();
+        let mut new_samplers = HashMap::new();
+        let mut new_shader_pipeline_ids = HashMap::new();
         // Track whether new commands are required.
-        let mut curr_pipeline_id = None;
+        let mut curr_pipeline_id: Option<PipelineId> = None;
         let mut curr_scissor = None;
         let mut curr_tex_sampler_id = None;
+        let mut curr_pick_id = None;
 
         // Collect all draw commands to avoid borrow errors.
         let draw_cmds: Vec<_> = draw.drain_commands().collect();
         let draw_state = draw.state.borrow_mut();
         let intermediary_state = draw_state.intermediary_state.borrow();
-        for cmd in draw_cmds {
+
+        // Draw the background image, if any, beneath all other primitives.
+        let background = draw_state.background_image.as_ref().map(|image| {
+            let prim = image.primitive(full_rect);
+            draw::DrawCommand::Primitive(draw::Primitive::Texture(prim))
+        });
+        for cmd in background.into_iter().chain(draw_cmds) {
             match cmd {
                 draw::DrawCommand::Context(ctxt) => curr_ctxt = ctxt,
                 draw::DrawCommand::Primitive(prim) => {
+                    self.primitive_count += 1;
+
                     // Track the prev index and vertex counts.
                     let prev_index_count = self.mesh.indices().len() as u32;
                     let prev_vert_count = self.mesh.vertex_count();
 
+                    // Project through the camera into point space, if there is one.
+                    let transform = match curr_ctxt.camera {
+                        None => curr_ctxt.transform,
+                        Some(ref camera) => camera.draw_transform(full_rect) * curr_ctxt.transform,
+                    };
+
                     // Info required during rendering.
-                    let ctxt = RenderContext {
-                        intermediary_mesh: &intermediary_state.intermediary_mesh,
-                        path_event_buffer: &intermediary_state.path_event_buffer,
-                        path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
-                        path_points_textured_buffer: &intermediary_state
-                            .path_points_textured_buffer,
-                        text_buffer: &intermediary_state.text_buffer,
-                        theme: &draw_state.theme,
-                        transform: &curr_ctxt.transform,
-                        fill_tessellator: &mut fill_tessellator,
-                        stroke_tessellator: &mut stroke_tessellator,
-                        glyph_cache: &mut self.glyph_cache,
-                        output_attachment_size: Vec2::new(px_to_pt(w_px), px_to_pt(h_px)),
-                        output_attachment_scale_factor: scale_factor,
+                    macro_rules! render_context {
+                        ($transform:expr) => {
+                            RenderContext {
+                                intermediary_mesh: &intermediary_state.intermediary_mesh,
+                                path_event_buffer: &intermediary_state.path_event_buffer,
+                                path_points_colored_buffer: &intermediary_state
+                                    .path_points_colored_buffer,
+                                path_points_textured_buffer: &intermediary_state
+                                    .path_points_textured_buffer,
+                                path_points_textured_colored_buffer: &intermediary_state
+                                    .path_points_textured_colored_buffer,
+                                text_buffer: &intermediary_state.text_buffer,
+                                theme: &draw_state.theme,
+                                transform: $transform,
+                                fill_tessellator: &mut fill_tessellator,
+                                stroke_tessellator: &mut stroke_tessellator,
+                                glyph_cache: &mut self.glyph_cache,
+                                output_attachment_size: Vec2::new(px_to_pt(w_px), px_to_pt(h_px)),
+                                output_attachment_scale_factor: scale_factor,
+                            }
+                        };
+                    }
+
+                    let (prim, instances) = match prim {
+                        draw::Primitive::Instances(instances) => {
+                            let (base, instances) = instances.into_parts();
+                            (base, Some(instances))
+                        }
+                        prim => (prim, None),
+                    };
+
+                    // Instances are transformed on the GPU within the space of the context's
+                    // transform. This is not possible after a camera's perspective projection or
+                    // if the transform cannot be inverted, in which case they are rendered on the
+                    // CPU instead.
+                    let inverse = match curr_ctxt.camera {
+                        None => Some(curr_ctxt.transform.inverse()).filter(|m| m.is_finite()),
+                        Some(_) => None,
                     };
 
                     // Render the primitive.
-                    let render = prim.render_primitive(ctxt, &mut self.mesh);
+                    let mut instance_range = None;
+                    let render = match instances {
+                        None => prim.render_primitive(render_context!(&transform), &mut self.mesh),
+                        Some(instances) => match inverse {
+                            Some(inverse) => {
+                                let start = self.instances.len() as u32;
+                                self.instances.extend(instances.iter().map(|instance| {
+                                    let t = curr_ctxt.transform * instance.transform * inverse;
+                                    InstanceData::new(t, instance.color)
+                                }));
+                                instance_range = Some(start..self.instances.len() as u32);
+                                prim.render_primitive(render_context!(&transform), &mut self.mesh)
+                            }
+                            None => {
+                                let mut render = None;
+                                let mut instance_mesh = draw::Mesh::default();
+                                for instance in instances {
+                                    let transform = transform * instance.transform;
+                                    instance_mesh.clear();
+                                    let r = prim.clone().render_primitive(
+                                        render_context!(&transform),
+                                        &mut instance_mesh,
+                                    );
+                                    extend_tinted(&mut self.mesh, &instance_mesh, instance.color);
+                                    render.get_or_insert(r);
+                                }
+                                render.unwrap_or_default()
+                            }
+                        },
+                    };
 
                     // If the mesh indices are unchanged, there's nothing to be drawn.
                     if prev_index_count == self.mesh.indices().len() as u32 {
@@ -594,6 +908,89 @@ impl Renderer {
                         continue;
                     }
 
+                    // Drawings with differing pick IDs must be drawn separately to the pick buffer.
+                    if curr_ctxt.pick_id != curr_pick_id {
+                        push_draw_cmd(
+                            &mut curr_start_index,
+                            prev_index_count,
+                            InstanceData::IDENTITY_RANGE,
+                            &mut self.render_commands,
+                        );
+                        curr_pick_id = curr_ctxt.pick_id;
+                        let slot = curr_pick_id.map(|id| {
+                            self.pick_ids.push(id);
+                            self.pick_ids.len() - 1
+                        });
+                        self.render_commands.push(RenderCommand::SetPickId(slot));
+                    }
+
+                    // Primitives with a user shader are drawn alone with their own pipeline and
+                    // bind group, after which the default pipeline and bind group must be reset.
+                    if let Some(material) = render.shader {
+                        push_draw_cmd(
+                            &mut curr_start_index,
+                            prev_index_count,
+                            InstanceData::IDENTITY_RANGE,
+                            &mut self.render_commands,
+                        );
+                        if Some(curr_ctxt.scissor) != curr_scissor {
+                            curr_scissor = Some(curr_ctxt.scissor);
+                            let cmd = RenderCommand::SetScissor(to_scissor(curr_ctxt.scissor));
+                            self.render_commands.push(cmd);
+                        }
+                        let pipeline_id = ShaderPipelineId {
+                            shader_id: material.id(),
+                            color_id: blend_component_hash(&curr_ctxt.blend.color),
+                            alpha_id: blend_component_hash(&curr_ctxt.blend.alpha),
+                            topology: curr_ctxt.topology,
+                        };
+                        let inputs = ShaderInputs {
+                            resolution: [w_px as f32, h_px as f32],
+                            mouse: material.mouse().unwrap_or(self.shader_mouse).to_array(),
+                            time: material.time().unwrap_or(self.shader_time),
+                            _padding: [0.0; 3],
+                        };
+                        let bind_group = create_shader_bind_group(
+                            device,
+                            &self.shader_bind_group_layout,
+                            &material,
+                            &inputs,
+                        );
+                        self.shader_bind_groups.push(bind_group);
+                        let bind_group_index = self.shader_bind_groups.len() - 1;
+                        new_shader_pipeline_ids.insert(pipeline_id, (material, curr_ctxt.blend));
+                        let cmd = RenderCommand::SetShaderPipeline(pipeline_id);
+                        self.render_commands.push(cmd);
+                        let cmd = RenderCommand::SetShaderBindGroup(bind_group_index);
+                        self.render_commands.push(cmd);
+                        let new_vs = self.mesh.points().len() - self.vertex_mode_buffer.len();
+                        let mode = render.vertex_mode;
+                        self.vertex_mode_buffer.extend((0..new_vs).map(|_| mode));
+                        let layer = render.texture_layer;
+                        self.texture_layer_buffer.extend((0..new_vs).map(|_| layer));
+                        push_draw_cmd(
+                            &mut curr_start_index,
+                            self.mesh.indices().len() as u32,
+                            instance_range.unwrap_or(InstanceData::IDENTITY_RANGE),
+                            &mut self.render_commands,
+                        );
+                        curr_pipeline_id = None;
+                        curr_tex_sampler_id = None;
+                        continue;
+                    }
+
+                    // Primitives without a texture never sample it, so they may continue to use
+                    // the bound texture and sampler as long as the pipeline remains compatible.
+                    // This allows untextured primitives to join the batch of a textured one.
+                    let default_sample_type = self.default_texture_view.sample_type();
+                    let bound_pipeline_id = match (&render.texture_view, curr_pipeline_id) {
+                        (None, Some(id)) if id.texture_sample_type == default_sample_type => {
+                            Some(id)
+                        }
+                        _ => None,
+                    };
+                    let bound_tex_sampler_id = bound_pipeline_id.and(curr_tex_sampler_id);
+
                     // Retrieve the current texture view and texture view ID. These are necessary
                     // for producing the current pipeline and bind group IDs. Also ensure we have
                     // an entry for them in our map.
@@ -603,6 +1000,10 @@ impl Renderer {
                     };
                     let tex_view_id = tex_view.id();
                     let texture_sample_type = tex_view.sample_type();
+                    let texture_view_dimension = match bound_pipeline_id {
+                        Some(id) => id.texture_view_dimension,
+                        None => tex_view.dimension(),
+                    };
                     new_tex_views.insert(tex_view_id, tex_view);
 
                     // Determine the new current bind group layout ID, pipeline ID, bind group ID
@@ -616,11 +1017,21 @@ impl Renderer {
                             alpha_id,
                             topology,
                             texture_sample_type,
+                            texture_view_dimension,
                         }
                     };
-                    let new_bind_group_id = {
-                        let sampler_id = sampler_descriptor_hash(&curr_ctxt.sampler);
-                        (sampler_id, tex_view_id)
+                    let new_bind_group_id = match bound_tex_sampler_id {
+                        Some(id) => id,
+                        None => {
+                            let mut sampler = curr_ctxt.sampler.clone();
+                            if let Some(address_mode) = render.address_mode {
+                                sampler.address_mode_u = address_mode;
+                                sampler.address_mode_v = address_mode;
+                            }
+                            let sampler_id = sampler_descriptor_hash(&sampler);
+                            new_samplers.entry(sampler_id).or_insert(sampler);
+                            (sampler_id, tex_view_id)
+                        }
                     };
                     let new_scissor = curr_ctxt.scissor;
 
@@ -637,6 +1048,7 @@ impl Renderer {
                         push_draw_cmd(
                             &mut curr_start_index,
                             prev_index_count,
+                            InstanceData::IDENTITY_RANGE,
                             &mut self.render_commands,
                         );
                     }
@@ -666,30 +1078,32 @@ impl Renderer {
                     // If necessary, push a new scissor command.
                     if scissor_changed {
                         curr_scissor = Some(new_scissor);
-                        let rect = match curr_ctxt.scissor {
-                            draw::Scissor::Full => full_rect,
-                            draw::Scissor::Rect(rect) => full_rect
-                                .overlap(rect)
-                                .unwrap_or(geom::Rect::from_w_h(0.0, 0.0)),
-                            draw::Scissor::NoOverlap => geom::Rect::from_w_h(0.0, 0.0),
-                        };
-                        let [left, bottom] = window_to_scissor(rect.bottom_left().into());
-                        let (width, height) = rect.w_h();
-                        let (width, height) = (pt_to_px(width), pt_to_px(height));
-                        let scissor = Scissor {
-                            left,
-                            bottom,
-                            width,
-                            height,
-                        };
-                        let cmd = RenderCommand::SetScissor(scissor);
+                        let cmd = RenderCommand::SetScissor(to_scissor(new_scissor));
                         self.render_commands.push(cmd);
                     }
 
-                    // Extend the vertex mode channel.
+                    // Extend the vertex mode and texture layer channels.
                     let mode = render.vertex_mode;
                     let new_vs = self.mesh.points().len() - self.vertex_mode_buffer.len();
                     self.vertex_mode_buffer.extend((0..new_vs).map(|_| mode));
+                    let layer = render.texture_layer;
+                    self.texture_layer_buffer.extend((0..new_vs).map(|_| layer));
+
+                    // Instanced primitives are drawn separately from the surrounding batch.
+                    if let Some(instance_range) = instance_range {
+                        push_draw_cmd(
+                            &mut curr_start_index,
+                            prev_index_count,
+                            InstanceData::IDENTITY_RANGE,
+                            &mut self.render_commands,
+                        );
+                        push_draw_cmd(
+                            &mut curr_start_index,
+                            self.mesh.indices().len() as u32,
+                            instance_range,
+                            &mut self.render_commands,
+                        );
+                    }
                 }
             }
         }
@@ -698,6 +1112,7 @@ impl Renderer {
         push_draw_cmd(
             &mut curr_start_index,
             self.mesh.indices().len() as u32,
+            InstanceData::IDENTITY_RANGE,
             &mut self.render_commands,
         );
 
@@ -710,21 +1125,29 @@ impl Renderer {
         for (new_id, (color_blend, alpha_blend, sampler_filtering)) in new_pipeline_ids {
             let bind_group_layout = self
                 .texture_bind_group_layouts
-                .entry(new_id.texture_sample_type)
+                .entry(new_id.texture_bind_group_layout_id())
                 .or_insert_with(|| {
                     create_texture_bind_group_layout(
                         device,
                         sampler_filtering,
                         new_id.texture_sample_type,
+                        new_id.texture_view_dimension,
                     )
                 });
-            let new_pipeline = create_render_pipeline(
-                device,
+            let fs_mod = match new_id.texture_view_dimension {
+                wgpu::TextureViewDimension::D2Array => &self.fs_array_mod,
+                _ => &self.fs_mod,
+            };
+            let bind_group_layouts = [
                 &self.uniform_bind_group_layout,
                 &self.text_bind_group_layout,
-                &bind_group_layout,
+                &*bind_group_layout,
+            ];
+            let new_pipeline = create_render_pipeline(
+                device,
+                &bind_group_layouts,
                 &self.vs_mod,
-                &self.fs_mod,
+                fs_mod,
                 self.output_color_format,
                 self.depth_texture.format(),
                 self.sample_count,
@@ -735,6 +1158,46 @@ impl Renderer {
             self.pipelines.insert(new_id, new_pipeline);
         }
 
+        // Clear out unnecessary user shader pipelines and modules.
+        self.shader_pipelines
+            .retain(|id, _| new_shader_pipeline_ids.contains_key(id));
+        self.shader_modules
+            .retain(|id, _| new_shader_pipeline_ids.keys().any(|p| p.shader_id == *id));
+        // Clear new combos that we already have.
+        new_shader_pipeline_ids.retain(|id, _| !self.shader_pipelines.contains_key(id));
+        // Compile new user shaders and create their pipelines as necessary.
+        for (new_id, (material, blend)) in new_shader_pipeline_ids {
+            if let Entry::Vacant(entry) = self.shader_modules.entry(new_id.shader_id) {
+                match create_shader_modules(device, material.source()) {
+                    Ok(modules) => {
+                        entry.insert(modules);
+                    }
+                    Err(err) => {
+                        self.render_commands.clear();
+                        return Err(err);
+                    }
+                }
+            }
+            let (vs_mod, fs_mod) = &self.shader_modules[&new_id.shader_id];
+            let bind_group_layouts = [
+                &self.uniform_bind_group_layout,
+                &self.shader_bind_group_layout,
+            ];
+            let new_pipeline = create_render_pipeline(
+                device,
+                &bind_group_layouts,
+                vs_mod,
+                fs_mod,
+                self.output_color_format,
+                self.depth_texture.format(),
+                self.sample_count,
+                blend.color,
+                blend.alpha,
+                new_id.topology,
+            );
+            self.shader_pipelines.insert(new_id, new_pipeline);
+        }
+
         // Clear out unnecessary bind groups.
         self.texture_bind_groups
             .retain(|id, _| new_tex_sampler_combos.contains_key(id));
@@ -750,17 +1213,19 @@ impl Renderer {
             let sampler = self
                 .texture_samplers
                 .entry(new_sampler_id)
-                .or_insert_with(|| device.create_sampler(&curr_ctxt.sampler));
+                .or_insert_with(|| device.create_sampler(&new_samplers[&new_sampler_id]));
             // Retrieve the texture view.
             let texture_view = &new_tex_views[&new_tex_view_id];
             // Retrieve the associated bind group layout.
             let bind_group_layout =
-                &self.texture_bind_group_layouts[&pipeline_id.texture_sample_type];
+                &self.texture_bind_group_layouts[&pipeline_id.texture_bind_group_layout_id()];
             // Create the bind group.
             let bind_group =
                 create_texture_bind_group(device, bind_group_layout, sampler, texture_view);
             self.texture_bind_groups.insert(new_id, bind_group);
         }
+
+        Ok(())
     }
 
     /// Encode a render pass with the given **Draw**ing to the given `output_attachment`.
@@ -779,12 +1244,74 @@ impl Renderer {
         output_attachment_size: [u32; 2],
         output_attachment: &wgpu::TextureView,
         resolve_target: Option<&wgpu::TextureView>,
-    ) {
+    ) -> Result<(), DrawError> {
+        let depth_load_op = wgpu::LoadOp::Clear(Frame::DEFAULT_CLEAR_DEPTH);
+        self.depth_frame = None;
+        self.encode_render_pass_inner(
+            device,
+            encoder,
+            draw,
+            scale_factor,
+            output_attachment_size,
+            output_attachment,
+            resolve_target.map(|view| &**view),
+            depth_load_op,
+        )
+    }
+
+    /// Encode a render pass with the given **Draw**ing to a raw texture view handle, e.g. that of
+    /// a window's surface texture.
+    ///
+    /// The handle must not be multisampled.
+    pub(crate) fn encode_render_pass_to_handle(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        draw: &draw::Draw,
+        scale_factor: f32,
+        output_attachment_size: [u32; 2],
+        output_attachment: &wgpu::TextureViewHandle,
+    ) -> Result<(), DrawError> {
+        let depth_load_op = wgpu::LoadOp::Clear(Frame::DEFAULT_CLEAR_DEPTH);
+        self.depth_frame = None;
+        self.encode_render_pass_inner(
+            device,
+            encoder,
+            draw,
+            scale_factor,
+            output_attachment_size,
+            output_attachment,
+            None,
+            depth_load_op,
+        )
+    }
+
+    // Encode the render pass, loading the depth buffer with the given operation.
+    #[allow(clippy::too_many_arguments)]
+    fn encode_render_pass_inner(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        draw: &draw::Draw,
+        scale_factor: f32,
+        output_attachment_size: [u32; 2],
+        output_attachment: &wgpu::TextureViewHandle,
+        resolve_target: Option<&wgpu::TextureViewHandle>,
+        depth_load_op: wgpu::LoadOp<f32>,
+    ) -> Result<(), DrawError> {
         self.clear();
-        self.fill(device, draw, scale_factor, output_attachment_size);
+        self.fill(device, draw, scale_factor, output_attachment_size)?;
+
+        // The pick buffer only describes the drawings of the frame to which it was rendered.
+        let clear_pick_buffer = matches!(depth_load_op, wgpu::LoadOp::Clear(_));
+        if clear_pick_buffer && self.pick_ids.is_empty() {
+            self.pick_buffer = None;
+        }
 
         let Renderer {
             ref pipelines,
+            ref shader_pipelines,
+            ref shader_bind_groups,
             ref glyph_cache,
             ref glyph_cache_texture,
             ref mut depth_texture,
@@ -794,8 +1321,14 @@ impl Renderer {
             ref texture_bind_groups,
             ref mesh,
             ref vertex_mode_buffer,
-            ref mut render_commands,
+            ref texture_layer_buffer,
+            ref instances,
+            ref render_commands,
             ref uniform_buffer,
+            ref vs_mod,
+            ref uniform_bind_group_layout,
+            ref pick_ids,
+            ref mut pick_buffer,
             scale_factor: ref mut old_scale_factor,
             ..
         } = *self;
@@ -830,15 +1363,17 @@ impl Renderer {
         // Create render pass builder.
         let render_pass_builder = wgpu::RenderPassBuilder::new()
             .color_attachment(output_attachment, |color| {
-                color.resolve_target(resolve_target).load_op(load_op)
+                color.resolve_target_handle(resolve_target).load_op(load_op)
             })
-            .depth_stencil_attachment(&*depth_texture_view, |depth| depth);
+            .depth_stencil_attachment(&*depth_texture_view, |depth| {
+                depth.depth_load_op(depth_load_op)
+            });
 
         // Guard for empty mesh.
         if mesh.points().is_empty() {
             // Encode the render pass. Only clears the frame.
             render_pass_builder.begin(encoder);
-            return;
+            return Ok(());
         }
 
         // Create the vertex and index buffers.
@@ -847,6 +1382,7 @@ impl Renderer {
         let colors_bytes = colors_as_bytes(mesh.colors());
         let tex_coords_bytes = tex_coords_as_bytes(mesh.tex_coords());
         let modes_bytes = vertex_modes_as_bytes(vertex_mode_buffer);
+        let layers_bytes = texture_layers_as_bytes(texture_layer_buffer);
         let indices_bytes = indices_as_bytes(mesh.indices());
         let point_buffer = device.create_buffer_init(&BufferInitDescriptor {
             label: Some("splatter Renderer point_buffer"),
@@ -868,6 +1404,16 @@ impl Renderer {
             contents: modes_bytes,
             usage: vertex_usage,
         });
+        let layer_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("splatter Renderer layer_buffer"),
+            contents: layers_bytes,
+            usage: vertex_usage,
+        });
+        let instance_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("splatter Renderer instance_buffer"),
+            contents: instances_as_bytes(instances),
+            usage: vertex_usage,
+        });
         let index_buffer = device.create_buffer_init(&BufferInitDescriptor {
             label: Some("splatter Renderer index_buffer"),
             contents: indices_bytes,
@@ -900,17 +1446,31 @@ impl Renderer {
         render_pass.set_vertex_buffer(1, color_buffer.slice(..));
         render_pass.set_vertex_buffer(2, tex_coords_buffer.slice(..));
         render_pass.set_vertex_buffer(3, mode_buffer.slice(..));
+        render_pass.set_vertex_buffer(4, layer_buffer.slice(..));
+        render_pass.set_vertex_buffer(5, instance_buffer.slice(..));
 
         // Set the uniform and text bind groups here.
         render_pass.set_bind_group(0, uniform_bind_group, &[]);
         render_pass.set_bind_group(1, text_bind_group, &[]);
 
         // Follow the render commands.
-        for cmd in render_commands.drain(..) {
-            match cmd {
+        for cmd in render_commands {
+            match *cmd {
                 RenderCommand::SetPipeline(id) => {
                     let pipeline = &pipelines[&id];
                     render_pass.set_pipeline(pipeline);
+                    // The text bind group may have been replaced by that of a user shader.
+                    render_pass.set_bind_group(1, text_bind_group, &[]);
+                }
+
+                RenderCommand::SetShaderPipeline(id) => {
+                    let pipeline = &shader_pipelines[&id];
+                    render_pass.set_pipeline(pipeline);
+                }
+
+                RenderCommand::SetShaderBindGroup(index) => {
+                    let bind_group = &shader_bind_groups[index];
+                    render_pass.set_bind_group(1, bind_group, &[]);
                 }
 
                 RenderCommand::SetBindGroup(tex_view_id) => {
@@ -920,22 +1480,86 @@ impl Renderer {
 
                 RenderCommand::SetScissor(Scissor {
                     left,
-                    bottom,
+                    top,
                     width,
                     height,
                 }) => {
-                    render_pass.set_scissor_rect(left, bottom, width, height);
+                    render_pass.set_scissor_rect(left, top, width, height);
                 }
 
+                RenderCommand::SetPickId(_) => (),
+
                 RenderCommand::DrawIndexed {
                     start_vertex,
-                    index_range,
+                    ref index_range,
+                    ref instance_range,
                 } => {
-                    let instance_range = 0..1u32;
-                    render_pass.draw_indexed(index_range, start_vertex, instance_range);
+                    render_pass.draw_indexed(
+                        index_range.clone(),
+                        start_vertex,
+                        instance_range.clone(),
+                    );
                 }
             }
         }
+        drop(render_pass);
+
+        // Render the IDs of pickable drawings to the pick buffer.
+        if !pick_ids.is_empty() {
+            let is_stale = match *pick_buffer {
+                Some(ref pick) => pick.size() != output_attachment_size,
+                None => true,
+            };
+            if is_stale {
+                let depth_format = depth_texture.format();
+                let pick = PickBuffer::new(device, output_attachment_size, depth_format);
+                *pick_buffer = Some(pick);
+            }
+            let buffers = pick::Buffers {
+                index: &index_buffer,
+                vertex: [
+                    &point_buffer,
+                    &color_buffer,
+                    &tex_coords_buffer,
+                    &mode_buffer,
+                    &layer_buffer,
+                    &instance_buffer,
+                ],
+            };
+            if let Some(ref mut pick) = *pick_buffer {
+                pick.encode_render_pass(
+                    device,
+                    encoder,
+                    render_commands,
+                    pick_ids,
+                    vs_mod,
+                    uniform_bind_group_layout,
+                    uniform_bind_group,
+                    buffers,
+                    clear_pick_buffer || is_stale,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read the ID of the pickable drawing covering the given pixel of the most recently rendered
+    /// frame.
+    ///
+    /// Pixel coordinates have their origin at the top left of the output attachment. Returns
+    /// `None` if no pickable drawing covers the pixel. See `Draw::pickable`.
+    ///
+    /// This blocks the current thread until the GPU has finished reading the pixel.
+    pub fn pick_at(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        pixel: [u32; 2],
+    ) -> Option<u64> {
+        self.pick_buffer
+            .as_ref()
+            .and_then(|pick| pick.read(device, queue, pixel))
     }
 
     /// Encode the necessary commands to render the contents of the given **Draw**ing to the given
@@ -946,7 +1570,7 @@ impl Renderer {
         encoder: &mut wgpu::CommandEncoder,
         draw: &draw::Draw,
         texture: &wgpu::Texture,
-    ) {
+    ) -> Result<(), DrawError> {
         let size = texture.size();
         let view = texture.view().build();
         // TODO: Should we expose this for rendering to textures?
@@ -960,23 +1584,50 @@ impl Renderer {
             size,
             &view,
             resolve_target,
-        );
+        )
     }
 
     /// Encode the necessary commands to render the contents of the given **Draw**ing to the given
     /// **Frame**.
+    ///
+    /// Depth is cleared at the start of each call, unless `Frame::clear_color_and_depth` has been
+    /// called for the frame. In that case, depth is retained between consecutive calls for the same
+    /// frame so that separately submitted drawings are depth tested against each other.
     pub fn render_to_frame(
         &mut self,
         device: &wgpu::Device,
         draw: &draw::Draw,
         scale_factor: f32,
         frame: &Frame,
-    ) {
+    ) -> Result<(), DrawError> {
         let size = frame.texture().size();
         let attachment = frame.texture_view();
         let resolve_target = None;
+
+        // Retain depth from earlier passes to the same frame if the user has opted in to sharing
+        // depth between passes and it has not since been cleared.
+        let depth_load_op = match frame.depth_clear() {
+            None => {
+                self.depth_frame = None;
+                wgpu::LoadOp::Clear(Frame::DEFAULT_CLEAR_DEPTH)
+            }
+            Some((clear_depth, depth_clears)) => {
+                let depth_frame = DepthFrame {
+                    window_id: frame.window_id(),
+                    nth: frame.nth(),
+                    depth_clears,
+                };
+                let depth_load_op = match self.depth_frame == Some(depth_frame) {
+                    true => wgpu::LoadOp::Load,
+                    false => wgpu::LoadOp::Clear(clear_depth),
+                };
+                self.depth_frame = Some(depth_frame);
+                depth_load_op
+            }
+        };
+
         let mut command_encoder = frame.command_encoder();
-        self.encode_render_pass(
+        self.encode_render_pass_inner(
             device,
             &mut *command_encoder,
             draw,
@@ -984,7 +1635,8 @@ impl Renderer {
             size,
             attachment,
             resolve_target,
-        );
+            depth_load_op,
+        )
     }
 }
 
@@ -994,6 +1646,16 @@ impl Default for Builder {
     }
 }
 
+impl std::error::Error for DrawError {}
+
+impl fmt::Display for DrawError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DrawError::Shader(ref err) => write!(f, "invalid shader: {}", err),
+        }
+    }
+}
+
 impl Deref for GlyphCache {
     type Target = text::GlyphCache<'static>;
     fn deref(&self) -> &Self::Target {
@@ -1007,6 +1669,70 @@ impl DerefMut for GlyphCache {
     }
 }
 
+/// Tessellate the primitives of the given **Draw** on the CPU, draining its commands.
+///
+/// `f` is called with the mesh and render info of each primitive in turn, with each mesh
+/// transformed by its context's transform. Text primitives are skipped, as their vertices refer
+/// to the glyph cache of a particular renderer.
+pub(crate) fn tessellate<F>(draw: &draw::Draw, f: F)
+where
+    F: FnMut(&draw::Mesh, PrimitiveRender),
+{
+    let draw_cmds: Vec<_> = draw.drain_commands().collect();
+    let draw_state = draw.state.borrow();
+    tessellate_commands(&draw_state, draw_cmds, f);
+}
+
+/// Tessellate the given commands on the CPU, as `tessellate` does, without draining the **Draw**
+/// whose state they belong to.
+pub(crate) fn tessellate_commands<I, F>(draw_state: &draw::State, draw_cmds: I, mut f: F)
+where
+    I: IntoIterator<Item = draw::DrawCommand>,
+    F: FnMut(&draw::Mesh, PrimitiveRender),
+{
+    let mut fill_tessellator = FillTessellator::new();
+    let mut stroke_tessellator = StrokeTessellator::new();
+    let mut glyph_cache = GlyphCache::new(
+        [1, 1],
+        Renderer::DEFAULT_GLYPH_CACHE_SCALE_TOLERANCE,
+        Renderer::DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE,
+    );
+    let mut mesh = draw::Mesh::default();
+    let mut transform = Mat4::IDENTITY;
+    let intermediary_state = draw_state.intermediary_state.borrow();
+    for cmd in draw_cmds {
+        let prim = match cmd {
+            draw::DrawCommand::Context(ctxt) => {
+                transform = ctxt.transform;
+                continue;
+            }
+            draw::DrawCommand::Primitive(draw::Primitive::Text(_)) => continue,
+            draw::DrawCommand::Primitive(prim) => prim,
+        };
+        let ctxt = RenderContext {
+            intermediary_mesh: &intermediary_state.intermediary_mesh,
+            path_event_buffer: &intermediary_state.path_event_buffer,
+            path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
+            path_points_textured_buffer: &intermediary_state.path_points_textured_buffer,
+            path_points_textured_colored_buffer: &intermediary_state
+                .path_points_textured_colored_buffer,
+            text_buffer: &intermediary_state.text_buffer,
+            theme: &draw_state.theme,
+            transform: &transform,
+            fill_tessellator: &mut fill_tessellator,
+            stroke_tessellator: &mut stroke_tessellator,
+            glyph_cache: &mut glyph_cache,
+            output_attachment_size: Vec2::ONE,
+            output_attachment_scale_factor: 1.0,
+        };
+        mesh.clear();
+        let render = prim.render_primitive(ctxt, &mut mesh);
+        if !mesh.indices().is_empty() {
+            f(&mesh, render);
+        }
+    }
+}
+
 fn create_depth_texture(
     device: &wgpu::Device,
     size: [u32; 2],
@@ -1045,6 +1771,13 @@ fn create_uniform_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLay
         .build(device)
 }
 
+fn create_shader_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    wgpu::BindGroupLayoutBuilder::new()
+        .uniform_buffer(wgpu::ShaderStages::FRAGMENT, false)
+        .uniform_buffer(wgpu::ShaderStages::FRAGMENT, false)
+        .build(device)
+}
+
 fn create_text_bind_group_layout(device: &wgpu::Device, filtering: bool) -> wgpu::BindGroupLayout {
     wgpu::BindGroupLayoutBuilder::new()
         .sampler(wgpu::ShaderStages::FRAGMENT, filtering)
@@ -1063,13 +1796,14 @@ fn create_texture_bind_group_layout(
     device: &wgpu::Device,
     filtering: bool,
     texture_sample_type: wgpu::TextureSampleType,
+    texture_view_dimension: wgpu::TextureViewDimension,
 ) -> wgpu::BindGroupLayout {
     wgpu::BindGroupLayoutBuilder::new()
         .sampler(wgpu::ShaderStages::FRAGMENT, filtering)
         .texture(
             wgpu::ShaderStages::FRAGMENT,
             false,
-            wgpu::TextureViewDimension::D2,
+            texture_view_dimension,
             texture_sample_type,
         )
         .build(device)
@@ -1109,11 +1843,103 @@ fn create_texture_bind_group(
         .build(device, layout)
 }
 
+fn create_shader_bind_group(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    material: &draw::primitive::shader::Material,
+    inputs: &ShaderInputs,
+) -> wgpu::BindGroup {
+    let inputs_buffer = device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("splatter Renderer shader_inputs_buffer"),
+        contents: shader_inputs_as_bytes(inputs),
+        usage: wgpu::BufferUsages::UNIFORM,
+    });
+    // Uniform buffers may not be empty and are padded to a multiple of 16 bytes.
+    let mut uniforms = material.uniforms().to_vec();
+    let len = uniforms.len().div_ceil(16).max(1) * 16;
+    uniforms.resize(len, 0);
+    let uniforms_buffer = device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("splatter Renderer shader_uniforms_buffer"),
+        contents: &uniforms,
+        usage: wgpu::BufferUsages::UNIFORM,
+    });
+    wgpu::BindGroupBuilder::new()
+        .buffer::<ShaderInputs>(&inputs_buffer, 0..1)
+        .buffer_bytes(&uniforms_buffer, 0, None)
+        .build(device, layout)
+}
+
+// Compile the given user fragment shader, prefixed with the declaration of the standard inputs,
+// along with a vertex shader providing only those outputs that the fragment shader consumes.
+//
+// Returns an error rather than leaving wgpu to panic if the source cannot be parsed or lacks a
+// fragment entry point named `main`.
+fn create_shader_modules(
+    device: &wgpu::Device,
+    source: &str,
+) -> Result<(wgpu::ShaderModule, wgpu::ShaderModule), DrawError> {
+    let prelude = include_str!("shaders/shader_inputs.wgsl");
+    let source = format!("{}\n{}", prelude, source);
+    let module = naga::front::wgsl::parse_str(&source)
+        .map_err(|err| DrawError::Shader(err.emit_to_string(&source)))?;
+    let locations = fragment_input_locations(&module)
+        .ok_or_else(|| DrawError::Shader("no `@fragment` entry point named `main`".into()))?;
+    let (mut outputs, mut assignments) = (String::new(), String::new());
+    for &(location, name, ty, value) in SHADER_VERTEX_OUTPUTS {
+        if locations.contains(&location) {
+            outputs.push_str(&format!("    @location({location}) {name}: {ty},\n"));
+            assignments.push_str(&format!("    out.{name} = {value};\n"));
+        }
+    }
+    let vs_source = include_str!("shaders/vs_shader.wgsl")
+        .replace("OUTPUTS", &outputs)
+        .replace("ASSIGNMENTS", &assignments);
+    let vs_mod = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("splatter Renderer user shader vertex stage"),
+        source: wgpu::ShaderSource::Wgsl(vs_source.into()),
+    });
+    let fs_mod = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("splatter Renderer user shader"),
+        source: wgpu::ShaderSource::Wgsl(source.into()),
+    });
+    Ok((vs_mod, fs_mod))
+}
+
+// The vertex outputs that user fragment shaders may consume, along with their location, type and
+// the value assigned within `vs_shader.wgsl`.
+const SHADER_VERTEX_OUTPUTS: &[(u32, &str, &str, &str)] = &[
+    (0, "color", "vec4<f32>", "color * instance_color"),
+    (1, "tex_coords", "vec2<f32>", "tex_coords"),
+    (2, "mode", "u32", "mode"),
+];
+
+// The locations of the inputs accepted by the fragment entry point `main` of the given module.
+//
+// Returns `None` if the module has no such entry point.
+fn fragment_input_locations(module: &naga::Module) -> Option<Vec<u32>> {
+    let entry_point = module
+        .entry_points
+        .iter()
+        .find(|ep| ep.stage == naga::ShaderStage::Fragment && ep.name == "main")?;
+    let location = |binding: &Option<naga::Binding>| match *binding {
+        Some(naga::Binding::Location { location, .. }) => Some(location),
+        _ => None,
+    };
+    let mut locations = vec![];
+    for arg in &entry_point.function.arguments {
+        match module.types[arg.ty].inner {
+            naga::TypeInner::Struct { ref members, .. } if arg.binding.is_none() => {
+                locations.extend(members.iter().filter_map(|m| location(&m.binding)));
+            }
+            _ => locations.extend(location(&arg.binding)),
+        }
+    }
+    Some(locations)
+}
+
 fn create_render_pipeline(
     device: &wgpu::Device,
-    uniform_layout: &wgpu::BindGroupLayout,
-    text_layout: &wgpu::BindGroupLayout,
-    texture_layout: &wgpu::BindGroupLayout,
+    bind_group_layouts: &[&wgpu::BindGroupLayout],
     vs_mod: &wgpu::ShaderModule,
     fs_mod: &wgpu::ShaderModule,
     dst_format: wgpu::TextureFormat,
@@ -1123,22 +1949,31 @@ fn create_render_pipeline(
     alpha_blend: wgpu::BlendComponent,
     topology: wgpu::PrimitiveTopology,
 ) -> wgpu::RenderPipeline {
-    let bind_group_layouts = &[uniform_layout, text_layout, texture_layout];
-    wgpu::RenderPipelineBuilder::from_layout_descriptor(&bind_group_layouts[..], vs_mod)
-        .fragment_shader(fs_mod)
-        .color_format(dst_format)
+    vertex_buffers(wgpu::RenderPipelineBuilder::from_layout_descriptor(
+        bind_group_layouts,
+        vs_mod,
+    ))
+    .fragment_shader(fs_mod)
+    .color_format(dst_format)
+    .depth_format(depth_format)
+    .sample_count(sample_count)
+    .color_blend(color_blend)
+    .alpha_blend(alpha_blend)
+    .primitive_topology(topology)
+    .build(device)
+}
+
+// Add the layouts of the vertex and instance buffers consumed by the vertex shader.
+fn vertex_buffers(builder: wgpu::RenderPipelineBuilder) -> wgpu::RenderPipelineBuilder {
+    builder
         .add_vertex_buffer::<draw::mesh::vertex::Point>(&wgpu::vertex_attr_array![0 => Float32x3])
         .add_vertex_buffer::<draw::mesh::vertex::Color>(&wgpu::vertex_attr_array![1 => Float32x4])
         .add_vertex_buffer::<draw::mesh::vertex::TexCoords>(
             &wgpu::vertex_attr_array![2 => Float32x2],
         )
         .add_vertex_buffer::<VertexMode>(&wgpu::vertex_attr_array![3 => Uint32])
-        .depth_format(depth_format)
-        .sample_count(sample_count)
-        .color_blend(color_blend)
-        .alpha_blend(alpha_blend)
-        .primitive_topology(topology)
-        .build(device)
+        .add_vertex_buffer::<u32>(&wgpu::vertex_attr_array![9 => Uint32])
+        .add_instance_buffer::<InstanceData>(&InstanceData::ATTRIBUTES)
 }
 
 fn sampler_descriptor_hash(desc: &wgpu::SamplerDescriptor) -> SamplerId {
@@ -1171,6 +2006,30 @@ fn uniforms_as_bytes(uniforms: &Uniforms) -> &[u8] {
     unsafe { wgpu::bytes::from(uniforms) }
 }
 
+// Extend the mesh with the vertices and indices of the given mesh, multiplying each vertex color
+// by the given color.
+fn extend_tinted(mesh: &mut draw::Mesh, other: &draw::Mesh, tint: Color) {
+    let offset = mesh.raw_vertex_count() as u32;
+    let points = other.points().iter();
+    let colors = other.colors().iter();
+    let tex_coords = other.tex_coords().iter();
+    let vertices = points.zip(colors).zip(tex_coords).map(|((&p, &c), &t)| {
+        let (r, g, b, a) = c.into_components();
+        let color = Color::new(r * tint.red, g * tint.green, b * tint.blue, a * tint.alpha);
+        draw::mesh::vertex::new(p, color, t)
+    });
+    let indices = other.indices().iter().map(|i| i + offset);
+    mesh.extend(vertices, indices);
+}
+
+fn shader_inputs_as_bytes(inputs: &ShaderInputs) -> &[u8] {
+    unsafe { wgpu::bytes::from(inputs) }
+}
+
+fn instances_as_bytes(data: &[InstanceData]) -> &[u8] {
+    unsafe { wgpu::bytes::from_slice(data) }
+}
+
 fn points_as_bytes(data: &[draw::mesh::vertex::Point]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
@@ -1187,6 +2046,10 @@ fn vertex_modes_as_bytes(data: &[VertexMode]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
 
+fn texture_layers_as_bytes(data: &[u32]) -> &[u8] {
+    unsafe { wgpu::bytes::from_slice(data) }
+}
+
 fn indices_as_bytes(data: &[u32]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
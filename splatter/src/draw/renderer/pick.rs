@@ -0,0 +1,288 @@
+//! An offscreen buffer storing the ID of the pickable drawing covering each pixel.
+//!
+//! See `Draw::pickable` and `App::pick_at`.
+
+use super::{create_depth_texture, RenderCommand, Scissor};
+use crate::wgpu;
+use std::collections::HashMap;
+use wgpu::util::{BufferInitDescriptor, DeviceExt};
+
+/// The ID texture for pickable drawings along with the resources required to render to it.
+#[derive(Debug)]
+pub(super) struct PickBuffer {
+    texture: wgpu::Texture,
+    texture_view: wgpu::TextureView,
+    depth_texture_view: wgpu::TextureView,
+    fs_mod: wgpu::ShaderModule,
+    bind_group_layout: wgpu::BindGroupLayout,
+    // One pipeline per topology used by pickable drawings.
+    pipelines: HashMap<wgpu::PrimitiveTopology, wgpu::RenderPipeline>,
+}
+
+/// The vertex and index buffers of the current frame, shared with the main render pass.
+pub(super) struct Buffers<'a> {
+    pub index: &'a wgpu::Buffer,
+    pub vertex: [&'a wgpu::Buffer; 6],
+}
+
+impl PickBuffer {
+    /// Each texel stores the low and high bits of the ID and whether or not the texel was drawn.
+    pub const TEXTURE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba32Uint;
+    // The size of the ID uniform within each slot of the uniform buffer.
+    const ID_SIZE: wgpu::BufferAddress = 16;
+
+    pub fn new(device: &wgpu::Device, size: [u32; 2], depth_format: wgpu::TextureFormat) -> Self {
+        let texture = wgpu::TextureBuilder::new()
+            .size(size)
+            .format(Self::TEXTURE_FORMAT)
+            .usage(wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC)
+            .build(device);
+        let texture_view = texture.view().build();
+        let sample_count = 1;
+        let depth_texture = create_depth_texture(device, size, depth_format, sample_count);
+        let depth_texture_view = depth_texture.view().build();
+        let fs_mod = device.create_shader_module(wgpu::include_wgsl!("shaders/fs_pick.wgsl"));
+        let bind_group_layout = wgpu::BindGroupLayoutBuilder::new()
+            .uniform_buffer(wgpu::ShaderStages::FRAGMENT, true)
+            .build(device);
+        PickBuffer {
+            texture,
+            texture_view,
+            depth_texture_view,
+            fs_mod,
+            bind_group_layout,
+            pipelines: Default::default(),
+        }
+    }
+
+    /// The size of the pick buffer in pixels.
+    pub fn size(&self) -> [u32; 2] {
+        self.texture.size()
+    }
+
+    /// Encode a render pass that draws the geometry of each pickable drawing described by the
+    /// given render commands, writing the drawing's ID to each covered texel.
+    ///
+    /// If `clear` is `false`, the IDs and depth of previous passes are retained.
+    #[allow(clippy::too_many_arguments)]
+    pub fn encode_render_pass(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        render_commands: &[RenderCommand],
+        pick_ids: &[u64],
+        vs_mod: &wgpu::ShaderModule,
+        uniform_bind_group_layout: &wgpu::BindGroupLayout,
+        uniform_bind_group: &wgpu::BindGroup,
+        buffers: Buffers,
+        clear: bool,
+    ) {
+        // Create a pipeline for each topology used by the commands.
+        for cmd in render_commands {
+            let topology = match *cmd {
+                RenderCommand::SetPipeline(id) => id.topology,
+                RenderCommand::SetShaderPipeline(id) => id.topology,
+                _ => continue,
+            };
+            if !self.pipelines.contains_key(&topology) {
+                let bind_group_layouts = [uniform_bind_group_layout, &self.bind_group_layout];
+                let depth_format = self.depth_texture_view.format();
+                let pipeline = create_render_pipeline(
+                    device,
+                    &bind_group_layouts,
+                    vs_mod,
+                    &self.fs_mod,
+                    depth_format,
+                    topology,
+                );
+                self.pipelines.insert(topology, pipeline);
+            }
+        }
+
+        // Each ID occupies its own slot within the uniform buffer, selected via dynamic offset.
+        let alignment = device.limits().min_uniform_buffer_offset_alignment as wgpu::BufferAddress;
+        let stride = Self::ID_SIZE.max(alignment);
+        let mut contents = vec![0u8; stride as usize * pick_ids.len()];
+        for (slot, &id) in contents.chunks_exact_mut(stride as usize).zip(pick_ids) {
+            let texel = id_to_texel(id);
+            for (bytes, value) in slot.chunks_exact_mut(4).zip(&texel) {
+                bytes.copy_from_slice(&value.to_ne_bytes());
+            }
+        }
+        let uniform_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("splatter Renderer pick_id_buffer"),
+            contents: &contents,
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let bind_group = wgpu::BindGroupBuilder::new()
+            .buffer_bytes(&uniform_buffer, 0, wgpu::BufferSize::new(Self::ID_SIZE))
+            .build(device, &self.bind_group_layout);
+
+        let (color_load_op, depth_load_op) = match clear {
+            true => (
+                wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                wgpu::LoadOp::Clear(crate::frame::Frame::DEFAULT_CLEAR_DEPTH),
+            ),
+            false => (wgpu::LoadOp::Load, wgpu::LoadOp::Load),
+        };
+        let mut render_pass = wgpu::RenderPassBuilder::new()
+            .color_attachment(&self.texture_view, |color| color.load_op(color_load_op))
+            .depth_stencil_attachment(&self.depth_texture_view, |depth| {
+                depth.depth_load_op(depth_load_op)
+            })
+            .begin(encoder);
+        render_pass.set_index_buffer(buffers.index.slice(..), super::Renderer::INDEX_FORMAT);
+        for (slot, buffer) in buffers.vertex.iter().enumerate() {
+            render_pass.set_vertex_buffer(slot as u32, buffer.slice(..));
+        }
+        render_pass.set_bind_group(0, uniform_bind_group, &[]);
+
+        // Follow the render commands, drawing only those within a pickable context.
+        let mut topology = wgpu::RenderPipelineBuilder::DEFAULT_PRIMITIVE_TOPOLOGY;
+        let mut pick_slot = None;
+        for cmd in render_commands {
+            match *cmd {
+                RenderCommand::SetPipeline(id) => topology = id.topology,
+                RenderCommand::SetShaderPipeline(id) => topology = id.topology,
+                RenderCommand::SetPickId(slot) => pick_slot = slot,
+                RenderCommand::SetScissor(Scissor {
+                    left,
+                    top,
+                    width,
+                    height,
+                }) => {
+                    render_pass.set_scissor_rect(left, top, width, height);
+                }
+                RenderCommand::DrawIndexed {
+                    start_vertex,
+                    ref index_range,
+                    ref instance_range,
+                } => {
+                    if let Some(slot) = pick_slot {
+                        let offset = (slot as wgpu::BufferAddress * stride) as u32;
+                        render_pass.set_pipeline(&self.pipelines[&topology]);
+                        render_pass.set_bind_group(1, &bind_group, &[offset]);
+                        render_pass.draw_indexed(
+                            index_range.clone(),
+                            start_vertex,
+                            instance_range.clone(),
+                        );
+                    }
+                }
+                RenderCommand::SetBindGroup(_) | RenderCommand::SetShaderBindGroup(_) => (),
+            }
+        }
+    }
+
+    /// Read the ID of the pickable drawing covering the given pixel.
+    ///
+    /// Returns `None` if no pickable drawing covers the pixel or if it lies outside the buffer.
+    /// This blocks the current thread until the read completes.
+    pub fn read(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        [x, y]: [u32; 2],
+    ) -> Option<u64> {
+        let [w, h] = self.size();
+        if x >= w || y >= h {
+            return None;
+        }
+        let texel_size = wgpu::texture_format_size_bytes(Self::TEXTURE_FORMAT) as u64;
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("splatter Renderer pick_read_buffer"),
+            size: texel_size,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let ce_desc = wgpu::CommandEncoderDescriptor {
+            label: Some("splatter_pick_read"),
+        };
+        let mut encoder = device.create_command_encoder(&ce_desc);
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d { x, y, z: 0 },
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: None,
+                    rows_per_image: None,
+                },
+            },
+            wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+        );
+        queue.submit(std::iter::once(encoder.finish()));
+
+        // The buffer is only mapped once the device is polled.
+        let slice = buffer.slice(..);
+        let (tx, rx) = futures::channel::oneshot::channel();
+        slice.map_async(wgpu::MapMode::Read, |res| {
+            tx.send(res).expect("failed to send map_async result");
+        });
+        device.poll(wgpu::Maintain::Wait);
+        futures::executor::block_on(rx)
+            .expect("failed to receive map_async result")
+            .ok()?;
+        let bytes = slice.get_mapped_range();
+        let mut texel = [0u32; 4];
+        for (value, bytes) in texel.iter_mut().zip(bytes.chunks_exact(4)) {
+            *value = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        texel_to_id(texel)
+    }
+}
+
+// The texel written for the given ID.
+fn id_to_texel(id: u64) -> [u32; 4] {
+    [id as u32, (id >> 32) as u32, 1, 0]
+}
+
+// The ID stored within the given texel, if any was written.
+fn texel_to_id([lo, hi, written, _]: [u32; 4]) -> Option<u64> {
+    match written {
+        0 => None,
+        _ => Some(lo as u64 | (hi as u64) << 32),
+    }
+}
+
+fn create_render_pipeline(
+    device: &wgpu::Device,
+    bind_group_layouts: &[&wgpu::BindGroupLayout],
+    vs_mod: &wgpu::ShaderModule,
+    fs_mod: &wgpu::ShaderModule,
+    depth_format: wgpu::TextureFormat,
+    topology: wgpu::PrimitiveTopology,
+) -> wgpu::RenderPipeline {
+    // Integer formats may not be blended.
+    let color_state = wgpu::ColorTargetState {
+        format: PickBuffer::TEXTURE_FORMAT,
+        blend: None,
+        write_mask: wgpu::ColorWrites::ALL,
+    };
+    super::vertex_buffers(wgpu::RenderPipelineBuilder::from_layout_descriptor(
+        bind_group_layouts,
+        vs_mod,
+    ))
+    .fragment_shader(fs_mod)
+    .color_state(color_state)
+    .depth_format(depth_format)
+    .primitive_topology(topology)
+    .build(device)
+}
+
+#[test]
+fn test_pick_id_texel() {
+    for &id in &[0, 1, 42, u32::MAX as u64 + 7, u64::MAX] {
+        assert_eq!(texel_to_id(id_to_texel(id)), Some(id));
+    }
+    assert_eq!(texel_to_id([0; 4]), None);
+}
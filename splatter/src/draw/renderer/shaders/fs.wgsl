@@ -16,6 +16,8 @@ fn main(
     @location(0) color: vec4<f32>,
     @location(1) tex_coords: vec2<f32>,
     @location(2) mode: u32,
+    // Only read by `fs_array.wgsl`, but every vertex output must be consumed.
+    @location(3) texture_layer: u32,
 ) -> FragmentOutput {
     let tex_color: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
     let text_color: vec4<f32> = textureSample(text, text_sampler, tex_coords);
@@ -30,7 +32,11 @@ fn main(
             if (mode == u32(2)) {
                 out_color = vec4<f32>(color.xyz, color.w * text_alpha);
             } else {
-                out_color = vec4<f32>(1.0, 0.0, 0.0, 1.0);
+                if (mode == u32(3)) {
+                    out_color = tex_color * color;
+                } else {
+                    out_color = vec4<f32>(1.0, 0.0, 0.0, 1.0);
+                }
             }
         }
     }
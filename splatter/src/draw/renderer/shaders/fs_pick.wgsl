@@ -0,0 +1,14 @@
+// The low and high bits of the drawing's ID, followed by `1` to mark the texel as written.
+@group(1) @binding(0)
+var<uniform> pick_id: vec4<u32>;
+
+// The vertex outputs are unused, but every vertex output must be consumed.
+@fragment
+fn main(
+    @location(0) color: vec4<f32>,
+    @location(1) tex_coords: vec2<f32>,
+    @location(2) mode: u32,
+    @location(3) texture_layer: u32,
+) -> @location(0) vec4<u32> {
+    return pick_id;
+}
@@ -0,0 +1,8 @@
+struct Inputs {
+    resolution: vec2<f32>,
+    mouse: vec2<f32>,
+    time: f32,
+};
+
+@group(1) @binding(0)
+var<uniform> inputs: Inputs;
@@ -6,6 +6,7 @@ struct VertexOutput {
     @location(0) color: vec4<f32>,
     @location(1) tex_coords: vec2<f32>,
     @location(2) mode: u32,
+    @location(3) texture_layer: u32,
     @builtin(position) pos: vec4<f32>,
 };
 
@@ -18,7 +19,14 @@ fn main(
     @location(1) color: vec4<f32>,
     @location(2) tex_coords: vec2<f32>,
     @location(3) mode: u32,
+    @location(4) instance_col0: vec4<f32>,
+    @location(5) instance_col1: vec4<f32>,
+    @location(6) instance_col2: vec4<f32>,
+    @location(7) instance_col3: vec4<f32>,
+    @location(8) instance_color: vec4<f32>,
+    @location(9) texture_layer: u32,
 ) -> VertexOutput {
-    let out_pos: vec4<f32> = uniforms.proj * vec4<f32>(position, 1.0);
-    return VertexOutput(color, tex_coords, mode, out_pos);
+    let instance = mat4x4<f32>(instance_col0, instance_col1, instance_col2, instance_col3);
+    let out_pos: vec4<f32> = uniforms.proj * instance * vec4<f32>(position, 1.0);
+    return VertexOutput(color * instance_color, tex_coords, mode, texture_layer, out_pos);
 }
@@ -0,0 +1,33 @@
+// The vertex shader for user fragment shaders. Every vertex output must be consumed by the
+// fragment shader, so the renderer fills in the output fields and their assignments below with
+// only those accepted by the user's fragment shader.
+
+struct Data {
+    proj: mat4x4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) pos: vec4<f32>,
+OUTPUTS};
+
+@group(0) @binding(0)
+var<uniform> uniforms: Data;
+
+@vertex
+fn main(
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) tex_coords: vec2<f32>,
+    @location(3) mode: u32,
+    @location(4) instance_col0: vec4<f32>,
+    @location(5) instance_col1: vec4<f32>,
+    @location(6) instance_col2: vec4<f32>,
+    @location(7) instance_col3: vec4<f32>,
+    @location(8) instance_color: vec4<f32>,
+    @location(9) texture_layer: u32,
+) -> VertexOutput {
+    let instance = mat4x4<f32>(instance_col0, instance_col1, instance_col2, instance_col3);
+    var out: VertexOutput;
+    out.pos = uniforms.proj * instance * vec4<f32>(position, 1.0);
+ASSIGNMENTS    return out;
+}
@@ -0,0 +1,277 @@
+//! Items related to writing the primitives accumulated by a **Draw** to an SVG document.
+//!
+//! See the [**Draw::to_svg**](../struct.Draw.html#method.to_svg) method.
+
+use crate::color::{LinSrgba, Srgba};
+use crate::draw;
+use crate::draw::mesh::vertex::{Color, TexCoords};
+use crate::draw::primitive::polygon::PolygonOptions;
+use crate::geom::{self, Point2};
+use crate::glam::Mat4;
+use lyon::path::PathEvent;
+use lyon::tessellation::{FillOptions, FillRule, LineCap, LineJoin, StrokeOptions};
+use std::fmt::Write;
+
+/// Draw API primitives that may be written to an SVG document.
+pub(crate) trait SvgPrimitive {
+    /// Write self to the given SVG document as zero or more elements.
+    fn svg_primitive(&self, ctxt: &SvgContext, svg: &mut String);
+}
+
+/// The context provided to primitives while writing an SVG document.
+pub(crate) struct SvgContext<'a> {
+    pub transform: &'a Mat4,
+    pub path_event_buffer: &'a [PathEvent],
+    pub path_points_colored_buffer: &'a [(Point2, Color)],
+    pub path_points_textured_buffer: &'a [(Point2, TexCoords)],
+    pub path_points_textured_colored_buffer: &'a [(Point2, Color, TexCoords)],
+    pub theme: &'a draw::Theme,
+}
+
+/// The fill and stroke with which an SVG element is painted.
+pub(crate) struct Paint<'a> {
+    pub fill: Option<(LinSrgba, FillRule)>,
+    pub stroke: Option<(LinSrgba, &'a StrokeOptions)>,
+}
+
+impl SvgPrimitive for draw::Primitive {
+    fn svg_primitive(&self, ctxt: &SvgContext, svg: &mut String) {
+        match self {
+            draw::Primitive::Ellipse(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Line(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Ngon(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Path(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Polygon(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Quad(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Rect(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Star(prim) => prim.svg_primitive(ctxt, svg),
+            draw::Primitive::Tri(prim) => prim.svg_primitive(ctxt, svg),
+            // TODO: Arrows, meshes, text and textures are not yet supported.
+            _ => (),
+        }
+    }
+}
+
+/// Write the given **Draw** state as an SVG document covering the given `view` rect.
+pub(crate) fn document(state: &draw::State, view: geom::Rect) -> String {
+    let mut svg = String::new();
+    let (w, h) = view.w_h();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+        w,
+        h,
+        view.left(),
+        -view.top(),
+        w,
+        h,
+    )
+    .unwrap();
+
+    // Flip the y axis so that positive y points upwards, as it does for **Draw**.
+    svg.push_str("<g transform=\"scale(1 -1)\">\n");
+
+    if let Some(color) = state.background_color {
+        let geometry = format!(
+            r#"x="{}" y="{}" width="{}" height="{}""#,
+            view.left(),
+            view.bottom(),
+            w,
+            h,
+        );
+        let paint = Paint {
+            fill: Some((color, FillRule::NonZero)),
+            stroke: None,
+        };
+        write_element(&mut svg, "rect", &geometry, &Mat4::IDENTITY, &paint);
+    }
+
+    let intermediary_state = state.intermediary_state.borrow();
+    let mut curr_ctxt = draw::Context::default();
+    for cmd in &state.ordered_commands() {
+        match cmd {
+            draw::DrawCommand::Context(ctxt) => curr_ctxt = ctxt.clone(),
+            draw::DrawCommand::Primitive(prim) => {
+                let ctxt = SvgContext {
+                    transform: &curr_ctxt.transform,
+                    path_event_buffer: &intermediary_state.path_event_buffer,
+                    path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
+                    path_points_textured_buffer: &intermediary_state.path_points_textured_buffer,
+                    path_points_textured_colored_buffer: &intermediary_state
+                        .path_points_textured_colored_buffer,
+                    theme: &state.theme,
+                };
+                prim.svg_primitive(&ctxt, &mut svg);
+            }
+        }
+    }
+
+    svg.push_str("</g>\n</svg>\n");
+    svg
+}
+
+/// Write a single SVG element with the given geometry attributes, transform and paint.
+pub(crate) fn write_element(
+    svg: &mut String,
+    name: &str,
+    geometry: &str,
+    transform: &Mat4,
+    paint: &Paint,
+) {
+    write!(svg, "<{} {}", name, geometry).unwrap();
+    if *transform != Mat4::IDENTITY {
+        let (x, y, t) = (transform.x_axis, transform.y_axis, transform.w_axis);
+        write!(
+            svg,
+            r#" transform="matrix({} {} {} {} {} {})""#,
+            x.x, x.y, y.x, y.y, t.x, t.y
+        )
+        .unwrap();
+    }
+    match paint.fill {
+        None => svg.push_str(r#" fill="none""#),
+        Some((color, rule)) => {
+            write_color(svg, "fill", color);
+            if rule == FillRule::EvenOdd {
+                svg.push_str(r#" fill-rule="evenodd""#);
+            }
+        }
+    }
+    if let Some((color, opts)) = paint.stroke {
+        write_color(svg, "stroke", color);
+        write!(
+            svg,
+            r#" stroke-width="{}" stroke-linecap="{}" stroke-linejoin="{}" stroke-miterlimit="{}""#,
+            opts.line_width,
+            line_cap(opts.start_cap),
+            line_join(opts.line_join),
+            opts.miter_limit,
+        )
+        .unwrap();
+    }
+    svg.push_str("/>\n");
+}
+
+/// Write an element for one of the polygon primitives, painted according to its options.
+pub(crate) fn write_polygon(
+    svg: &mut String,
+    ctxt: &SvgContext,
+    opts: &PolygonOptions,
+    theme_primitive: &draw::theme::Primitive,
+    name: &str,
+    geometry: &str,
+) {
+    let local_transform = opts.position.transform() * opts.orientation.transform();
+    let transform = *ctxt.transform * local_transform;
+    // TODO: Gradient fills are not yet supported and are written with the fill color.
+    let fill = match opts.no_fill {
+        true => None,
+        false => {
+            let color = opts
+                .color
+                .unwrap_or_else(|| ctxt.theme.fill_lin_srgba(theme_primitive));
+            Some((color, FillOptions::default().fill_rule))
+        }
+    };
+    let stroke = opts.stroke.as_ref().map(|stroke| {
+        let color = opts
+            .stroke_color
+            .unwrap_or_else(|| ctxt.theme.stroke_lin_srgba(theme_primitive));
+        (color, stroke)
+    });
+    let paint = Paint { fill, stroke };
+    write_element(svg, name, geometry, &transform, &paint);
+}
+
+/// Produce the `d` attribute of a `<path>` element from the given path events.
+pub(crate) fn path_data<I>(events: I) -> String
+where
+    I: IntoIterator<Item = PathEvent>,
+{
+    let mut d = String::new();
+    for event in events {
+        match event {
+            PathEvent::Begin { at } => write!(d, "M{} {} ", at.x, at.y),
+            PathEvent::Line { to, .. } => write!(d, "L{} {} ", to.x, to.y),
+            PathEvent::Quadratic { ctrl, to, .. } => {
+                write!(d, "Q{} {} {} {} ", ctrl.x, ctrl.y, to.x, to.y)
+            }
+            PathEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => write!(
+                d,
+                "C{} {} {} {} {} {} ",
+                ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+            ),
+            PathEvent::End { close: true, .. } => write!(d, "Z "),
+            PathEvent::End { close: false, .. } => Ok(()),
+        }
+        .unwrap();
+    }
+    d.truncate(d.trim_end().len());
+    d
+}
+
+/// Produce the `d` attribute of a `<path>` element from the given polyline.
+pub(crate) fn points_data<I>(points: I, close: bool) -> String
+where
+    I: IntoIterator<Item = Point2>,
+{
+    let points = points.into_iter().map(|p| p.to_array().into());
+    path_data(lyon::path::iterator::FromPolyline::new(close, points))
+}
+
+// Write the given color as a hex attribute, along with its opacity if not fully opaque.
+fn write_color(svg: &mut String, attr: &str, color: LinSrgba) {
+    let (r, g, b, a) = Srgba::from_linear(color).into_components();
+    let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    write!(
+        svg,
+        r##" {}="#{:02x}{:02x}{:02x}""##,
+        attr,
+        byte(r),
+        byte(g),
+        byte(b)
+    )
+    .unwrap();
+    if a < 1.0 {
+        write!(svg, r#" {}-opacity="{}""#, attr, a.max(0.0)).unwrap();
+    }
+}
+
+fn line_cap(cap: LineCap) -> &'static str {
+    match cap {
+        LineCap::Butt => "butt",
+        LineCap::Square => "square",
+        LineCap::Round => "round",
+    }
+}
+
+fn line_join(join: LineJoin) -> &'static str {
+    match join {
+        LineJoin::Miter => "miter",
+        LineJoin::MiterClip => "miter-clip",
+        LineJoin::Round => "round",
+        LineJoin::Bevel => "bevel",
+    }
+}
+
+#[test]
+fn test_svg_document() {
+    let draw = draw::Draw::new();
+    draw.rect().w_h(20.0, 10.0).color(crate::color::RED);
+    draw.translate(crate::glam::vec3(5.0, 0.0, 0.0))
+        .ellipse()
+        .radius(4.0)
+        .no_fill()
+        .stroke_weight(2.0);
+    draw.path().stroke().points(vec![[0.0, 0.0], [10.0, 10.0]]);
+    let svg = draw.to_svg(geom::Rect::from_w_h(100.0, 50.0));
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.contains(r#"viewBox="-50 -25 100 50""#));
+    assert!(svg.contains(
+        r##"<rect x="-10" y="-5" width="20" height="10" fill="#ff0000" fill-rule="evenodd"/>"##
+    ));
+    assert!(svg.contains(r#"<circle r="4" transform="matrix(1 0 0 1 5 0)" fill="none""#));
+    assert!(svg.contains(r##"<path d="M0 0 L10 10" fill="none" stroke="#000000""##));
+}
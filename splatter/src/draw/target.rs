@@ -0,0 +1,128 @@
+//! Offscreen textures to which a **Draw** may render and which may be sampled by later drawings.
+//!
+//! See `App::new_texture_target` and `Draw::to_texture`.
+
+use crate::draw;
+use crate::geom;
+use crate::wgpu;
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::Arc;
+
+/// An offscreen texture to which the contents of a **Draw** may be rendered.
+///
+/// The target implements `wgpu::ToTextureView`, allowing its contents to be sampled by drawings
+/// submitted to other targets, e.g. `draw.texture(&target)`. A texture may not be sampled within
+/// the same pass in which it is rendered to, so feedback effects should ping-pong between two
+/// targets, drawing the previous frame's target into the next.
+///
+/// The contents of the target persist between renders unless the **Draw** specifies a
+/// background color.
+pub struct TextureTarget {
+    device_queue_pair: Arc<wgpu::DeviceQueuePair>,
+    texture: wgpu::Texture,
+    texture_view: wgpu::TextureView,
+    renderer: RefCell<draw::Renderer>,
+}
+
+impl TextureTarget {
+    /// The usage of the texture backing each target.
+    pub const TEXTURE_USAGE: wgpu::TextureUsages = wgpu::TextureUsages::RENDER_ATTACHMENT
+        .union(wgpu::TextureUsages::TEXTURE_BINDING)
+        .union(wgpu::TextureUsages::COPY_SRC);
+
+    /// Create a new target of the given size in pixels and format on the given device.
+    pub fn new(
+        device_queue_pair: Arc<wgpu::DeviceQueuePair>,
+        [width, height]: [u32; 2],
+        format: wgpu::TextureFormat,
+    ) -> Self {
+        let device = device_queue_pair.device();
+        let size = [width, height];
+        let texture = wgpu::TextureBuilder::new()
+            .size(size)
+            .format(format)
+            .usage(Self::TEXTURE_USAGE)
+            .build(device);
+        let texture_view = texture.view().build();
+        let scale_factor = 1.0;
+        let msaa_samples = 1;
+        let renderer =
+            draw::RendererBuilder::new().build(device, size, scale_factor, msaa_samples, format);
+        TextureTarget {
+            device_queue_pair,
+            texture,
+            texture_view,
+            renderer: RefCell::new(renderer),
+        }
+    }
+
+    /// The size of the target in pixels.
+    pub fn size_pixels(&self) -> [u32; 2] {
+        self.texture.size()
+    }
+
+    /// The rectangle representing the position and dimensions of the target.
+    ///
+    /// Like `Window::rect`, the rectangle is centred on `[0.0, 0.0]`.
+    pub fn rect(&self) -> geom::Rect {
+        let [w, h] = self.texture.size();
+        geom::Rect::from_w_h(w as f32, h as f32)
+    }
+
+    /// The format of the target's texture.
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.texture.format()
+    }
+
+    /// The texture to which the target is rendered.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// The view of the target's texture.
+    ///
+    /// The same view is returned each time, allowing the renderer to reuse its bind group.
+    pub fn texture_view(&self) -> &wgpu::TextureView {
+        &self.texture_view
+    }
+
+    /// The device and queue used to render to the target.
+    pub fn device_queue_pair(&self) -> &Arc<wgpu::DeviceQueuePair> {
+        &self.device_queue_pair
+    }
+}
+
+impl wgpu::ToTextureView for TextureTarget {
+    fn to_texture_view(&self) -> wgpu::TextureView {
+        self.texture_view.clone()
+    }
+}
+
+impl fmt::Debug for TextureTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TextureTarget")
+            .field("texture", &self.texture)
+            .field("texture_view", &self.texture_view)
+            .finish()
+    }
+}
+
+impl draw::Draw {
+    /// Render the **Draw**'s inner list of commands to the given **TextureTarget**.
+    ///
+    /// The commands are submitted to the target's queue immediately, so the result may be sampled
+    /// by any drawing that is rendered afterwards.
+    pub fn to_texture(&self, target: &TextureTarget) -> Result<(), draw::renderer::DrawError> {
+        let device = target.device_queue_pair.device();
+        let ce_desc = wgpu::CommandEncoderDescriptor {
+            label: Some("splatter_draw_to_texture"),
+        };
+        let mut encoder = device.create_command_encoder(&ce_desc);
+        let mut renderer = target.renderer.borrow_mut();
+        renderer.render_to_texture(device, &mut encoder, self, &target.texture)?;
+        let queue = target.device_queue_pair.queue();
+        queue.submit(std::iter::once(encoder.finish()));
+        Ok(())
+    }
+}
@@ -28,10 +28,12 @@ pub enum Primitive {
     Ellipse,
     Line,
     Mesh,
+    Ngon,
     Path,
     Polygon,
     Quad,
     Rect,
+    Star,
     Text,
     Texture,
     Tri,
@@ -1,4 +1,7 @@
 //! A suite of common interpolation functions often referred to as "easing" and "tweening"
 //! functions. This API is provided by the [pennereq crate](https://docs.rs/pennereq).
+//!
+//! For easing functions that map a single `0.0..=1.0` progress value, e.g. for use with
+//! `map_range_eased`, see the `math::ease` module.
 
 pub use pennereq::*;
@@ -20,6 +20,14 @@ pub use winit::keyboard::{Key, ModifiersState};
 pub trait LoopEvent: 'static + From<Update> {
     /// Produce a loop event from the given winit event.
     fn from_winit_event<T>(_: &winit::event::Event<T>, _: &App) -> Option<Self>;
+
+    /// Produce a loop event from the given gamepad event.
+    ///
+    /// By default, gamepad events are ignored.
+    #[cfg(feature = "gamepad")]
+    fn from_gamepad_event(_: crate::gamepad::Event) -> Option<Self> {
+        None
+    }
 }
 
 /// Update event, emitted on each pass of an application loop.
@@ -31,7 +39,8 @@ pub struct Update {
     pub since_last: std::time::Duration,
     /// The duration since the start of the app loop.
     ///
-    /// Specifically, this is the duration of time since the given `model` function returned.
+    /// Specifically, this is the duration of time since the given `model` function returned,
+    /// excluding any time during which the app was paused.
     pub since_start: std::time::Duration,
 }
 
@@ -63,6 +72,12 @@ pub enum Event {
     Suspended,
     /// The application has been awakened.
     Resumed,
+
+    /// A gamepad was connected or disconnected, or one of its buttons or axes changed.
+    ///
+    /// Requires the `gamepad` feature.
+    #[cfg(feature = "gamepad")]
+    Gamepad(crate::gamepad::Event),
 }
 
 /// The event associated with a touch at a single point.
@@ -349,6 +364,11 @@ impl LoopEvent for Event {
         };
         Some(event)
     }
+
+    #[cfg(feature = "gamepad")]
+    fn from_gamepad_event(event: crate::gamepad::Event) -> Option<Self> {
+        Some(Event::Gamepad(event))
+    }
 }
 
 impl From<Update> for Event {
@@ -1,9 +1,12 @@
 //! Items related to the **Frame** type, describing a single frame of graphics for a single window.
 
 use crate::color::IntoLinSrgba;
+use crate::image;
 use crate::wgpu;
+use std::cell::Cell;
+use std::fmt;
 use std::ops;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::time::Duration;
 
@@ -23,6 +26,9 @@ pub struct Frame<'swap_chain> {
     raw_frame: RawFrame<'swap_chain>,
     render_data: &'swap_chain RenderData,
     capture_data: &'swap_chain CaptureData,
+    // The value to which depth was last cleared and the number of times depth has been cleared,
+    // if depth is shared between the draw passes to this frame.
+    depth_clear: Cell<Option<(f32, u32)>>,
 }
 
 /// Data specific to the intermediary textures.
@@ -40,10 +46,25 @@ pub struct RenderData {
 pub(crate) struct CaptureData {
     // If `Some`, indicates a path to which the current frame should be written.
     pub(crate) next_frame_path: Mutex<Option<PathBuf>>,
+    // If `Some`, every frame is written to the next path in the sequence.
+    pub(crate) sequence: Mutex<Option<CaptureSequence>>,
+    // Callbacks to which the current frame's pixels should be delivered.
+    pub(crate) next_frame_fns: Mutex<Vec<CaptureFn>>,
     // The `TextureCapturer` used to capture the frame.
     pub(crate) texture_capturer: wgpu::TextureCapturer,
 }
 
+/// A user callback that receives a captured frame as a non-linear sRGBA image.
+pub(crate) struct CaptureFn(Box<dyn FnOnce(image::RgbaImage) + Send>);
+
+/// A numbered sequence of frames to capture, one per frame, until stopped.
+#[derive(Debug)]
+pub(crate) struct CaptureSequence {
+    dir: PathBuf,
+    pattern: String,
+    next_index: u64,
+}
+
 /// Intermediary textures used as a target before resolving multisampling and writing to the
 /// swapchain texture.
 #[derive(Debug)]
@@ -73,16 +94,21 @@ impl<'swap_chain> Frame<'swap_chain> {
     pub const TEXTURE_FORMAT: wgpu::TextureFormat =
         wgpu::RenderPipelineBuilder::DEFAULT_COLOR_FORMAT;
 
+    /// The depth to which the draw renderer's depth buffer is cleared at the start of each pass.
+    pub const DEFAULT_CLEAR_DEPTH: f32 = 1.0;
+
     // Initialise a new empty frame ready for "drawing".
     pub(crate) fn new_empty(
         raw_frame: RawFrame<'swap_chain>,
         render_data: &'swap_chain RenderData,
         capture_data: &'swap_chain CaptureData,
     ) -> Self {
+        let depth_clear = Cell::new(None);
         Frame {
             raw_frame,
             render_data,
             capture_data,
+            depth_clear,
         }
     }
 
@@ -93,6 +119,7 @@ impl<'swap_chain> Frame<'swap_chain> {
             ref capture_data,
             ref render_data,
             ref mut raw_frame,
+            ..
         } = *self;
 
         // Resolve the MSAA if necessary.
@@ -105,19 +132,29 @@ impl<'swap_chain> Frame<'swap_chain> {
             );
         }
 
-        // Check to see if the user specified capturing the frame.
-        let mut snapshot_capture = None;
+        // Check to see if the user specified capturing the frame, either once or as part of a
+        // sequence.
+        let mut paths = vec![];
         if let Ok(mut guard) = capture_data.next_frame_path.lock() {
-            if let Some(path) = guard.take() {
-                let device = raw_frame.device_queue_pair().device();
-                let mut encoder = raw_frame.command_encoder();
-                let snapshot = capture_data.texture_capturer.capture(
-                    device,
-                    &mut *encoder,
-                    &render_data.intermediary_lin_srgba.texture,
-                );
-                snapshot_capture = Some((path, snapshot));
-            }
+            paths.extend(guard.take());
+        }
+        if let Ok(mut guard) = capture_data.sequence.lock() {
+            paths.extend(guard.as_mut().map(|seq| seq.next_path()));
+        }
+        let mut fns = vec![];
+        if let Ok(mut guard) = capture_data.next_frame_fns.lock() {
+            fns.append(&mut guard);
+        }
+        let mut snapshot_capture = None;
+        if !paths.is_empty() || !fns.is_empty() {
+            let device = raw_frame.device_queue_pair().device();
+            let mut encoder = raw_frame.command_encoder();
+            let snapshot = capture_data.texture_capturer.capture(
+                device,
+                &mut *encoder,
+                &render_data.intermediary_lin_srgba.texture,
+            );
+            snapshot_capture = Some((paths, fns, snapshot));
         }
 
         // Convert the linear sRGBA image to the swapchain image.
@@ -135,19 +172,24 @@ impl<'swap_chain> Frame<'swap_chain> {
         raw_frame.submit_inner();
 
         // If the user did specify capturing the frame, submit the asynchronous read.
-        if let Some((path, snapshot)) = snapshot_capture {
+        if let Some((paths, fns, snapshot)) = snapshot_capture {
             let result = snapshot.read(move |result| match result {
                 // TODO: Log errors, don't print to stderr.
                 Err(e) => eprintln!("failed to async read captured frame: {:?}", e),
                 Ok(image) => {
                     let image = image.to_owned();
-                    if let Err(e) = image.save(&path) {
-                        // TODO: Log errors, don't print to stderr.
-                        eprintln!(
-                            "failed to save captured frame to \"{}\": {}",
-                            path.display(),
-                            e
-                        );
+                    for path in paths {
+                        if let Err(e) = image.save(&path) {
+                            // TODO: Log errors, don't print to stderr.
+                            eprintln!(
+                                "failed to save captured frame to \"{}\": {}",
+                                path.display(),
+                                e
+                            );
+                        }
+                    }
+                    for CaptureFn(f) in fns {
+                        f(image.clone());
                     }
                 }
             });
@@ -251,6 +293,33 @@ impl<'swap_chain> Frame<'swap_chain> {
         self.raw_frame.clear(self.texture_view(), color);
     }
 
+    /// Clear the texture with the given color and the depth buffer with the given depth.
+    ///
+    /// By default, the depth buffer is cleared to `DEFAULT_CLEAR_DEPTH` at the start of every
+    /// draw renderer pass. After calling this method, depth is instead shared between all later
+    /// passes to the same frame, so that geometry submitted via separate `draw.to_frame` calls is
+    /// depth tested against each other. Call it again to clear depth part way through a frame.
+    ///
+    /// With the draw renderer's default depth test, fragments pass when their depth is less than
+    /// or equal to the value in the depth buffer, where `0.0` is nearest and `1.0` is furthest.
+    pub fn clear_color_and_depth<C>(&self, color: C, depth: f32)
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        self.clear(color);
+        let clears = self.depth_clear.get().map_or(0, |(_, clears)| clears);
+        self.depth_clear.set(Some((depth, clears.wrapping_add(1))));
+    }
+
+    /// The value to which depth should be cleared, along with the number of times
+    /// `clear_color_and_depth` has been called for this frame.
+    ///
+    /// Returns `None` if `clear_color_and_depth` has not been called, in which case depth is not
+    /// shared between passes.
+    pub(crate) fn depth_clear(&self) -> Option<(f32, u32)> {
+        self.depth_clear.get()
+    }
+
     /// Submit the frame to the GPU!
     ///
     /// Note that you do not need to call this manually as submission will occur automatically when
@@ -272,11 +341,76 @@ impl CaptureData {
     pub(crate) fn new(max_jobs: u32, timeout: Option<Duration>) -> Self {
         CaptureData {
             next_frame_path: Default::default(),
+            sequence: Default::default(),
+            next_frame_fns: Default::default(),
             texture_capturer: wgpu::TextureCapturer::new(Some(max_jobs), timeout),
         }
     }
 }
 
+impl CaptureFn {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: 'static + Send + FnOnce(image::RgbaImage),
+    {
+        CaptureFn(Box::new(f))
+    }
+}
+
+impl fmt::Debug for CaptureFn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CaptureFn")
+    }
+}
+
+impl CaptureSequence {
+    /// The number of digits used for the frame number if the pattern does not specify any.
+    const DEFAULT_DIGITS: usize = 5;
+
+    // Begin a new sequence writing files named after `pattern` into `dir`.
+    //
+    // The first run of `#` characters in `pattern` is replaced with the zero-padded frame number,
+    // padded to the number of `#`s. If `pattern` contains no `#`, the frame number is appended to
+    // the file stem, e.g. `frame.png` becomes `frame_00001.png`.
+    pub(crate) fn new(dir: &Path, pattern: &str) -> Self {
+        let pattern = if pattern.contains('#') {
+            pattern.to_string()
+        } else {
+            let digits = "#".repeat(Self::DEFAULT_DIGITS);
+            match pattern.rfind('.') {
+                Some(ix) if ix > 0 => format!("{}_{}{}", &pattern[..ix], digits, &pattern[ix..]),
+                _ => format!("{}_{}", pattern, digits),
+            }
+        };
+        CaptureSequence {
+            dir: dir.to_path_buf(),
+            pattern,
+            next_index: 1,
+        }
+    }
+
+    // Produce the path for the next frame in the sequence and advance the frame number.
+    pub(crate) fn next_path(&mut self) -> PathBuf {
+        let start = self
+            .pattern
+            .find('#')
+            .expect("pattern always contains a `#`");
+        let digits = self.pattern[start..]
+            .chars()
+            .take_while(|&c| c == '#')
+            .count();
+        let file_name = format!(
+            "{}{:0width$}{}",
+            &self.pattern[..start],
+            self.next_index,
+            &self.pattern[start + digits..],
+            width = digits,
+        );
+        self.next_index += 1;
+        self.dir.join(file_name)
+    }
+}
+
 impl RenderData {
     /// Initialise the render data.
     ///
@@ -372,3 +506,12 @@ fn create_intermediary_lin_srgba(
         texture_view,
     }
 }
+
+#[test]
+fn test_capture_sequence_paths() {
+    let mut seq = CaptureSequence::new(Path::new("out"), "frame.png");
+    assert_eq!(seq.next_path(), Path::new("out/frame_00001.png"));
+    assert_eq!(seq.next_path(), Path::new("out/frame_00002.png"));
+    let mut seq = CaptureSequence::new(Path::new("out"), "f-###.tiff");
+    assert_eq!(seq.next_path(), Path::new("out/f-001.tiff"));
+}
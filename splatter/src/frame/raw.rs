@@ -26,6 +26,15 @@ use std::sync::Arc;
 /// matches the device. In an effort to reduce the chance for errors to occur, **RawFrame**
 /// provides access to a `wgpu::CommandEncoder` whose commands are guaranteed to be submitted to
 /// the correct `wgpu::Queue` at the end of the **view** function.
+///
+/// ## Ordering
+///
+/// All commands for the frame, including the render passes encoded by `Draw::to_frame`, are
+/// encoded into this same command encoder in the order in which they are issued and are executed
+/// by the GPU in that order. For example, a compute pass encoded via **command_encoder** before
+/// calling `draw.to_frame(app, &frame)` is guaranteed to complete before the draw render pass
+/// reads its results. Use **flush** to submit the commands encoded so far mid-frame, e.g. before
+/// writing new data to a buffer via `wgpu::Queue::write_buffer` that earlier passes must not see.
 pub struct RawFrame<'swap_chain> {
     command_encoder: Option<RefCell<wgpu::CommandEncoder>>,
     window_id: window::Id,
@@ -46,10 +55,7 @@ impl<'swap_chain> RawFrame<'swap_chain> {
         texture_format: wgpu::TextureFormat,
         window_rect: geom::Rect,
     ) -> Self {
-        let ce_desc = wgpu::CommandEncoderDescriptor {
-            label: Some("splatter_raw_frame"),
-        };
-        let command_encoder = device_queue_pair.device().create_command_encoder(&ce_desc);
+        let command_encoder = create_command_encoder(device_queue_pair.device());
         let command_encoder = Some(RefCell::new(command_encoder));
         let frame = RawFrame {
             command_encoder,
@@ -83,6 +89,9 @@ impl<'swap_chain> RawFrame<'swap_chain> {
 
     /// Access the command encoder in order to encode commands that will be submitted to the swap
     /// chain queue at the end of the call to **view**.
+    ///
+    /// Commands are executed in the order in which they are encoded. See the **Ordering** section
+    /// of the **RawFrame** docs for details.
     pub fn command_encoder(&self) -> RefMut<wgpu::CommandEncoder> {
         match self.command_encoder {
             Some(ref ce) => ce.borrow_mut(),
@@ -90,6 +99,27 @@ impl<'swap_chain> RawFrame<'swap_chain> {
         }
     }
 
+    /// Submit the commands encoded so far to the queue without ending the frame.
+    ///
+    /// The frame continues with a new command encoder, so that any following commands, including
+    /// those encoded by `Draw::to_frame`, are submitted after the flushed commands. This is useful
+    /// for ensuring that the results of a manually encoded pass (e.g. a compute simulation) are
+    /// complete before data is written via the queue or read back to the CPU within the same
+    /// frame.
+    ///
+    /// Note: Be careful that you do not currently possess a lock to the frame's command encoder or
+    /// this method will panic.
+    pub fn flush(&self) {
+        let ce = match self.command_encoder {
+            Some(ref ce) => ce,
+            None => unreachable!("`RawFrame`'s command_encoder was `None`"),
+        };
+        let next_encoder = create_command_encoder(self.device_queue_pair.device());
+        let command_encoder = std::mem::replace(&mut *ce.borrow_mut(), next_encoder);
+        let queue = self.device_queue_pair.queue();
+        queue.submit(std::iter::once(command_encoder.finish()));
+    }
+
     /// The `Id` of the window whose wgpu surface is associated with this frame.
     pub fn window_id(&self) -> window::Id {
         self.window_id
@@ -150,6 +180,14 @@ impl<'swap_chain> RawFrame<'swap_chain> {
     }
 }
 
+// Create a command encoder for encoding the commands of a frame.
+fn create_command_encoder(device: &wgpu::Device) -> wgpu::CommandEncoder {
+    let ce_desc = wgpu::CommandEncoderDescriptor {
+        label: Some("splatter_raw_frame"),
+    };
+    device.create_command_encoder(&ce_desc)
+}
+
 impl<'swap_chain> Drop for RawFrame<'swap_chain> {
     fn drop(&mut self) {
         // Submit the commands if the user hasn't done so already.
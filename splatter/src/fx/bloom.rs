@@ -0,0 +1,359 @@
+//! A glow effect that blurs the brightest parts of a texture over their surroundings.
+
+use crate::draw::TextureTarget;
+use crate::wgpu::{self, util::DeviceExt, BufferInitDescriptor};
+use std::cell::RefCell;
+
+/// Adds a glow around the brightest parts of a texture.
+///
+/// The effect is applied in four fragment passes:
+///
+/// 1. A bright pass keeps only the portion of each color whose luminance exceeds the `threshold`.
+/// 2. A horizontal and then a vertical gaussian blur spread the highlights by the `radius`.
+/// 3. The blurred highlights are scaled by the `intensity` and added to the source.
+///
+/// The bright pass and blur operate at half of the source resolution. The GPU resources for these
+/// passes are created on first use and re-created only if the source size or destination format
+/// changes, so a single **Bloom** should be kept and reused between frames.
+///
+/// ```no_run
+/// # use splatter::prelude::*;
+/// # use splatter::draw::TextureTarget;
+/// # use splatter::fx::Bloom;
+/// # struct Model { scene: TextureTarget, bloomed: TextureTarget, bloom: Bloom }
+/// fn view(app: &App, model: &Model, frame: Frame) {
+///     let draw = Draw::new();
+///     draw.background().color(BLACK);
+///     draw.ellipse().color(WHITE);
+///     draw.to_texture(&model.scene).unwrap();
+///
+///     model.bloom.apply(&model.scene, &model.bloomed);
+///
+///     let draw = app.draw();
+///     draw.texture(&model.bloomed).wh(app.window_rect().wh());
+///     draw.to_frame(app, &frame).unwrap();
+/// }
+/// # fn main() {}
+/// ```
+#[derive(Debug)]
+pub struct Bloom {
+    threshold: f32,
+    radius: f32,
+    intensity: f32,
+    resources: RefCell<Option<Resources>>,
+}
+
+// The GPU resources used to apply the effect for a specific source size and destination format.
+#[derive(Debug)]
+struct Resources {
+    src_size: [u32; 2],
+    dst_format: wgpu::TextureFormat,
+    sampler: wgpu::Sampler,
+    bind_group_layout: wgpu::BindGroupLayout,
+    composite_bind_group_layout: wgpu::BindGroupLayout,
+    bright_pipeline: wgpu::RenderPipeline,
+    blur_pipeline: wgpu::RenderPipeline,
+    composite_pipeline: wgpu::RenderPipeline,
+    // Ping-pong textures for the bright pass and blur.
+    textures: [wgpu::TextureView; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct Uniforms {
+    direction: [f32; 2],
+    radius: f32,
+    threshold: f32,
+    intensity: f32,
+    // Pads the struct to the 16 byte alignment of uniform buffers.
+    _padding: [f32; 3],
+}
+
+impl Bloom {
+    /// The default luminance above which colors contribute to the glow.
+    pub const DEFAULT_THRESHOLD: f32 = 0.8;
+    /// The default radius of the blur in pixels of the source texture.
+    pub const DEFAULT_RADIUS: f32 = 16.0;
+    /// The default factor by which the glow is scaled before it is added to the source.
+    pub const DEFAULT_INTENSITY: f32 = 1.0;
+    /// The format of the intermediary textures used for the bright pass and blur.
+    pub const INTERMEDIARY_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+    // The largest supported radius in texels of the intermediary textures, bounding the number of
+    // samples taken by each blur pass.
+    const MAX_RADIUS_TEXELS: f32 = 64.0;
+
+    /// Create a new **Bloom** effect with the default parameters.
+    pub fn new() -> Self {
+        Bloom {
+            threshold: Self::DEFAULT_THRESHOLD,
+            radius: Self::DEFAULT_RADIUS,
+            intensity: Self::DEFAULT_INTENSITY,
+            resources: RefCell::new(None),
+        }
+    }
+
+    /// The luminance above which colors contribute to the glow.
+    ///
+    /// By default, this is `Bloom::DEFAULT_THRESHOLD`.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// The radius of the blur in pixels of the source texture.
+    ///
+    /// The radius is limited to 128 pixels.
+    ///
+    /// By default, this is `Bloom::DEFAULT_RADIUS`.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// The factor by which the glow is scaled before it is added to the source.
+    ///
+    /// By default, this is `Bloom::DEFAULT_INTENSITY`.
+    pub fn intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// Apply the effect to the contents of the `src` target, writing the result to `dst`.
+    ///
+    /// The commands are submitted to the queue of the `src` target immediately. Both targets must
+    /// share the same device and must not be the same target.
+    pub fn apply(&self, src: &TextureTarget, dst: &TextureTarget) {
+        let device_queue_pair = src.device_queue_pair();
+        let device = device_queue_pair.device();
+        let ce_desc = wgpu::CommandEncoderDescriptor {
+            label: Some("splatter_bloom"),
+        };
+        let mut encoder = device.create_command_encoder(&ce_desc);
+        self.encode(device, &mut encoder, src.texture_view(), dst.texture_view());
+        device_queue_pair
+            .queue()
+            .submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Encode the passes necessary to apply the effect to `src`, writing the result to `dst`.
+    ///
+    /// The `src` texture must be single-sampled, have a filterable float sample type and the
+    /// `TextureUsages::TEXTURE_BINDING` enabled. The `dst` texture must have the
+    /// `TextureUsages::RENDER_ATTACHMENT` enabled.
+    pub fn encode(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        src: &wgpu::TextureView,
+        dst: &wgpu::TextureView,
+    ) {
+        let src_size = src.size();
+        let dst_format = dst.format();
+        let mut resources = self.resources.borrow_mut();
+        let is_stale = match *resources {
+            Some(ref res) => res.src_size != src_size || res.dst_format != dst_format,
+            None => true,
+        };
+        if is_stale {
+            *resources = Some(Resources::new(device, src_size, dst_format));
+        }
+        let res = resources
+            .as_ref()
+            .expect("bloom resources were just created");
+
+        // The radius is specified in source pixels, while blurring occurs at half resolution.
+        let radius = (self.radius * 0.5).clamp(0.0, Self::MAX_RADIUS_TEXELS);
+        let [w, h] = res.textures[0].size();
+        let uniforms = |direction| Uniforms {
+            direction,
+            radius,
+            threshold: self.threshold,
+            intensity: self.intensity,
+            _padding: [0.0; 3],
+        };
+        let [tex_a, tex_b] = &res.textures;
+        let zero = [0.0, 0.0];
+        let horizontal = [1.0 / w as f32, 0.0];
+        let vertical = [0.0, 1.0 / h as f32];
+
+        let bright = res.bind_group(device, src, &uniforms(zero));
+        pass(encoder, tex_a, &res.bright_pipeline, &bright);
+        let blur_h = res.bind_group(device, tex_a, &uniforms(horizontal));
+        pass(encoder, tex_b, &res.blur_pipeline, &blur_h);
+        let blur_v = res.bind_group(device, tex_b, &uniforms(vertical));
+        pass(encoder, tex_a, &res.blur_pipeline, &blur_v);
+        let composite = res.composite_bind_group(device, src, tex_a, &uniforms(zero));
+        pass(encoder, dst, &res.composite_pipeline, &composite);
+    }
+}
+
+impl Resources {
+    fn new(device: &wgpu::Device, src_size: [u32; 2], dst_format: wgpu::TextureFormat) -> Self {
+        let vs_mod = device.create_shader_module(wgpu::include_wgsl!("shaders/vs.wgsl"));
+        let bright_mod = create_fragment_module(device, include_str!("shaders/bright.wgsl"));
+        let blur_mod = create_fragment_module(device, include_str!("shaders/blur.wgsl"));
+        let composite_mod = create_fragment_module(device, include_str!("shaders/composite.wgsl"));
+
+        let sampler_desc = wgpu::SamplerBuilder::new()
+            .mag_filter(wgpu::FilterMode::Linear)
+            .min_filter(wgpu::FilterMode::Linear)
+            .into_descriptor();
+        let sampler = device.create_sampler(&sampler_desc);
+
+        let sample_type = wgpu::TextureSampleType::Float { filterable: true };
+        let stages = wgpu::ShaderStages::FRAGMENT;
+        let dim = wgpu::TextureViewDimension::D2;
+        let bind_group_layout = wgpu::BindGroupLayoutBuilder::new()
+            .texture(stages, false, dim, sample_type)
+            .sampler(stages, true)
+            .uniform_buffer(stages, false)
+            .build(device);
+        let composite_bind_group_layout = wgpu::BindGroupLayoutBuilder::new()
+            .texture(stages, false, dim, sample_type)
+            .texture(stages, false, dim, sample_type)
+            .sampler(stages, true)
+            .uniform_buffer(stages, false)
+            .build(device);
+
+        let format = Bloom::INTERMEDIARY_FORMAT;
+        let layout = pipeline_layout(device, &bind_group_layout);
+        let composite_layout = pipeline_layout(device, &composite_bind_group_layout);
+        let bright_pipeline = render_pipeline(device, &layout, &vs_mod, &bright_mod, format);
+        let blur_pipeline = render_pipeline(device, &layout, &vs_mod, &blur_mod, format);
+        let composite_pipeline = render_pipeline(
+            device,
+            &composite_layout,
+            &vs_mod,
+            &composite_mod,
+            dst_format,
+        );
+
+        let [w, h] = src_size;
+        let size = [(w / 2).max(1), (h / 2).max(1)];
+        let texture = || {
+            wgpu::TextureBuilder::new()
+                .size(size)
+                .format(format)
+                .usage(
+                    wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+                )
+                .build(device)
+                .view()
+                .build()
+        };
+        let textures = [texture(), texture()];
+
+        Resources {
+            src_size,
+            dst_format,
+            sampler,
+            bind_group_layout,
+            composite_bind_group_layout,
+            bright_pipeline,
+            blur_pipeline,
+            composite_pipeline,
+            textures,
+        }
+    }
+
+    fn bind_group(
+        &self,
+        device: &wgpu::Device,
+        texture: &wgpu::TextureView,
+        uniforms: &Uniforms,
+    ) -> wgpu::BindGroup {
+        let buffer = uniform_buffer(device, uniforms);
+        wgpu::BindGroupBuilder::new()
+            .texture_view(texture)
+            .sampler(&self.sampler)
+            .buffer::<Uniforms>(&buffer, 0..1)
+            .build(device, &self.bind_group_layout)
+    }
+
+    fn composite_bind_group(
+        &self,
+        device: &wgpu::Device,
+        texture: &wgpu::TextureView,
+        bloom_texture: &wgpu::TextureView,
+        uniforms: &Uniforms,
+    ) -> wgpu::BindGroup {
+        let buffer = uniform_buffer(device, uniforms);
+        wgpu::BindGroupBuilder::new()
+            .texture_view(texture)
+            .texture_view(bloom_texture)
+            .sampler(&self.sampler)
+            .buffer::<Uniforms>(&buffer, 0..1)
+            .build(device, &self.composite_bind_group_layout)
+    }
+}
+
+impl Default for Bloom {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Encode a pass that covers the whole `dst` texture with the given pipeline.
+fn pass(
+    encoder: &mut wgpu::CommandEncoder,
+    dst: &wgpu::TextureView,
+    pipeline: &wgpu::RenderPipeline,
+    bind_group: &wgpu::BindGroup,
+) {
+    let mut render_pass = wgpu::RenderPassBuilder::new()
+        .color_attachment(dst, |color| color)
+        .begin(encoder);
+    render_pass.set_pipeline(pipeline);
+    render_pass.set_bind_group(0, bind_group, &[]);
+    render_pass.draw(0..3, 0..1);
+}
+
+// Compile the given fragment shader, prefixed with the declaration of the uniforms.
+fn create_fragment_module(device: &wgpu::Device, source: &str) -> wgpu::ShaderModule {
+    let prelude = include_str!("shaders/uniforms.wgsl");
+    let source = format!("{}\n{}", prelude, source);
+    device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("splatter_bloom"),
+        source: wgpu::ShaderSource::Wgsl(source.into()),
+    })
+}
+
+fn pipeline_layout(
+    device: &wgpu::Device,
+    bind_group_layout: &wgpu::BindGroupLayout,
+) -> wgpu::PipelineLayout {
+    let desc = wgpu::PipelineLayoutDescriptor {
+        label: Some("splatter_bloom"),
+        bind_group_layouts: &[bind_group_layout],
+        push_constant_ranges: &[],
+    };
+    device.create_pipeline_layout(&desc)
+}
+
+fn render_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_mod: &wgpu::ShaderModule,
+    fs_mod: &wgpu::ShaderModule,
+    dst_format: wgpu::TextureFormat,
+) -> wgpu::RenderPipeline {
+    wgpu::RenderPipelineBuilder::from_layout(layout, vs_mod)
+        .fragment_shader(fs_mod)
+        .color_format(dst_format)
+        .color_blend(wgpu::BlendComponent::REPLACE)
+        .alpha_blend(wgpu::BlendComponent::REPLACE)
+        .primitive_topology(wgpu::PrimitiveTopology::TriangleList)
+        .build(device)
+}
+
+fn uniform_buffer(device: &wgpu::Device, uniforms: &Uniforms) -> wgpu::Buffer {
+    device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("splatter_bloom_uniforms"),
+        contents: uniforms_as_bytes(uniforms),
+        usage: wgpu::BufferUsages::UNIFORM,
+    })
+}
+
+fn uniforms_as_bytes(uniforms: &Uniforms) -> &[u8] {
+    unsafe { wgpu::bytes::from(uniforms) }
+}
@@ -0,0 +1,9 @@
+//! Post-processing effects applied to rendered textures.
+//!
+//! Effects read from one texture and write to another, making them a natural fit for the
+//! offscreen targets produced by `App::new_texture_target`. E.g. a sketch may render its scene to
+//! one target, apply an effect into a second and then draw the second to the window.
+
+pub use self::bloom::Bloom;
+
+pub mod bloom;
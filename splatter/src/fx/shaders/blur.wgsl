@@ -0,0 +1,30 @@
+struct FragmentOutput {
+    @location(0) out_color: vec4<f32>,
+};
+
+@group(0) @binding(0)
+var tex: texture_2d<f32>;
+@group(0) @binding(1)
+var tex_sampler: sampler;
+@group(0) @binding(2)
+var<uniform> uniforms: Uniforms;
+
+// One direction of a separable gaussian blur.
+@fragment
+fn main(
+    @location(0) tex_coords: vec2<f32>,
+) -> FragmentOutput {
+    let r: i32 = i32(ceil(uniforms.radius));
+    let sigma: f32 = max(uniforms.radius / 3.0, 0.0001);
+    var sum: vec3<f32> = vec3<f32>(0.0);
+    var total: f32 = 0.0;
+    for (var i: i32 = -r; i <= r; i = i + 1) {
+        let x: f32 = f32(i);
+        let weight: f32 = exp(-(x * x) / (2.0 * sigma * sigma));
+        let uv: vec2<f32> = tex_coords + uniforms.direction * x;
+        sum = sum + textureSampleLevel(tex, tex_sampler, uv, 0.0).rgb * weight;
+        total = total + weight;
+    }
+    let out_color: vec4<f32> = vec4<f32>(sum / total, 1.0);
+    return FragmentOutput(out_color);
+}
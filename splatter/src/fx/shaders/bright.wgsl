@@ -0,0 +1,22 @@
+struct FragmentOutput {
+    @location(0) out_color: vec4<f32>,
+};
+
+@group(0) @binding(0)
+var tex: texture_2d<f32>;
+@group(0) @binding(1)
+var tex_sampler: sampler;
+@group(0) @binding(2)
+var<uniform> uniforms: Uniforms;
+
+// Keep only the portion of each color that is brighter than the threshold.
+@fragment
+fn main(
+    @location(0) tex_coords: vec2<f32>,
+) -> FragmentOutput {
+    let color: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
+    let luma: f32 = dot(color.rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+    let scale: f32 = max(luma - uniforms.threshold, 0.0) / max(luma, 0.0001);
+    let out_color: vec4<f32> = vec4<f32>(color.rgb * scale, 1.0);
+    return FragmentOutput(out_color);
+}
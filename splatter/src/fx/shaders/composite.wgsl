@@ -0,0 +1,23 @@
+struct FragmentOutput {
+    @location(0) out_color: vec4<f32>,
+};
+
+@group(0) @binding(0)
+var tex: texture_2d<f32>;
+@group(0) @binding(1)
+var bloom_tex: texture_2d<f32>;
+@group(0) @binding(2)
+var tex_sampler: sampler;
+@group(0) @binding(3)
+var<uniform> uniforms: Uniforms;
+
+// Add the blurred highlights to the source.
+@fragment
+fn main(
+    @location(0) tex_coords: vec2<f32>,
+) -> FragmentOutput {
+    let color: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
+    let bloom: vec3<f32> = textureSample(bloom_tex, tex_sampler, tex_coords).rgb;
+    let out_color: vec4<f32> = vec4<f32>(color.rgb + bloom * uniforms.intensity, color.a);
+    return FragmentOutput(out_color);
+}
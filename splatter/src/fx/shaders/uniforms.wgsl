@@ -0,0 +1,8 @@
+struct Uniforms {
+    // The offset between samples of the blur, in texture coordinates.
+    direction: vec2<f32>,
+    // The radius of the blur in texels.
+    radius: f32,
+    threshold: f32,
+    intensity: f32,
+};
@@ -0,0 +1,15 @@
+struct VertexOutput {
+    @location(0) tex_coords: vec2<f32>,
+    @builtin(position) out_pos: vec4<f32>,
+};
+
+// Produces a single triangle covering the whole target.
+@vertex
+fn main(
+    @builtin(vertex_index) index: u32,
+) -> VertexOutput {
+    let pos: vec2<f32> = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u)) * 2.0 - 1.0;
+    let out_pos: vec4<f32> = vec4<f32>(pos, 0.0, 1.0);
+    let tex_coords: vec2<f32> = vec2<f32>(pos.x * 0.5 + 0.5, 1.0 - (pos.y * 0.5 + 0.5));
+    return VertexOutput(tex_coords, out_pos);
+}
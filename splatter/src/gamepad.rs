@@ -0,0 +1,280 @@
+//! Items for sketches that respond to gamepad input, e.g. from a game controller.
+//!
+//! Gamepads are discovered and polled via [gilrs](https://docs.rs/gilrs), which maps the buttons
+//! and axes of common controllers to a standard layout. The state of each connected gamepad is
+//! available anywhere the **App** is via `app.gamepads()`, while each change is also emitted as
+//! an `Event::Gamepad` to the `event` function.
+//!
+//! Gamepads are polled once per update, so gamepad input alone does not wake an app running in
+//! `LoopMode::Wait`.
+//!
+//! This module requires the `gamepad` feature.
+//!
+//! ```no_run
+//! use splatter::gamepad::Button;
+//! use splatter::prelude::*;
+//!
+//! fn update(app: &App, position: &mut Vec2, _update: Update) {
+//!     let gamepads = app.gamepads();
+//!     if let Some(pad) = gamepads.iter().next() {
+//!         *position += pad.left_stick() * 4.0;
+//!         if pad.is_pressed(Button::South) {
+//!             *position = Vec2::ZERO;
+//!         }
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+
+use crate::glam::Vec2;
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+pub use gilrs::{Axis, Button, GamepadId};
+
+/// The state of all gamepads that have connected during the life of the **App**.
+pub struct Gamepads {
+    // `None` if gamepad input is not supported on the platform.
+    gilrs: Option<gilrs::Gilrs>,
+    deadzone: Cell<f32>,
+    pads: BTreeMap<GamepadId, Gamepad>,
+}
+
+/// The state of a single gamepad.
+#[derive(Clone, Debug)]
+pub struct Gamepad {
+    id: GamepadId,
+    name: String,
+    is_connected: bool,
+    deadzone: f32,
+    buttons: HashMap<Button, f32>,
+    axes: HashMap<Axis, f32>,
+}
+
+/// A change in the state of a gamepad.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    /// The gamepad was connected.
+    Connected(GamepadId),
+    /// The gamepad was disconnected.
+    Disconnected(GamepadId),
+    /// The given button was pressed.
+    ButtonPressed(GamepadId, Button),
+    /// The given button was released.
+    ButtonReleased(GamepadId, Button),
+    /// The given axis moved to the given value, before the deadzone is applied.
+    ///
+    /// Stick axes range from `-1.0` to `1.0`, where positive values point right and up.
+    AxisChanged(GamepadId, Axis, f32),
+}
+
+impl Gamepads {
+    /// The default deadzone applied to axis values.
+    pub const DEFAULT_DEADZONE: f32 = 0.1;
+
+    pub(crate) fn new() -> Self {
+        // TODO: Log errors, don't print to stderr.
+        let gilrs = match gilrs::Gilrs::new() {
+            Ok(gilrs) => Some(gilrs),
+            Err(gilrs::Error::NotImplemented(gilrs)) => Some(gilrs),
+            Err(err) => {
+                eprintln!("failed to initialise gamepad support: {}", err);
+                None
+            }
+        };
+        let mut pads = BTreeMap::new();
+        if let Some(ref gilrs) = gilrs {
+            for (id, pad) in gilrs.gamepads() {
+                pads.insert(id, Gamepad::new(id, pad.name()));
+            }
+        }
+        Gamepads {
+            gilrs,
+            deadzone: Cell::new(Self::DEFAULT_DEADZONE),
+            pads,
+        }
+    }
+
+    /// Apply all pending gamepad input to the tracked state, returning the resulting events.
+    pub(crate) fn poll(&mut self) -> Vec<Event> {
+        let mut events = vec![];
+        let gilrs = match self.gilrs {
+            Some(ref mut gilrs) => gilrs,
+            None => return events,
+        };
+        let deadzone = self.deadzone.get();
+        while let Some(gilrs::Event { id, event, .. }) = gilrs.next_event() {
+            let pad = self
+                .pads
+                .entry(id)
+                .or_insert_with(|| Gamepad::new(id, gilrs.gamepad(id).name()));
+            pad.deadzone = deadzone;
+            let event = match event {
+                gilrs::EventType::Connected => {
+                    pad.is_connected = true;
+                    Event::Connected(id)
+                }
+                gilrs::EventType::Disconnected => {
+                    pad.is_connected = false;
+                    pad.buttons.clear();
+                    pad.axes.clear();
+                    Event::Disconnected(id)
+                }
+                gilrs::EventType::ButtonPressed(button, _) => {
+                    pad.buttons.insert(button, 1.0);
+                    Event::ButtonPressed(id, button)
+                }
+                gilrs::EventType::ButtonReleased(button, _) => {
+                    pad.buttons.remove(&button);
+                    Event::ButtonReleased(id, button)
+                }
+                gilrs::EventType::ButtonChanged(button, value, _) => {
+                    if let Some(v) = pad.buttons.get_mut(&button) {
+                        *v = value;
+                    }
+                    continue;
+                }
+                gilrs::EventType::AxisChanged(axis, value, _) => {
+                    pad.axes.insert(axis, value);
+                    Event::AxisChanged(id, axis, value)
+                }
+                _ => continue,
+            };
+            events.push(event);
+        }
+        events
+    }
+
+    /// The deadzone applied to axis values.
+    ///
+    /// By default, this is `Gamepads::DEFAULT_DEADZONE`.
+    pub fn deadzone(&self) -> f32 {
+        self.deadzone.get()
+    }
+
+    /// Specify the deadzone applied to axis values, within the range `0.0..1.0`.
+    ///
+    /// Axis values whose magnitude is below the deadzone are reported as `0.0`, while the rest of
+    /// the range is rescaled so that values still reach `1.0`. This hides the drift of sticks that
+    /// do not return exactly to their centre.
+    pub fn set_deadzone(&self, deadzone: f32) {
+        self.deadzone.set(deadzone.clamp(0.0, 0.99));
+    }
+
+    /// The gamepad with the given ID, if it has connected during the life of the **App**.
+    pub fn get(&self, id: GamepadId) -> Option<Gamepad> {
+        self.pads.get(&id).map(|pad| self.with_deadzone(pad))
+    }
+
+    /// An iterator yielding each of the currently connected gamepads.
+    pub fn iter(&self) -> impl Iterator<Item = Gamepad> + '_ {
+        self.pads
+            .values()
+            .filter(|pad| pad.is_connected)
+            .map(move |pad| self.with_deadzone(pad))
+    }
+
+    /// The number of currently connected gamepads.
+    pub fn len(&self) -> usize {
+        self.pads.values().filter(|pad| pad.is_connected).count()
+    }
+
+    /// Whether or not there are no connected gamepads.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // A copy of the gamepad reflecting the current deadzone.
+    fn with_deadzone(&self, pad: &Gamepad) -> Gamepad {
+        let mut pad = pad.clone();
+        pad.deadzone = self.deadzone.get();
+        pad
+    }
+}
+
+impl Gamepad {
+    fn new(id: GamepadId, name: &str) -> Self {
+        Gamepad {
+            id,
+            name: name.to_string(),
+            is_connected: true,
+            deadzone: Gamepads::DEFAULT_DEADZONE,
+            buttons: Default::default(),
+            axes: Default::default(),
+        }
+    }
+
+    /// The unique ID of the gamepad.
+    pub fn id(&self) -> GamepadId {
+        self.id
+    }
+
+    /// The name of the gamepad as reported by the OS or controller mapping.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether or not the gamepad is currently connected.
+    pub fn is_connected(&self) -> bool {
+        self.is_connected
+    }
+
+    /// Whether or not the given button is currently pressed.
+    pub fn is_pressed(&self, button: Button) -> bool {
+        self.buttons.contains_key(&button)
+    }
+
+    /// The value of the given button within the range `0.0..=1.0`.
+    ///
+    /// Analog buttons such as triggers report values between `0.0` and `1.0`, while digital
+    /// buttons report either `0.0` or `1.0`.
+    pub fn button_value(&self, button: Button) -> f32 {
+        self.buttons.get(&button).copied().unwrap_or(0.0)
+    }
+
+    /// The value of the given axis with the deadzone applied, within the range `-1.0..=1.0`.
+    pub fn axis(&self, axis: Axis) -> f32 {
+        let value = self.axes.get(&axis).copied().unwrap_or(0.0);
+        apply_deadzone(value, self.deadzone)
+    }
+
+    /// The position of the left stick with the deadzone applied, where *y* points up.
+    pub fn left_stick(&self) -> Vec2 {
+        Vec2::new(self.axis(Axis::LeftStickX), self.axis(Axis::LeftStickY))
+    }
+
+    /// The position of the right stick with the deadzone applied, where *y* points up.
+    pub fn right_stick(&self) -> Vec2 {
+        Vec2::new(self.axis(Axis::RightStickX), self.axis(Axis::RightStickY))
+    }
+}
+
+impl fmt::Debug for Gamepads {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Gamepads")
+            .field("deadzone", &self.deadzone)
+            .field("pads", &self.pads)
+            .finish()
+    }
+}
+
+// Zero values within the deadzone and rescale the remaining range to reach `1.0`.
+fn apply_deadzone(value: f32, deadzone: f32) -> f32 {
+    let magnitude = value.abs();
+    if magnitude <= deadzone {
+        return 0.0;
+    }
+    let scaled = ((magnitude - deadzone) / (1.0 - deadzone)).min(1.0);
+    scaled.copysign(value)
+}
+
+#[test]
+fn test_apply_deadzone() {
+    assert_eq!(apply_deadzone(0.05, 0.1), 0.0);
+    assert_eq!(apply_deadzone(-0.1, 0.1), 0.0);
+    assert_eq!(apply_deadzone(1.0, 0.1), 1.0);
+    assert_eq!(apply_deadzone(-1.0, 0.1), -1.0);
+    assert!((apply_deadzone(0.55, 0.1) - 0.5).abs() < 1e-6);
+    assert_eq!(apply_deadzone(0.5, 0.0), 0.5);
+}
@@ -10,6 +10,9 @@
 //! - A function for finding the centroid.
 
 pub mod path;
+pub mod path2d;
+pub mod sdf;
 
 pub use self::path::{path, Path};
+pub use self::path2d::Path2d;
 pub use splatter_core::geom::*;
@@ -0,0 +1,395 @@
+//! A standalone 2D path for geometric queries such as length, sampling and hit-testing.
+//!
+//! Unlike the **Draw** path API, a **Path2d** is independent of any drawing context or
+//! tessellation. Curves are approximated by line segments within the path's tolerance when
+//! measuring or testing the path. Convert to lyon path events via `Path2d::events` to draw it.
+//!
+//! ```
+//! use splatter::geom::{pt2, Path2d};
+//!
+//! let path = Path2d::new()
+//!     .move_to(pt2(0.0, 0.0))
+//!     .line_to(pt2(10.0, 0.0))
+//!     .line_to(pt2(10.0, 10.0))
+//!     .line_to(pt2(0.0, 10.0))
+//!     .close();
+//! assert!(path.contains(pt2(8.0, 2.0)));
+//! assert_eq!(path.length(), 40.0);
+//! assert_eq!(path.sample(0.25), Some(pt2(10.0, 0.0)));
+//! ```
+
+use crate::geom::{Point2, Rect};
+use lyon::path::PathEvent;
+
+/// A 2D path made of one or more sub-paths of lines and bezier curves.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path2d {
+    subpaths: Vec<SubPath>,
+    // The position to start a new sub-path from after `close`.
+    cursor: Option<Point2>,
+    tolerance: f32,
+}
+
+/// A single segment of a sub-path within a **Path2d**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Segment {
+    /// A straight line to the given point.
+    Line(Point2),
+    /// A quadratic bezier curve to the given point.
+    Quadratic { ctrl: Point2, to: Point2 },
+    /// A cubic bezier curve to the given point.
+    Cubic {
+        ctrl1: Point2,
+        ctrl2: Point2,
+        to: Point2,
+    },
+}
+
+/// A connected sequence of segments within a **Path2d**.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubPath {
+    /// The point at which the sub-path begins.
+    pub start: Point2,
+    /// The segments of the sub-path in order.
+    pub segments: Vec<Segment>,
+    /// Whether or not the sub-path is closed with a line back to its start.
+    pub closed: bool,
+}
+
+impl Path2d {
+    /// The default maximum distance between a curve and the line segments approximating it.
+    pub const DEFAULT_TOLERANCE: f32 = 0.1;
+
+    /// Create an empty path.
+    pub fn new() -> Self {
+        Path2d {
+            subpaths: vec![],
+            cursor: None,
+            tolerance: Self::DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Specify the maximum distance between a curve and the line segments approximating it when
+    /// measuring or testing the path.
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Begin a new sub-path at the given point.
+    pub fn move_to(mut self, to: Point2) -> Self {
+        self.subpaths.push(SubPath {
+            start: to,
+            segments: vec![],
+            closed: false,
+        });
+        self.cursor = None;
+        self
+    }
+
+    /// Add a straight line from the current position to the given point.
+    ///
+    /// If there is no current sub-path, a new one begins at the given point.
+    pub fn line_to(self, to: Point2) -> Self {
+        self.segment_to(to, Segment::Line(to))
+    }
+
+    /// Add a quadratic bezier curve from the current position to the given point.
+    ///
+    /// If there is no current sub-path, a new one begins at the control point.
+    pub fn quadratic_to(self, ctrl: Point2, to: Point2) -> Self {
+        self.segment_to(ctrl, Segment::Quadratic { ctrl, to })
+    }
+
+    /// Add a cubic bezier curve from the current position to the given point.
+    ///
+    /// If there is no current sub-path, a new one begins at the first control point.
+    pub fn curve_to(self, ctrl1: Point2, ctrl2: Point2, to: Point2) -> Self {
+        self.segment_to(ctrl1, Segment::Cubic { ctrl1, ctrl2, to })
+    }
+
+    /// Close the current sub-path with a straight line back to its start.
+    ///
+    /// Any following segments begin a new sub-path at the start of the closed sub-path.
+    pub fn close(mut self) -> Self {
+        if let Some(subpath) = self.subpaths.last_mut().filter(|s| !s.closed) {
+            subpath.closed = true;
+            self.cursor = Some(subpath.start);
+        }
+        self
+    }
+
+    /// The sub-paths that make up the path.
+    pub fn subpaths(&self) -> &[SubPath] {
+        &self.subpaths
+    }
+
+    /// Whether or not the path contains no segments.
+    pub fn is_empty(&self) -> bool {
+        self.subpaths.iter().all(|s| s.segments.is_empty())
+    }
+
+    /// The total length of the path, including the closing line of each closed sub-path.
+    pub fn length(&self) -> f32 {
+        self.flattened_edges().map(|(a, b)| a.distance(b)).sum()
+    }
+
+    /// The point at the given fraction of the path's total length, where `0.0` is the start of the
+    /// first sub-path and `1.0` is the end of the last.
+    ///
+    /// `t` is clamped to the range `0.0..=1.0`. Returns `None` if the path is empty.
+    pub fn sample(&self, t: f32) -> Option<Point2> {
+        let mut remaining = t.clamp(0.0, 1.0) * self.length();
+        let mut last = None;
+        for (a, b) in self.flattened_edges() {
+            let len = a.distance(b);
+            if remaining <= len && len > 0.0 {
+                return Some(a.lerp(b, remaining / len));
+            }
+            remaining -= len;
+            last = Some(b);
+        }
+        last
+    }
+
+    /// Whether or not the given point lies within the area enclosed by the path.
+    ///
+    /// Every sub-path is treated as closed and the area is determined by the even-odd rule,
+    /// matching the default fill rule used when tessellating paths.
+    pub fn contains(&self, point: Point2) -> bool {
+        let mut inside = false;
+        for polyline in self.polylines() {
+            let edges = polyline.iter().zip(polyline.iter().cycle().skip(1));
+            for (&a, &b) in edges {
+                if (a.y > point.y) != (b.y > point.y) {
+                    let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                    if point.x < x {
+                        inside = !inside;
+                    }
+                }
+            }
+        }
+        inside
+    }
+
+    /// The bounding rectangle of the path, or `None` if the path is empty.
+    pub fn bounds(&self) -> Option<Rect> {
+        let mut points = self.polylines().into_iter().flatten();
+        let first = points.next()?;
+        let rect = points.fold(Rect::from_corners(first, first), |r, p| r.stretch_to(p));
+        Some(rect)
+    }
+
+    /// The path described as a sequence of lyon path events, e.g. for drawing via
+    /// `draw.path().events(..)` or for building a `geom::Path`.
+    pub fn events(&self) -> Vec<PathEvent> {
+        let p = |pt: Point2| lyon::math::point(pt.x, pt.y);
+        let mut events = vec![];
+        for subpath in self.subpaths.iter().filter(|s| !s.segments.is_empty()) {
+            events.push(PathEvent::Begin {
+                at: p(subpath.start),
+            });
+            let mut from = subpath.start;
+            for segment in &subpath.segments {
+                let event = match *segment {
+                    Segment::Line(to) => PathEvent::Line {
+                        from: p(from),
+                        to: p(to),
+                    },
+                    Segment::Quadratic { ctrl, to } => PathEvent::Quadratic {
+                        from: p(from),
+                        ctrl: p(ctrl),
+                        to: p(to),
+                    },
+                    Segment::Cubic { ctrl1, ctrl2, to } => PathEvent::Cubic {
+                        from: p(from),
+                        ctrl1: p(ctrl1),
+                        ctrl2: p(ctrl2),
+                        to: p(to),
+                    },
+                };
+                events.push(event);
+                from = segment.to();
+            }
+            events.push(PathEvent::End {
+                last: p(from),
+                first: p(subpath.start),
+                close: subpath.closed,
+            });
+        }
+        events
+    }
+
+    // Add the given segment, beginning a new sub-path at `start` if necessary.
+    fn segment_to(mut self, start: Point2, segment: Segment) -> Self {
+        let needs_subpath = match self.subpaths.last() {
+            None => true,
+            Some(subpath) => subpath.closed,
+        };
+        if needs_subpath {
+            let start = self.cursor.take().unwrap_or(start);
+            self = self.move_to(start);
+        }
+        self.subpaths
+            .last_mut()
+            .expect("no sub-path")
+            .segments
+            .push(segment);
+        self
+    }
+
+    // Each sub-path approximated by a polyline within the path's tolerance.
+    pub(crate) fn polylines(&self) -> Vec<Vec<Point2>> {
+        self.subpaths
+            .iter()
+            .filter(|s| !s.segments.is_empty())
+            .map(|subpath| subpath.flatten(self.tolerance))
+            .collect()
+    }
+
+    // Each line segment of the flattened path, including the closing line of closed sub-paths.
+    fn flattened_edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        let closed = self
+            .subpaths
+            .iter()
+            .filter(|s| !s.segments.is_empty())
+            .map(|s| s.closed);
+        self.polylines()
+            .into_iter()
+            .zip(closed)
+            .flat_map(|(polyline, closed)| {
+                let mut edges: Vec<_> = polyline.windows(2).map(|w| (w[0], w[1])).collect();
+                if closed {
+                    edges.push((polyline[polyline.len() - 1], polyline[0]));
+                }
+                edges
+            })
+    }
+}
+
+impl Segment {
+    /// The point at which the segment ends.
+    pub fn to(&self) -> Point2 {
+        match *self {
+            Segment::Line(to) | Segment::Quadratic { to, .. } | Segment::Cubic { to, .. } => to,
+        }
+    }
+}
+
+impl SubPath {
+    // The points of a polyline approximating the sub-path within the given tolerance, excluding
+    // the closing line.
+    fn flatten(&self, tolerance: f32) -> Vec<Point2> {
+        let mut points = vec![self.start];
+        let mut from = self.start;
+        for segment in &self.segments {
+            match *segment {
+                Segment::Line(to) => points.push(to),
+                Segment::Quadratic { ctrl, to } => {
+                    let n = subdivisions(2.0, (from - 2.0 * ctrl + to).length(), tolerance);
+                    points.extend((1..=n).map(|i| {
+                        let t = i as f32 / n as f32;
+                        let mt = 1.0 - t;
+                        from * (mt * mt) + ctrl * (2.0 * mt * t) + to * (t * t)
+                    }));
+                }
+                Segment::Cubic { ctrl1, ctrl2, to } => {
+                    let dd = (from - 2.0 * ctrl1 + ctrl2)
+                        .length()
+                        .max((ctrl1 - 2.0 * ctrl2 + to).length());
+                    let n = subdivisions(3.0, dd, tolerance);
+                    points.extend((1..=n).map(|i| {
+                        let t = i as f32 / n as f32;
+                        let mt = 1.0 - t;
+                        from * (mt * mt * mt)
+                            + ctrl1 * (3.0 * mt * mt * t)
+                            + ctrl2 * (3.0 * mt * t * t)
+                            + to * (t * t * t)
+                    }));
+                }
+            }
+            from = segment.to();
+        }
+        points
+    }
+}
+
+impl Default for Path2d {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&Path2d> for crate::geom::Path {
+    fn from(path: &Path2d) -> Self {
+        path.events()
+            .into_iter()
+            .collect::<lyon::path::Path>()
+            .into()
+    }
+}
+
+// The number of uniform subdivisions required to approximate a bezier curve of the given degree
+// within the given tolerance, via Wang's formula.
+//
+// `dd` is the greatest magnitude of the second differences of the curve's control points.
+fn subdivisions(degree: f32, dd: f32, tolerance: f32) -> usize {
+    let n = (degree * (degree - 1.0) / 8.0 * dd / tolerance.max(f32::EPSILON)).sqrt();
+    (n.ceil() as usize).clamp(1, 1024)
+}
+
+#[test]
+fn test_path2d_lines() {
+    use crate::geom::pt2;
+
+    let square = Path2d::new()
+        .move_to(pt2(0.0, 0.0))
+        .line_to(pt2(10.0, 0.0))
+        .line_to(pt2(10.0, 10.0))
+        .line_to(pt2(0.0, 10.0))
+        .close();
+    assert_eq!(square.length(), 40.0);
+    assert_eq!(square.sample(0.0), Some(pt2(0.0, 0.0)));
+    assert_eq!(square.sample(0.375), Some(pt2(10.0, 5.0)));
+    assert_eq!(square.sample(1.0), Some(pt2(0.0, 0.0)));
+    assert!(square.contains(pt2(5.0, 5.0)));
+    assert!(!square.contains(pt2(15.0, 5.0)));
+    let bounds = square.bounds().unwrap();
+    assert_eq!((bounds.w(), bounds.h()), (10.0, 10.0));
+    assert_eq!(square.events().len(), 5);
+
+    // A second sub-path inside the first forms a hole via the even-odd rule.
+    let hole = square
+        .move_to(pt2(2.0, 2.0))
+        .line_to(pt2(8.0, 2.0))
+        .line_to(pt2(8.0, 8.0))
+        .line_to(pt2(2.0, 8.0));
+    assert!(!hole.contains(pt2(5.0, 5.0)));
+    assert!(hole.contains(pt2(1.0, 5.0)));
+
+    assert_eq!(Path2d::new().sample(0.5), None);
+    assert_eq!(Path2d::new().bounds(), None);
+}
+
+#[test]
+fn test_path2d_curves() {
+    use crate::geom::pt2;
+
+    // A cubic approximating a quarter circle of radius 10.
+    let k = 0.552_284_8 * 10.0;
+    let arc = Path2d::new()
+        .tolerance(0.001)
+        .move_to(pt2(10.0, 0.0))
+        .curve_to(pt2(10.0, k), pt2(k, 10.0), pt2(0.0, 10.0));
+    let quarter = std::f32::consts::FRAC_PI_2 * 10.0;
+    assert!((arc.length() - quarter).abs() < 0.01);
+    let mid = arc.sample(0.5).unwrap();
+    assert!((mid.length() - 10.0).abs() < 0.01);
+    let bounds = arc.bounds().unwrap();
+    assert!((bounds.right() - 10.0).abs() < 1e-4);
+    assert!((bounds.top() - 10.0).abs() < 1e-4);
+
+    // A quadratic with its control point on the line is straight.
+    let line = Path2d::new().quadratic_to(pt2(5.0, 0.0), pt2(10.0, 0.0));
+    assert_eq!(line.length(), 5.0);
+}
@@ -0,0 +1,390 @@
+//! Render graphics to an offscreen target without a window or event loop.
+//!
+//! This is useful for generating images in environments where no display is available, e.g. CI
+//! or a server. Create a [**Headless**](./struct.Headless.html) target via
+//! [**splatter::headless**](../fn.headless.html), produce a **Frame** with `Headless::frame`
+//! and then draw to it exactly as you would within a `view` function.
+//!
+//! ```no_run
+//! use splatter::prelude::*;
+//!
+//! fn main() {
+//!     let headless = splatter::headless(640, 480).build().unwrap();
+//!     let draw = Draw::new();
+//!     draw.background().color(PLUM);
+//!     draw.ellipse().color(STEELBLUE);
+//!
+//!     let frame = headless.frame();
+//!     draw.to_headless_frame(&headless, &frame).unwrap();
+//!     frame.submit();
+//!
+//!     let image = headless.read_image().unwrap();
+//!     image.save("frame.png").unwrap();
+//! }
+//! ```
+//!
+//! To run an existing app or sketch offscreen, including its `view` function, see
+//! `app::Builder::headless`.
+
+use crate::color::IntoLinSrgba;
+use crate::draw;
+use crate::frame::{self, Frame, RawFrame};
+use crate::geom;
+use crate::image;
+use crate::wgpu;
+use crate::window;
+use std::cell::{Cell, RefCell};
+use std::sync::Arc;
+use wgpu_upstream::InstanceDescriptor;
+
+/// A context for building a **Headless** render target.
+#[derive(Debug)]
+pub struct Builder {
+    size: [u32; 2],
+    backends: wgpu::Backends,
+    power_preference: wgpu::PowerPreference,
+    force_fallback_adapter: bool,
+    device_desc: Option<wgpu::DeviceDescriptor<'static>>,
+    msaa_samples: Option<u32>,
+    clear_color: Option<wgpu::Color>,
+}
+
+/// An offscreen render target along with the device and queue used to draw to it.
+///
+/// The **Frame**s produced by a **Headless** target behave the same as those delivered to a
+/// window's `view` function, allowing for the same drawing code to be shared between both.
+pub struct Headless {
+    // Keeps the adapter on which the device was requested alive.
+    _adapters: wgpu::AdapterMap,
+    device_queue_pair: Arc<wgpu::DeviceQueuePair>,
+    // Stands in for a window's swap chain texture.
+    texture: wgpu::Texture,
+    texture_view: wgpu::TextureView,
+    render_data: frame::RenderData,
+    capture_data: frame::CaptureData,
+    texture_capturer: wgpu::TextureCapturer,
+    pub(crate) renderer: RefCell<draw::Renderer>,
+    msaa_samples: u32,
+    clear_color: wgpu::Color,
+    is_invalidated: Cell<bool>,
+    frame_count: Cell<u64>,
+}
+
+/// Errors that might occur while building the **Headless** target.
+#[derive(Debug)]
+pub enum BuildError {
+    NoAvailableAdapter,
+}
+
+/// Errors that might occur while rendering via `Headless::render`.
+#[derive(Debug)]
+pub enum RenderError {
+    /// The **Draw** could not be rendered to the frame.
+    Draw(draw::renderer::DrawError),
+    /// The rendered pixels could not be read back from the target.
+    Read(wgpu::BufferAsyncError),
+}
+
+impl Builder {
+    /// The texture format of the headless target, equivalent to a typical swap chain format.
+    pub const TEXTURE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+    /// Begin building a headless target with the given size in pixels.
+    pub fn new(width: u32, height: u32) -> Self {
+        Builder {
+            size: [width, height],
+            backends: wgpu::DEFAULT_BACKENDS,
+            power_preference: wgpu::DEFAULT_POWER_PREFERENCE,
+            force_fallback_adapter: false,
+            device_desc: None,
+            msaa_samples: None,
+            clear_color: None,
+        }
+    }
+
+    /// Specify the set of preferred WGPU backends.
+    ///
+    /// By default, this is `wgpu::DEFAULT_BACKENDS`.
+    pub fn backends(mut self, backends: wgpu::Backends) -> Self {
+        self.backends = backends;
+        self
+    }
+
+    /// Specify the power preference used when requesting the adapter.
+    ///
+    /// By default, this is `wgpu::DEFAULT_POWER_PREFERENCE`.
+    pub fn power_preference(mut self, pref: wgpu::PowerPreference) -> Self {
+        self.power_preference = pref;
+        self
+    }
+
+    /// Indicates that only a fallback adapter can be returned, e.g. a software renderer.
+    ///
+    /// This can be useful on machines without a GPU, such as many CI runners.
+    pub fn force_fallback_adapter(mut self, force: bool) -> Self {
+        self.force_fallback_adapter = force;
+        self
+    }
+
+    /// Specify a device descriptor to use when requesting the logical device.
+    pub fn device_descriptor(mut self, device_desc: wgpu::DeviceDescriptor<'static>) -> Self {
+        self.device_desc = Some(device_desc);
+        self
+    }
+
+    /// Specify the number of samples per pixel for the multisample anti-aliasing render pass.
+    ///
+    /// By default, this is `Frame::DEFAULT_MSAA_SAMPLES`. As with windows, the nearest sample
+    /// count supported by the adapter is used if the requested count is unsupported.
+    pub fn msaa_samples(mut self, msaa_samples: u32) -> Self {
+        self.msaa_samples = Some(msaa_samples);
+        self
+    }
+
+    /// The color with which the target is cleared before the first frame.
+    ///
+    /// By default, this is opaque black.
+    pub fn clear_color<C>(mut self, color: C) -> Self
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        let lin_srgba = color.into_lin_srgba();
+        let (r, g, b, a) = lin_srgba.into_components();
+        let (r, g, b, a) = (r as f64, g as f64, b as f64, a as f64);
+        self.clear_color = Some(wgpu::Color { r, g, b, a });
+        self
+    }
+
+    /// Request the adapter and device and create the offscreen target.
+    ///
+    /// This blocks the current thread until the device is ready.
+    pub fn build(self) -> Result<Headless, BuildError> {
+        futures::executor::block_on(self.build_async())
+    }
+
+    /// The same as `build`, but asynchronous.
+    pub async fn build_async(self) -> Result<Headless, BuildError> {
+        let Builder {
+            size,
+            backends,
+            power_preference,
+            force_fallback_adapter,
+            device_desc,
+            msaa_samples,
+            clear_color,
+        } = self;
+
+        // Request the adapter and device.
+        let instance = wgpu::Instance::new(InstanceDescriptor {
+            backends,
+            ..Default::default()
+        });
+        let adapters = wgpu::AdapterMap::default();
+        let request_adapter_opts = wgpu::RequestAdapterOptions {
+            power_preference,
+            compatible_surface: None,
+            force_fallback_adapter,
+        };
+        let adapter = adapters
+            .get_or_request_async(request_adapter_opts, &instance)
+            .await
+            .ok_or(BuildError::NoAvailableAdapter)?;
+        let device_desc = device_desc.unwrap_or_else(wgpu::default_device_descriptor);
+        let device_queue_pair = adapter.get_or_request_device_async(device_desc).await;
+        let device = device_queue_pair.device();
+
+        // Create the target texture along with the intermediary frame data.
+        let texture = wgpu::TextureBuilder::new()
+            .size(size)
+            .format(Builder::TEXTURE_FORMAT)
+            .usage(
+                wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::COPY_SRC,
+            )
+            .build(device);
+        let texture_view = texture.view().build();
+        let msaa_samples = msaa_samples.unwrap_or(Frame::DEFAULT_MSAA_SAMPLES);
+        let format_features = adapter.get_texture_format_features(Builder::TEXTURE_FORMAT);
+        let supported_samples =
+            wgpu::nearest_supported_sample_count(format_features.flags, msaa_samples);
+        if supported_samples != msaa_samples {
+            // TODO: Log errors, don't print to stderr.
+            eprintln!(
+                "requested `msaa_samples` of {} is not supported by the adapter, falling back to {}",
+                msaa_samples, supported_samples,
+            );
+        }
+        let msaa_samples = supported_samples;
+        let render_data =
+            frame::RenderData::new(device, size, Builder::TEXTURE_FORMAT, msaa_samples);
+        let capture_data = frame::CaptureData::new(1, None);
+        let texture_capturer = wgpu::TextureCapturer::default();
+
+        // The renderer used for `Draw::to_headless_frame`.
+        let scale_factor = 1.0;
+        let renderer = draw::RendererBuilder::new().build(
+            device,
+            size,
+            scale_factor,
+            msaa_samples,
+            Frame::TEXTURE_FORMAT,
+        );
+
+        let clear_color = clear_color.unwrap_or(wgpu::Color {
+            a: 1.0,
+            ..Default::default()
+        });
+
+        Ok(Headless {
+            _adapters: adapters,
+            device_queue_pair,
+            texture,
+            texture_view,
+            render_data,
+            capture_data,
+            texture_capturer,
+            renderer: RefCell::new(renderer),
+            msaa_samples,
+            clear_color,
+            is_invalidated: Cell::new(true),
+            frame_count: Cell::new(0),
+        })
+    }
+}
+
+impl Headless {
+    /// The `window::Id` reported by **Frame**s produced by a **Headless** target.
+    ///
+    /// As there is no window, this does not refer to any window within an **App**.
+    pub fn id(&self) -> window::Id {
+        window::Id::from(u64::MAX)
+    }
+
+    /// The size of the target in pixels.
+    pub fn size_pixels(&self) -> [u32; 2] {
+        self.texture.size()
+    }
+
+    /// The rectangle representing the position and dimensions of the target.
+    ///
+    /// Like `Window::rect`, the rectangle is centred on `[0.0, 0.0]`.
+    pub fn rect(&self) -> geom::Rect {
+        let [w, h] = self.texture.size();
+        geom::Rect::from_w_h(w as f32, h as f32)
+    }
+
+    /// The number of samples used for multisample anti-aliasing.
+    pub fn msaa_samples(&self) -> u32 {
+        self.msaa_samples
+    }
+
+    /// The device and queue used to render to the target.
+    pub fn device_queue_pair(&self) -> &Arc<wgpu::DeviceQueuePair> {
+        &self.device_queue_pair
+    }
+
+    /// The target texture to which each **Frame** is written upon submission.
+    ///
+    /// This is the equivalent of a window's swap chain texture and is formatted as
+    /// `Builder::TEXTURE_FORMAT`.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// The number of frames produced by this target so far.
+    pub fn elapsed_frames(&self) -> u64 {
+        self.frame_count.get()
+    }
+
+    /// Produce a new **Frame** ready for drawing.
+    ///
+    /// The frame's contents persist from the previous frame, just as they do for a window. The
+    /// frame is written to the target when it is submitted or dropped.
+    ///
+    /// Note: `Window` capture methods are not available for headless frames. Use `read_image`
+    /// once the frame has been submitted instead.
+    pub fn frame(&self) -> Frame<'_> {
+        let nth = self.frame_count.get();
+        self.frame_count.set(nth + 1);
+        let raw_frame = RawFrame::new_empty(
+            self.device_queue_pair.clone(),
+            self.id(),
+            nth,
+            &self.texture_view,
+            Builder::TEXTURE_FORMAT,
+            self.rect(),
+        );
+        if self.is_invalidated.replace(false) {
+            raw_frame.clear(self.render_data.texture_view(), self.clear_color);
+        }
+        Frame::new_empty(raw_frame, &self.render_data, &self.capture_data)
+    }
+
+    /// Read the pixels of the most recently submitted frame back to the CPU.
+    ///
+    /// The returned image is non-linear sRGBA, the same as the images written by
+    /// `Window::capture_frame`. This blocks the current thread until the read completes.
+    pub fn read_image(&self) -> Result<image::RgbaImage, wgpu::BufferAsyncError> {
+        let device = self.device_queue_pair.device();
+        let ce_desc = wgpu::CommandEncoderDescriptor {
+            label: Some("splatter_headless_read_image"),
+        };
+        let mut encoder = device.create_command_encoder(&ce_desc);
+        let snapshot = self
+            .texture_capturer
+            .capture(device, &mut encoder, &self.texture);
+        let queue = self.device_queue_pair.queue();
+        queue.submit(std::iter::once(encoder.finish()));
+
+        // The buffer is only mapped once the device is polled, so drive both together.
+        let read = snapshot.read_async();
+        let poll = async {
+            device.poll(wgpu::Maintain::Wait);
+        };
+        let (result, ()) = futures::executor::block_on(futures::future::join(read, poll));
+        let image = result?.to_owned();
+        Ok(image)
+    }
+
+    /// Draw the given **Draw** to a new frame and read the resulting pixels back.
+    ///
+    /// This is short-hand for producing a frame, drawing to it, submitting it and calling
+    /// `read_image`.
+    pub fn render(&self, draw: &draw::Draw) -> Result<image::RgbaImage, RenderError> {
+        let frame = self.frame();
+        draw.to_headless_frame(self, &frame)?;
+        frame.submit();
+        let image = self.read_image()?;
+        Ok(image)
+    }
+}
+
+impl From<draw::renderer::DrawError> for RenderError {
+    fn from(err: draw::renderer::DrawError) -> Self {
+        RenderError::Draw(err)
+    }
+}
+
+impl From<wgpu::BufferAsyncError> for RenderError {
+    fn from(err: wgpu::BufferAsyncError) -> Self {
+        RenderError::Read(err)
+    }
+}
+
+impl draw::Draw {
+    /// Render the **Draw**'s inner list of commands to the given **Frame** produced by a
+    /// **Headless** target.
+    ///
+    /// This is the headless equivalent of `Draw::to_frame`.
+    pub fn to_headless_frame(
+        &self,
+        headless: &Headless,
+        frame: &Frame,
+    ) -> Result<(), draw::renderer::DrawError> {
+        let device = headless.device_queue_pair.device();
+        let scale_factor = 1.0;
+        let mut renderer = headless.renderer.borrow_mut();
+        renderer.render_to_frame(device, self, scale_factor, frame)
+    }
+}
@@ -13,11 +13,12 @@
 //! examples](https://github.com/splatter-org/splatter/tree/master/examples) to get an idea of how
 //! splatter applications are structured and how the API works.
 
+pub use bytemuck;
 pub use find_folder;
 pub use lyon;
 pub use winit;
 
-pub use self::app::{App, LoopMode};
+pub use self::app::{App, LoopMode, TimeMode};
 pub use self::draw::Draw;
 pub use self::event::Event;
 pub use self::frame::Frame;
@@ -29,18 +30,34 @@ pub use splatter_mesh as mesh;
 pub use splatter_wgpu as wgpu;
 
 pub mod app;
+pub mod audio;
+pub mod camera;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod clipboard;
+pub mod compute;
 pub mod draw;
 pub mod ease;
 pub mod event;
 pub mod frame;
+pub mod fx;
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
 pub mod geom;
+pub mod headless;
 pub mod image;
 pub mod io;
+pub mod midi;
 pub mod noise;
+pub mod osc;
+mod overlay;
 pub mod prelude;
+pub mod sprite;
 pub mod state;
+pub mod storage;
+pub mod test;
 pub mod text;
 pub mod time;
+pub mod video;
 pub mod window;
 
 /// Begin building the `App`.
@@ -66,3 +83,11 @@ pub fn app<M: 'static>(model: app::ModelFn<M>) -> app::Builder<M, Event> {
 pub fn sketch(view: app::SketchViewFn) -> app::SketchBuilder<Event> {
     app::Builder::sketch(view)
 }
+
+/// Begin building a **Headless** target of the given size in pixels.
+///
+/// This allows for rendering graphics offscreen without a window or event loop, e.g. to generate
+/// images in CI or on a server. See the [**headless**](./headless/index.html) module docs.
+pub fn headless(width: u32, height: u32) -> headless::Builder {
+    headless::Builder::new(width, height)
+}
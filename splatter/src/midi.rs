@@ -0,0 +1,323 @@
+//! Items for sketches that respond to MIDI input, e.g. from a controller during a performance.
+//!
+//! MIDI messages typically arrive on a dedicated input thread. The **Sender** and **Receiver**
+//! pair returned by `queue` allow raw messages to be decoded on that thread and queued, before
+//! being drained on the main thread within `update` where the model may be mutated.
+//!
+//! ```
+//! use splatter::midi::{self, MidiMessage};
+//!
+//! let (sender, receiver) = midi::queue();
+//!
+//! // Within the MIDI input callback.
+//! sender.send_bytes(&[0x90, 60, 100]);
+//!
+//! // Within `update`.
+//! for msg in receiver.drain() {
+//!     if let MidiMessage::NoteOn { note, velocity, .. } = msg {
+//!         assert_eq!((note, velocity), (60, 100));
+//!     }
+//! }
+//! ```
+//!
+//! With the `midi` feature enabled, `App::midi_ports` lists the available input ports and
+//! `App::midi_input` connects to one via [midir](https://docs.rs/midir), calling back with each
+//! decoded message on the input thread.
+
+#[cfg(feature = "midi")]
+use std::fmt;
+use std::sync::mpsc;
+
+/// A decoded MIDI channel message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MidiMessage {
+    /// A key was pressed.
+    NoteOn { channel: u8, note: u8, velocity: u8 },
+    /// A key was released.
+    ///
+    /// Note-on messages with a velocity of `0` are decoded as note-off messages.
+    NoteOff { channel: u8, note: u8, velocity: u8 },
+    /// A controller, e.g. a knob or fader, changed value.
+    ControlChange {
+        channel: u8,
+        controller: u8,
+        value: u8,
+    },
+    /// The pitch bend wheel moved, where `value` is within `-8192..=8191` and `0` is centred.
+    PitchBend { channel: u8, value: i16 },
+}
+
+/// Decodes raw MIDI messages and queues them for the **Receiver**.
+///
+/// The **Sender** may be cloned in order to queue messages from multiple input ports.
+#[derive(Clone, Debug)]
+pub struct Sender {
+    tx: mpsc::Sender<MidiMessage>,
+}
+
+/// Drains the messages queued by the **Sender**.
+#[derive(Debug)]
+pub struct Receiver {
+    rx: mpsc::Receiver<MidiMessage>,
+}
+
+/// Create a connected **Sender** and **Receiver** for queueing decoded MIDI messages.
+pub fn queue() -> (Sender, Receiver) {
+    let (tx, rx) = mpsc::channel();
+    (Sender { tx }, Receiver { rx })
+}
+
+/// A connection to a MIDI input port.
+///
+/// The connection's callback is called on the input thread until the **Input** is dropped.
+#[cfg(feature = "midi")]
+pub struct Input {
+    port: String,
+    _connection: midir::MidiInputConnection<()>,
+}
+
+/// Errors that might occur while connecting to a MIDI input port.
+#[cfg(feature = "midi")]
+#[derive(Debug)]
+pub enum InputError {
+    /// The system's MIDI API could not be initialised.
+    Init(midir::InitError),
+    /// No input port with the given name exists.
+    NoSuchPort,
+    /// The port exists, but could not be connected to.
+    Connect(midir::ConnectError<midir::MidiInput>),
+}
+
+/// The names of the MIDI input ports available on the system.
+///
+/// Returns an empty list if the system's MIDI API could not be initialised.
+///
+/// See `App::midi_ports`.
+#[cfg(feature = "midi")]
+pub fn ports() -> Vec<String> {
+    let midi_in = match midir::MidiInput::new(CLIENT_NAME) {
+        Ok(midi_in) => midi_in,
+        Err(_) => return vec![],
+    };
+    midi_in
+        .ports()
+        .iter()
+        .filter_map(|port| midi_in.port_name(port).ok())
+        .collect()
+}
+
+/// Connect to the MIDI input port with the given name, calling `callback` with each message
+/// received on the port.
+///
+/// The callback is called on the input thread. Unsupported messages are ignored. A **Sender**
+/// may be moved into the callback to queue messages for the main thread.
+///
+/// See `App::midi_input`.
+#[cfg(feature = "midi")]
+pub fn input<F>(port: &str, mut callback: F) -> Result<Input, InputError>
+where
+    F: 'static + Send + FnMut(MidiMessage),
+{
+    let midi_in = midir::MidiInput::new(CLIENT_NAME)?;
+    let midi_port = midi_in
+        .ports()
+        .into_iter()
+        .find(|p| midi_in.port_name(p).map(|n| n == port).unwrap_or(false))
+        .ok_or(InputError::NoSuchPort)?;
+    let connection = midi_in.connect(
+        &midi_port,
+        CLIENT_NAME,
+        move |_stamp, bytes, _| {
+            if let Some(msg) = MidiMessage::from_bytes(bytes) {
+                callback(msg);
+            }
+        },
+        (),
+    )?;
+    let port = port.to_string();
+    Ok(Input {
+        port,
+        _connection: connection,
+    })
+}
+
+// The name by which splatter identifies itself to the system's MIDI API.
+#[cfg(feature = "midi")]
+const CLIENT_NAME: &str = "splatter";
+
+impl MidiMessage {
+    /// Decode a raw MIDI message.
+    ///
+    /// Returns `None` for incomplete messages and for message types that are not yet supported,
+    /// such as system exclusive messages.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&status, data) = bytes.split_first()?;
+        let channel = status & 0x0F;
+        let data = |i: usize| data.get(i).map(|&b| b & 0x7F);
+        let msg = match status & 0xF0 {
+            0x80 => MidiMessage::NoteOff {
+                channel,
+                note: data(0)?,
+                velocity: data(1)?,
+            },
+            0x90 => match (data(0)?, data(1)?) {
+                (note, 0) => MidiMessage::NoteOff {
+                    channel,
+                    note,
+                    velocity: 0,
+                },
+                (note, velocity) => MidiMessage::NoteOn {
+                    channel,
+                    note,
+                    velocity,
+                },
+            },
+            0xB0 => MidiMessage::ControlChange {
+                channel,
+                controller: data(0)?,
+                value: data(1)?,
+            },
+            0xE0 => {
+                let value = (data(1)? as i16) << 7 | data(0)? as i16;
+                MidiMessage::PitchBend {
+                    channel,
+                    value: value - 8192,
+                }
+            }
+            _ => return None,
+        };
+        Some(msg)
+    }
+
+    /// The channel on which the message was sent, within `0..16`.
+    pub fn channel(&self) -> u8 {
+        match *self {
+            MidiMessage::NoteOn { channel, .. }
+            | MidiMessage::NoteOff { channel, .. }
+            | MidiMessage::ControlChange { channel, .. }
+            | MidiMessage::PitchBend { channel, .. } => channel,
+        }
+    }
+}
+
+impl Sender {
+    /// Queue the given message.
+    ///
+    /// Returns `false` if the **Receiver** has been dropped.
+    pub fn send(&self, msg: MidiMessage) -> bool {
+        self.tx.send(msg).is_ok()
+    }
+
+    /// Decode and queue the given raw MIDI message, ignoring unsupported messages.
+    ///
+    /// Returns `false` if the **Receiver** has been dropped.
+    pub fn send_bytes(&self, bytes: &[u8]) -> bool {
+        match MidiMessage::from_bytes(bytes) {
+            None => true,
+            Some(msg) => self.send(msg),
+        }
+    }
+}
+
+impl Receiver {
+    /// Produce an iterator yielding all messages queued since the last drain, without blocking.
+    pub fn drain(&self) -> impl '_ + Iterator<Item = MidiMessage> {
+        self.rx.try_iter()
+    }
+}
+
+#[cfg(feature = "midi")]
+impl Input {
+    /// The name of the connected port.
+    pub fn port(&self) -> &str {
+        &self.port
+    }
+}
+
+#[cfg(feature = "midi")]
+impl fmt::Debug for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Input").field("port", &self.port).finish()
+    }
+}
+
+#[cfg(feature = "midi")]
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InputError::Init(ref err) => err.fmt(f),
+            InputError::NoSuchPort => write!(f, "no MIDI input port with the given name"),
+            InputError::Connect(ref err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "midi")]
+impl std::error::Error for InputError {}
+
+#[cfg(feature = "midi")]
+impl From<midir::InitError> for InputError {
+    fn from(err: midir::InitError) -> Self {
+        InputError::Init(err)
+    }
+}
+
+#[cfg(feature = "midi")]
+impl From<midir::ConnectError<midir::MidiInput>> for InputError {
+    fn from(err: midir::ConnectError<midir::MidiInput>) -> Self {
+        InputError::Connect(err)
+    }
+}
+
+#[test]
+fn test_midi_message_from_bytes() {
+    let decode = MidiMessage::from_bytes;
+    assert_eq!(
+        decode(&[0x93, 60, 100]),
+        Some(MidiMessage::NoteOn {
+            channel: 3,
+            note: 60,
+            velocity: 100
+        })
+    );
+    assert_eq!(
+        decode(&[0x90, 60, 0]),
+        Some(MidiMessage::NoteOff {
+            channel: 0,
+            note: 60,
+            velocity: 0
+        })
+    );
+    assert_eq!(
+        decode(&[0xB1, 7, 127]),
+        Some(MidiMessage::ControlChange {
+            channel: 1,
+            controller: 7,
+            value: 127
+        })
+    );
+    let bend = |lsb, msb| decode(&[0xE0, lsb, msb]);
+    assert_eq!(
+        bend(0, 0x40),
+        Some(MidiMessage::PitchBend {
+            channel: 0,
+            value: 0
+        })
+    );
+    assert_eq!(
+        bend(0, 0),
+        Some(MidiMessage::PitchBend {
+            channel: 0,
+            value: -8192
+        })
+    );
+    assert_eq!(
+        bend(0x7F, 0x7F),
+        Some(MidiMessage::PitchBend {
+            channel: 0,
+            value: 8191
+        })
+    );
+    assert_eq!(decode(&[0x90, 60]), None);
+    assert_eq!(decode(&[0xF0, 1, 2, 0xF7]), None);
+}
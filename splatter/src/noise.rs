@@ -1,4 +1,233 @@
 //! Items related to [noise generation](https://docs.rs/noise/0.5.1/noise/), API offered via the
 //! [noise-rs crate](https://docs.rs/noise).
+//!
+//! On top of the re-exported noise-rs API, this module provides a small set of seeded noise
+//! sources - [**Perlin**](./struct.Perlin.html), [**Simplex**](./struct.Simplex.html) and
+//! [**OpenSimplex**](./struct.OpenSimplex.html) - that are constructed from a `u64` seed and
+//! always produce values within the range `[-1, 1]`. Given the same seed, these sources produce
+//! exactly the same values on every run, making it easy to reproduce a sketch.
+//!
+//! The original noise-rs types of the same name remain accessible via `noise::noise`.
 
+pub use noise;
 pub use noise::*;
+
+/// A seeded noise source that may be sampled in 2, 3 or 4 dimensions.
+///
+/// All values returned by the `get_*` methods are within the range `[-1, 1]`.
+pub trait Noise {
+    /// Sample the noise at the given 2D point.
+    fn get_2d(&self, x: f64, y: f64) -> f64;
+
+    /// Sample the noise at the given 3D point.
+    fn get_3d(&self, x: f64, y: f64, z: f64) -> f64;
+
+    /// Sample the noise at the given 4D point.
+    fn get_4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64;
+
+    /// Layer multiple octaves of this noise source as fractal brownian motion.
+    ///
+    /// - `octaves` is the number of layers sampled. Values less than `1` are treated as `1`.
+    /// - `lacunarity` is the multiplier applied to the frequency of each successive octave.
+    /// - `gain` is the multiplier applied to the amplitude of each successive octave.
+    ///
+    /// The sum of all octaves is normalised so that the result remains within `[-1, 1]`.
+    fn fbm(self, octaves: usize, lacunarity: f64, gain: f64) -> Fbm<Self>
+    where
+        Self: Sized,
+    {
+        Fbm {
+            source: self,
+            octaves: octaves.max(1),
+            lacunarity,
+            gain,
+        }
+    }
+}
+
+/// Fractal brownian motion over some seeded noise source.
+///
+/// Created via the [**Noise::fbm**](./trait.Noise.html#method.fbm) method.
+#[derive(Clone, Copy, Debug)]
+pub struct Fbm<N> {
+    source: N,
+    octaves: usize,
+    lacunarity: f64,
+    gain: f64,
+}
+
+// Fold a `u64` seed into the `u32` seed expected by noise-rs without discarding the upper bits.
+fn fold_seed(seed: u64) -> u32 {
+    (seed ^ (seed >> 32)) as u32
+}
+
+macro_rules! seeded_noise {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name {
+            seed: u64,
+            source: ::noise::$name,
+        }
+
+        impl $name {
+            /// The seed used by the `Default` implementation.
+            pub const DEFAULT_SEED: u64 = 0;
+
+            /// Create a new noise source from the given seed.
+            pub fn new(seed: u64) -> Self {
+                let source = ::noise::$name::new(fold_seed(seed));
+                $name { seed, source }
+            }
+
+            /// Produce a new noise source with the given seed.
+            pub fn set_seed(self, seed: u64) -> Self {
+                Self::new(seed)
+            }
+
+            /// The seed from which this noise source was created.
+            pub fn seed(&self) -> u64 {
+                self.seed
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new(Self::DEFAULT_SEED)
+            }
+        }
+
+        impl Noise for $name {
+            fn get_2d(&self, x: f64, y: f64) -> f64 {
+                self.source.get([x, y]).clamp(-1.0, 1.0)
+            }
+
+            fn get_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+                self.source.get([x, y, z]).clamp(-1.0, 1.0)
+            }
+
+            fn get_4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64 {
+                self.source.get([x, y, z, w]).clamp(-1.0, 1.0)
+            }
+        }
+
+        impl_noise_fn!($name);
+    };
+}
+
+// Allow for using the seeded noise sources anywhere a noise-rs `NoiseFn` is expected.
+macro_rules! impl_noise_fn {
+    ($name:ident $(<$param:ident>)?) => {
+        impl$(<$param: Noise>)? NoiseFn<f64, 2> for $name$(<$param>)? {
+            fn get(&self, [x, y]: [f64; 2]) -> f64 {
+                self.get_2d(x, y)
+            }
+        }
+
+        impl$(<$param: Noise>)? NoiseFn<f64, 3> for $name$(<$param>)? {
+            fn get(&self, [x, y, z]: [f64; 3]) -> f64 {
+                self.get_3d(x, y, z)
+            }
+        }
+
+        impl$(<$param: Noise>)? NoiseFn<f64, 4> for $name$(<$param>)? {
+            fn get(&self, [x, y, z, w]: [f64; 4]) -> f64 {
+                self.get_4d(x, y, z, w)
+            }
+        }
+    };
+}
+
+seeded_noise! {
+    /// Seeded [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise).
+    Perlin
+}
+
+seeded_noise! {
+    /// Seeded [Simplex noise](https://en.wikipedia.org/wiki/Simplex_noise).
+    Simplex
+}
+
+seeded_noise! {
+    /// Seeded [OpenSimplex noise](https://en.wikipedia.org/wiki/OpenSimplex_noise).
+    OpenSimplex
+}
+
+impl<N> Fbm<N> {
+    /// The number of octaves sampled.
+    pub fn octaves(&self) -> usize {
+        self.octaves
+    }
+
+    /// The frequency multiplier applied to each successive octave.
+    pub fn lacunarity(&self) -> f64 {
+        self.lacunarity
+    }
+
+    /// The amplitude multiplier applied to each successive octave.
+    pub fn gain(&self) -> f64 {
+        self.gain
+    }
+
+    // Sum the octaves produced by `sample` at increasing frequencies, normalised by the total
+    // amplitude.
+    fn sum_octaves<F>(&self, mut sample: F) -> f64
+    where
+        F: FnMut(f64) -> f64,
+    {
+        let mut sum = 0.0;
+        let mut total_amplitude = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        for _ in 0..self.octaves {
+            sum += sample(frequency) * amplitude;
+            total_amplitude += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+        if total_amplitude == 0.0 {
+            return 0.0;
+        }
+        (sum / total_amplitude).clamp(-1.0, 1.0)
+    }
+}
+
+impl<N> Noise for Fbm<N>
+where
+    N: Noise,
+{
+    fn get_2d(&self, x: f64, y: f64) -> f64 {
+        self.sum_octaves(|f| self.source.get_2d(x * f, y * f))
+    }
+
+    fn get_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        self.sum_octaves(|f| self.source.get_3d(x * f, y * f, z * f))
+    }
+
+    fn get_4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64 {
+        self.sum_octaves(|f| self.source.get_4d(x * f, y * f, z * f, w * f))
+    }
+}
+
+impl_noise_fn!(Fbm<N>);
+
+#[test]
+fn test_seeded_noise_is_deterministic() {
+    let a = Perlin::new(u64::MAX).fbm(4, 2.0, 0.5);
+    let b = Perlin::new(u64::MAX).fbm(4, 2.0, 0.5);
+    for i in 0..64 {
+        let x = i as f64 * 0.173;
+        let y = i as f64 * -0.291;
+        let n = a.get_3d(x, y, 0.5);
+        assert_eq!(n, b.get_3d(x, y, 0.5));
+        assert!((-1.0..=1.0).contains(&n));
+    }
+    assert_eq!(
+        Simplex::new(7).get_2d(0.3, 0.7),
+        Simplex::new(7).get_2d(0.3, 0.7)
+    );
+    assert_eq!(
+        OpenSimplex::new(7).get_4d(0.1, 0.2, 0.3, 0.4),
+        OpenSimplex::new(7).get([0.1, 0.2, 0.3, 0.4])
+    );
+}
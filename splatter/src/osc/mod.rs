@@ -0,0 +1,146 @@
+//! Items for sending and receiving Open Sound Control (OSC) messages over UDP.
+//!
+//! OSC is commonly used to drive visuals from other software or devices within an installation.
+//! A **Receiver** never blocks, so it may be drained within `update` without stalling the render
+//! loop while waiting on the network.
+//!
+//! ```no_run
+//! use splatter::osc;
+//!
+//! let mut receiver = osc::receiver(9000).unwrap();
+//! let sender = osc::sender("127.0.0.1", 9000).unwrap();
+//! sender.send(("/radius", vec![osc::Type::Float(42.0)])).unwrap();
+//!
+//! // Within `update`.
+//! for msg in receiver.drain() {
+//!     println!("{}: {:?}", msg.addr, msg.args);
+//! }
+//! ```
+
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs, UdpSocket};
+
+pub mod packet;
+
+pub use self::packet::{
+    now, Bundle, DecodeError, Message, OscType as Type, Packet, TimeTag, IMMEDIATELY,
+};
+
+/// The maximum size of a UDP datagram, and in turn of a received packet.
+pub const MAX_PACKET_SIZE: usize = 65_507;
+
+/// Receives OSC packets sent to a UDP port.
+#[derive(Debug)]
+pub struct Receiver {
+    socket: UdpSocket,
+    buffer: Vec<u8>,
+}
+
+/// Sends OSC packets to a UDP address.
+///
+/// The socket is not connected, so that `send_to` may send to other addresses.
+#[derive(Debug)]
+pub struct Sender {
+    socket: UdpSocket,
+    addr: SocketAddr,
+}
+
+/// Bind a non-blocking **Receiver** to the given port on all network interfaces.
+pub fn receiver(port: u16) -> std::io::Result<Receiver> {
+    let socket = UdpSocket::bind(("0.0.0.0", port))?;
+    socket.set_nonblocking(true)?;
+    let buffer = vec![0; MAX_PACKET_SIZE];
+    Ok(Receiver { socket, buffer })
+}
+
+/// Create a **Sender** that sends packets to the given host and port.
+pub fn sender(host: &str, port: u16) -> std::io::Result<Sender> {
+    let addr = (host, port).to_socket_addrs()?.next().ok_or_else(|| {
+        let kind = std::io::ErrorKind::InvalidInput;
+        std::io::Error::new(kind, "no address found for host")
+    })?;
+    let socket = match addr {
+        SocketAddr::V4(_) => UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?,
+        SocketAddr::V6(_) => UdpSocket::bind((Ipv6Addr::UNSPECIFIED, 0))?,
+    };
+    Ok(Sender { socket, addr })
+}
+
+impl Receiver {
+    /// The address to which the **Receiver** is bound.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.socket.local_addr()
+    }
+
+    /// Receive the next pending packet along with the address from which it was sent.
+    ///
+    /// Returns `None` if no packets are pending. Packets that fail to decode are skipped.
+    pub fn try_recv(&mut self) -> Option<(Packet, SocketAddr)> {
+        loop {
+            let (len, addr) = match self.socket.recv_from(&mut self.buffer) {
+                Ok(recv) => recv,
+                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => return None,
+                Err(err) => {
+                    // TODO: Log errors, don't print to stderr.
+                    eprintln!("failed to receive OSC packet: {}", err);
+                    return None;
+                }
+            };
+            match Packet::decode(&self.buffer[..len]) {
+                Ok(packet) => return Some((packet, addr)),
+                Err(err) => {
+                    // TODO: Log errors, don't print to stderr.
+                    eprintln!("{} from {}", err, addr);
+                }
+            }
+        }
+    }
+
+    /// Produce an iterator yielding all pending packets without blocking.
+    ///
+    /// Bundles are yielded as-is, allowing their time tags to be inspected.
+    pub fn packets(&mut self) -> impl '_ + Iterator<Item = (Packet, SocketAddr)> {
+        std::iter::from_fn(move || self.try_recv())
+    }
+
+    /// Produce an iterator yielding all messages within all pending packets without blocking.
+    ///
+    /// The messages within bundles are yielded in order, regardless of the bundle's time tag.
+    pub fn drain(&mut self) -> impl '_ + Iterator<Item = Message> {
+        self.packets().flat_map(|(packet, _)| {
+            let mut msgs = vec![];
+            flatten(packet, &mut msgs);
+            msgs
+        })
+    }
+}
+
+impl Sender {
+    /// Encode and send the given packet, e.g. a **Message**, **Bundle** or `(addr, args)` pair.
+    pub fn send<P>(&self, packet: P) -> std::io::Result<usize>
+    where
+        P: Into<Packet>,
+    {
+        self.socket.send_to(&packet.into().encode(), self.addr)
+    }
+
+    /// Encode and send the given packet to some other address.
+    pub fn send_to<P, A>(&self, packet: P, addr: A) -> std::io::Result<usize>
+    where
+        P: Into<Packet>,
+        A: ToSocketAddrs,
+    {
+        self.socket.send_to(&packet.into().encode(), addr)
+    }
+}
+
+// Collect all messages within the packet in order.
+fn flatten(packet: Packet, msgs: &mut Vec<Message>) {
+    match packet {
+        Packet::Message(msg) => msgs.push(msg),
+        Packet::Bundle(bundle) => {
+            for packet in bundle.content {
+                flatten(packet, msgs);
+            }
+        }
+    }
+}
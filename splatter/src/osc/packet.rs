@@ -0,0 +1,162 @@
+//! OSC 1.0 packets, encoded and decoded via the `rosc` crate.
+
+use std::convert::TryFrom;
+
+pub use rosc::{OscError as DecodeError, OscTime as TimeTag, OscType};
+
+/// An OSC packet, being either a single message or a bundle of packets.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Packet {
+    Message(Message),
+    Bundle(Bundle),
+}
+
+/// An OSC message, consisting of an address pattern and a list of arguments.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    /// The address pattern, e.g. `/synth/1/freq`.
+    pub addr: String,
+    /// The arguments of the message.
+    pub args: Vec<OscType>,
+}
+
+/// A collection of packets that should be handled atomically at the time described by `timetag`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bundle {
+    pub timetag: TimeTag,
+    pub content: Vec<Packet>,
+}
+
+/// The special time tag indicating that a bundle should be handled immediately.
+pub const IMMEDIATELY: TimeTag = TimeTag {
+    seconds: 0,
+    fractional: 1,
+};
+
+/// The time tag for the current system time.
+///
+/// Returns `IMMEDIATELY` if the system time cannot be represented, i.e. if it is before the UNIX
+/// epoch.
+pub fn now() -> TimeTag {
+    TimeTag::try_from(std::time::SystemTime::now()).unwrap_or(IMMEDIATELY)
+}
+
+impl Packet {
+    /// Decode a packet from the given bytes, e.g. the contents of a UDP datagram.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (_rest, packet) = rosc::decoder::decode_udp(bytes)?;
+        Ok(packet.into())
+    }
+
+    /// Encode the packet as bytes, e.g. for sending as a UDP datagram.
+    pub fn encode(&self) -> Vec<u8> {
+        let packet = rosc::OscPacket::from(self.clone());
+        rosc::encoder::encode(&packet).expect("encoding to a `Vec` cannot fail")
+    }
+}
+
+impl From<Message> for Packet {
+    fn from(msg: Message) -> Self {
+        Packet::Message(msg)
+    }
+}
+
+impl From<Bundle> for Packet {
+    fn from(bundle: Bundle) -> Self {
+        Packet::Bundle(bundle)
+    }
+}
+
+impl<A> From<(A, Vec<OscType>)> for Message
+where
+    A: Into<String>,
+{
+    fn from((addr, args): (A, Vec<OscType>)) -> Self {
+        let addr = addr.into();
+        Message { addr, args }
+    }
+}
+
+impl<A> From<(A, Vec<OscType>)> for Packet
+where
+    A: Into<String>,
+{
+    fn from(msg: (A, Vec<OscType>)) -> Self {
+        Packet::Message(msg.into())
+    }
+}
+
+impl From<rosc::OscPacket> for Packet {
+    fn from(packet: rosc::OscPacket) -> Self {
+        match packet {
+            rosc::OscPacket::Message(msg) => Packet::Message(Message {
+                addr: msg.addr,
+                args: msg.args,
+            }),
+            rosc::OscPacket::Bundle(bundle) => Packet::Bundle(Bundle {
+                timetag: bundle.timetag,
+                content: bundle.content.into_iter().map(Packet::from).collect(),
+            }),
+        }
+    }
+}
+
+impl From<Packet> for rosc::OscPacket {
+    fn from(packet: Packet) -> Self {
+        match packet {
+            Packet::Message(msg) => rosc::OscPacket::Message(rosc::OscMessage {
+                addr: msg.addr,
+                args: msg.args,
+            }),
+            Packet::Bundle(bundle) => rosc::OscPacket::Bundle(rosc::OscBundle {
+                timetag: bundle.timetag,
+                content: bundle.content.into_iter().map(Into::into).collect(),
+            }),
+        }
+    }
+}
+
+#[test]
+fn test_packet_encode_decode() {
+    let msg = Message::from((
+        "/synth/1",
+        vec![
+            OscType::Int(-3),
+            OscType::Float(0.5),
+            OscType::String("saw".into()),
+            OscType::Blob(vec![1, 2, 3, 4, 5]),
+            OscType::Long(1 << 40),
+            OscType::Double(0.25),
+            OscType::Time(IMMEDIATELY),
+            OscType::Char('x'),
+            OscType::Bool(true),
+            OscType::Bool(false),
+            OscType::Nil,
+            OscType::Inf,
+        ],
+    ));
+    let bytes = Packet::from(msg.clone()).encode();
+    assert_eq!(bytes.len() % 4, 0);
+    assert_eq!(&bytes[..12], b"/synth/1\0\0\0\0");
+    assert_eq!(
+        Packet::decode(&bytes).unwrap(),
+        Packet::Message(msg.clone())
+    );
+
+    let bundle = Packet::Bundle(Bundle {
+        timetag: TimeTag {
+            seconds: 3_900_000_000,
+            fractional: 1 << 31,
+        },
+        content: vec![
+            msg.into(),
+            Packet::Bundle(Bundle {
+                timetag: IMMEDIATELY,
+                content: vec![("/empty", vec![]).into()],
+            }),
+        ],
+    });
+    assert_eq!(Packet::decode(&bundle.encode()).unwrap(), bundle);
+    assert!(Packet::decode(b"/bad\0\0\0\0,i\0\0").is_err());
+    assert!(Packet::decode(b"no-slash\0\0\0\0").is_err());
+}
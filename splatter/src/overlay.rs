@@ -0,0 +1,159 @@
+//! The performance overlay drawn over a window's `view` when enabled via `App::set_show_fps`.
+
+use crate::color::{rgba, WHITE};
+use crate::draw::{self, Draw};
+use crate::geom;
+use crate::wgpu;
+use instant::Instant;
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Tracks the frame times of a single window and draws them over the window's surface texture.
+#[derive(Debug, Default)]
+pub(crate) struct FpsOverlay {
+    draw: Draw,
+    renderer: Option<draw::Renderer>,
+    last_frame: Option<Instant>,
+    // The intervals between the most recent frames, newest last.
+    frame_times: VecDeque<Duration>,
+}
+
+/// Statistics from the renderer used by the window's `view`, if any.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct RenderStats {
+    pub draw_calls: usize,
+    pub primitives: usize,
+}
+
+impl FpsOverlay {
+    // The number of frames over which the average frame time is measured.
+    const AVERAGE_FRAMES: usize = 60;
+    const FONT_SIZE: u32 = 11;
+    const LINE_HEIGHT: f32 = 14.0;
+    const PADDING: f32 = 6.0;
+    const MARGIN: f32 = 8.0;
+    const WIDTH: f32 = 170.0;
+
+    /// Record that the window presented a new frame at the given moment.
+    pub fn record_frame(&mut self, now: Instant) {
+        if let Some(last) = self.last_frame.replace(now) {
+            if self.frame_times.len() == Self::AVERAGE_FRAMES {
+                self.frame_times.pop_front();
+            }
+            self.frame_times.push_back(now.duration_since(last));
+        }
+    }
+
+    /// Encode the commands that draw the overlay in the top left corner of the surface texture.
+    #[allow(clippy::too_many_arguments)]
+    pub fn encode(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        surface_texture: &wgpu::TextureViewHandle,
+        format: wgpu::TextureFormat,
+        size_pixels: [u32; 2],
+        scale_factor: f32,
+        stats: Option<RenderStats>,
+    ) {
+        let text = overlay_text(&self.frame_times, stats);
+        let line_count = text.lines().count() as f32;
+
+        // Lay out the overlay within the window's coordinates, with the origin at the centre.
+        let [w_px, h_px] = size_pixels;
+        let window_rect =
+            geom::Rect::from_w_h(w_px as f32 / scale_factor, h_px as f32 / scale_factor);
+        let h = line_count * Self::LINE_HEIGHT + Self::PADDING * 2.0;
+        let rect = geom::Rect::from_w_h(Self::WIDTH, h)
+            .top_left_of(window_rect)
+            .shift(geom::vec2(Self::MARGIN, -Self::MARGIN));
+
+        self.draw.reset();
+        self.draw
+            .rect()
+            .xy(rect.xy())
+            .wh(rect.wh())
+            .color(rgba(0.0, 0.0, 0.0, 0.6));
+        self.draw
+            .text(&text)
+            .xy(rect.xy())
+            .wh(rect.pad(Self::PADDING).wh())
+            .font_size(Self::FONT_SIZE)
+            .line_spacing(Self::LINE_HEIGHT - Self::FONT_SIZE as f32)
+            .no_line_wrap()
+            .left_justify()
+            .align_text_top()
+            .color(WHITE);
+
+        let renderer = self.renderer.get_or_insert_with(|| {
+            // The surface texture is never multisampled.
+            let sample_count = 1;
+            draw::RendererBuilder::new().build(
+                device,
+                size_pixels,
+                scale_factor,
+                sample_count,
+                format,
+            )
+        });
+        renderer
+            .encode_render_pass_to_handle(
+                device,
+                encoder,
+                &self.draw,
+                scale_factor,
+                size_pixels,
+                surface_texture,
+            )
+            .expect("the overlay draws no user shaders");
+    }
+}
+
+// The text shown by the overlay for the given frame times, newest last.
+fn overlay_text(frame_times: &VecDeque<Duration>, stats: Option<RenderStats>) -> String {
+    let mut text = String::new();
+    match frame_times.back() {
+        None => text.push_str("-- ms\n-- fps"),
+        Some(&last) => {
+            let total: Duration = frame_times.iter().sum();
+            let avg_secs = total.as_secs_f64() / frame_times.len() as f64;
+            let last_secs = last.as_secs_f64();
+            let fps = |secs: f64| if secs > 0.0 { 1.0 / secs } else { 0.0 };
+            write!(
+                text,
+                "{:.2} ms ({:.2} avg)\n{:.1} fps ({:.1} avg)",
+                last_secs * 1_000.0,
+                avg_secs * 1_000.0,
+                fps(last_secs),
+                fps(avg_secs),
+            )
+            .unwrap();
+        }
+    }
+    if let Some(stats) = stats {
+        write!(
+            text,
+            "\n{} draw calls, {} primitives",
+            stats.draw_calls, stats.primitives
+        )
+        .unwrap();
+    }
+    text
+}
+
+#[test]
+fn test_overlay_text() {
+    assert_eq!(overlay_text(&VecDeque::new(), None), "-- ms\n-- fps");
+    let frame_times: VecDeque<_> = vec![Duration::from_millis(10), Duration::from_millis(30)]
+        .into_iter()
+        .collect();
+    let stats = RenderStats {
+        draw_calls: 3,
+        primitives: 42,
+    };
+    assert_eq!(
+        overlay_text(&frame_times, Some(stats)),
+        "30.00 ms (20.00 avg)\n33.3 fps (50.0 avg)\n3 draw calls, 42 primitives"
+    );
+}
@@ -1,6 +1,10 @@
 //! A collection of commonly used items that we recommend importing for ease of use.
 
-pub use crate::app::{self, App, LoopMode};
+pub use crate::app::{self, App, LoopMode, TimeMode};
+pub use crate::camera::OrbitCamera;
+pub use crate::draw::properties::{
+    Gradient, LinearGradient, RadialGradient, StrokeAlign, StrokeDash,
+};
 pub use crate::draw::Draw;
 pub use crate::event::WindowEvent::*;
 pub use crate::event::{
@@ -10,11 +14,11 @@ pub use crate::event::{
 pub use crate::frame::{Frame, RawFrame};
 pub use crate::io::{load_from_json, load_from_toml, safe_file_save, save_to_json, save_to_toml};
 pub use crate::text::{self, text};
-pub use crate::time::DurationF64;
+pub use crate::time::{DurationF64, Timeline, Tween};
 pub use crate::wgpu;
 pub use crate::wgpu::blend::{
-    ADD as BLEND_ADD, DARKEST as BLEND_DARKEST, LIGHTEST as BLEND_LIGHTEST, NORMAL as BLEND_NORMAL,
-    REVERSE_SUBTRACT as BLEND_REVERSE_SUBTRACT, SUBTRACT as BLEND_SUBTRACT,
+    BlendMode, ADD as BLEND_ADD, DARKEST as BLEND_DARKEST, LIGHTEST as BLEND_LIGHTEST,
+    NORMAL as BLEND_NORMAL, REVERSE_SUBTRACT as BLEND_REVERSE_SUBTRACT, SUBTRACT as BLEND_SUBTRACT,
 };
 pub use crate::wgpu::util::{BufferInitDescriptor, DeviceExt};
 pub use crate::window::{self, Id as WindowId};
@@ -0,0 +1,136 @@
+//! Playing frame-by-frame animations from a texture laid out as a grid of frames.
+//!
+//! See the [**SpriteSheet**](./struct.SpriteSheet.html) type and `Draw::sprite`.
+
+use crate::geom;
+use crate::glam::Vec2;
+use crate::wgpu;
+
+/// A texture laid out as a grid of equally sized animation frames.
+///
+/// Frames are ordered from left to right, then from the top row to the bottom row. The animation
+/// plays at a fixed rate and loops once the last frame has been shown.
+#[derive(Clone, Debug)]
+pub struct SpriteSheet {
+    texture_view: wgpu::TextureView,
+    cols: u32,
+    rows: u32,
+    frame_count: u32,
+    fps: f32,
+}
+
+impl SpriteSheet {
+    /// Create a sprite sheet from a texture divided into `cols` columns and `rows` rows of frames,
+    /// played at `fps` frames per second.
+    ///
+    /// **Panics** if `cols` or `rows` is `0`.
+    pub fn from_grid(texture: &dyn wgpu::ToTextureView, cols: u32, rows: u32, fps: f32) -> Self {
+        assert!(
+            cols > 0 && rows > 0,
+            "a sprite sheet must have at least one row and column"
+        );
+        SpriteSheet {
+            texture_view: texture.to_texture_view(),
+            cols,
+            rows,
+            frame_count: cols * rows,
+            fps,
+        }
+    }
+
+    /// Limit the animation to the first `count` frames of the grid.
+    ///
+    /// This is useful for sheets whose last row is only partially filled.
+    ///
+    /// By default, every cell of the grid is a frame. The count is clamped to the number of
+    /// cells and to a minimum of `1`.
+    pub fn frame_count(mut self, count: u32) -> Self {
+        self.frame_count = count.clamp(1, self.cols * self.rows);
+        self
+    }
+
+    /// The view of the sheet's texture.
+    pub fn texture_view(&self) -> &wgpu::TextureView {
+        &self.texture_view
+    }
+
+    /// The number of frames in the animation.
+    pub fn num_frames(&self) -> u32 {
+        self.frame_count
+    }
+
+    /// The rate at which frames are played in frames per second.
+    pub fn fps(&self) -> f32 {
+        self.fps
+    }
+
+    /// The duration of a single loop of the animation in seconds.
+    pub fn duration_secs(&self) -> f32 {
+        self.frame_count as f32 / self.fps
+    }
+
+    /// The size of a single frame in pixels.
+    pub fn frame_size(&self) -> Vec2 {
+        let [w, h] = self.texture_view.size();
+        Vec2::new(w as f32 / self.cols as f32, h as f32 / self.rows as f32)
+    }
+
+    /// The index of the frame shown at the given time in seconds.
+    pub fn frame_index(&self, secs: f32) -> u32 {
+        frame_index(secs, self.fps, self.frame_count)
+    }
+
+    /// The area of the texture occupied by the frame shown at the given time in seconds.
+    ///
+    /// The area is described in texture coordinates, as expected by `Texture::area`.
+    pub fn frame_at(&self, secs: f32) -> geom::Rect {
+        self.frame_area(self.frame_index(secs))
+    }
+
+    /// The area of the texture occupied by the frame at the given index.
+    ///
+    /// The area is described in texture coordinates, as expected by `Texture::area`. Indices
+    /// beyond the last frame wrap around to the start of the animation.
+    pub fn frame_area(&self, index: u32) -> geom::Rect {
+        frame_area(index % self.frame_count, self.cols, self.rows)
+    }
+}
+
+// The index of the frame shown at the given time within a looping animation.
+fn frame_index(secs: f32, fps: f32, frame_count: u32) -> u32 {
+    let frame = (secs * fps).floor();
+    if !frame.is_finite() {
+        return 0;
+    }
+    (frame as i64).rem_euclid(frame_count as i64) as u32
+}
+
+// The area of the cell at the given index in texture coordinates, with the origin at the bottom
+// left of the texture.
+fn frame_area(index: u32, cols: u32, rows: u32) -> geom::Rect {
+    let col = (index % cols) as f32;
+    let row = (index / cols) as f32;
+    let (cols, rows) = (cols as f32, rows as f32);
+    let x = geom::Range::new(col / cols, (col + 1.0) / cols);
+    let y = geom::Range::new(1.0 - (row + 1.0) / rows, 1.0 - row / rows);
+    geom::Rect { x, y }
+}
+
+#[test]
+fn test_sprite_frames() {
+    assert_eq!(frame_index(0.0, 10.0, 4), 0);
+    assert_eq!(frame_index(0.25, 10.0, 4), 2);
+    assert_eq!(frame_index(0.45, 10.0, 4), 0);
+    assert_eq!(frame_index(-0.05, 10.0, 4), 3);
+    assert_eq!(frame_index(1.0, 0.0, 4), 0);
+    assert_eq!(frame_index(f32::INFINITY, 10.0, 4), 0);
+
+    // The second frame of a 4x2 grid is in the second column of the top row.
+    let area = frame_area(1, 4, 2);
+    assert_eq!(area.x, geom::Range::new(0.25, 0.5));
+    assert_eq!(area.y, geom::Range::new(0.5, 1.0));
+    // The fifth frame wraps to the first column of the bottom row.
+    let area = frame_area(4, 4, 2);
+    assert_eq!(area.x, geom::Range::new(0.0, 0.25));
+    assert_eq!(area.y, geom::Range::new(0.0, 0.5));
+}
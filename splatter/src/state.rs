@@ -53,6 +53,10 @@ pub mod keys {
         pub mods: ModifiersState,
         /// The state of all keys as tracked via the splatter App event handling.
         pub down: Down,
+        /// Keys that were pressed since the previous update.
+        pressed: HashSet<Key>,
+        /// Keys that were released since the previous update.
+        released: HashSet<Key>,
     }
 
     /// The set of keys that are currently pressed.
@@ -61,6 +65,45 @@ pub mod keys {
         pub(crate) keys: HashSet<Key>,
     }
 
+    impl Keys {
+        /// Whether or not the given key is currently down.
+        pub fn is_down(&self, key: &Key) -> bool {
+            self.down.contains(key)
+        }
+
+        /// Whether or not the given key was pressed since the previous update.
+        ///
+        /// Key repeats while the key is held do not count as presses.
+        pub fn pressed_this_frame(&self, key: &Key) -> bool {
+            self.pressed.contains(key)
+        }
+
+        /// Whether or not the given key was released since the previous update.
+        pub fn released_this_frame(&self, key: &Key) -> bool {
+            self.released.contains(key)
+        }
+
+        /// Mark the given key as down.
+        pub(crate) fn press(&mut self, key: Key) {
+            if self.down.keys.insert(key.clone()) {
+                self.pressed.insert(key);
+            }
+        }
+
+        /// Mark the given key as up.
+        pub(crate) fn release(&mut self, key: &Key) {
+            if self.down.keys.remove(key) {
+                self.released.insert(key.clone());
+            }
+        }
+
+        /// Forget the keys pressed and released during the last frame.
+        pub(crate) fn next_frame(&mut self) {
+            self.pressed.clear();
+            self.released.clear();
+        }
+    }
+
     impl Deref for Down {
         type Target = HashSet<Key>;
         fn deref(&self) -> &Self::Target {
@@ -71,13 +114,16 @@ pub mod keys {
 
 /// Tracked state related to the mouse.
 pub mod mouse {
-    use crate::geom::Point2;
+    use crate::geom::{Point2, Vec2};
     use crate::window;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
 
     #[doc(inline)]
     pub use crate::event::MouseButton as Button;
 
+    /// The number of points scrolled per line for devices that report scrolling in lines.
+    pub const SCROLL_LINE_HEIGHT: f32 = 20.0;
+
     /// The state of the `Mouse` at a single moment in time.
     #[derive(Clone, Debug, PartialEq)]
     pub struct Mouse {
@@ -89,6 +135,10 @@ pub mod mouse {
         pub y: f32,
         /// A map describing the state of each mouse button.
         pub buttons: ButtonMap,
+        /// The scroll accumulated since the previous update.
+        scroll: Vec2,
+        /// The position of the mouse at the previous update.
+        prev_position: Point2,
     }
 
     /// Whether the button is up or down.
@@ -106,6 +156,8 @@ pub mod mouse {
     #[derive(Clone, Debug, PartialEq)]
     pub struct ButtonMap {
         buttons: HashMap<Button, ButtonPosition>,
+        pressed: HashSet<Button>,
+        released: HashSet<Button>,
     }
 
     impl Mouse {
@@ -116,6 +168,8 @@ pub mod mouse {
                 buttons: ButtonMap::new(),
                 x: 0.0,
                 y: 0.0,
+                scroll: Vec2::ZERO,
+                prev_position: Point2::ZERO,
             }
         }
 
@@ -123,6 +177,44 @@ pub mod mouse {
         pub fn position(&self) -> Point2 {
             [self.x, self.y].into()
         }
+
+        /// The distance scrolled by the mouse wheel or touchpad since the previous update.
+        ///
+        /// Pixel deltas are given in points, while line deltas are converted to points assuming
+        /// `SCROLL_LINE_HEIGHT` points per line.
+        pub fn scroll_delta(&self) -> Vec2 {
+            self.scroll
+        }
+
+        /// The distance the mouse has been dragged with the given button held since the previous
+        /// update.
+        ///
+        /// If the button was pressed since the previous update, the distance is measured from the
+        /// position at which it was pressed. If the button is up, the result is zero.
+        pub fn drag_delta(&self, button: Button) -> Vec2 {
+            match self.buttons[button] {
+                ButtonPosition::Up => Vec2::ZERO,
+                ButtonPosition::Down(pressed_at) => {
+                    let start = match self.buttons.pressed_this_frame(button) {
+                        true => pressed_at,
+                        false => self.prev_position,
+                    };
+                    self.position() - start
+                }
+            }
+        }
+
+        /// Accumulate the given scroll delta.
+        pub(crate) fn scroll(&mut self, delta: Vec2) {
+            self.scroll += delta;
+        }
+
+        /// Reset the per-frame scroll and drag state.
+        pub(crate) fn next_frame(&mut self) {
+            self.scroll = Vec2::ZERO;
+            self.prev_position = self.position();
+            self.buttons.next_frame();
+        }
     }
 
     impl Default for Mouse {
@@ -167,6 +259,8 @@ pub mod mouse {
         pub fn new() -> Self {
             ButtonMap {
                 buttons: HashMap::with_capacity(5),
+                pressed: HashSet::new(),
+                released: HashSet::new(),
             }
         }
 
@@ -178,6 +272,8 @@ pub mod mouse {
                     .iter()
                     .map(|(&button, &point)| (button, point.relative_to(xy)))
                     .collect(),
+                pressed: self.pressed,
+                released: self.released,
             }
         }
 
@@ -196,16 +292,38 @@ pub mod mouse {
             &self[Button::Right]
         }
 
+        /// Whether or not the given button was pressed since the previous update.
+        pub fn pressed_this_frame(&self, button: Button) -> bool {
+            self.pressed.contains(&button)
+        }
+
+        /// Whether or not the given button was released since the previous update.
+        pub fn released_this_frame(&self, button: Button) -> bool {
+            self.released.contains(&button)
+        }
+
         /// Sets the `Button` in the `Down` position.
         pub fn press(&mut self, button: Button, xy: Point2) {
+            if self[button].is_up() {
+                self.pressed.insert(button);
+            }
             self[button] = ButtonPosition::Down(xy);
         }
 
         /// Set's the `Button` in the `Up` position.
         pub fn release(&mut self, button: Button) {
+            if self[button].is_down() {
+                self.released.insert(button);
+            }
             self[button] = ButtonPosition::Up;
         }
 
+        /// Forget the buttons pressed and released during the last frame.
+        pub(crate) fn next_frame(&mut self) {
+            self.pressed.clear();
+            self.released.clear();
+        }
+
         /// An iterator yielding all pressed mouse buttons along with the location at which they
         /// were originally pressed.
         pub fn pressed(&self) -> impl Iterator<Item = (Button, Point2)> + '_ {
@@ -263,3 +381,47 @@ pub mod time {
         }
     }
 }
+
+#[test]
+fn test_key_and_button_presses_last_one_frame() {
+    use crate::event::{Key, MouseButton};
+    use crate::winit::keyboard::NamedKey;
+    let space = Key::Named(NamedKey::Space);
+    let mut keys = Keys::default();
+    keys.press(space.clone());
+    keys.press(space.clone());
+    assert!(keys.is_down(&space));
+    assert!(keys.pressed_this_frame(&space));
+    keys.next_frame();
+    assert!(keys.is_down(&space));
+    assert!(!keys.pressed_this_frame(&space));
+    keys.release(&space);
+    assert!(!keys.is_down(&space));
+    assert!(keys.released_this_frame(&space));
+
+    let mut mouse = Mouse::new();
+    mouse.buttons.press(MouseButton::Left, mouse.position());
+    assert!(mouse.buttons.left().is_down());
+    assert!(mouse.buttons.pressed_this_frame(MouseButton::Left));
+    mouse.buttons.next_frame();
+    assert!(!mouse.buttons.pressed_this_frame(MouseButton::Left));
+}
+
+#[test]
+fn test_mouse_scroll_and_drag_deltas() {
+    use crate::event::MouseButton;
+    use crate::geom::vec2;
+    let mut mouse = Mouse::new();
+    mouse.x = 10.0;
+    mouse.buttons.press(MouseButton::Left, mouse.position());
+    mouse.x = 15.0;
+    mouse.scroll(vec2(0.0, 1.0));
+    mouse.scroll(vec2(0.0, 2.0));
+    assert_eq!(mouse.drag_delta(MouseButton::Left), vec2(5.0, 0.0));
+    assert_eq!(mouse.drag_delta(MouseButton::Right), vec2(0.0, 0.0));
+    assert_eq!(mouse.scroll_delta(), vec2(0.0, 3.0));
+    mouse.next_frame();
+    mouse.y = -4.0;
+    assert_eq!(mouse.drag_delta(MouseButton::Left), vec2(0.0, -4.0));
+    assert_eq!(mouse.scroll_delta(), vec2(0.0, 0.0));
+}
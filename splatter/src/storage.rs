@@ -0,0 +1,147 @@
+//! A small keyed store for persisting settings between runs of a sketch.
+//!
+//! Each value is serialized as JSON to its own file within the platform's configuration directory
+//! for the app, e.g. `~/.config/<app>` on Linux, `~/Library/Application Support/<app>` on macOS
+//! and `%APPDATA%\<app>\config` on Windows. This allows sketches to remember parameters such as
+//! slider values or window sizes across restarts.
+//!
+//! ```no_run
+//! use serde_derive::{Deserialize, Serialize};
+//! use splatter::prelude::*;
+//!
+//! #[derive(Default, Deserialize, Serialize)]
+//! struct Settings {
+//!     radius: f32,
+//! }
+//!
+//! fn model(app: &App) -> Settings {
+//!     app.storage().load("settings").unwrap_or_default()
+//! }
+//!
+//! fn exit(app: &App, settings: Settings) {
+//!     app.storage().store("settings", &settings).unwrap();
+//! }
+//! # fn main() {}
+//! ```
+
+use crate::io::{self, JsonFileError};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// A keyed store of serializable values within a directory.
+///
+/// See the module documentation for details.
+#[derive(Clone, Debug)]
+pub struct Storage {
+    // `None` if the platform provides no configuration directory.
+    dir: Option<PathBuf>,
+}
+
+impl Storage {
+    /// A store within the platform's configuration directory for the app with the given name.
+    ///
+    /// The store has no directory if the platform provides no configuration directory, e.g. on
+    /// the web. In this case, storing values fails and loading values returns `None`.
+    pub fn new(app_name: &str) -> Self {
+        Storage {
+            dir: config_dir(app_name),
+        }
+    }
+
+    /// A store within the given directory.
+    pub fn at<P>(dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Storage {
+            dir: Some(dir.into()),
+        }
+    }
+
+    /// The directory in which values are stored, if any.
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// The path of the file in which the value for the given key is stored, if any.
+    ///
+    /// Returns `None` if the store has no directory or if the key is invalid. Keys must be
+    /// non-empty and may not contain path separators or begin with a `.`.
+    pub fn path(&self, key: &str) -> Option<PathBuf> {
+        let is_valid = !key.is_empty() && !key.starts_with('.') && !key.contains(['/', '\\']);
+        let dir = self.dir.as_ref().filter(|_| is_valid)?;
+        Some(dir.join(format!("{}.json", key)))
+    }
+
+    /// Serialize the given value and store it under the given key, replacing any existing value.
+    ///
+    /// The file is written safely via `io::safe_file_save`, so an existing value is never left
+    /// partially written.
+    pub fn store<T>(&self, key: &str, value: &T) -> Result<(), JsonFileError>
+    where
+        T: Serialize,
+    {
+        let path = self.path(key).ok_or_else(|| {
+            let msg = format!("no storage path for the key {:?}", key);
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, msg)
+        })?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        io::save_to_json(path, value)
+    }
+
+    /// Load the value stored under the given key.
+    ///
+    /// Returns `None` if there is no value for the key or if it can not be deserialized as `T`,
+    /// e.g. because the type has changed since the value was stored.
+    pub fn load<T>(&self, key: &str) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        let path = self.path(key)?;
+        io::load_from_json(path).ok()
+    }
+
+    /// Remove the value stored under the given key, if any.
+    pub fn remove(&self, key: &str) -> std::io::Result<()> {
+        match self.path(key) {
+            Some(path) if path.exists() => std::fs::remove_file(path),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn config_dir(app_name: &str) -> Option<PathBuf> {
+    directories::ProjectDirs::from("", "", app_name).map(|dirs| dirs.config_dir().to_path_buf())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn config_dir(_app_name: &str) -> Option<PathBuf> {
+    None
+}
+
+#[test]
+fn test_storage() {
+    let dir = std::env::temp_dir().join(format!("splatter-storage-{}", std::process::id()));
+    let storage = Storage::at(&dir);
+    assert_eq!(storage.load::<Vec<f32>>("sliders"), None);
+    storage.store("sliders", &vec![0.5f32, 1.0]).unwrap();
+    assert_eq!(storage.load::<Vec<f32>>("sliders"), Some(vec![0.5, 1.0]));
+    // A value of a different type is not loaded.
+    assert_eq!(storage.load::<String>("sliders"), None);
+    // Keys containing dots are stored separately from one another.
+    storage.store("window.size", &[640u32, 480]).unwrap();
+    storage.store("window.pos", &[10u32, 20]).unwrap();
+    assert_eq!(storage.load::<[u32; 2]>("window.size"), Some([640, 480]));
+    assert_eq!(storage.load::<[u32; 2]>("window.pos"), Some([10, 20]));
+    assert_eq!(storage.load::<[u32; 2]>("window"), None);
+    // Keys may not escape the directory.
+    assert!(storage.store("../sliders", &1).is_err());
+    assert_eq!(storage.path(""), None);
+    storage.remove("sliders").unwrap();
+    assert_eq!(storage.load::<Vec<f32>>("sliders"), None);
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,173 @@
+//! Utilities for regression testing sketches against previously rendered "golden" images.
+//!
+//! Frames rendered via a **Headless** target may be compared against a PNG stored alongside the
+//! tests. If the golden image does not yet exist, it is written from the rendered frame so that
+//! the first run of a new test records the expected result. Set the `SPLATTER_UPDATE_GOLDEN`
+//! environment variable to overwrite existing golden images after an intentional change.
+//!
+//! ```no_run
+//! use splatter::prelude::*;
+//!
+//! let headless = splatter::headless(64, 64).build().unwrap();
+//! let draw = Draw::new();
+//! draw.background().color(BLACK);
+//! draw.ellipse().radius(20.0).color(PLUM);
+//! let image = headless.render(&draw).unwrap();
+//! splatter::test::assert_frame_matches(&image, "tests/golden/ellipse.png", 2);
+//! ```
+
+use crate::image::{self, Rgba, RgbaImage};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that, when set, causes golden images to be overwritten.
+pub const UPDATE_GOLDEN_ENV_VAR: &str = "SPLATTER_UPDATE_GOLDEN";
+
+/// The result of comparing two images via `compare_images`.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    /// The number of pixels whose difference exceeds the tolerance.
+    pub mismatched_pixels: usize,
+    /// The largest difference between any channel of two corresponding pixels.
+    pub max_difference: u8,
+    /// An image highlighting mismatched pixels in red over a faded copy of the expected image.
+    pub diff: RgbaImage,
+}
+
+impl Comparison {
+    /// Whether or not every pixel was within the tolerance.
+    pub fn is_match(&self) -> bool {
+        self.mismatched_pixels == 0
+    }
+}
+
+/// Compare the `actual` image against the `expected` image.
+///
+/// A pixel mismatches if any of its channels differs from the corresponding channel of the
+/// expected pixel by more than `tolerance`.
+///
+/// **Panics** if the images differ in size.
+pub fn compare_images(actual: &RgbaImage, expected: &RgbaImage, tolerance: u8) -> Comparison {
+    assert_eq!(
+        actual.dimensions(),
+        expected.dimensions(),
+        "cannot compare images of different sizes"
+    );
+    let mut mismatched_pixels = 0;
+    let mut max_difference = 0;
+    let (w, h) = expected.dimensions();
+    let diff = RgbaImage::from_fn(w, h, |x, y| {
+        let a = actual.get_pixel(x, y);
+        let e = expected.get_pixel(x, y);
+        let difference = pixel_difference(a, e);
+        max_difference = max_difference.max(difference);
+        if difference > tolerance {
+            mismatched_pixels += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let [r, g, b, _] = e.0;
+            let luma = (r as u32 + g as u32 + b as u32) / 3;
+            let faded = (luma / 4) as u8;
+            Rgba([faded, faded, faded, 255])
+        }
+    });
+    Comparison {
+        mismatched_pixels,
+        max_difference,
+        diff,
+    }
+}
+
+/// Assert that the given rendered frame matches the golden image at the given path, within the
+/// given per-channel `tolerance`.
+///
+/// If the golden image does not exist or the `SPLATTER_UPDATE_GOLDEN` environment variable is
+/// set, the frame is written to the path instead and the assertion passes.
+///
+/// On mismatch, the frame and a diff image are written next to the golden image with the
+/// `.actual.png` and `.diff.png` extensions before panicking.
+pub fn assert_frame_matches<P>(frame_pixels: &RgbaImage, golden_path: P, tolerance: u8)
+where
+    P: AsRef<Path>,
+{
+    let golden_path = golden_path.as_ref();
+    if env::var_os(UPDATE_GOLDEN_ENV_VAR).is_some() || !golden_path.exists() {
+        if let Some(dir) = golden_path.parent() {
+            std::fs::create_dir_all(dir).expect("failed to create golden image directory");
+        }
+        frame_pixels
+            .save(golden_path)
+            .expect("failed to write golden image");
+        return;
+    }
+
+    let expected = image::open(golden_path)
+        .unwrap_or_else(|err| panic!("failed to open {}: {}", golden_path.display(), err))
+        .into_rgba8();
+    assert_eq!(
+        frame_pixels.dimensions(),
+        expected.dimensions(),
+        "frame size differs from golden image {}",
+        golden_path.display(),
+    );
+    let comparison = compare_images(frame_pixels, &expected, tolerance);
+    if comparison.is_match() {
+        return;
+    }
+
+    let actual_path = sibling_path(golden_path, "actual");
+    let diff_path = sibling_path(golden_path, "diff");
+    frame_pixels
+        .save(&actual_path)
+        .expect("failed to write actual image");
+    comparison
+        .diff
+        .save(&diff_path)
+        .expect("failed to write diff image");
+    panic!(
+        "frame does not match golden image {}: {} pixels differ by more than {} (max {}). \
+         See {} and {}",
+        golden_path.display(),
+        comparison.mismatched_pixels,
+        tolerance,
+        comparison.max_difference,
+        actual_path.display(),
+        diff_path.display(),
+    );
+}
+
+// The largest difference between any channel of the two pixels.
+fn pixel_difference(a: &Rgba<u8>, b: &Rgba<u8>) -> u8 {
+    a.0.iter()
+        .zip(b.0.iter())
+        .map(|(&a, &b)| (a as i16 - b as i16).unsigned_abs() as u8)
+        .max()
+        .unwrap_or(0)
+}
+
+// E.g. `golden/ellipse.png` becomes `golden/ellipse.diff.png`.
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}.{}.png", stem, suffix))
+}
+
+#[test]
+fn test_compare_images() {
+    let expected = RgbaImage::from_pixel(4, 4, Rgba([100, 100, 100, 255]));
+    let mut actual = expected.clone();
+    actual.put_pixel(1, 2, Rgba([103, 100, 100, 255]));
+    actual.put_pixel(3, 0, Rgba([100, 90, 100, 255]));
+
+    let comparison = compare_images(&actual, &expected, 3);
+    assert_eq!(comparison.mismatched_pixels, 1);
+    assert_eq!(comparison.max_difference, 10);
+    assert_eq!(*comparison.diff.get_pixel(3, 0), Rgba([255, 0, 0, 255]));
+    assert_eq!(*comparison.diff.get_pixel(1, 2), Rgba([25, 25, 25, 255]));
+    assert!(compare_images(&actual, &expected, 10).is_match());
+
+    let path = Path::new("golden/ellipse.png");
+    assert_eq!(
+        sibling_path(path, "diff"),
+        Path::new("golden/ellipse.diff.png")
+    );
+}
@@ -4,6 +4,7 @@ use crate::text::{Font, FontCollection};
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 
 /// A type-safe wrapper around the `FontId`.
 ///
@@ -42,6 +43,12 @@ pub enum Error {
     NoFont,
 }
 
+/// Types that may be used to select the `Font` with which text is laid out and drawn.
+pub trait IntoFont {
+    /// Produce the selected font, or `None` if the default font should be used.
+    fn into_font(self) -> Option<Font>;
+}
+
 /// The name of the default directory that is searched for fonts.
 pub const DEFAULT_DIRECTORY_NAME: &str = "fonts";
 
@@ -118,6 +125,35 @@ pub fn id(font: &Font) -> Id {
     Id((hasher.finish() % std::usize::MAX as u64) as usize)
 }
 
+/// Register the given font so that it may later be selected by its `Id`.
+///
+/// The returned `Id` is the same `Id` produced by the `id` function, which is also the key used
+/// for the font's glyphs within the draw renderer's glyph cache. Registering the same font more
+/// than once produces the same `Id`.
+pub fn register(font: Font) -> Id {
+    let id = id(&font);
+    registry()
+        .lock()
+        .expect("failed to acquire the font registry lock")
+        .insert(id, font);
+    id
+}
+
+/// The font previously registered with the given `Id`, if there is one.
+pub fn registered(id: Id) -> Option<Font> {
+    registry()
+        .lock()
+        .expect("failed to acquire the font registry lock")
+        .get(&id)
+        .cloned()
+}
+
+// The process-wide map of fonts registered via `register`.
+fn registry() -> &'static Mutex<HashMap<Id, Font>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<Id, Font>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
 /// Load a `FontCollection` from a file at a given path.
 pub fn collection_from_file<P>(path: P) -> Result<FontCollection, std::io::Error>
 where
@@ -140,6 +176,12 @@ where
     collection.into_font().or(Err(Error::NoFont))
 }
 
+/// Load a single `Font` from the given bytes of a `.ttf` or `.otf` file.
+pub fn from_bytes(bytes: Vec<u8>) -> Result<Font, Error> {
+    let collection = FontCollection::from_bytes(bytes).map_err(|e| Error::Io(e.into()))?;
+    collection.into_font().or(Err(Error::NoFont))
+}
+
 /// Load the default notosans font.
 ///
 /// This function is only available if the `notosans` feature is enabled, which it is by default.
@@ -189,6 +231,36 @@ pub fn default(assets: &Path) -> Result<Font, Error> {
     Err(Error::NoFont)
 }
 
+/// Load the font used for text that does not select a font, if one can be found.
+///
+/// This is also used to draw glyphs that are missing from a selected font.
+#[allow(unreachable_code)]
+pub fn fallback() -> Option<Font> {
+    #[cfg(feature = "notosans")]
+    {
+        return Some(default_notosans());
+    }
+    let assets = crate::app::find_assets_path().ok()?;
+    default(&assets).ok()
+}
+
+impl IntoFont for Font {
+    fn into_font(self) -> Option<Font> {
+        Some(self)
+    }
+}
+
+impl IntoFont for Id {
+    fn into_font(self) -> Option<Font> {
+        let font = registered(self);
+        if font.is_none() {
+            // TODO: Log errors, don't print to stderr.
+            eprintln!("no font registered with {:?}, using the default font", self);
+        }
+        font
+    }
+}
+
 impl Iterator for NewIds {
     type Item = Id;
     fn next(&mut self) -> Option<Self::Item> {
@@ -226,3 +298,14 @@ impl std::fmt::Display for Error {
         }
     }
 }
+
+#[cfg(feature = "notosans")]
+#[test]
+fn test_register_font() {
+    let font = default_notosans();
+    let font_id = register(font.clone());
+    assert_eq!(font_id, id(&font));
+    assert_eq!(register(font), font_id);
+    assert!(registered(font_id).is_some());
+    assert!(Id(font_id.index().wrapping_add(1)).into_font().is_none());
+}
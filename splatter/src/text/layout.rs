@@ -1,5 +1,6 @@
 //! Items related to the styling of text.
 
+use crate::text::font::IntoFont;
 use crate::text::{Align, Font, FontSize, Justify, Scalar, Wrap};
 
 /// A context for building a text layout.
@@ -67,8 +68,14 @@ impl Builder {
     }
 
     /// A method for specifying the `Font` used for displaying the `Text`.
-    pub fn font(mut self, font: Font) -> Self {
-        self.font = Some(Some(font));
+    ///
+    /// The font may be given directly or via the `font::Id` of a registered font. If no font is
+    /// registered with the given `Id`, the default font is used.
+    pub fn font<F>(mut self, font: F) -> Self
+    where
+        F: IntoFont,
+    {
+        self.font = Some(font.into_font());
         self
     }
 
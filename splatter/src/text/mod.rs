@@ -194,7 +194,12 @@ impl<'a> Builder<'a> {
     }
 
     /// A method for specifying the `Font` used for displaying the `Text`.
-    pub fn font(self, font: Font) -> Self {
+    ///
+    /// The font may be given directly or via the `font::Id` of a registered font.
+    pub fn font<F>(self, font: F) -> Self
+    where
+        F: font::IntoFont,
+    {
         self.map_layout(|l| l.font(font))
     }
 
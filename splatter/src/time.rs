@@ -1,4 +1,7 @@
-//! Extensions and utilities for working with time.
+//! Extensions and utilities for working with time, including **Tween**s and **Timeline**s for
+//! time-based animation.
+
+use crate::math::ease::Ease;
 
 /// An extension for the `std::time::Duration` type providing some simple methods for easy access
 /// to an `f64` representation of seconds, ms, mins, hrs, and other units of time.
@@ -42,3 +45,224 @@ impl DurationF64 for std::time::Duration {
         self.as_secs() as f64 + self.subsec_nanos() as f64 * 1e-9
     }
 }
+
+/// Animates a value from `from` to `to` over `duration` seconds, shaped by an easing function.
+///
+/// Times are given in seconds, e.g. as tracked by `app.time`.
+///
+/// ```
+/// # use splatter::prelude::*;
+/// let tween = Tween::new(0.0, 100.0, 2.0, ease::quad_in).start(1.0);
+/// assert_eq!(tween.value_at(0.0), 0.0);
+/// assert_eq!(tween.value_at(2.0), 25.0);
+/// assert_eq!(tween.value_at(5.0), 100.0);
+/// assert!(tween.is_finished(3.0));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Tween {
+    /// The value at the start of the tween.
+    pub from: f32,
+    /// The value at the end of the tween.
+    pub to: f32,
+    /// The duration of the tween in seconds.
+    pub duration: f32,
+    /// The easing function applied to the progress of the tween.
+    pub ease: Ease,
+    /// The time in seconds at which the tween starts.
+    pub start: f32,
+}
+
+/// A sequence of possibly overlapping **Tween**s animating a single value.
+///
+/// At any moment, the value is that of the latest tween to have started, or the initial value of
+/// the first tween if none have started yet.
+///
+/// ```
+/// # use splatter::prelude::*;
+/// let timeline = Timeline::new()
+///     .then(Tween::new(0.0, 1.0, 1.0, ease::linear))
+///     .then(Tween::new(1.0, 0.0, 2.0, ease::linear));
+/// assert_eq!(timeline.value_at(0.5), 0.5);
+/// assert_eq!(timeline.value_at(2.0), 0.5);
+/// assert_eq!(timeline.duration(), 3.0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Timeline {
+    tweens: Vec<Tween>,
+}
+
+impl Tween {
+    /// A tween from `from` to `to` over `duration` seconds, starting at time `0.0`.
+    pub fn new(from: f32, to: f32, duration: f32, ease: Ease) -> Self {
+        Tween {
+            from,
+            to,
+            duration,
+            ease,
+            start: 0.0,
+        }
+    }
+
+    /// Start the tween at the given time in seconds.
+    pub fn start(mut self, at: f32) -> Self {
+        self.start = at;
+        self
+    }
+
+    /// The time in seconds at which the tween finishes.
+    pub fn end(&self) -> f32 {
+        self.start + self.duration
+    }
+
+    /// The linear progress of the tween at the given time, clamped to `0.0..=1.0`.
+    pub fn progress(&self, now: f32) -> f32 {
+        if self.duration <= 0.0 {
+            return if now < self.start { 0.0 } else { 1.0 };
+        }
+        ((now - self.start) / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// The value of the tween at the given time.
+    pub fn value_at(&self, now: f32) -> f32 {
+        let t = (self.ease)(self.progress(now));
+        self.from + (self.to - self.from) * t
+    }
+
+    /// Whether or not the tween has finished by the given time.
+    pub fn is_finished(&self, now: f32) -> bool {
+        now >= self.end()
+    }
+}
+
+impl Timeline {
+    /// An empty timeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the tween to start when all previously added tweens have finished.
+    ///
+    /// The tween's own start time is treated as a delay relative to this point.
+    pub fn then(self, tween: Tween) -> Self {
+        let at = self.end_time();
+        self.at(at, tween)
+    }
+
+    /// Add the tween to start at the same time as the previously added tween.
+    ///
+    /// The tween's own start time is treated as a delay relative to this point.
+    pub fn with(self, tween: Tween) -> Self {
+        let at = self.tweens.last().map(|t| t.start).unwrap_or(0.0);
+        self.at(at, tween)
+    }
+
+    /// Add the tween to start at the given time within the timeline.
+    ///
+    /// The tween's own start time is treated as a delay relative to this point.
+    pub fn at(mut self, time: f32, tween: Tween) -> Self {
+        let start = time + tween.start;
+        self.tweens.push(tween.start(start));
+        self
+    }
+
+    /// Offset all tweens so that the timeline starts at the given time in seconds.
+    pub fn start(mut self, at: f32) -> Self {
+        let offset = at - self.start_time();
+        for tween in &mut self.tweens {
+            tween.start += offset;
+        }
+        self
+    }
+
+    /// The tweens within the timeline in the order in which they were added.
+    pub fn tweens(&self) -> &[Tween] {
+        &self.tweens
+    }
+
+    /// The time in seconds at which the first tween starts.
+    pub fn start_time(&self) -> f32 {
+        self.tweens
+            .iter()
+            .map(|t| t.start)
+            .fold(None, |min: Option<f32>, s| {
+                Some(min.map_or(s, |m| m.min(s)))
+            })
+            .unwrap_or(0.0)
+    }
+
+    /// The time in seconds at which the last tween finishes.
+    pub fn end_time(&self) -> f32 {
+        self.tweens
+            .iter()
+            .map(|t| t.end())
+            .fold(None, |max: Option<f32>, e| {
+                Some(max.map_or(e, |m| m.max(e)))
+            })
+            .unwrap_or(0.0)
+    }
+
+    /// The time in seconds from the start of the first tween to the end of the last.
+    pub fn duration(&self) -> f32 {
+        self.end_time() - self.start_time()
+    }
+
+    /// The value of the timeline at the given time.
+    ///
+    /// Returns `0.0` if the timeline is empty.
+    pub fn value_at(&self, now: f32) -> f32 {
+        let active = self.tweens.iter().filter(|t| t.start <= now).fold(
+            None,
+            |latest: Option<&Tween>, t| match latest {
+                Some(l) if l.start > t.start => Some(l),
+                _ => Some(t),
+            },
+        );
+        match active.or_else(|| self.first()) {
+            Some(tween) => tween.value_at(now),
+            None => 0.0,
+        }
+    }
+
+    /// The value of each tween at the given time in the order in which they were added.
+    ///
+    /// Useful for driving several properties from a single timeline.
+    pub fn values_at(&self, now: f32) -> impl Iterator<Item = f32> + '_ {
+        self.tweens.iter().map(move |t| t.value_at(now))
+    }
+
+    /// Whether or not all tweens have finished by the given time.
+    pub fn is_finished(&self, now: f32) -> bool {
+        self.tweens.iter().all(|t| t.is_finished(now))
+    }
+
+    // The earliest starting tween.
+    fn first(&self) -> Option<&Tween> {
+        self.tweens
+            .iter()
+            .fold(None, |first: Option<&Tween>, t| match first {
+                Some(f) if f.start <= t.start => Some(f),
+                _ => Some(t),
+            })
+    }
+}
+
+#[test]
+fn test_timeline_sequence_and_overlap() {
+    use crate::math::ease;
+    let timeline = Timeline::new()
+        .then(Tween::new(0.0, 10.0, 1.0, ease::linear))
+        .then(Tween::new(10.0, 20.0, 1.0, ease::linear).start(0.5))
+        .with(Tween::new(-1.0, 1.0, 4.0, ease::linear))
+        .start(10.0);
+    assert_eq!(timeline.start_time(), 10.0);
+    assert_eq!(timeline.end_time(), 15.5);
+    assert_eq!(timeline.value_at(9.0), 0.0);
+    assert_eq!(timeline.value_at(10.5), 5.0);
+    assert_eq!(timeline.value_at(11.25), 10.0);
+    // The last two tweens start together, so the last added takes precedence.
+    assert_eq!(timeline.value_at(13.5), 0.0);
+    let values: Vec<f32> = timeline.values_at(12.0).collect();
+    assert_eq!(values, vec![10.0, 15.0, -0.75]);
+    assert!(!timeline.is_finished(15.0));
+    assert!(timeline.is_finished(15.5));
+}
@@ -0,0 +1,390 @@
+//! Items for sketches driven by the frames of a video file or a webcam.
+//!
+//! Frames are decoded by an `ffmpeg` process that must be installed and available on the `PATH`.
+//! A background thread reads each decoded frame from the process and keeps only the most recent,
+//! so a slow render loop drops frames rather than falling behind or blocking. Calling `update`
+//! once per frame uploads the latest frame (if any arrived since the last call) to the texture.
+//!
+//! ```no_run
+//! use splatter::prelude::*;
+//! use splatter::video::VideoTexture;
+//!
+//! fn update(app: &App, video: &mut VideoTexture, _update: Update) {
+//!     video.update(app);
+//! }
+//!
+//! fn view(app: &App, video: &VideoTexture, frame: Frame) {
+//!     let draw = app.draw();
+//!     draw.texture(video);
+//!     draw.to_frame(app, &frame).unwrap();
+//! }
+//! # fn main() {}
+//! ```
+
+use crate::wgpu;
+use crate::App;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::{error, fmt, ops, thread};
+
+/// A texture showing the most recent frame decoded from a video file.
+///
+/// The video plays in real time from the moment it is opened and stops at its last frame.
+pub struct VideoTexture {
+    child: Child,
+    shared: Arc<Shared>,
+    thread: Option<thread::JoinHandle<()>>,
+    frame_size: Option<[u32; 2]>,
+    texture: Option<(wgpu::Texture, wgpu::TextureView)>,
+}
+
+/// A texture showing the most recent frame captured by a webcam.
+///
+/// Dereferences to a **VideoTexture**, where `update` and friends are documented.
+#[derive(Debug)]
+pub struct WebcamTexture {
+    video: VideoTexture,
+}
+
+/// Errors that might occur when opening a video or webcam.
+#[derive(Debug)]
+pub enum Error {
+    /// The `ffmpeg` process could not be started, e.g. because it is not installed.
+    Io(io::Error),
+    /// There is no default webcam device on the current platform.
+    NoDefaultDevice,
+}
+
+// State shared between the texture and its decoding thread.
+#[derive(Default)]
+struct Shared {
+    // The most recent frame that has not yet been uploaded.
+    latest: Mutex<Option<Frame>>,
+    finished: AtomicBool,
+}
+
+// A single decoded RGBA frame.
+struct Frame {
+    size: [u32; 2],
+    data: Vec<u8>,
+}
+
+impl VideoTexture {
+    /// The format of the texture to which frames are uploaded.
+    pub const TEXTURE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+    /// Begin decoding the video file at the given path.
+    pub fn open<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        // Read the input at its native frame rate, rather than as fast as possible.
+        let mut command = ffmpeg();
+        command.arg("-re").arg("-i").arg(path.as_ref());
+        Self::spawn(command)
+    }
+
+    fn spawn(mut command: Command) -> Result<Self, Error> {
+        // Output each frame as a PAM image, whose header describes the frame's size.
+        let mut child = command
+            .args(["-f", "image2pipe", "-c:v", "pam", "-pix_fmt", "rgba", "-"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdout = child.stdout.take().expect("failed to take ffmpeg stdout");
+        let shared = Arc::new(Shared::default());
+        let thread_shared = shared.clone();
+        let thread = thread::Builder::new()
+            .name("splatter_video".into())
+            .spawn(move || decode(stdout, &thread_shared))?;
+        Ok(VideoTexture {
+            child,
+            shared,
+            thread: Some(thread),
+            frame_size: None,
+            texture: None,
+        })
+    }
+
+    /// Upload the most recent frame to the texture, if a new frame has arrived since the last
+    /// call.
+    ///
+    /// This should be called once per update. Returns whether or not a new frame was uploaded.
+    pub fn update(&mut self, app: &App) -> bool {
+        let frame = self.shared.latest.lock().unwrap().take();
+        let device_queue_pair = app.main_device_queue_pair();
+        let (device, queue) = (device_queue_pair.device(), device_queue_pair.queue());
+
+        // Until the first frame arrives the texture is a single transparent texel.
+        let size = frame.as_ref().map(|f| f.size).unwrap_or([1, 1]);
+        let is_stale = match self.texture {
+            None => true,
+            Some((ref texture, _)) => frame.is_some() && texture.size() != size,
+        };
+        if is_stale {
+            let texture = wgpu::TextureBuilder::new()
+                .size(size)
+                .format(Self::TEXTURE_FORMAT)
+                .usage(wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST)
+                .build(device);
+            let texture_view = texture.view().build();
+            self.texture = Some((texture, texture_view));
+        }
+
+        let frame = match frame {
+            None => return false,
+            Some(frame) => frame,
+        };
+        let (texture, _) = self.texture.as_ref().expect("no video texture");
+        let [w, h] = frame.size;
+        queue.write_texture(
+            texture.as_image_copy(),
+            &frame.data,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(w * 4),
+                rows_per_image: Some(h),
+            },
+            texture.extent(),
+        );
+        self.frame_size = Some(frame.size);
+        true
+    }
+
+    /// The size of the most recently uploaded frame in pixels.
+    ///
+    /// Returns `None` if no frame has been uploaded yet.
+    pub fn size(&self) -> Option<[u32; 2]> {
+        self.frame_size
+    }
+
+    /// Whether or not decoding has stopped, either because the end of the video was reached or
+    /// because the `ffmpeg` process failed.
+    ///
+    /// The last decoded frame remains available.
+    pub fn is_finished(&self) -> bool {
+        self.shared.finished.load(Ordering::Relaxed)
+    }
+
+    /// The texture to which frames are uploaded.
+    ///
+    /// Returns `None` if `update` has not yet been called. The texture is replaced whenever the
+    /// size of the frames changes.
+    pub fn texture(&self) -> Option<&wgpu::Texture> {
+        self.texture.as_ref().map(|(texture, _)| texture)
+    }
+}
+
+impl WebcamTexture {
+    /// The device opened by `WebcamTexture::default`, if the platform has one.
+    pub const DEFAULT_DEVICE: Option<&'static str> = default_device();
+
+    /// Begin capturing from the webcam with the given platform-specific device name.
+    ///
+    /// On Linux this is a video4linux device path, e.g. `/dev/video0`. On macOS this is an
+    /// AVFoundation device index or name, e.g. `0`. On Windows this is a DirectShow device,
+    /// e.g. `video=Integrated Camera`.
+    pub fn open(device: &str) -> Result<Self, Error> {
+        let mut command = ffmpeg();
+        if let Some(format) = input_format() {
+            command.args(["-f", format]);
+        }
+        command.args(["-i", device]);
+        let video = VideoTexture::spawn(command)?;
+        Ok(WebcamTexture { video })
+    }
+
+    /// Begin capturing from the platform's default webcam device.
+    pub fn open_default() -> Result<Self, Error> {
+        let device = Self::DEFAULT_DEVICE.ok_or(Error::NoDefaultDevice)?;
+        Self::open(device)
+    }
+}
+
+impl Default for WebcamTexture {
+    /// Begin capturing from the platform's default webcam device.
+    ///
+    /// **Panics** if the webcam could not be opened. Use `WebcamTexture::open_default` to
+    /// handle the error instead.
+    fn default() -> Self {
+        Self::open_default().expect("failed to open the default webcam")
+    }
+}
+
+impl ops::Deref for WebcamTexture {
+    type Target = VideoTexture;
+    fn deref(&self) -> &Self::Target {
+        &self.video
+    }
+}
+
+impl ops::DerefMut for WebcamTexture {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.video
+    }
+}
+
+impl wgpu::ToTextureView for VideoTexture {
+    /// **Panics** if `update` has not yet been called.
+    fn to_texture_view(&self) -> wgpu::TextureView {
+        let (_, texture_view) = self
+            .texture
+            .as_ref()
+            .expect("`VideoTexture::update` must be called before the texture is used");
+        texture_view.clone()
+    }
+}
+
+impl wgpu::ToTextureView for WebcamTexture {
+    fn to_texture_view(&self) -> wgpu::TextureView {
+        self.video.to_texture_view()
+    }
+}
+
+impl Drop for VideoTexture {
+    fn drop(&mut self) {
+        // Killing the process closes its stdout, ending the decoding thread.
+        self.child.kill().ok();
+        self.child.wait().ok();
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
+
+impl fmt::Debug for VideoTexture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("VideoTexture")
+            .field("frame_size", &self.frame_size)
+            .field("is_finished", &self.is_finished())
+            .finish()
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Io(ref err) => Some(err),
+            Error::NoDefaultDevice => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref err) => write!(f, "failed to start ffmpeg: {}", err),
+            Error::NoDefaultDevice => write!(f, "no default webcam device on this platform"),
+        }
+    }
+}
+
+// An ffmpeg command that only reports errors, to which input and then output options are added.
+fn ffmpeg() -> Command {
+    let mut command = Command::new("ffmpeg");
+    command.args(["-loglevel", "error", "-nostdin"]);
+    command
+}
+
+// The ffmpeg input format used for webcams on the current platform.
+fn input_format() -> Option<&'static str> {
+    if cfg!(target_os = "linux") {
+        Some("v4l2")
+    } else if cfg!(target_os = "macos") {
+        Some("avfoundation")
+    } else if cfg!(target_os = "windows") {
+        Some("dshow")
+    } else {
+        None
+    }
+}
+
+// DirectShow devices are only addressable by name, so Windows has no default.
+const fn default_device() -> Option<&'static str> {
+    if cfg!(target_os = "linux") {
+        Some("/dev/video0")
+    } else if cfg!(target_os = "macos") {
+        Some("0")
+    } else {
+        None
+    }
+}
+
+// Read frames until the process exits, replacing any frame that has not yet been uploaded.
+fn decode(stdout: ChildStdout, shared: &Shared) {
+    let mut reader = BufReader::new(stdout);
+    let mut spare = vec![];
+    while let Ok(Some(size)) = read_pam_header(&mut reader) {
+        let [w, h] = size;
+        let mut data = std::mem::take(&mut spare);
+        data.resize(w as usize * h as usize * 4, 0);
+        if reader.read_exact(&mut data).is_err() {
+            break;
+        }
+        let dropped = shared.latest.lock().unwrap().replace(Frame { size, data });
+        if let Some(frame) = dropped {
+            spare = frame.data;
+        }
+    }
+    shared.finished.store(true, Ordering::Relaxed);
+}
+
+// Read the header of a single RGBA PAM image, returning the image's size.
+//
+// Returns `None` at the end of the stream.
+fn read_pam_header<R: BufRead>(reader: &mut R) -> io::Result<Option<[u32; 2]>> {
+    let invalid = |msg| io::Error::new(io::ErrorKind::InvalidData, msg);
+    let (mut w, mut h) = (None, None);
+    let mut line = String::new();
+    let mut is_first = true;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return match is_first {
+                true => Ok(None),
+                false => Err(invalid("unexpected end of PAM header")),
+            };
+        }
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next()) {
+            (Some("P7"), _) if is_first => (),
+            (Some("WIDTH"), Some(n)) => w = n.parse().ok(),
+            (Some("HEIGHT"), Some(n)) => h = n.parse().ok(),
+            (Some("DEPTH"), Some("4")) | (Some("MAXVAL"), Some("255")) => (),
+            (Some("TUPLTYPE"), _) | (None, _) => (),
+            (Some(word), _) if word.starts_with('#') => (),
+            (Some("ENDHDR"), _) => break,
+            _ => return Err(invalid("unsupported PAM header")),
+        }
+        is_first = false;
+    }
+    match (w, h) {
+        (Some(w), Some(h)) => Ok(Some([w, h])),
+        _ => Err(invalid("PAM header is missing the image size")),
+    }
+}
+
+#[test]
+fn test_read_pam_header() {
+    let header = "P7\nWIDTH 320\nHEIGHT 240\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n";
+    let mut bytes = header.as_bytes().to_vec();
+    bytes.extend_from_slice(&[7; 4]);
+    let mut reader = io::Cursor::new(bytes);
+    assert_eq!(read_pam_header(&mut reader).unwrap(), Some([320, 240]));
+    let mut texel = [0; 4];
+    reader.read_exact(&mut texel).unwrap();
+    assert_eq!(texel, [7; 4]);
+    assert_eq!(read_pam_header(&mut reader).unwrap(), None);
+
+    let rgb = "P7\nWIDTH 1\nHEIGHT 1\nDEPTH 3\nMAXVAL 255\nENDHDR\n";
+    assert!(read_pam_header(&mut io::Cursor::new(rgb)).is_err());
+}
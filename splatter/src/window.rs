@@ -11,9 +11,12 @@ use crate::frame::{self, Frame, RawFrame};
 use crate::geom;
 use crate::geom::Point2;
 use crate::glam::Vec2;
+use crate::image;
+use crate::overlay::FpsOverlay;
 use crate::wgpu;
 use crate::App;
 use std::any::Any;
+use std::cell::Cell;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
@@ -250,6 +253,9 @@ pub struct Window {
     pub(crate) tracked_state: TrackedState,
     pub(crate) is_invalidated: bool, // Whether framebuffer must be cleared
     pub(crate) clear_color: wgpu::Color,
+    // Whether to draw the FPS overlay, or `None` to follow `App::set_show_fps`.
+    pub(crate) show_fps: Cell<Option<bool>>,
+    pub(crate) fps_overlay: Option<FpsOverlay>,
 }
 
 // Data related to `Frame`s produced for this window's surface textures.
@@ -426,10 +432,11 @@ impl<'app> Builder<'app> {
     /// `Window::build` method will `panic!` if the user tries to specify `msaa_samples` as well as
     /// a `raw_view` method.
     ///
-    /// *TODO: Perhaps it would be worth adding two separate methods for specifying msaa samples.
-    /// One for forcing a certain number of samples and returning an error otherwise, and another
-    /// for attempting to use the given number of samples but falling back to a supported value in
-    /// the case that the specified number is not supported.*
+    /// The requested number of samples is validated against the sample counts supported by the
+    /// adapter for the surface format (typically some subset of 1, 2, 4, 8 and 16) when the window
+    /// is built. If the requested count is unsupported, the nearest supported count is used instead
+    /// and a warning is emitted. The count that is actually in use can be retrieved via
+    /// `Window::msaa_samples`, which is also the count used by the `Draw` renderer.
     pub fn msaa_samples(mut self, msaa_samples: u32) -> Self {
         self.msaa_samples = Some(msaa_samples);
         self
@@ -939,7 +946,18 @@ impl<'app> Builder<'app> {
         let (frame_data, msaa_samples) = match user_functions.view {
             Some(View::WithModel(_)) | Some(View::Sketch(_)) | None => {
                 let msaa_samples = msaa_samples.unwrap_or(Frame::DEFAULT_MSAA_SAMPLES);
-                // TODO: Verity that requested sample count is valid for surface?
+                let format_features = adapter.get_texture_format_features(surface_conf.format);
+                let supported_samples =
+                    wgpu::nearest_supported_sample_count(format_features.flags, msaa_samples);
+                if supported_samples != msaa_samples {
+                    // TODO: Log errors, don't print to stderr.
+                    eprintln!(
+                        "requested `msaa_samples` of {} is not supported by the adapter for \
+                         surface format {:?}, falling back to {}",
+                        msaa_samples, surface_conf.format, supported_samples,
+                    );
+                }
+                let msaa_samples = supported_samples;
                 let surface_dims = [surface_conf.width, surface_conf.height];
                 let render =
                     frame::RenderData::new(device, surface_dims, surface_conf.format, msaa_samples);
@@ -971,6 +989,8 @@ impl<'app> Builder<'app> {
             tracked_state,
             is_invalidated,
             clear_color,
+            show_fps: Cell::new(None),
+            fps_overlay: None,
         };
         app.windows.borrow_mut().insert(window_id, window);
 
@@ -1261,6 +1281,14 @@ impl Window {
         self.window.set_title(title);
     }
 
+    /// Specify whether or not to draw an overlay showing the frame time and frame rate in the top
+    /// left corner of this window.
+    ///
+    /// This overrides the setting applied to all windows via `App::set_show_fps`.
+    pub fn set_show_fps(&self, show: bool) {
+        self.show_fps.set(Some(show));
+    }
+
     /// Set the visibility of the window.
     ///
     /// ## Platform-specific
@@ -1290,7 +1318,7 @@ impl Window {
         self.window.set_maximized(maximized)
     }
 
-    /// Set the window to fullscreen on the primary monitor.
+    /// Set the window to borderless fullscreen on its current monitor.
     ///
     /// `true` enables fullscreen, `false` disables fullscreen.
     ///
@@ -1306,6 +1334,24 @@ impl Window {
         }
     }
 
+    /// Toggle the window between fullscreen on its current monitor and windowed mode.
+    ///
+    /// The resulting resize is delivered as a regular **Resized** event, upon which the window's
+    /// surface, the draw renderer and any UI input state are updated to match the new size.
+    pub fn toggle_fullscreen(&self) {
+        self.set_fullscreen(!self.is_fullscreen());
+    }
+
+    /// Set the window to borderless fullscreen on the monitor at the given index.
+    ///
+    /// Monitors are indexed in the order produced by `App::available_monitors`. If there is no
+    /// monitor at the given index, the window is left unchanged.
+    pub fn set_fullscreen_monitor(&self, index: usize) {
+        if let Some(monitor) = self.window.available_monitors().nth(index) {
+            self.set_fullscreen_with(Some(Fullscreen::Borderless(Some(monitor))));
+        }
+    }
+
     /// Set the window to fullscreen.
     ///
     /// Call this method again with `None` to revert back from fullscreen.
@@ -1375,6 +1421,23 @@ impl Window {
         self.window.set_window_icon(window_icon)
     }
 
+    /// Sets the window icon from raw, non-premultiplied RGBA8 pixel data of the given size.
+    ///
+    /// This allows for generating an icon procedurally, e.g. from the current frame. See
+    /// `set_window_icon` for platform-specific details.
+    ///
+    /// Returns an error if the length of `rgba` is not `width * height * 4`.
+    pub fn set_icon(
+        &self,
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+    ) -> Result<(), winit::window::BadIcon> {
+        let icon = winit::window::Icon::from_rgba(rgba, width, height)?;
+        self.set_window_icon(Some(icon));
+        Ok(())
+    }
+
     /// Sets the location of IME candidate box in client area coordinates relative to the top left.
     ///
     /// ## Platform-specific
@@ -1491,6 +1554,24 @@ impl Window {
         self.msaa_samples
     }
 
+    /// Load the image at the given path into a texture on the window's device.
+    ///
+    /// A full mip chain is generated for the texture so that it does not alias when drawn smaller
+    /// than its original size. The resulting texture may be passed directly to `draw.texture`.
+    pub fn load_texture<P>(&self, path: P) -> image::ImageResult<wgpu::Texture>
+    where
+        P: AsRef<Path>,
+    {
+        wgpu::Texture::from_path_with_mipmaps(self, path)
+    }
+
+    /// Upload the given image to a texture on the window's device.
+    ///
+    /// Like `load_texture`, a full mip chain is generated for the texture.
+    pub fn texture_from_image(&self, image: &image::DynamicImage) -> wgpu::Texture {
+        wgpu::Texture::from_image_with_mipmaps(self, image)
+    }
+
     // Custom methods.
 
     // A utility function to simplify the reconfiguration of the window's wgpu surface.
@@ -1541,6 +1622,20 @@ impl Window {
         geom::Rect::from_w_h(w, h)
     }
 
+    /// The rectangle representing the position and dimensions of the window in physical pixels.
+    ///
+    /// Like `rect`, the position is always `[0.0, 0.0]`. The dimensions are equal to the result
+    /// of `inner_size_pixels`, i.e. the dimensions of `rect` multiplied by `scale_factor`. This is
+    /// useful for pixel-perfect work, e.g. mapping the pixels of an image one-to-one onto the
+    /// window.
+    ///
+    /// Note that `Draw` coordinates are described in points, so a pixel rect must be divided by
+    /// `scale_factor` before drawing with it.
+    pub fn rect_pixels(&self) -> geom::Rect {
+        let (w, h) = self.inner_size_pixels();
+        geom::Rect::from_w_h(w as f32, h as f32)
+    }
+
     /// Capture the next frame right before it is drawn to this window and write it to an image
     /// file at the given path. If a frame already exists, it will be captured before its `submit`
     /// method is called or before it is `drop`ped.
@@ -1553,6 +1648,75 @@ impl Window {
         self.capture_frame_inner(path.as_ref());
     }
 
+    /// Capture the next frame right before it is drawn to this window and deliver its pixels to
+    /// the given callback rather than writing them to a file.
+    ///
+    /// Once the GPU readback completes, `callback` is called with the frame as a non-linear sRGBA
+    /// `image::RgbaImage`. Use `image.dimensions()` and `image.into_raw()` to retrieve the size
+    /// and the raw RGBA bytes, e.g. for streaming frames into a video encoder.
+    ///
+    /// Note: The callback is called on the capture thread pool rather than the main thread. Like
+    /// `capture_frame`, the readback is asynchronous and the callback should not be expected to
+    /// be called immediately.
+    pub fn capture_frame_to<F>(&self, callback: F)
+    where
+        F: 'static + Send + FnOnce(image::RgbaImage),
+    {
+        self.capture_data()
+            .next_frame_fns
+            .lock()
+            .expect("failed to lock `capture_next_frame_fns`")
+            .push(frame::CaptureFn::new(callback));
+    }
+
+    /// Capture every following frame to a numbered image file within `dir` until `stop_capture`
+    /// is called.
+    ///
+    /// The first run of `#` characters in `pattern` is replaced with the zero-padded frame number,
+    /// e.g. `"frame_#####.png"` produces `frame_00001.png`, `frame_00002.png` and so on. If
+    /// `pattern` contains no `#` characters, a five digit frame number is appended to the file
+    /// stem. Numbering always begins at `1`.
+    ///
+    /// Each frame is captured via the same asynchronous capture jobs as `capture_frame`. Use
+    /// `await_capture_frame_jobs` (or `App::await_capture_frame_jobs`) to ensure all frames have
+    /// been written before exiting. This also happens implicitly when the window is dropped.
+    pub fn capture_frame_sequence<P>(&self, dir: P, pattern: &str)
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref();
+        if !dir.exists() {
+            std::fs::create_dir_all(dir)
+                .expect("failed to create `capture_frame_sequence` directory");
+        }
+        let mut sequence = self
+            .capture_data()
+            .sequence
+            .lock()
+            .expect("failed to lock `capture_sequence`");
+        *sequence = Some(frame::CaptureSequence::new(dir, pattern));
+    }
+
+    /// Stop capturing a frame sequence started via `capture_frame_sequence`.
+    ///
+    /// Frames that have already been captured will continue to be written.
+    pub fn stop_capture(&self) {
+        let mut sequence = self
+            .capture_data()
+            .sequence
+            .lock()
+            .expect("failed to lock `capture_sequence`");
+        *sequence = None;
+    }
+
+    /// Whether or not a frame sequence is currently being captured.
+    pub fn is_capturing_frame_sequence(&self) -> bool {
+        self.frame_data
+            .as_ref()
+            .and_then(|data| data.capture.sequence.lock().ok().map(|seq| seq.is_some()))
+            .unwrap_or(false)
+    }
+
     /// Produces a reference to the inner winit window.
     ///
     /// This is sometimes useful for integration with other winit-aware libraries (e.g. UI).
@@ -1568,16 +1732,21 @@ impl Window {
         }
 
         let mut capture_next_frame_path = self
-            .frame_data
-            .as_ref()
-            .expect("window capture requires that `view` draws to a `Frame` (not a `RawFrame`)")
-            .capture
+            .capture_data()
             .next_frame_path
             .lock()
             .expect("failed to lock `capture_next_frame_path`");
         *capture_next_frame_path = Some(path.to_path_buf());
     }
 
+    fn capture_data(&self) -> &frame::CaptureData {
+        &self
+            .frame_data
+            .as_ref()
+            .expect("window capture requires that `view` draws to a `Frame` (not a `RawFrame`)")
+            .capture
+    }
+
     /// Block and wait for all active capture frame jobs to complete.
     ///
     /// This is called implicitly when the window is dropped to ensure any pending captures
@@ -0,0 +1,60 @@
+use splatter::image::RgbaImage;
+use splatter::prelude::*;
+use std::sync::Mutex;
+
+// The image read back from the headless target on exit.
+static IMAGE: Mutex<Option<RgbaImage>> = Mutex::new(None);
+
+fn model(_app: &App) -> u32 {
+    0
+}
+
+fn update(_app: &App, updates: &mut u32, _update: Update) {
+    *updates += 1;
+}
+
+fn view(app: &App, _updates: &u32, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(RED);
+    // Cover the right half of the target.
+    let rect = app.window_rect();
+    draw.rect()
+        .x_y(rect.w() / 4.0, 0.0)
+        .w_h(rect.w() / 2.0, rect.h())
+        .color(BLUE);
+    draw.to_frame(app, &frame).unwrap();
+}
+
+fn exit(app: &App, updates: u32) {
+    assert_eq!(updates, 3);
+    let headless = app.headless().expect("app should be headless");
+    assert_eq!(headless.elapsed_frames(), 3);
+    *IMAGE.lock().unwrap() = Some(headless.read_image().unwrap());
+}
+
+#[test]
+fn headless_app_renders_view_offscreen() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    splatter::headless(16, 16)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    splatter::app(model)
+        .update(update)
+        .view(view)
+        .exit(exit)
+        .headless(16, 16)
+        .loop_mode(LoopMode::loop_ntimes(3))
+        .run();
+
+    let image = IMAGE.lock().unwrap().take().expect("exit was not called");
+    assert_eq!(image.dimensions(), (16, 16));
+    for (x, _, pixel) in image.enumerate_pixels() {
+        let expected = if x < 8 {
+            [255, 0, 0, 255]
+        } else {
+            [0, 0, 255, 255]
+        };
+        assert_eq!(pixel.0, expected, "at x {}", x);
+    }
+}
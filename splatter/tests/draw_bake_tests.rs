@@ -0,0 +1,39 @@
+use splatter::prelude::*;
+
+// A wave drawn as a polyline along with a filled ellipse.
+fn view(draw: &Draw) {
+    let points = (0..=32).map(|i| {
+        let x = i as f32 - 16.0;
+        pt2(x, (x * 0.4).sin() * 8.0)
+    });
+    draw.polyline().weight(3.0).color(RED).points(points);
+    draw.x(8.0).ellipse().radius(4.0).color(BLUE);
+}
+
+#[test]
+fn baked_drawing_matches_direct_drawing() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(32, 32)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    let draw = Draw::new();
+    draw.background().color(BLACK);
+    view(&draw.rotate(0.5).scale(0.75));
+    let expected = headless.render(&draw).unwrap();
+
+    let baked = Draw::new().bake(view);
+    assert!(baked.vertex_count() > 0);
+
+    // The same baked drawing may be redrawn any number of times.
+    for _ in 0..2 {
+        let draw = Draw::new();
+        draw.background().color(BLACK);
+        draw.rotate(0.5).scale(0.75).baked(&baked);
+        let image = headless.render(&draw).unwrap();
+        for (a, b) in image.pixels().zip(expected.pixels()) {
+            assert_eq!(a, b);
+        }
+    }
+}
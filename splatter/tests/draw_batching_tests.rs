@@ -0,0 +1,98 @@
+use splatter::headless::{self, Headless};
+use splatter::image;
+use splatter::prelude::*;
+
+// Fill a renderer with the given drawing, returning the number of draw calls produced.
+fn draw_call_count(headless: &Headless, draw: &Draw) -> usize {
+    let device = headless.device_queue_pair().device();
+    let size = headless.size_pixels();
+    let mut renderer = splatter::draw::RendererBuilder::new().build(
+        device,
+        size,
+        1.0,
+        1,
+        headless::Builder::TEXTURE_FORMAT,
+    );
+    renderer.fill(device, draw, 1.0, size).unwrap();
+    renderer.draw_call_count()
+}
+
+#[test]
+fn consecutive_primitives_share_a_draw_call() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(64, 64)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    let draw = Draw::new();
+    for i in 0..1000 {
+        let a = i as f32 * 0.1;
+        let b = a + 0.1;
+        draw.line()
+            .start(pt2(a.cos(), a.sin()) * 30.0)
+            .end(pt2(b.cos(), b.sin()) * 30.0)
+            .hsv(i as f32 / 1000.0, 1.0, 1.0);
+    }
+    assert_eq!(draw_call_count(&headless, &draw), 1);
+}
+
+#[test]
+fn untextured_primitives_join_textured_batch() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(16, 16)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    let red = image::RgbaImage::from_pixel(4, 4, image::Rgba([255, 0, 0, 255]));
+    let red = image::DynamicImage::ImageRgba8(red);
+    let texture = wgpu::Texture::from_image(&**headless.device_queue_pair(), &red);
+
+    // Alternate between textured and untextured primitives down the left and right halves.
+    let view = || {
+        let draw = Draw::new();
+        draw.background().color(BLACK);
+        for i in 0..4 {
+            let y = 6.0 - i as f32 * 4.0;
+            draw.texture(&texture).x_y(-4.0, y).w_h(8.0, 4.0);
+            draw.rect().x_y(4.0, y).w_h(8.0, 4.0).color(WHITE);
+        }
+        draw
+    };
+
+    // Filling the renderer drains the drawing, so a fresh one is rendered below.
+    assert_eq!(draw_call_count(&headless, &view()), 1);
+
+    let image = headless.render(&view()).unwrap();
+    for (x, _, pixel) in image.enumerate_pixels() {
+        let expected = match x < 8 {
+            true => [255, 0, 0, 255],
+            false => [255, 255, 255, 255],
+        };
+        assert_eq!(pixel.0, expected, "pixel in column {}", x);
+    }
+}
+
+#[test]
+fn textured_quads_with_markers_share_a_draw_call() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(512, 512)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    let red = image::RgbaImage::from_pixel(4, 4, image::Rgba([255, 0, 0, 255]));
+    let red = image::DynamicImage::ImageRgba8(red);
+    let texture = wgpu::Texture::from_image(&**headless.device_queue_pair(), &red);
+
+    // The drawing measured by the `textured_with_markers` benchmark.
+    let draw = Draw::new();
+    for i in 0..1000 {
+        let x = (i % 40) as f32 * 10.0 - 200.0;
+        let y = (i / 40) as f32 * 10.0 - 200.0;
+        draw.texture(&texture).x_y(x, y).w_h(8.0, 8.0);
+        draw.ellipse().x_y(x, y).radius(2.0).color(WHITE);
+    }
+    assert_eq!(draw_call_count(&headless, &draw), 1);
+}
@@ -0,0 +1,39 @@
+use splatter::headless;
+use splatter::prelude::*;
+
+#[test]
+fn pick_at_reads_the_id_of_the_drawing_beneath() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(16, 16)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+    let device_queue_pair = headless.device_queue_pair();
+    let (device, queue) = (device_queue_pair.device(), device_queue_pair.queue());
+    let size = headless.size_pixels();
+    let texture = wgpu::TextureBuilder::new()
+        .size(size)
+        .format(headless::Builder::TEXTURE_FORMAT)
+        .usage(wgpu::TextureUsages::RENDER_ATTACHMENT)
+        .build(device);
+    let mut renderer = splatter::draw::RendererBuilder::new().build(
+        device,
+        size,
+        1.0,
+        1,
+        headless::Builder::TEXTURE_FORMAT,
+    );
+
+    // Cover the left half with one pickable drawing, leaving the right half empty.
+    let draw = Draw::new();
+    draw.pickable(42).rect().x_y(-4.0, 0.0).w_h(8.0, 16.0);
+    let desc = wgpu::CommandEncoderDescriptor { label: None };
+    let mut encoder = device.create_command_encoder(&desc);
+    renderer
+        .render_to_texture(device, &mut encoder, &draw, &texture)
+        .unwrap();
+    queue.submit(Some(encoder.finish()));
+
+    assert_eq!(renderer.pick_at(device, queue, [2, 8]), Some(42));
+    assert_eq!(renderer.pick_at(device, queue, [13, 8]), None);
+}
@@ -0,0 +1,91 @@
+use splatter::prelude::*;
+
+#[test]
+fn shader_may_accept_a_subset_of_vertex_outputs() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(16, 16)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    // Accept only the texture coordinates, with red provided via `inputs.time`.
+    let draw = Draw::new();
+    draw.shader(
+        "@fragment
+        fn main(@location(1) tex_coords: vec2<f32>) -> @location(0) vec4<f32> {
+            return vec4<f32>(inputs.time, 0.0, step(0.5, tex_coords.x), 1.0);
+        }",
+    )
+    .time(1.0);
+
+    let image = headless.render(&draw).unwrap();
+    for (x, _, pixel) in image.enumerate_pixels() {
+        let expected = if x < 8 {
+            [255, 0, 0, 255]
+        } else {
+            [255, 0, 255, 255]
+        };
+        assert_eq!(pixel.0, expected, "at x {}", x);
+    }
+}
+
+#[test]
+fn shader_may_accept_no_vertex_outputs() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(16, 16)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    let draw = Draw::new();
+    draw.shader(
+        "@fragment
+        fn main() -> @location(0) vec4<f32> {
+            return vec4<f32>(0.0, 1.0, 0.0, 1.0);
+        }",
+    );
+
+    let image = headless.render(&draw).unwrap();
+    assert!(image.pixels().all(|pixel| pixel.0 == [0, 255, 0, 255]));
+}
+
+#[test]
+fn shader_reads_uniforms() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(16, 16)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    let draw = Draw::new();
+    draw.shader(
+        "struct Uniforms { color: vec4<f32> };
+        @group(1) @binding(1) var<uniform> uniforms: Uniforms;
+
+        @fragment
+        fn main() -> @location(0) vec4<f32> {
+            return uniforms.color;
+        }",
+    )
+    .uniforms(&[0.0f32, 0.0, 1.0, 1.0]);
+
+    let image = headless.render(&draw).unwrap();
+    assert!(image.pixels().all(|pixel| pixel.0 == [0, 0, 255, 255]));
+}
+
+#[test]
+fn invalid_shader_returns_an_error() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(16, 16)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    let draw = Draw::new();
+    draw.shader("@fragment fn main() -> @location(0) vec4<f32> { return oops; }");
+    assert!(headless.render(&draw).is_err());
+
+    let draw = Draw::new();
+    draw.shader("@fragment fn not_main() -> @location(0) vec4<f32> { return vec4<f32>(1.0); }");
+    assert!(headless.render(&draw).is_err());
+}
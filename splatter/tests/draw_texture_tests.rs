@@ -0,0 +1,144 @@
+use splatter::image::{self, Rgba};
+use splatter::prelude::*;
+
+// The color of the cell at the given column and row (from the top) of the atlas.
+fn cell_color(col: u32, row: u32) -> Rgba<u8> {
+    Rgba([
+        col as u8 * 85,
+        row as u8 * 85,
+        255 - (col + row) as u8 * 20,
+        255,
+    ])
+}
+
+#[test]
+fn texture_area_samples_atlas_cell() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(16, 16)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    // A 4x4 atlas where each cell is 4x4 pixels of a unique color.
+    let atlas = image::RgbaImage::from_fn(16, 16, |x, y| cell_color(x / 4, y / 4));
+    let atlas = image::DynamicImage::ImageRgba8(atlas);
+    let texture = wgpu::Texture::from_image(&**headless.device_queue_pair(), &atlas);
+
+    // Draw the cell in the second column of the third row across the whole target.
+    let (col, row) = (1, 2);
+    let area = Rect::from_corner_points(
+        [col as f32 / 4.0, (3 - row) as f32 / 4.0],
+        [(col + 1) as f32 / 4.0, (4 - row) as f32 / 4.0],
+    );
+    let sampler = wgpu::SamplerBuilder::new()
+        .mag_filter(wgpu::FilterMode::Nearest)
+        .min_filter(wgpu::FilterMode::Nearest)
+        .into_descriptor();
+    let draw = Draw::new();
+    draw.sampler(sampler)
+        .texture(&texture)
+        .w_h(16.0, 16.0)
+        .area(area);
+
+    let image = headless.render(&draw).unwrap();
+    let expected = cell_color(col, row);
+    for pixel in image.pixels() {
+        for (a, b) in pixel.0.iter().zip(expected.0.iter()) {
+            assert!(
+                (*a as i32 - *b as i32).abs() <= 2,
+                "{:?} != {:?}",
+                pixel,
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn texture_array_samples_each_layer() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(16, 16)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    // A two layer array, the first red and the second blue.
+    let red = image::RgbaImage::from_pixel(4, 4, Rgba([255, 0, 0, 255]));
+    let blue = image::RgbaImage::from_pixel(4, 4, Rgba([0, 0, 255, 255]));
+    let device_queue_pair = headless.device_queue_pair();
+    let array = wgpu::Texture::load_array_from_image_buffers(
+        device_queue_pair.device(),
+        device_queue_pair.queue(),
+        wgpu::TextureUsages::TEXTURE_BINDING,
+        [&red, &blue],
+    )
+    .unwrap();
+
+    // Draw the first layer over the left half and the second over the right half.
+    let draw = Draw::new();
+    draw.texture_array(&array)
+        .x_y(-4.0, 0.0)
+        .w_h(8.0, 16.0)
+        .array_layer(0);
+    draw.texture_array(&array)
+        .x_y(4.0, 0.0)
+        .w_h(8.0, 16.0)
+        .array_layer(1);
+
+    let image = headless.render(&draw).unwrap();
+    for (x, _, pixel) in image.enumerate_pixels() {
+        let expected = if x < 8 {
+            red.get_pixel(0, 0)
+        } else {
+            blue.get_pixel(0, 0)
+        };
+        for (a, b) in pixel.0.iter().zip(expected.0.iter()) {
+            assert!(
+                (*a as i32 - *b as i32).abs() <= 2,
+                "{:?} != {:?} at x {}",
+                pixel,
+                expected,
+                x
+            );
+        }
+    }
+}
+
+#[test]
+fn texture_with_mipmaps_samples_generated_levels() {
+    // Rendering requires an adapter. Fail rather than pass silently when none is available.
+    let headless = splatter::headless(16, 16)
+        .msaa_samples(1)
+        .build()
+        .expect("no GPU adapter available for headless rendering");
+
+    // A texture four times the size of the target, so that drawing it samples its third level.
+    // Another texture of the same format is loaded first, so that the mipmap pipeline is reused.
+    let device_queue_pair = &**headless.device_queue_pair();
+    let red = image::RgbaImage::from_pixel(64, 64, Rgba([255, 0, 0, 255]));
+    let blue = image::RgbaImage::from_pixel(64, 64, Rgba([0, 0, 255, 255]));
+    let _red = wgpu::Texture::from_image_with_mipmaps(
+        device_queue_pair,
+        &image::DynamicImage::ImageRgba8(red),
+    );
+    let texture = wgpu::Texture::from_image_with_mipmaps(
+        device_queue_pair,
+        &image::DynamicImage::ImageRgba8(blue.clone()),
+    );
+    assert_eq!(texture.mip_level_count(), 7);
+
+    let draw = Draw::new();
+    draw.texture(&texture).w_h(16.0, 16.0);
+    let image = headless.render(&draw).unwrap();
+    let expected = blue.get_pixel(0, 0);
+    for pixel in image.pixels() {
+        for (a, b) in pixel.0.iter().zip(expected.0.iter()) {
+            assert!(
+                (*a as i32 - *b as i32).abs() <= 2,
+                "{:?} != {:?}",
+                pixel,
+                expected
+            );
+        }
+    }
+}
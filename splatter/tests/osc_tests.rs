@@ -0,0 +1,59 @@
+use splatter::osc;
+
+#[test]
+fn osc_float_round_trip() {
+    let mut receiver = osc::receiver(0).unwrap();
+    let port = receiver.local_addr().unwrap().port();
+    let sender = osc::sender("127.0.0.1", port).unwrap();
+
+    // The receiver never blocks, even when nothing has been sent.
+    assert!(receiver.try_recv().is_none());
+
+    sender
+        .send(("/radius", vec![osc::Type::Float(42.5)]))
+        .unwrap();
+    let bundle = osc::Bundle {
+        timetag: osc::now(),
+        content: vec![
+            ("/a", vec![]).into(),
+            ("/b", vec![osc::Type::Int(1)]).into(),
+        ],
+    };
+    sender.send(bundle).unwrap();
+
+    // Allow time for the datagrams to arrive.
+    let mut msgs = vec![];
+    for _ in 0..100 {
+        msgs.extend(receiver.drain());
+        if msgs.len() == 3 {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    let addrs: Vec<_> = msgs.iter().map(|msg| msg.addr.as_str()).collect();
+    assert_eq!(addrs, ["/radius", "/a", "/b"]);
+    assert_eq!(msgs[0].args, vec![osc::Type::Float(42.5)]);
+}
+
+#[test]
+fn osc_send_to_another_address() {
+    let mut receiver = osc::receiver(0).unwrap();
+    let port = receiver.local_addr().unwrap().port();
+    let other = osc::receiver(0).unwrap();
+    let sender = osc::sender("127.0.0.1", other.local_addr().unwrap().port()).unwrap();
+
+    sender
+        .send_to(("/other", vec![]), ("127.0.0.1", port))
+        .unwrap();
+
+    let mut msgs = vec![];
+    for _ in 0..100 {
+        msgs.extend(receiver.drain());
+        if !msgs.is_empty() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(msgs.len(), 1);
+    assert_eq!(msgs[0].addr, "/other");
+}
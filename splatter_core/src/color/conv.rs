@@ -4,7 +4,7 @@
 //! If a desired conversion is missing, feel free to open an issue or pull request!
 
 use crate::color::white_point::D65;
-use crate::color::{self, encoding, Alpha, Component, IntoColor, LinSrgba};
+use crate::color::{self, encoding, Alpha, Component, FromColor, IntoColor, LinSrgba};
 use crate::math::num_traits::Float;
 
 /// Types that may be converted directly into a linear sRGBA color representation.
@@ -132,6 +132,124 @@ where
     }
 }
 
+/// Types that may be produced directly from a linear sRGBA color representation.
+///
+/// This is the inverse of `IntoLinSrgba` and allows for generic color APIs that return the same
+/// color type that they were given, e.g. `color::lerp`. Converting into a type without an alpha
+/// channel discards the alpha.
+pub trait FromLinSrgba<S>
+where
+    S: Component,
+{
+    /// Convert the linear sRGBA color into `Self`.
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self;
+}
+
+impl<S> FromLinSrgba<S> for color::Xyz<D65, S>
+where
+    S: Component + Float,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        from_lin_srgb_without_alpha(color)
+    }
+}
+
+impl<S> FromLinSrgba<S> for color::Yxy<D65, S>
+where
+    S: Component + Float,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        from_lin_srgb_without_alpha(color)
+    }
+}
+
+impl<S> FromLinSrgba<S> for color::Lab<D65, S>
+where
+    S: Component + Float,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        from_lin_srgb_without_alpha(color)
+    }
+}
+
+impl<S> FromLinSrgba<S> for color::Lch<D65, S>
+where
+    S: Component + Float,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        from_lin_srgb_without_alpha(color)
+    }
+}
+
+impl<T, S> FromLinSrgba<S> for color::LinSrgb<T>
+where
+    T: Component,
+    S: Component,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        color.color.into_format()
+    }
+}
+
+impl<T, S> FromLinSrgba<S> for color::Srgb<T>
+where
+    T: Component,
+    S: Component + Float,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        color::Srgb::from_linear(color.color).into_format()
+    }
+}
+
+impl<S> FromLinSrgba<S> for color::Hsl<encoding::Srgb, S>
+where
+    S: Component + Float,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        from_lin_srgb_without_alpha(color)
+    }
+}
+
+impl<S> FromLinSrgba<S> for color::Hsv<encoding::Srgb, S>
+where
+    S: Component + Float,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        from_lin_srgb_without_alpha(color)
+    }
+}
+
+impl<S> FromLinSrgba<S> for color::Hwb<encoding::Srgb, S>
+where
+    S: Component + Float,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        from_lin_srgb_without_alpha(color)
+    }
+}
+
+impl<S> FromLinSrgba<S> for color::SrgbLuma<S>
+where
+    S: Component + Float,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        from_lin_srgb_without_alpha(color)
+    }
+}
+
+impl<C, S, T> FromLinSrgba<S> for Alpha<C, T>
+where
+    C: FromLinSrgba<S>,
+    S: Component,
+    T: Component,
+{
+    fn from_lin_srgba(color: LinSrgba<S>) -> Self {
+        let alpha = color.alpha.convert();
+        let color = C::from_lin_srgba(color);
+        Alpha { color, alpha }
+    }
+}
+
 fn into_lin_srgb_with_alpha<C, S>(color: C) -> LinSrgba<S>
 where
     C: IntoColor<D65, S>,
@@ -141,3 +259,11 @@ where
     let alpha = S::max_intensity();
     Alpha { color, alpha }
 }
+
+fn from_lin_srgb_without_alpha<C, S>(color: LinSrgba<S>) -> C
+where
+    C: FromColor<D65, S>,
+    S: Component + Float,
+{
+    C::from_rgb::<encoding::Srgb>(color.color)
+}
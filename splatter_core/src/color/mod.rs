@@ -4,11 +4,16 @@
 //! See the [**named**](./named/index.html) module for a set of provided color constants.
 
 pub mod conv;
+pub mod mix;
+pub mod oklab;
+pub mod palette;
 
-pub use self::conv::IntoLinSrgba;
+pub use self::conv::{FromLinSrgba, IntoLinSrgba};
+pub use self::mix::{gradient, lerp, lerp_in_hsl, lerp_in_oklch};
 pub use self::named::*;
+pub use self::oklab::{oklab, oklch, Oklab, Oklaba, Oklch, Oklcha};
 #[doc(inline)]
-pub use palette::*;
+pub use ::palette::*;
 
 /// The default scalar value for working with color components, hues, etc.
 pub type DefaultScalar = f32;
@@ -121,6 +126,23 @@ pub fn rgb_u32(c: u32) -> Rgb<u8> {
     rgb8(red, green, blue)
 }
 
+/// Parse a color from a hexadecimal string of the form `"#rrggbb"` or `"#rrggbbaa"`.
+///
+/// The leading `#` is optional. Returns `None` if the string is malformed.
+pub fn hex(s: &str) -> Option<Rgba8> {
+    let digits = s.strip_prefix('#').unwrap_or(s);
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let component = |i: usize| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).ok();
+    let alpha = match digits.len() {
+        6 => 255,
+        8 => component(3)?,
+        _ => return None,
+    };
+    Some(rgba8(component(0)?, component(1)?, component(2)?, alpha))
+}
+
 /// A short-hand constructor for `Hsl::new(RgbHue::from_degrees(h * 360.0), s, l)`.
 ///
 /// The given hue expects a value between `0.0` and `1.0` where `0.0` is 0 degress and `1.0` is
@@ -137,6 +159,11 @@ pub fn hsla(h: f32, s: f32, l: f32, a: f32) -> Hsla {
     Hsla::new(RgbHue::from_degrees(h * 360.0), s, l, a)
 }
 
+/// The same as `hsl`, but with the hue given in degrees, where `360.0` is a full turn.
+pub fn hsl_degrees(h: f32, s: f32, l: f32) -> Hsl {
+    Hsl::new(RgbHue::from_degrees(h), s, l)
+}
+
 /// A short-hand constructor for `Hsv::new(RgbHue::from_degrees(h * 360.0), s, v)`.
 ///
 /// The given hue expects a value between `0.0` and `1.0` where `0.0` is 0 degress and `1.0` is
@@ -153,6 +180,11 @@ pub fn hsva(h: f32, s: f32, v: f32, a: f32) -> Hsva {
     Hsva::new(RgbHue::from_degrees(h * 360.0), s, v, a)
 }
 
+/// The same as `hsv`, but with the hue given in degrees, where `360.0` is a full turn.
+pub fn hsv_degrees(h: f32, s: f32, v: f32) -> Hsv {
+    Hsv::new(RgbHue::from_degrees(h), s, v)
+}
+
 /// A short-hand constructor for `Gray::new`.
 pub fn gray<T>(g: T) -> Gray<T>
 where
@@ -165,3 +197,18 @@ where
 fn test_rgb_u32() {
     assert_eq!(rgb_u32(0xFF8000), rgb8(255, 128, 0));
 }
+
+#[test]
+fn test_hex() {
+    assert_eq!(hex("#FF8000"), Some(rgba8(255, 128, 0, 255)));
+    assert_eq!(hex("ff800080"), Some(rgba8(255, 128, 0, 128)));
+    assert_eq!(hex("#FF80"), None);
+    assert_eq!(hex("#GG8000"), None);
+    assert_eq!(hex("#+F8000"), None);
+}
+
+#[test]
+fn test_hsv_degrees() {
+    assert_eq!(hsv_degrees(90.0, 0.5, 1.0), hsv(0.25, 0.5, 1.0));
+    assert_eq!(hsl_degrees(90.0, 0.5, 1.0), hsl(0.25, 0.5, 1.0));
+}
@@ -0,0 +1,207 @@
+//! The perceptually uniform OKLab color space and its polar form, OKLCH.
+//!
+//! See Björn Ottosson's [introduction to OKLab](https://bottosson.github.io/posts/oklab/) for
+//! details. Distances in OKLab correspond more closely to perceived differences than those in
+//! sRGB or HSL, making it well suited to smooth gradients and interpolation.
+
+use crate::color::conv::{FromLinSrgba, IntoLinSrgba};
+use crate::color::{Alpha, Component, DefaultScalar, LabHue, LinSrgb, LinSrgba, Mix};
+
+/// A color in the OKLab color space.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Oklab {
+    /// The perceived lightness, where `0.0` is black and `1.0` is white.
+    pub l: DefaultScalar,
+    /// How green (negative) or red (positive) the color is.
+    pub a: DefaultScalar,
+    /// How blue (negative) or yellow (positive) the color is.
+    pub b: DefaultScalar,
+}
+
+/// A color in the OKLCH color space, the polar form of the OKLab color space.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Oklch {
+    /// The perceived lightness, where `0.0` is black and `1.0` is white.
+    pub l: DefaultScalar,
+    /// The colorfulness, where `0.0` is gray. Colors within the sRGB gamut do not exceed `0.33`.
+    pub chroma: DefaultScalar,
+    /// The hue, or the angle within the *a*, *b* plane of the OKLab color space.
+    pub hue: LabHue<DefaultScalar>,
+}
+
+/// The same as `Oklab`, but with an alpha value representing opacity.
+pub type Oklaba = Alpha<Oklab, DefaultScalar>;
+
+/// The same as `Oklch`, but with an alpha value representing opacity.
+pub type Oklcha = Alpha<Oklch, DefaultScalar>;
+
+// The conversion matrices are kept exactly as published, beyond `f32` precision.
+#[allow(clippy::excessive_precision)]
+impl Oklab {
+    /// Construct a new OKLab color.
+    pub fn new(l: DefaultScalar, a: DefaultScalar, b: DefaultScalar) -> Self {
+        Oklab { l, a, b }
+    }
+
+    /// Convert a linear sRGB color into the OKLab color space.
+    pub fn from_lin_srgb(color: LinSrgb) -> Self {
+        let (r, g, b) = color.into_components();
+        let l = 0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b;
+        let m = 0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b;
+        let s = 0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b;
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+        Oklab {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+
+    /// Convert the OKLab color into the linear sRGB color space.
+    ///
+    /// Colors outside of the sRGB gamut produce components outside of the range `0.0..=1.0`.
+    pub fn into_lin_srgb(self) -> LinSrgb {
+        let Oklab { l, a, b } = self;
+        let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+        let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+        let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+        LinSrgb::new(
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        )
+    }
+}
+
+impl Oklch {
+    /// Construct a new OKLCH color.
+    pub fn new<H>(l: DefaultScalar, chroma: DefaultScalar, hue: H) -> Self
+    where
+        H: Into<LabHue<DefaultScalar>>,
+    {
+        let hue = hue.into();
+        Oklch { l, chroma, hue }
+    }
+}
+
+/// A short-hand constructor for `Oklab::new`.
+pub fn oklab(l: f32, a: f32, b: f32) -> Oklab {
+    Oklab::new(l, a, b)
+}
+
+/// A short-hand constructor for `Oklch::new(l, chroma, LabHue::from_degrees(hue))`.
+///
+/// Unlike `hsl`, the given hue is expected in degrees.
+pub fn oklch(l: f32, chroma: f32, hue: f32) -> Oklch {
+    Oklch::new(l, chroma, LabHue::from_degrees(hue))
+}
+
+impl From<Oklch> for Oklab {
+    fn from(color: Oklch) -> Self {
+        let Oklch { l, chroma, hue } = color;
+        let (sin, cos) = hue.to_radians().sin_cos();
+        Oklab::new(l, chroma * cos, chroma * sin)
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(color: Oklab) -> Self {
+        let Oklab { l, a, b } = color;
+        let chroma = (a * a + b * b).sqrt();
+        let hue = LabHue::from_radians(b.atan2(a));
+        Oklch { l, chroma, hue }
+    }
+}
+
+impl Mix for Oklab {
+    type Scalar = DefaultScalar;
+
+    fn mix(&self, other: &Self, factor: Self::Scalar) -> Self {
+        let t = factor.clamp(0.0, 1.0);
+        Oklab {
+            l: self.l + t * (other.l - self.l),
+            a: self.a + t * (other.a - self.a),
+            b: self.b + t * (other.b - self.b),
+        }
+    }
+}
+
+impl Mix for Oklch {
+    type Scalar = DefaultScalar;
+
+    /// Mix the colors, where the hue travels along the shortest path around the hue circle.
+    fn mix(&self, other: &Self, factor: Self::Scalar) -> Self {
+        let t = factor.clamp(0.0, 1.0);
+        let hue_diff = (other.hue - self.hue).to_degrees();
+        Oklch {
+            l: self.l + t * (other.l - self.l),
+            chroma: self.chroma + t * (other.chroma - self.chroma),
+            hue: self.hue + t * hue_diff,
+        }
+    }
+}
+
+impl<S> IntoLinSrgba<S> for Oklab
+where
+    S: Component,
+{
+    fn into_lin_srgba(self) -> LinSrgba<S> {
+        self.into_lin_srgb().into_lin_srgba()
+    }
+}
+
+impl<S> IntoLinSrgba<S> for Oklch
+where
+    S: Component,
+{
+    fn into_lin_srgba(self) -> LinSrgba<S> {
+        Oklab::from(self).into_lin_srgba()
+    }
+}
+
+impl FromLinSrgba<DefaultScalar> for Oklab {
+    fn from_lin_srgba(color: LinSrgba) -> Self {
+        Oklab::from_lin_srgb(color.color)
+    }
+}
+
+impl FromLinSrgba<DefaultScalar> for Oklch {
+    fn from_lin_srgba(color: LinSrgba) -> Self {
+        Oklab::from_lin_srgba(color).into()
+    }
+}
+
+#[test]
+fn test_oklch_midpoint_lightness() {
+    use crate::color::{lerp, rgb, Srgb};
+
+    let (red, cyan) = (rgb(1.0, 0.0, 0.0), rgb(0.0, 1.0, 1.0));
+    let (red_lch, cyan_lch) = (
+        Oklch::from_lin_srgba(red.into_lin_srgba()),
+        Oklch::from_lin_srgba(cyan.into_lin_srgba()),
+    );
+
+    // White round-trips with no chroma.
+    let white = Oklab::from_lin_srgba(rgb(1.0f32, 1.0, 1.0).into_lin_srgba());
+    assert!((white.l - 1.0).abs() < 1e-3 && white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+    let red_again = Srgb::from_linear(Oklab::from(red_lch).into_lin_srgb());
+    assert!((red_again.red - 1.0).abs() < 1e-3 && red_again.green.abs() < 1e-3);
+
+    // The OKLCH midpoint has the average lightness of its ends while remaining colorful.
+    let expected_l = (red_lch.l + cyan_lch.l) / 2.0;
+    let oklch_mid = red_lch.mix(&cyan_lch, 0.5);
+    assert!((oklch_mid.l - expected_l).abs() < 1e-3);
+    assert!(oklch_mid.chroma > 0.1);
+
+    // The naive sRGB midpoint is a gray that is far darker than either end.
+    let srgb_mid = rgb(0.5, 0.5, 0.5);
+    let srgb_mid = Oklch::from_lin_srgba(srgb_mid.into_lin_srgba());
+    assert!(expected_l - srgb_mid.l > 0.1);
+    assert!(srgb_mid.chroma < 0.01);
+
+    // Interpolating in linear sRGB is better, but still loses lightness and colorfulness.
+    let lin_mid: Srgb = lerp(red, cyan, 0.5);
+    let lin_mid = Oklch::from_lin_srgba(lin_mid.into_lin_srgba());
+    assert!((oklch_mid.l - expected_l).abs() < (lin_mid.l - expected_l).abs());
+}
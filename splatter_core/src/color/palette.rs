@@ -0,0 +1,44 @@
+//! Functions for generating palettes of visually distinct colors.
+
+use crate::color::{hsv, Hsv};
+use crate::math::num_traits::Float;
+
+/// The golden angle in turns, i.e. `1.0 - 1.0 / φ` where `φ` is the golden ratio.
+///
+/// Stepping around the color wheel by this angle never revisits a hue and spreads any number of
+/// consecutive hues roughly evenly.
+pub const GOLDEN_ANGLE: f32 = 0.381_966_02;
+
+/// The hue of the `i`th color spaced by the golden angle, in turns within the range `0.0..1.0`.
+pub fn golden_angle_hue(i: usize) -> f32 {
+    // Accumulate in `f64` so that the hues of large indices remain accurate.
+    Float::fract(i as f64 * GOLDEN_ANGLE as f64) as f32
+}
+
+/// The `i`th color of a palette whose hues are spaced by the golden angle.
+///
+/// The first color is red and each following color is a distinct hue. The saturation and value
+/// are fixed at `0.65` and `0.95` respectively, producing bright colors that are easy to tell
+/// apart. The fields of the returned color may be modified for a different saturation or value.
+///
+/// ```
+/// # use splatter_core::color::palette::golden_angle;
+/// let colors: Vec<_> = (0..8).map(golden_angle).collect();
+/// ```
+pub fn golden_angle(i: usize) -> Hsv {
+    hsv(golden_angle_hue(i), 0.65, 0.95)
+}
+
+#[test]
+fn test_golden_angle_hue() {
+    assert_eq!(golden_angle_hue(0), 0.0);
+    let mut hues = [0.0; 16];
+    for (i, hue) in hues.iter_mut().enumerate() {
+        *hue = golden_angle_hue(i);
+    }
+    assert!(hues.iter().all(|h| (0.0..1.0).contains(h)));
+    // The hues of consecutive colors are spread around the whole color wheel.
+    hues.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    let max_gap = hues.windows(2).map(|w| w[1] - w[0]).fold(0.0, f32::max);
+    assert!(max_gap < 0.1);
+}
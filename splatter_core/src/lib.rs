@@ -32,6 +32,9 @@
 
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod color;
 pub mod geom;
 pub mod math;
@@ -6,6 +6,8 @@ use num_traits::{Float, NumCast, One};
 
 pub use num_traits;
 
+pub mod ease;
+
 const ONE_TURN_DEGREES_F32: f32 = 360.0;
 const ONE_TURN_DEGREES_F64: f64 = 360.0;
 
@@ -104,7 +106,8 @@ impl Mat4LookTo for glam::Mat4 {}
 /// Maps a value from an input range to an output range.
 ///
 /// Note that `map_range` doesn't clamp the output: if `val` is outside the input range, the mapped
-/// value will be outside the output range. (Use `clamp` to restrict the output, if desired.)
+/// value will be outside the output range. (Use `map_range_clamped` to restrict the output, if
+/// desired.)
 ///
 /// # Examples
 /// ```
@@ -127,13 +130,82 @@ impl Mat4LookTo for glam::Mat4 {}
 // ranges with unsigned types, etc. Would also reduce size of code generated due to all the panic
 // branches.
 pub fn map_range<X, Y>(val: X, in_min: X, in_max: X, out_min: Y, out_max: Y) -> Y
+where
+    X: NumCast,
+    Y: NumCast,
+{
+    let (t, out_min_f, out_max_f) =
+        map_range_args("map_range", val, in_min, in_max, out_min, out_max);
+    map_range_result("map_range", out_min_f + t * (out_max_f - out_min_f))
+}
+
+/// Maps a value from an input range to an output range, clamping the result to the output range.
+///
+/// # Examples
+/// ```
+/// # use splatter_core::prelude::*;
+/// assert_eq!(map_range_clamped(15, 0, 10, 0.0, 1.0), 1.0);
+/// assert_eq!(map_range_clamped(-5, 0, 10, 1.0, 0.0), 1.0);
+/// ```
+/// ```
+/// # use splatter_core::prelude::*;
+/// // Useful for mapping to an index without exceeding the bounds of a collection.
+/// let shapes = ["circle", "square", "triangle"];
+/// let index = map_range_clamped(1.2, 0.0, 1.0, 0, shapes.len() - 1);
+/// assert_eq!(shapes[index], "triangle");
+/// ```
+pub fn map_range_clamped<X, Y>(val: X, in_min: X, in_max: X, out_min: Y, out_max: Y) -> Y
+where
+    X: NumCast,
+    Y: NumCast,
+{
+    let name = "map_range_clamped";
+    let (t, out_min_f, out_max_f) = map_range_args(name, val, in_min, in_max, out_min, out_max);
+    let result = out_min_f + t * (out_max_f - out_min_f);
+    map_range_result(name, clamp(result, out_min_f, out_max_f))
+}
+
+/// Maps a value from an input range to an output range, shaping the progression between the two
+/// with the given easing function.
+///
+/// The position of `val` within the input range is clamped to `0.0..=1.0` before being passed to
+/// `ease`. See the [`ease`] module for a collection of easing functions.
+///
+/// # Examples
+/// ```
+/// # use splatter_core::prelude::*;
+/// assert_eq!(map_range_eased(5.0, 0.0, 10.0, 0.0, 1.0, ease::cubic_in), 0.125);
+/// assert_eq!(map_range_eased(20.0, 0.0, 10.0, 0.0, 1.0, ease::cubic_in), 1.0);
+/// ```
+pub fn map_range_eased<X, Y, F>(val: X, in_min: X, in_max: X, out_min: Y, out_max: Y, ease: F) -> Y
+where
+    X: NumCast,
+    Y: NumCast,
+    F: Fn(f32) -> f32,
+{
+    let name = "map_range_eased";
+    let (t, out_min_f, out_max_f) = map_range_args(name, val, in_min, in_max, out_min, out_max);
+    let eased = ease(clamp(t, 0.0, 1.0) as f32) as f64;
+    map_range_result(name, out_min_f + eased * (out_max_f - out_min_f))
+}
+
+// Casts the `map_range` arguments to `f64`, returning the position of `val` within the input
+// range along with the bounds of the output range.
+fn map_range_args<X, Y>(
+    name: &str,
+    val: X,
+    in_min: X,
+    in_max: X,
+    out_min: Y,
+    out_max: Y,
+) -> (f64, f64, f64)
 where
     X: NumCast,
     Y: NumCast,
 {
     macro_rules! unwrap_or_panic {
         ($result:expr, $arg:expr) => {
-            $result.unwrap_or_else(|| panic!("[map_range] failed to cast {} arg to `f64`", $arg))
+            $result.unwrap_or_else(|| panic!("[{}] failed to cast {} arg to `f64`", name, $arg))
         };
     }
 
@@ -143,8 +215,17 @@ where
     let out_min_f: f64 = unwrap_or_panic!(NumCast::from(out_min), "fourth");
     let out_max_f: f64 = unwrap_or_panic!(NumCast::from(out_max), "fifth");
 
-    NumCast::from((val_f - in_min_f) / (in_max_f - in_min_f) * (out_max_f - out_min_f) + out_min_f)
-        .unwrap_or_else(|| panic!("[map_range] failed to cast result to target type"))
+    let t = (val_f - in_min_f) / (in_max_f - in_min_f);
+    (t, out_min_f, out_max_f)
+}
+
+// Casts the mapped result back to the target type.
+fn map_range_result<Y>(name: &str, result: f64) -> Y
+where
+    Y: NumCast,
+{
+    NumCast::from(result)
+        .unwrap_or_else(|| panic!("[{}] failed to cast result to target type", name))
 }
 
 /// The max between two partially ordered values.
@@ -0,0 +1,200 @@
+//! Easing functions for shaping the progression of a value over the range `0.0..=1.0`.
+//!
+//! Each function maps `0.0` to `0.0` and `1.0` to `1.0`. Some functions, e.g. `elastic_out` or
+//! `back_in`, overshoot this range in between.
+//!
+//! All functions share the `Ease` signature and may be passed directly to
+//! [`map_range_eased`](crate::math::map_range_eased).
+//!
+//! ```
+//! # use splatter_core::math::{ease, map_range_eased};
+//! let y = map_range_eased(5.0, 0.0, 10.0, 0.0, 100.0, ease::quad_in);
+//! assert_eq!(y, 25.0);
+//! ```
+
+use core::f32::consts::PI;
+use num_traits::Float;
+
+/// The signature shared by all easing functions.
+pub type Ease = fn(f32) -> f32;
+
+/// No easing.
+pub fn linear(t: f32) -> f32 {
+    t
+}
+
+/// Quadratic ease, accelerating from zero velocity.
+pub fn quad_in(t: f32) -> f32 {
+    t * t
+}
+
+/// Quadratic ease, decelerating to zero velocity.
+pub fn quad_out(t: f32) -> f32 {
+    t * (2.0 - t)
+}
+
+/// Quadratic ease, accelerating until halfway then decelerating.
+pub fn quad_in_out(t: f32) -> f32 {
+    in_out(t, quad_in)
+}
+
+/// Cubic ease, accelerating from zero velocity.
+pub fn cubic_in(t: f32) -> f32 {
+    t * t * t
+}
+
+/// Cubic ease, decelerating to zero velocity.
+pub fn cubic_out(t: f32) -> f32 {
+    out(t, cubic_in)
+}
+
+/// Cubic ease, accelerating until halfway then decelerating.
+pub fn cubic_in_out(t: f32) -> f32 {
+    in_out(t, cubic_in)
+}
+
+/// Sinusoidal ease, accelerating from zero velocity.
+pub fn sine_in(t: f32) -> f32 {
+    1.0 - Float::cos(t * PI / 2.0)
+}
+
+/// Sinusoidal ease, decelerating to zero velocity.
+pub fn sine_out(t: f32) -> f32 {
+    Float::sin(t * PI / 2.0)
+}
+
+/// Sinusoidal ease, accelerating until halfway then decelerating.
+pub fn sine_in_out(t: f32) -> f32 {
+    (1.0 - Float::cos(t * PI)) / 2.0
+}
+
+/// Exponential ease, accelerating from zero velocity.
+pub fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        Float::powf(2.0, 10.0 * (t - 1.0))
+    }
+}
+
+/// Exponential ease, decelerating to zero velocity.
+pub fn expo_out(t: f32) -> f32 {
+    out(t, expo_in)
+}
+
+/// Exponential ease, accelerating until halfway then decelerating.
+pub fn expo_in_out(t: f32) -> f32 {
+    in_out(t, expo_in)
+}
+
+/// Ease that pulls back slightly before accelerating.
+pub fn back_in(t: f32) -> f32 {
+    const S: f32 = 1.70158;
+    t * t * ((S + 1.0) * t - S)
+}
+
+/// Ease that overshoots the target slightly before settling.
+pub fn back_out(t: f32) -> f32 {
+    out(t, back_in)
+}
+
+/// Ease that pulls back at the start and overshoots at the end.
+pub fn back_in_out(t: f32) -> f32 {
+    in_out(t, back_in)
+}
+
+/// Ease that oscillates with growing amplitude before snapping to the target.
+pub fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    let period = 0.3;
+    let t = t - 1.0;
+    -Float::powf(2.0, 10.0 * t) * Float::sin((t - period / 4.0) * 2.0 * PI / period)
+}
+
+/// Ease that overshoots the target and oscillates with decaying amplitude.
+pub fn elastic_out(t: f32) -> f32 {
+    out(t, elastic_in)
+}
+
+/// Ease that oscillates at both the start and the end.
+pub fn elastic_in_out(t: f32) -> f32 {
+    in_out(t, elastic_in)
+}
+
+/// Ease that bounces with growing height before reaching the target.
+pub fn bounce_in(t: f32) -> f32 {
+    out(t, bounce_out)
+}
+
+/// Ease that bounces off the target with decaying height.
+pub fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+/// Ease that bounces at both the start and the end.
+pub fn bounce_in_out(t: f32) -> f32 {
+    in_out(t, bounce_in)
+}
+
+// Reverse the given ease, e.g. turning an "in" ease into its "out" counterpart.
+fn out(t: f32, ease: Ease) -> f32 {
+    1.0 - ease(1.0 - t)
+}
+
+// Apply the given "in" ease to the first half and its reverse to the second half.
+fn in_out(t: f32, ease: Ease) -> f32 {
+    if t < 0.5 {
+        ease(t * 2.0) / 2.0
+    } else {
+        1.0 - ease((1.0 - t) * 2.0) / 2.0
+    }
+}
+
+#[test]
+fn test_ease_endpoints() {
+    let eases: &[Ease] = &[
+        linear,
+        quad_in,
+        quad_out,
+        quad_in_out,
+        cubic_in,
+        cubic_out,
+        cubic_in_out,
+        sine_in,
+        sine_out,
+        sine_in_out,
+        expo_in,
+        expo_out,
+        expo_in_out,
+        back_in,
+        back_out,
+        back_in_out,
+        elastic_in,
+        elastic_out,
+        elastic_in_out,
+        bounce_in,
+        bounce_out,
+        bounce_in_out,
+    ];
+    for ease in eases {
+        assert!(ease(0.0).abs() < 1e-2, "{}", ease(0.0));
+        assert!((ease(1.0) - 1.0).abs() < 1e-2, "{}", ease(1.0));
+    }
+    assert_eq!(quad_in_out(0.5), 0.5);
+    assert_eq!(cubic_out(0.5), 0.875);
+}
@@ -2,11 +2,12 @@
 
 pub use crate::color::named::*;
 pub use crate::color::{
-    gray, hsl, hsla, hsv, hsva, lin_srgb, lin_srgba, rgb, rgb8, rgba, rgba8, srgb, srgb8, srgba,
-    srgba8,
+    gray, hex, hsl, hsla, hsv, hsva, lin_srgb, lin_srgba, oklab, oklch, rgb, rgb8, rgba, rgba8,
+    srgb, srgb8, srgba, srgba8,
 };
 pub use crate::color::{
-    Gray, Hsl, Hsla, Hsv, Hsva, LinSrgb, LinSrgba, Rgb, Rgb8, Rgba, Rgba8, Srgb, Srgba,
+    Gray, Hsl, Hsla, Hsv, Hsva, LinSrgb, LinSrgba, Oklab, Oklch, Rgb, Rgb8, Rgba, Rgba8, Srgb,
+    Srgba,
 };
 pub use crate::geom::{self, pt2, pt3, Cuboid, Point2, Point3, Rect};
 #[allow(deprecated)]
@@ -19,8 +20,8 @@ pub use crate::glam::{
 };
 pub use crate::math::num_traits::*;
 pub use crate::math::{
-    clamp, deg_to_rad, fmod, map_range, partial_max, partial_min, rad_to_deg, rad_to_turns,
-    turns_to_rad, Mat4LookTo, Vec2Angle, Vec2Rotate,
+    clamp, deg_to_rad, ease, fmod, map_range, map_range_clamped, map_range_eased, partial_max,
+    partial_min, rad_to_deg, rad_to_turns, turns_to_rad, Mat4LookTo, Vec2Angle, Vec2Rotate,
 };
 
 // NOTE: These helper functions rely on a thread-local RNG and are currently only available via std.
@@ -4,10 +4,75 @@
 //! Helper functions include [**random_f32()**](./fn.random_f32.html),
 //! [**random_f64()**](./fn.random_f64.html) and [**random_range(min,
 //! max)**](./fn.random_range.html).
+//!
+//! By default, the helper functions draw from the thread-local RNG provided by the `rand` crate.
+//! Calling [**set_seed(seed)**](./fn.set_seed.html) switches the helpers over to a seeded PRNG
+//! for the current thread so that the same sequence of values is produced on every run.
 
 pub use self::rand::*;
 pub use rand;
 
+#[cfg(feature = "std")]
+use self::rand::rngs::SmallRng;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    // The seeded stream used by the helper functions, if any.
+    static SEEDED_RNG: RefCell<Option<SmallRng>> = const { RefCell::new(None) };
+}
+
+/// Seed the RNG used by the helper functions on the current thread.
+///
+/// After calling this, [**random**](./fn.random.html), [**random_f32**](./fn.random_f32.html),
+/// [**random_f64**](./fn.random_f64.html), [**random_range**](./fn.random_range.html) and
+/// [**random_ascii**](./fn.random_ascii.html) all draw from a `SmallRng` seeded with the given
+/// value, producing an identical sequence of values for an identical seed.
+///
+/// Calling `set_seed` again restarts the sequence from the beginning.
+///
+/// NOTE: The seeded RNG is thread-local and is currently only available with the "std" feature
+/// enabled.
+#[cfg(feature = "std")]
+pub fn set_seed(seed: u64) {
+    SEEDED_RNG.with(|rng| *rng.borrow_mut() = Some(SmallRng::seed_from_u64(seed)));
+}
+
+/// Remove any seed set via [**set_seed**](./fn.set_seed.html) on the current thread, returning
+/// the helper functions to the non-deterministic thread-local RNG.
+#[cfg(feature = "std")]
+pub fn clear_seed() {
+    SEEDED_RNG.with(|rng| *rng.borrow_mut() = None);
+}
+
+// Call the given function with the seeded RNG if one is set, or the thread-local RNG otherwise.
+#[cfg(feature = "std")]
+fn with_rng<F, T>(f: F) -> T
+where
+    F: FnOnce(&mut dyn RngCore) -> T,
+{
+    SEEDED_RNG.with(|rng| match *rng.borrow_mut() {
+        Some(ref mut rng) => f(rng),
+        None => f(&mut rand::thread_rng()),
+    })
+}
+
+/// Generate a random value of type `T`.
+///
+/// This behaves the same as `rand::random`, however draws from the seeded RNG in the case that
+/// one has been set via [**set_seed**](./fn.set_seed.html).
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random<T>() -> T
+where
+    distributions::Standard: distributions::Distribution<T>,
+{
+    with_rng(|rng| rng.gen())
+}
+
 /// A wrapper function around the `random` function that avoids the need for specifying a type in
 /// the case that it cannot be inferred. The primary purpose for this is to simplify the random API
 /// for new rust users.
@@ -16,7 +81,7 @@ pub use rand;
 /// the "std" feature enabled.
 #[cfg(feature = "std")]
 pub fn random_f32() -> f32 {
-    rand::random()
+    random()
 }
 
 /// A wrapper function around the `random` function that avoids the need for specifying a type in
@@ -27,7 +92,7 @@ pub fn random_f32() -> f32 {
 /// the "std" feature enabled.
 #[cfg(feature = "std")]
 pub fn random_f64() -> f64 {
-    rand::random()
+    random()
 }
 
 /// A function for generating a random value within the given range.
@@ -38,8 +103,8 @@ pub fn random_f64() -> f64 {
 /// If the given `min` is greater than the given `max`, they will be swapped before calling
 /// `gen_range` internally to avoid triggering a `panic!`.
 ///
-/// This calls `rand::thread_rng().gen_range(min..max)` internally, in turn using the thread-local
-/// default random number generator.
+/// This calls `gen_range(min..max)` internally, in turn using the thread-local default random
+/// number generator or the seeded RNG if one has been set via [**set_seed**](./fn.set_seed.html).
 ///
 /// NOTE: This helper function relies on a thread-local RNG and is currently only available with
 /// the "std" feature enabled.
@@ -49,7 +114,7 @@ where
     T: PartialOrd + distributions::uniform::SampleUniform,
 {
     let (min, max) = if min <= max { (min, max) } else { (max, min) };
-    rand::thread_rng().gen_range(min..max)
+    with_rng(|rng| rng.gen_range(min..max))
 }
 
 /// Generates and returns a random ascii character.
@@ -68,6 +133,27 @@ pub fn random_ascii() -> char {
                             abcdefghijklmnopqrstuvwxyz\
                             0123456789)(*&^%$#@!~. ";
 
-    let idx = rand::thread_rng().gen_range(0..ASCIISET.len());
+    let idx = with_rng(|rng| rng.gen_range(0..ASCIISET.len()));
     ASCIISET[idx] as char
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_seeded_helpers_repeat() {
+    set_seed(42);
+    let a: (f32, f64, u32, char) = (
+        random_f32(),
+        random_f64(),
+        random_range(0, 100),
+        random_ascii(),
+    );
+    set_seed(42);
+    let b: (f32, f64, u32, char) = (
+        random_f32(),
+        random_f64(),
+        random_range(0, 100),
+        random_ascii(),
+    );
+    clear_seed();
+    assert_eq!(a, b);
+}
@@ -6,7 +6,8 @@ use egui::{pos2, ClippedPrimitive, PlatformOutput};
 use egui_wgpu::renderer::ScreenDescriptor;
 use splatter::wgpu::ToTextureView;
 use splatter::{wgpu, winit::event::WindowEvent::*};
-use std::{cell::RefCell, ops::Deref, sync::Mutex, time::Duration};
+use std::sync::{Arc, Mutex};
+use std::{cell::RefCell, collections::HashMap, ops::Deref, time::Duration};
 use winit::event::MouseButton;
 use winit::keyboard::NamedKey;
 
@@ -19,6 +20,21 @@ pub struct Egui {
     context: egui::Context,
     renderer: RefCell<Renderer>,
     input: Input,
+    // Textures registered via `image`, keyed by the texture they were registered from. The
+    // texture is retained so that its id cannot be reused by another texture while registered.
+    images: HashMap<wgpu::TextureId, (wgpu::Texture, egui::TextureId)>,
+    // Ids of dropped `RegisteredTexture`s, freed at the beginning of the next frame.
+    pending_frees: PendingFrees,
+}
+
+/// A texture registered with an `Egui` via `register_texture`.
+///
+/// The texture id is freed automatically when the handle is dropped. The free is deferred until
+/// the `Egui` begins its next frame, so it is safe to drop the handle while the current frame may
+/// still refer to it.
+pub struct RegisteredTexture {
+    id: egui::TextureId,
+    pending_frees: PendingFrees,
 }
 
 /// A wrapper around all necessary state for rendering a `Egui` to a single texture (often a window
@@ -37,6 +53,8 @@ pub struct Input {
     pub raw: egui::RawInput,
     pub window_size_pixels: [u32; 2],
     pub window_scale_factor: f32,
+    /// Whether or not the window is currently hidden from view, during which input is ignored.
+    pub occluded: bool,
 }
 
 /// A wrapper around a `CtxRef` on which `begin_frame` was called.
@@ -50,6 +68,8 @@ pub struct FrameCtx<'a> {
 
 struct RepaintSignal(Mutex<splatter::app::Proxy>);
 
+type PendingFrees = Arc<Mutex<Vec<egui::TextureId>>>;
+
 impl Egui {
     /// Construct the `Egui` from its parts.
     ///
@@ -70,10 +90,14 @@ impl Egui {
         let renderer = RefCell::new(Renderer::new(device, target_format, target_msaa_samples));
         let input = Input::new(window_scale_factor, window_size_pixels);
         let context = Default::default();
+        let images = Default::default();
+        let pending_frees = Default::default();
         Self {
             renderer,
             input,
             context,
+            images,
+            pending_frees,
         }
     }
 
@@ -120,6 +144,11 @@ impl Egui {
     }
 
     /// Registers a wgpu::Texture with a egui::TextureId.
+    ///
+    /// Each call registers a new id which must be paired with a call to `free_texture` once it is
+    /// no longer needed, otherwise the texture's GPU resources are leaked. See `register_texture`
+    /// for a handle that frees the id on drop, or `image` for registering a texture once and
+    /// reusing it across frames.
     pub fn texture_from_wgpu_texture(
         &mut self,
         device: &wgpu::Device,
@@ -153,16 +182,76 @@ impl Egui {
         Ok(())
     }
 
+    /// Registers a wgpu::Texture with a egui::TextureId that is freed when the returned handle is
+    /// dropped.
+    pub fn register_texture(
+        &mut self,
+        device: &wgpu::Device,
+        texture: &wgpu::Texture,
+        texture_filter: wgpu::FilterMode,
+    ) -> RegisteredTexture {
+        let id = self.texture_from_wgpu_texture(device, texture, texture_filter);
+        let pending_frees = self.pending_frees.clone();
+        RegisteredTexture { id, pending_frees }
+    }
+
+    /// Produce an `egui::Image` widget displaying the given texture at the given size in points.
+    ///
+    /// The texture is registered with a linear filter the first time it is passed to this method,
+    /// after which the same `egui::TextureId` is reused. This makes it suitable for calling every
+    /// frame, e.g. `ui.add(egui.image(device, &texture, [w, h]))`. The texture is kept alive
+    /// until the id is freed via `free_texture`.
+    pub fn image(
+        &mut self,
+        device: &wgpu::Device,
+        texture: &wgpu::Texture,
+        size: impl Into<egui::Vec2>,
+    ) -> egui::Image<'static> {
+        let renderer = &self.renderer;
+        let (_, id) = self.images.entry(texture.id()).or_insert_with(|| {
+            let id = renderer.borrow_mut().renderer.register_native_texture(
+                device,
+                &texture.to_texture_view(),
+                wgpu::FilterMode::Linear,
+            );
+            (texture.clone(), id)
+        });
+        egui::Image::from_texture(egui::load::SizedTexture::new(*id, size))
+    }
+
+    /// Free a texture registered via `texture_from_wgpu_texture` or `image`.
+    ///
+    /// The id must not be used again after calling this.
+    pub fn free_texture(&mut self, id: egui::TextureId) {
+        self.images.retain(|_, (_, image_id)| *image_id != id);
+        self.renderer.borrow_mut().renderer.free_texture(&id);
+    }
+
     /// Draws the contents of the inner `context` to the given frame.
+    ///
+    /// The tracked `Input::window_scale_factor` is used to map points to pixels.
     pub fn draw_to_frame(&self, frame: &splatter::Frame) -> Result<(), egui_wgpu::WgpuError> {
         let mut renderer = self.renderer.borrow_mut();
-        renderer.draw_to_frame(&self.context, frame)
+        let scale_factor = self.input.window_scale_factor;
+        renderer.draw_to_frame_with_scale_factor(&self.context, frame, scale_factor)
     }
 
     fn begin_frame_inner(&mut self) {
+        self.free_pending_textures();
         self.context.begin_frame(self.input.raw.take());
     }
 
+    // Free the ids of all `RegisteredTexture`s dropped since the last frame began.
+    fn free_pending_textures(&mut self) {
+        let ids = match self.pending_frees.lock() {
+            Ok(mut pending) => std::mem::take(&mut *pending),
+            Err(_) => return,
+        };
+        for id in ids {
+            self.free_texture(id);
+        }
+    }
+
     fn end_frame_inner(&mut self) -> egui::PlatformOutput {
         let egui::FullOutput {
             shapes,
@@ -176,6 +265,26 @@ impl Egui {
     }
 }
 
+impl RegisteredTexture {
+    /// The id with which the texture is registered.
+    pub fn id(&self) -> egui::TextureId {
+        self.id
+    }
+
+    /// Produce an `egui::Image` widget displaying the texture at the given size in points.
+    pub fn image(&self, size: impl Into<egui::Vec2>) -> egui::Image<'static> {
+        egui::Image::from_texture(egui::load::SizedTexture::new(self.id, size))
+    }
+}
+
+impl Drop for RegisteredTexture {
+    fn drop(&mut self) {
+        if let Ok(mut pending) = self.pending_frees.lock() {
+            pending.push(self.id);
+        }
+    }
+}
+
 impl Input {
     /// Initialise user input and window event tracking with the given target scale factor and size
     /// in pixels.
@@ -190,6 +299,7 @@ impl Input {
             pointer_pos,
             window_scale_factor,
             window_size_pixels,
+            occluded: false,
         };
         input.raw.screen_rect = Some(input.egui_window_rect());
         input
@@ -210,6 +320,23 @@ impl Input {
                 self.raw.pixels_per_point = Some(self.window_scale_factor);
                 self.raw.screen_rect = Some(self.egui_window_rect());
             }
+            Focused(focused) => {
+                self.raw.focused = *focused;
+                self.raw.events.push(egui::Event::WindowFocused(*focused));
+                // Keys and buttons released while unfocused are never reported.
+                if !*focused {
+                    self.raw.modifiers = Default::default();
+                    self.raw.events.push(egui::Event::PointerGone);
+                }
+            }
+            Occluded(occluded) => {
+                self.occluded = *occluded;
+                if *occluded {
+                    self.raw.events.push(egui::Event::PointerGone);
+                }
+            }
+            // Ignore all user input while the window is hidden from view.
+            _ if self.occluded => {}
             MouseInput { state, button, .. } => {
                 let maybe_button = match button {
                     MouseButton::Back | MouseButton::Forward | MouseButton::Other(_) => None,
@@ -317,6 +444,9 @@ impl Renderer {
     }
 
     /// Encode a render pass for drawing the given context's texture to the given `dst_texture`.
+    ///
+    /// Pending texture updates are always uploaded, but the render pass itself is skipped if the
+    /// destination has no area or the scale factor is not a positive, finite value.
     pub fn encode_render_pass(
         &mut self,
         _context: &egui::Context,
@@ -337,6 +467,11 @@ impl Renderer {
         for (id, image_delta) in &textures.set {
             renderer.update_texture(device, queue, *id, image_delta);
         }
+        // Nothing can be drawn to a target without area, e.g. a minimized window.
+        let [w_px, h_px] = dst_size_pixels;
+        if w_px == 0 || h_px == 0 || !(dst_scale_factor > 0.0 && dst_scale_factor.is_finite()) {
+            return Ok(());
+        }
         renderer.update_buffers(device, queue, encoder, paint_jobs, &screen_descriptor);
         let mut render_pass = encoder.begin_render_pass(&egui_wgpu::wgpu::RenderPassDescriptor {
             label: Some("nannou_egui_render_pass"),
@@ -355,17 +490,39 @@ impl Renderer {
     }
 
     /// Encodes a render pass for drawing the given context's texture to the given frame.
+    ///
+    /// The scale factor is derived from the ratio of the frame's size in pixels to its size in
+    /// points. Prefer `draw_to_frame_with_scale_factor` when the window's scale factor is known.
     pub fn draw_to_frame(
         &mut self,
         context: &egui::Context,
         frame: &splatter::Frame,
+    ) -> Result<(), egui_wgpu::WgpuError> {
+        let [width_px, _] = frame.texture_size();
+        let width = frame.rect().w();
+        // A zero-width frame has no meaningful ratio, in which case nothing will be drawn anyway.
+        let scale_factor = if width > 0.0 {
+            width_px as f32 / width
+        } else {
+            1.0
+        };
+        self.draw_to_frame_with_scale_factor(context, frame, scale_factor)
+    }
+
+    /// Encodes a render pass for drawing the given context's texture to the given frame using the
+    /// given scale factor to map points to pixels.
+    ///
+    /// The render pass is skipped if the frame has no area, e.g. while the window is minimized.
+    pub fn draw_to_frame_with_scale_factor(
+        &mut self,
+        context: &egui::Context,
+        frame: &splatter::Frame,
+        scale_factor: f32,
     ) -> Result<(), egui_wgpu::WgpuError> {
         let device_queue_pair = frame.device_queue_pair();
         let device = device_queue_pair.device();
         let queue = device_queue_pair.queue();
         let size_pixels = frame.texture_size();
-        let [width_px, _] = size_pixels;
-        let scale_factor = width_px as f32 / frame.rect().w();
         let texture_view = frame.texture_view();
         let mut encoder = frame.command_encoder();
         self.encode_render_pass(
@@ -1,21 +1,202 @@
-use std::borrow::{Borrow, Cow};
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Borrow, Cow, ToOwned};
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, ops, slice};
 
 /// Types that may be used as a data channel within a mesh.
 pub trait Channel {
     /// The type contained within the channel.
     type Element;
+    /// The number of elements within the channel.
+    fn len(&self) -> usize;
+    /// Borrow the element at the given index, or `None` if the index is out of bounds.
+    fn get(&self, index: usize) -> Option<&Self::Element>;
+    /// Borrow the element at the given index, or describe the out of bounds access as an error.
+    fn get_checked(&self, index: usize) -> Result<&Self::Element, OutOfBounds> {
+        self.get(index).ok_or(OutOfBounds {
+            index,
+            len: self.len(),
+        })
+    }
+    /// Whether or not the channel contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// An iterator yielding references to each element within the channel.
+    fn iter(&self) -> Iter<'_, Self>
+    where
+        Self: Sized,
+    {
+        let range = 0..self.len();
+        Iter {
+            channel: self,
+            range,
+        }
+    }
+}
+
+/// Channels whose elements are stored contiguously in memory.
+pub trait SliceChannel: Channel {
     /// Borrow the data channel.
     fn channel(&self) -> &[Self::Element];
 }
 
 /// Types that may be used as a data channel within a mesh.
-pub trait ChannelMut: Channel {
+pub trait ChannelMut: SliceChannel {
     /// Mutably borrow the data channel.
     fn channel_mut(&mut self) -> &mut [Self::Element];
+    /// An iterator yielding mutable references to each element within the channel.
+    fn iter_mut(&mut self) -> slice::IterMut<'_, Self::Element> {
+        self.channel_mut().iter_mut()
+    }
+}
+
+/// A channel viewing every `stride`th element of a slice, starting at `offset`.
+///
+/// This allows for building meshes directly over interleaved vertex data, e.g.
+/// `[pos, color, pos, color, ..]`, without first de-interleaving each channel into its own
+/// buffer. Only complete strides are counted, so all channels over the same data have the same
+/// length.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StridedChannel<'a, T> {
+    data: &'a [T],
+    stride: usize,
+    offset: usize,
+}
+
+/// The error returned by `Channel::get_checked` when an index is out of bounds of a channel.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// The index that was accessed.
+    pub index: usize,
+    /// The length of the channel at the time of access.
+    pub len: usize,
+}
+
+/// The error returned when combining a mesh with a channel whose length differs from the number of
+/// vertices in the mesh, e.g. via `try_with_colors`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChannelLengthMismatch {
+    /// The number of raw vertices within the mesh.
+    pub vertex_count: usize,
+    /// The length of the channel that was to be combined with the mesh.
+    pub channel_len: usize,
+}
+
+/// An iterator yielding references to each element of a **Channel**.
+#[derive(Clone, Debug)]
+pub struct Iter<'a, C> {
+    channel: &'a C,
+    range: ops::Range<usize>,
+}
+
+impl<'a, T> StridedChannel<'a, T> {
+    /// View every `stride`th element of `data`, starting at `offset`.
+    ///
+    /// Element `i` of the channel refers to `data[i * stride + offset]`.
+    ///
+    /// **Panics** if `stride` is `0` or if `offset` is not less than `stride`.
+    pub fn new(data: &'a [T], stride: usize, offset: usize) -> Self {
+        assert!(stride > 0, "`stride` must be greater than 0");
+        assert!(offset < stride, "`offset` must be less than `stride`");
+        StridedChannel {
+            data,
+            stride,
+            offset,
+        }
+    }
+}
+
+impl<'a, T> Channel for StridedChannel<'a, T> {
+    type Element = T;
+    #[inline]
+    fn len(&self) -> usize {
+        self.data.len() / self.stride
+    }
+    #[inline]
+    fn get(&self, index: usize) -> Option<&Self::Element> {
+        if index < self.len() {
+            self.data.get(index * self.stride + self.offset)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds of channel with length {}",
+            self.index, self.len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBounds {}
+
+impl fmt::Display for ChannelLengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "channel length {} differs from the mesh's vertex count {}",
+            self.channel_len, self.vertex_count
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChannelLengthMismatch {}
+
+impl<'a, C> Iterator for Iter<'a, C>
+where
+    C: Channel,
+{
+    type Item = &'a C::Element;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().and_then(|i| self.channel.get(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a, C> DoubleEndedIterator for Iter<'a, C>
+where
+    C: Channel,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().and_then(|i| self.channel.get(i))
+    }
+}
+
+impl<'a, C> ExactSizeIterator for Iter<'a, C> where C: Channel {}
+
+// Implement **Channel** for a type via its **SliceChannel** implementation.
+macro_rules! impl_channel_via_slice {
+    () => {
+        #[inline]
+        fn len(&self) -> usize {
+            self.channel().len()
+        }
+        #[inline]
+        fn get(&self, index: usize) -> Option<&Self::Element> {
+            self.channel().get(index)
+        }
+    };
 }
 
 impl<'a, T> Channel for &'a [T] {
     type Element = T;
+    impl_channel_via_slice!();
+}
+
+impl<T> SliceChannel for &[T] {
     #[inline]
     fn channel(&self) -> &[Self::Element] {
         self
@@ -24,6 +205,10 @@ impl<'a, T> Channel for &'a [T] {
 
 impl<'a, T> Channel for &'a mut [T] {
     type Element = T;
+    impl_channel_via_slice!();
+}
+
+impl<T> SliceChannel for &mut [T] {
     #[inline]
     fn channel(&self) -> &[Self::Element] {
         self
@@ -37,14 +222,21 @@ impl<'a, T> ChannelMut for &'a mut [T] {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> Channel for Box<[T]> {
     type Element = T;
+    impl_channel_via_slice!();
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SliceChannel for Box<[T]> {
     #[inline]
     fn channel(&self) -> &[Self::Element] {
         &self[..]
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> ChannelMut for Box<[T]> {
     #[inline]
     fn channel_mut(&mut self) -> &mut [Self::Element] {
@@ -52,14 +244,21 @@ impl<T> ChannelMut for Box<[T]> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> Channel for Vec<T> {
     type Element = T;
+    impl_channel_via_slice!();
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SliceChannel for Vec<T> {
     #[inline]
     fn channel(&self) -> &[Self::Element] {
         &self[..]
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> ChannelMut for Vec<T> {
     #[inline]
     fn channel_mut(&mut self) -> &mut [Self::Element] {
@@ -67,22 +266,49 @@ impl<T> ChannelMut for Vec<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a, T> Channel for Cow<'a, [T]>
 where
     [T]: ToOwned,
 {
     type Element = T;
+    impl_channel_via_slice!();
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> SliceChannel for Cow<'a, [T]>
+where
+    [T]: ToOwned,
+{
     #[inline]
     fn channel(&self) -> &[Self::Element] {
         self.borrow()
     }
 }
 
+// As the elements of a `VecDeque` are not necessarily contiguous, it cannot implement
+// `SliceChannel` or `ChannelMut`. Use `VecDeque::make_contiguous` where a slice is required.
+#[cfg(feature = "alloc")]
+impl<T> Channel for VecDeque<T> {
+    type Element = T;
+    #[inline]
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+    #[inline]
+    fn get(&self, index: usize) -> Option<&Self::Element> {
+        VecDeque::get(self, index)
+    }
+}
+
 macro_rules! impl_channel_for_arrays {
     ($($N:expr)*) => {
         $(
             impl<T> Channel for [T; $N] {
                 type Element = T;
+                impl_channel_via_slice!();
+            }
+            impl<T> SliceChannel for [T; $N] {
                 #[inline]
                 fn channel(&self) -> &[Self::Element] {
                     &self[..]
@@ -0,0 +1,54 @@
+//! Iterating over the unique edges of indexed triangle meshes.
+
+use crate::{Channel, Indices};
+use alloc::{vec, vec::Vec};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// An iterator yielding each unique edge of a mesh, produced by `edges`.
+pub type Edges<I> = std::vec::IntoIter<(I, I)>;
+
+/// Produce an iterator yielding each edge of the given indexed triangle mesh exactly once.
+///
+/// Each edge is normalized so that its smaller index comes first, i.e. `(min, max)`. Edges shared
+/// by multiple triangles are yielded only once, in the order in which they are first encountered.
+/// Degenerate edges between a vertex and itself are skipped, as are any trailing indices that do
+/// not form a complete triangle.
+///
+/// This is useful for drawing wireframes without overdrawing shared edges, or as the basis for
+/// adjacency queries.
+pub fn edges<M>(mesh: &M) -> Edges<M::Index>
+where
+    M: Indices,
+    M::Index: Copy + Ord + Hash,
+{
+    let indices: Vec<M::Index> = mesh.indices().iter().copied().collect();
+    let mut seen = HashSet::new();
+    let mut edges = vec![];
+    for tri in indices.chunks_exact(3) {
+        for &(a, b) in &[(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+            if a == b {
+                continue;
+            }
+            let edge = (a.min(b), a.max(b));
+            if seen.insert(edge) {
+                edges.push(edge);
+            }
+        }
+    }
+    edges.into_iter()
+}
+
+#[test]
+fn test_edges() {
+    use crate::{from_points, with_indices};
+
+    // A quad made of two triangles sharing the edge between vertices 0 and 2, followed by a
+    // degenerate triangle and a trailing incomplete triangle.
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+    let indices = vec![0u32, 1, 2, 2, 3, 0, 1, 1, 3, 0, 1];
+    let mesh = with_indices(from_points(points), indices);
+    let edges: Vec<_> = edges(&mesh).collect();
+    assert_eq!(edges, vec![(0, 1), (1, 2), (0, 2), (2, 3), (0, 3), (1, 3)]);
+    assert_eq!(mesh.edges().count(), 6);
+}
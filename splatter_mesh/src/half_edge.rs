@@ -0,0 +1,408 @@
+//! Half-edge connectivity for traversing indexed triangle meshes.
+//!
+//! A **HalfEdgeMesh** splits each triangle edge into a directed half-edge owned by that triangle.
+//! Each half-edge knows the next half-edge around its triangle and its twin traversing the same
+//! edge in the opposite direction within the neighbouring triangle, if any. This allows for
+//! efficiently finding the faces around a vertex, the neighbours of a vertex and the boundary
+//! loops of the mesh.
+//!
+//! The half-edges of face `f` are `3 * f`, `3 * f + 1` and `3 * f + 2`, in winding order.
+
+use crate::{Channel, Indices, OutOfBounds, Points};
+use alloc::{vec, vec::Vec};
+use core::convert::TryInto;
+use std::collections::HashMap;
+use std::{error, fmt};
+
+/// An indexed triangle mesh along with the half-edge connectivity between its triangles.
+#[derive(Clone, Debug)]
+pub struct HalfEdgeMesh<P> {
+    points: Vec<P>,
+    // The origin vertex of each half-edge. Also the mesh's triangle indices.
+    origins: Vec<usize>,
+    twins: Vec<Option<usize>>,
+    // An outgoing half-edge for each vertex, preferring boundary half-edges, or `None` if the
+    // vertex is not referenced by any face.
+    outgoing: Vec<Option<usize>>,
+}
+
+/// The reasons a **HalfEdgeMesh** may fail to build from an indexed triangle mesh.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A triangle index is out of bounds of the mesh's points.
+    IndexOutOfBounds(OutOfBounds),
+    /// The number of indices is not a multiple of three.
+    IncompleteTriangle {
+        /// The number of indices.
+        len: usize,
+    },
+    /// The face at the given index references the same vertex more than once.
+    DegenerateFace(usize),
+    /// Edges, as `(min, max)` vertex pairs, that are either shared by more than two faces or
+    /// traversed in the same direction by two faces.
+    NonManifoldEdges(Vec<(usize, usize)>),
+    /// Vertices whose faces do not form a single fan, e.g. where two cones meet at their tips.
+    NonManifoldVertices(Vec<usize>),
+}
+
+impl<P> HalfEdgeMesh<P> {
+    /// Build the half-edge connectivity for the given points and triangle indices.
+    ///
+    /// Returns an error rather than producing incorrect twins if the mesh is not a manifold, in
+    /// which case all offending edges (or vertices) are reported.
+    ///
+    /// **Panics** if an index does not fit within a `usize`.
+    pub fn from_indexed<I>(points: Vec<P>, indices: I) -> Result<Self, Error>
+    where
+        I: IntoIterator,
+        I::Item: TryInto<usize>,
+    {
+        let origins: Vec<usize> = indices
+            .into_iter()
+            .map(|i| {
+                i.try_into()
+                    .unwrap_or_else(|_err| panic!("index out of range of valid usize values"))
+            })
+            .collect();
+        if !origins.len().is_multiple_of(3) {
+            let len = origins.len();
+            return Err(Error::IncompleteTriangle { len });
+        }
+        if let Some(&index) = origins.iter().find(|&&i| i >= points.len()) {
+            let len = points.len();
+            return Err(Error::IndexOutOfBounds(OutOfBounds { index, len }));
+        }
+        for (f, tri) in origins.chunks_exact(3).enumerate() {
+            if tri[0] == tri[1] || tri[1] == tri[2] || tri[2] == tri[0] {
+                return Err(Error::DegenerateFace(f));
+            }
+        }
+
+        // Pair each half-edge with the half-edge traversing the same edge in reverse.
+        let mut directed: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for h in 0..origins.len() {
+            directed
+                .entry((origins[h], origins[next(h)]))
+                .or_default()
+                .push(h);
+        }
+        let mut non_manifold_edges = vec![];
+        let mut twins = vec![None; origins.len()];
+        for (&(a, b), hs) in &directed {
+            let reverse = directed.get(&(b, a)).map(|hs| hs.len()).unwrap_or(0);
+            if hs.len() > 1 || reverse > 1 {
+                if a < b || reverse == 0 {
+                    non_manifold_edges.push((a.min(b), a.max(b)));
+                }
+            } else if reverse == 1 {
+                twins[hs[0]] = Some(directed[&(b, a)][0]);
+            }
+        }
+        if !non_manifold_edges.is_empty() {
+            non_manifold_edges.sort_unstable();
+            non_manifold_edges.dedup();
+            return Err(Error::NonManifoldEdges(non_manifold_edges));
+        }
+
+        // Prefer boundary half-edges so that walking a vertex's fan visits every face.
+        let mut outgoing = vec![None; points.len()];
+        for (h, &v) in origins.iter().enumerate() {
+            if outgoing[v].is_none() || twins[h].is_none() {
+                outgoing[v] = Some(h);
+            }
+        }
+
+        let mesh = HalfEdgeMesh {
+            points,
+            origins,
+            twins,
+            outgoing,
+        };
+
+        // Each face around a vertex owns exactly one of the vertex's outgoing half-edges.
+        let mut outgoing_counts = vec![0; mesh.points.len()];
+        for &v in &mesh.origins {
+            outgoing_counts[v] += 1;
+        }
+        let non_manifold_vertices: Vec<usize> = (0..mesh.vertex_count())
+            .filter(|&v| mesh.outgoing_half_edges(v).len() != outgoing_counts[v])
+            .collect();
+        if !non_manifold_vertices.is_empty() {
+            return Err(Error::NonManifoldVertices(non_manifold_vertices));
+        }
+
+        Ok(mesh)
+    }
+
+    /// Build the half-edge connectivity for the given indexed triangle mesh.
+    ///
+    /// See `HalfEdgeMesh::from_indexed` for details.
+    pub fn from_mesh<M>(mesh: &M) -> Result<Self, Error>
+    where
+        M: Points<Point = P> + Indices,
+        P: Clone,
+        M::Index: Clone + TryInto<usize>,
+    {
+        let points = mesh.points().iter().cloned().collect();
+        HalfEdgeMesh::from_indexed(points, mesh.indices().iter().cloned())
+    }
+
+    /// The number of vertices within the mesh.
+    pub fn vertex_count(&self) -> usize {
+        self.points.len()
+    }
+
+    /// The number of triangular faces within the mesh.
+    pub fn face_count(&self) -> usize {
+        self.origins.len() / 3
+    }
+
+    /// The number of half-edges within the mesh, three per face.
+    pub fn half_edge_count(&self) -> usize {
+        self.origins.len()
+    }
+
+    /// The vertex from which the given half-edge starts.
+    pub fn origin(&self, half_edge: usize) -> usize {
+        self.origins[half_edge]
+    }
+
+    /// The vertex at which the given half-edge ends.
+    pub fn target(&self, half_edge: usize) -> usize {
+        self.origins[next(half_edge)]
+    }
+
+    /// The face that owns the given half-edge.
+    pub fn face(&self, half_edge: usize) -> usize {
+        half_edge / 3
+    }
+
+    /// The half-edge traversing the same edge in the opposite direction, or `None` if the
+    /// half-edge lies on the boundary of the mesh.
+    pub fn twin(&self, half_edge: usize) -> Option<usize> {
+        self.twins[half_edge]
+    }
+
+    /// The half-edge following the given half-edge around its face.
+    pub fn next(&self, half_edge: usize) -> usize {
+        next(half_edge)
+    }
+
+    /// The half-edge preceding the given half-edge around its face.
+    pub fn prev(&self, half_edge: usize) -> usize {
+        prev(half_edge)
+    }
+
+    /// Whether or not the given half-edge lies on the boundary of the mesh.
+    pub fn is_boundary_half_edge(&self, half_edge: usize) -> bool {
+        self.twins[half_edge].is_none()
+    }
+
+    /// Whether or not the given vertex lies on the boundary of the mesh.
+    pub fn is_boundary_vertex(&self, vertex: usize) -> bool {
+        match self.outgoing[vertex] {
+            None => false,
+            Some(h) => self.twins[h].is_none() || self.twins[prev(h)].is_none(),
+        }
+    }
+
+    /// The three half-edges of the given face in winding order.
+    pub fn face_half_edges(&self, face: usize) -> [usize; 3] {
+        let h = face * 3;
+        [h, h + 1, h + 2]
+    }
+
+    /// The three vertices of the given face in winding order.
+    pub fn face_vertices(&self, face: usize) -> [usize; 3] {
+        self.face_half_edges(face).map(|h| self.origins[h])
+    }
+
+    /// The half-edges starting at the given vertex, in order around the vertex.
+    ///
+    /// For boundary vertices, the first half-edge lies on the boundary.
+    pub fn outgoing_half_edges(&self, vertex: usize) -> Vec<usize> {
+        let mut half_edges = vec![];
+        let start = match self.outgoing[vertex] {
+            None => return half_edges,
+            Some(h) => h,
+        };
+        let mut h = start;
+        loop {
+            half_edges.push(h);
+            h = match self.twins[prev(h)] {
+                Some(h) if h != start => h,
+                _ => break,
+            };
+        }
+        half_edges
+    }
+
+    /// The faces around the given vertex, in order around the vertex.
+    pub fn faces_around_vertex(&self, vertex: usize) -> Vec<usize> {
+        self.outgoing_half_edges(vertex)
+            .into_iter()
+            .map(|h| self.face(h))
+            .collect()
+    }
+
+    /// The vertices sharing an edge with the given vertex, in order around the vertex.
+    pub fn neighbors(&self, vertex: usize) -> Vec<usize> {
+        let half_edges = self.outgoing_half_edges(vertex);
+        let mut neighbors: Vec<usize> = half_edges.iter().map(|&h| self.target(h)).collect();
+        // The fan of a boundary vertex ends with an edge that has no outgoing half-edge.
+        if let Some(&last) = half_edges.last() {
+            if self.twins[prev(last)].is_none() {
+                neighbors.push(self.origins[prev(last)]);
+            }
+        }
+        neighbors
+    }
+
+    /// The closed loops of boundary vertices, each following the winding order of the faces
+    /// along the boundary.
+    pub fn boundary_loops(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.origins.len()];
+        let mut loops = vec![];
+        for start in 0..self.origins.len() {
+            if visited[start] || self.twins[start].is_some() {
+                continue;
+            }
+            let mut vertices = vec![];
+            let mut h = start;
+            while !visited[h] {
+                visited[h] = true;
+                vertices.push(self.origins[h]);
+                // Rotate around the target vertex until reaching its outgoing boundary half-edge.
+                h = next(h);
+                while let Some(twin) = self.twins[h] {
+                    h = next(twin);
+                }
+            }
+            loops.push(vertices);
+        }
+        loops
+    }
+}
+
+impl<P> Points for HalfEdgeMesh<P> {
+    type Point = P;
+    type Points = Vec<P>;
+    fn points(&self) -> &Self::Points {
+        &self.points
+    }
+}
+
+impl<P> Indices for HalfEdgeMesh<P> {
+    type Index = usize;
+    type Indices = Vec<usize>;
+    fn indices(&self) -> &Self::Indices {
+        &self.origins
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::IndexOutOfBounds(err) => write!(f, "{}", err),
+            Error::IncompleteTriangle { len } => {
+                write!(
+                    f,
+                    "{} indices do not describe a whole number of triangles",
+                    len
+                )
+            }
+            Error::DegenerateFace(face) => {
+                write!(f, "face {} references the same vertex more than once", face)
+            }
+            Error::NonManifoldEdges(edges) => write!(f, "non-manifold edges: {:?}", edges),
+            Error::NonManifoldVertices(vertices) => {
+                write!(f, "non-manifold vertices: {:?}", vertices)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+// The half-edge following the given half-edge around its face.
+fn next(h: usize) -> usize {
+    if h % 3 == 2 {
+        h - 2
+    } else {
+        h + 1
+    }
+}
+
+// The half-edge preceding the given half-edge around its face.
+fn prev(h: usize) -> usize {
+    if h.is_multiple_of(3) {
+        h + 2
+    } else {
+        h - 1
+    }
+}
+
+#[test]
+fn test_half_edge_mesh() {
+    use crate::{from_points, with_indices};
+
+    // A square fan of four triangles around the centre vertex 4.
+    //
+    // 3 - 2
+    // | 4 |
+    // 0 - 1
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.5, 0.5]];
+    let indices = vec![0u32, 1, 4, 1, 2, 4, 2, 3, 4, 3, 0, 4];
+    let mesh = with_indices(from_points(points.clone()), indices.clone());
+    let he = HalfEdgeMesh::from_mesh(&mesh).unwrap();
+    assert_eq!(he.face_count(), 4);
+    assert_eq!(he.face_vertices(1), [1, 2, 4]);
+
+    // Navigation.
+    assert_eq!(he.next(2), 0);
+    assert_eq!(he.prev(0), 2);
+    assert_eq!((he.origin(1), he.target(1)), (1, 4));
+    let twin = he.twin(1).unwrap();
+    assert_eq!((he.origin(twin), he.target(twin)), (4, 1));
+    assert_eq!(he.twin(0), None);
+
+    // Interior vertex.
+    assert!(!he.is_boundary_vertex(4));
+    let mut faces = he.faces_around_vertex(4);
+    faces.sort_unstable();
+    assert_eq!(faces, vec![0, 1, 2, 3]);
+    let mut neighbors = he.neighbors(4);
+    neighbors.sort_unstable();
+    assert_eq!(neighbors, vec![0, 1, 2, 3]);
+
+    // Boundary vertex.
+    assert!(he.is_boundary_vertex(1));
+    assert_eq!(he.faces_around_vertex(1), vec![1, 0]);
+    assert_eq!(he.neighbors(1), vec![2, 4, 0]);
+
+    // Boundary loops follow the winding of the faces.
+    assert_eq!(he.boundary_loops(), vec![vec![0, 1, 2, 3]]);
+
+    // Removing a face opens the fan.
+    let open = HalfEdgeMesh::from_indexed(points.clone(), indices[..9].to_vec()).unwrap();
+    assert!(open.is_boundary_vertex(4));
+    assert_eq!(open.faces_around_vertex(4), vec![0, 1, 2]);
+    assert_eq!(open.boundary_loops(), vec![vec![0, 1, 2, 3, 4]]);
+
+    // Edges shared by more than two faces are non-manifold.
+    let mut non_manifold = indices.clone();
+    non_manifold.extend_from_slice(&[1, 0, 3, 0, 1, 2]);
+    let err = HalfEdgeMesh::from_indexed(points.clone(), non_manifold).unwrap_err();
+    assert_eq!(err, Error::NonManifoldEdges(vec![(0, 1), (1, 2)]));
+
+    // Two fans meeting only at vertex 4 form a non-manifold vertex.
+    let bowtie = vec![0u32, 1, 4, 2, 3, 4];
+    let err = HalfEdgeMesh::from_indexed(points.clone(), bowtie).unwrap_err();
+    assert_eq!(err, Error::NonManifoldVertices(vec![4]));
+
+    let err = HalfEdgeMesh::from_indexed(points, vec![0u32, 1, 5]).unwrap_err();
+    assert_eq!(
+        err,
+        Error::IndexOutOfBounds(OutOfBounds { index: 5, len: 5 })
+    );
+}
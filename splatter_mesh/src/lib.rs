@@ -1,17 +1,85 @@
 //! An API for composing **Mesh**s. **Mesh**s may be composed of different sets of channels
 //! including position, color, texture-coordinate and normals. Note that this is quite a low-level
 //! representation. For a higher-level, graphics-related mesh API, see the `draw` module.
-
+//!
+//! ## Crate `[features]`
+//!
+//! - `std`: Enabled by default, enables the Rust std library along with the mesh processing
+//!   modules that depend on it, e.g. `edges`, `half_edge`, `simplify` and `winding`.
+//! - `alloc`: Enables the `Vec`, `Box` and `Cow` channel and mesh implementations without
+//!   requiring `std`. Implied by `std`.
+//! - `libm`: provides core math support via `splatter_core` in the case that `std` is not
+//!   enabled. This feature must be enabled if `std` is disabled.
+//! - `serde1`: enables serde serialization/deserialization of the vertex types.
+//! - `rayon`: enables the `par` module for iterating over the vertices and triangles of large
+//!   meshes in parallel.
+//!
+//! For compatibility with a `#![no_std]` environment be sure to disable default features (i.e.
+//! `default-features = false`) and enable the `libm` feature, along with `alloc` if available.
+//! The mesh traits, the mesh types and the array and slice channels remain available.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
 use core::cell::{Ref, RefMut};
 use core::cmp;
 use core::convert::{TryFrom, TryInto};
+use core::fmt;
+#[cfg(feature = "std")]
+use core::hash::Hash;
 use core::ops::{self, Deref, DerefMut};
 use splatter_core::geom;
 
 pub mod channel;
+#[cfg(feature = "std")]
+pub mod edges;
+#[cfg(feature = "std")]
+pub mod half_edge;
+#[cfg(feature = "rayon")]
+pub mod par;
+#[cfg(feature = "alloc")]
+pub mod shape;
+#[cfg(feature = "std")]
+pub mod simplify;
+#[cfg(feature = "std")]
+pub mod subdivide;
+pub mod summary;
+pub mod transform;
+#[cfg(feature = "alloc")]
+pub mod unweld;
+#[cfg(feature = "std")]
+pub mod uv;
 pub mod vertex;
-
-pub use self::channel::{Channel, ChannelMut};
+#[cfg(feature = "std")]
+pub mod winding;
+
+pub use self::channel::{
+    Channel, ChannelLengthMismatch, ChannelMut, OutOfBounds, SliceChannel, StridedChannel,
+};
+#[cfg(feature = "std")]
+pub use self::edges::{edges, Edges};
+#[cfg(feature = "std")]
+pub use self::half_edge::HalfEdgeMesh;
+#[cfg(feature = "rayon")]
+pub use self::par::{par_triangles, par_vertices};
+#[cfg(feature = "std")]
+pub use self::simplify::simplify;
+#[cfg(feature = "std")]
+pub use self::subdivide::loop_subdivide;
+pub use self::summary::{summary, ChannelLens, MeshSummary};
+pub use self::transform::{transform_mesh, transform_mesh_with_normals};
+#[cfg(feature = "alloc")]
+pub use self::unweld::unweld;
+#[cfg(feature = "std")]
+pub use self::uv::{project_uvs_box, project_uvs_planar, project_uvs_spherical};
+#[cfg(feature = "std")]
+pub use self::winding::{fix_winding, is_consistently_wound};
 
 // Traits describing meshes with access to certain channels.
 
@@ -21,6 +89,15 @@ pub trait GetVertex<I> {
     type Vertex;
     /// Create a vertex containing all channel properties for the given index.
     fn get_vertex(&self, index: I) -> Option<Self::Vertex>;
+
+    /// Create a vertex containing all channel properties for the given index, describing the
+    /// channel that the index is out of bounds of upon failure.
+    ///
+    /// The error is `None` if the cause is unknown. By default, this calls `get_vertex` and so
+    /// never describes the cause. The meshes within this crate describe it via `OutOfBounds`.
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        self.get_vertex(index).ok_or(None)
+    }
 }
 
 /// All meshes must contain at least one vertex channel.
@@ -73,6 +150,42 @@ pub trait Normals {
     fn normals(&self) -> &Self::Normals;
 }
 
+/// Meshes whose vertex points may be mutated in place.
+pub trait PointsMut: Points {
+    /// Mutably borrow the points of the vertex channel.
+    fn points_mut(&mut self) -> &mut [Self::Point];
+}
+
+/// Meshes whose vertex normals may be mutated in place.
+pub trait NormalsMut: Normals {
+    /// Mutably borrow the normals of the normal channel.
+    fn normals_mut(&mut self) -> &mut [Self::Normal];
+}
+
+/// Meshes that contain a channel of polygonal faces, each described by a sequence of indices.
+///
+/// Unlike the **Indices** channel, faces may contain any number of indices. This allows for
+/// preserving the original face structure of a mesh (e.g. quads or n-gons) while a triangulated
+/// **Indices** channel may be produced via `triangulate`.
+pub trait Faces {
+    /// The type used to index into the vertex buffer.
+    type Index;
+    /// The type used to represent a single face.
+    type Face: Face<Index = Self::Index>;
+    /// The channel type containing faces.
+    type Faces: Channel<Element = Self::Face>;
+    /// Borrow the face channel from the mesh.
+    fn faces(&self) -> &Self::Faces;
+}
+
+/// Types that may describe a single polygonal face via a sequence of vertex indices.
+pub trait Face {
+    /// The type used to index into the vertex buffer.
+    type Index;
+    /// Borrow the indices of the face's vertices in order.
+    fn indices(&self) -> &[Self::Index];
+}
+
 /// Meshes that can push vertices of type **V** while keeping all non-index channels the same
 /// length before and after the push.
 pub trait PushVertex<V> {
@@ -83,6 +196,19 @@ pub trait PushVertex<V> {
     fn push_vertex(&mut self, vertex: V);
 }
 
+/// Meshes that can remove their oldest vertex of type **V** while keeping all non-index channels
+/// the same length before and after the removal.
+///
+/// This is useful for meshes with a fixed-size window of recent vertices, e.g. a trail, where new
+/// vertices are pushed via **PushVertex** and the oldest are popped from the front.
+pub trait PopFrontVertex<V> {
+    /// Remove the first vertex from the mesh, returning it or `None` if the mesh is empty.
+    ///
+    /// Implementation requires that all non-index channels maintain the same length before and
+    /// after a call to this method.
+    fn pop_front_vertex(&mut self) -> Option<V>;
+}
+
 /// Meshes that contain an **Indices** channel and can push new indices to it.
 pub trait PushIndex {
     /// The inner index type.
@@ -130,12 +256,68 @@ pub trait ExtendFromSlice<'a> {
     fn extend_from_slice(&mut self, slice: Self::Slice);
 }
 
+/// Meshes that may be extended from named slices of data for each of their channels.
+///
+/// Unlike `ExtendFromSlice`, the slices are named via a `ChannelSlices` rather than nested in the
+/// order in which the mesh's adaptors were applied. The set of named slices must match the mesh's
+/// set of channels exactly, otherwise the mesh will not implement this trait for them.
+pub trait ExtendFromChannels<S> {
+    /// Extend every channel of the mesh with its corresponding slice.
+    ///
+    /// Indices and faces are appended as they are, without being rebased.
+    ///
+    /// Returns an error and leaves the mesh unchanged if the length of the colors, texture
+    /// coordinates or normals slice differs from that of the points slice.
+    fn extend_from_channels(&mut self, channels: S) -> Result<(), ChannelLengthMismatch>;
+}
+
+/// Meshes that can report the length of each of their channels, e.g. for `summary`.
+pub trait ChannelLengths {
+    /// The length of each channel within the mesh, or `None` for absent channels.
+    fn channel_lens(&self) -> ChannelLens;
+}
+
+/// Meshes that may be extended with all channels of another mesh of the same type.
+///
+/// As both meshes share the same type, they are guaranteed to carry the same set of channels.
+pub trait Append: Sized {
+    /// Append all vertices of `other` to the mesh, rebasing the indices of `other` by the number
+    /// of vertices in the mesh.
+    ///
+    /// Returns an error and leaves the mesh unchanged if a rebased index cannot be represented by
+    /// the mesh's index type.
+    fn append(&mut self, other: Self) -> Result<(), IndexOverflow>;
+}
+
+/// The error returned by `Append::append` and `concat` when a rebased index does not fit within
+/// the mesh's index type, e.g. a `u16` index beyond `65535`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IndexOverflow {
+    /// The rebased index.
+    pub index: usize,
+}
+
+/// Named slices of data for each channel of a mesh, used to extend the mesh via
+/// `ExtendFromChannels`.
+///
+/// Begin with `ChannelSlices::new` and name a slice for each of the mesh's other channels, e.g.
+/// `ChannelSlices::new(&points).normals(&normals).indices(&indices)`.
+#[derive(Debug)]
+pub struct ChannelSlices<'a, P, I = (), C = (), T = (), N = (), F = ()> {
+    points: &'a [P],
+    indices: &'a [I],
+    colors: &'a [C],
+    tex_coords: &'a [T],
+    normals: &'a [N],
+    faces: &'a [F],
+}
+
 // Mesh types.
 
 /// The base mesh type with only a single vertex channel.
 ///
-/// Extra channels can be added to the mesh via the `WithIndices`, `WithColors`, `WithTexCoords`
-/// and `WithNormals` adaptor types.
+/// Extra channels can be added to the mesh via the `WithIndices`, `WithColors`, `WithTexCoords`,
+/// `WithNormals` and `WithFaces` adaptor types.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct MeshPoints<P> {
     points: P,
@@ -169,6 +351,45 @@ pub struct WithNormals<M, N> {
     normals: N,
 }
 
+/// A `Mesh` type with an added channel containing polygonal faces.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WithFaces<M, F> {
+    mesh: M,
+    faces: F,
+}
+
+// **Face** implementations.
+
+impl<I> Face for &[I] {
+    type Index = I;
+    fn indices(&self) -> &[Self::Index] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Face for Box<[I]> {
+    type Index = I;
+    fn indices(&self) -> &[Self::Index] {
+        &self[..]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Face for Vec<I> {
+    type Index = I;
+    fn indices(&self) -> &[Self::Index] {
+        &self[..]
+    }
+}
+
+impl<I, const N: usize> Face for [I; N] {
+    type Index = I;
+    fn indices(&self) -> &[Self::Index] {
+        &self[..]
+    }
+}
+
 // **GetVertex** implementations.
 
 impl<'a, M, I> GetVertex<I> for &'a M
@@ -179,6 +400,9 @@ where
     fn get_vertex(&self, index: I) -> Option<Self::Vertex> {
         (**self).get_vertex(index)
     }
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        (**self).get_vertex_checked(index)
+    }
 }
 
 impl<'a, M, I> GetVertex<I> for &'a mut M
@@ -189,6 +413,9 @@ where
     fn get_vertex(&self, index: I) -> Option<Self::Vertex> {
         (**self).get_vertex(index)
     }
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        (**self).get_vertex_checked(index)
+    }
 }
 
 impl<'a, M, I> GetVertex<I> for Ref<'a, M>
@@ -199,6 +426,9 @@ where
     fn get_vertex(&self, index: I) -> Option<Self::Vertex> {
         (**self).get_vertex(index)
     }
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        (**self).get_vertex_checked(index)
+    }
 }
 
 impl<'a, M, I> GetVertex<I> for RefMut<'a, M>
@@ -209,6 +439,9 @@ where
     fn get_vertex(&self, index: I) -> Option<Self::Vertex> {
         (**self).get_vertex(index)
     }
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        (**self).get_vertex_checked(index)
+    }
 }
 
 impl<P, I> GetVertex<I> for MeshPoints<P>
@@ -222,7 +455,13 @@ where
         let index = index
             .try_into()
             .unwrap_or_else(|_err| panic!("index out of range of valid `usize` values"));
-        self.points.channel().get(index).cloned()
+        self.points.get(index).cloned()
+    }
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        let index = index
+            .try_into()
+            .unwrap_or_else(|_err| panic!("index out of range of valid `usize` values"));
+        self.points.get_checked(index).cloned().map_err(Some)
     }
 }
 
@@ -234,6 +473,9 @@ where
     fn get_vertex(&self, index: Ix) -> Option<Self::Vertex> {
         self.mesh.get_vertex(index)
     }
+    fn get_vertex_checked(&self, index: Ix) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        self.mesh.get_vertex_checked(index)
+    }
 }
 
 impl<M, C, I> GetVertex<I> for WithColors<M, C>
@@ -249,12 +491,20 @@ where
             let index: usize = index
                 .try_into()
                 .unwrap_or_else(|_err| panic!("index out of range of valid usize values"));
-            self.colors.channel().get(index).map(|color: &C::Element| {
+            self.colors.get(index).map(|color: &C::Element| {
                 let color = color.clone();
                 vertex::WithColor { vertex, color }
             })
         })
     }
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        let vertex = self.mesh.get_vertex_checked(index)?;
+        let index: usize = index
+            .try_into()
+            .unwrap_or_else(|_err| panic!("index out of range of valid usize values"));
+        let color = self.colors.get_checked(index).map_err(Some)?.clone();
+        Ok(vertex::WithColor { vertex, color })
+    }
 }
 
 impl<M, T, I> GetVertex<I> for WithTexCoords<M, T>
@@ -270,12 +520,20 @@ where
             let index: usize = index
                 .try_into()
                 .unwrap_or_else(|_err| panic!("index out of range of valid usize values"));
-            self.tex_coords.channel().get(index).map(|tex_coords| {
+            self.tex_coords.get(index).map(|tex_coords| {
                 let tex_coords = tex_coords.clone();
                 vertex::WithTexCoords { vertex, tex_coords }
             })
         })
     }
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        let vertex = self.mesh.get_vertex_checked(index)?;
+        let index: usize = index
+            .try_into()
+            .unwrap_or_else(|_err| panic!("index out of range of valid usize values"));
+        let tex_coords = self.tex_coords.get_checked(index).map_err(Some)?.clone();
+        Ok(vertex::WithTexCoords { vertex, tex_coords })
+    }
 }
 
 impl<M, N, I> GetVertex<I> for WithNormals<M, N>
@@ -291,12 +549,33 @@ where
             let index: usize = index
                 .try_into()
                 .unwrap_or_else(|_err| panic!("index out of range of valid usize values"));
-            self.normals.channel().get(index).map(|normal| {
+            self.normals.get(index).map(|normal| {
                 let normal = normal.clone();
                 vertex::WithNormal { vertex, normal }
             })
         })
     }
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        let vertex = self.mesh.get_vertex_checked(index)?;
+        let index: usize = index
+            .try_into()
+            .unwrap_or_else(|_err| panic!("index out of range of valid usize values"));
+        let normal = self.normals.get_checked(index).map_err(Some)?.clone();
+        Ok(vertex::WithNormal { vertex, normal })
+    }
+}
+
+impl<M, F, I> GetVertex<I> for WithFaces<M, F>
+where
+    M: GetVertex<I>,
+{
+    type Vertex = M::Vertex;
+    fn get_vertex(&self, index: I) -> Option<Self::Vertex> {
+        self.mesh.get_vertex(index)
+    }
+    fn get_vertex_checked(&self, index: I) -> Result<Self::Vertex, Option<OutOfBounds>> {
+        self.mesh.get_vertex_checked(index)
+    }
 }
 
 // **Points** implementations.
@@ -400,6 +679,91 @@ where
     }
 }
 
+impl<M, F> Points for WithFaces<M, F>
+where
+    M: Points,
+{
+    type Point = M::Point;
+    type Points = M::Points;
+    fn points(&self) -> &Self::Points {
+        self.mesh.points()
+    }
+}
+
+// **PointsMut** implementations.
+
+impl<P> PointsMut for MeshPoints<P>
+where
+    P: ChannelMut,
+{
+    fn points_mut(&mut self) -> &mut [Self::Point] {
+        self.points.channel_mut()
+    }
+}
+
+impl<'a, M> PointsMut for &'a mut M
+where
+    M: PointsMut,
+{
+    fn points_mut(&mut self) -> &mut [Self::Point] {
+        (**self).points_mut()
+    }
+}
+
+impl<'a, M> PointsMut for RefMut<'a, M>
+where
+    M: PointsMut,
+{
+    fn points_mut(&mut self) -> &mut [Self::Point] {
+        (**self).points_mut()
+    }
+}
+
+impl<M, I> PointsMut for WithIndices<M, I>
+where
+    M: PointsMut,
+{
+    fn points_mut(&mut self) -> &mut [Self::Point] {
+        self.mesh.points_mut()
+    }
+}
+
+impl<M, C> PointsMut for WithColors<M, C>
+where
+    M: PointsMut,
+{
+    fn points_mut(&mut self) -> &mut [Self::Point] {
+        self.mesh.points_mut()
+    }
+}
+
+impl<M, T> PointsMut for WithTexCoords<M, T>
+where
+    M: PointsMut,
+{
+    fn points_mut(&mut self) -> &mut [Self::Point] {
+        self.mesh.points_mut()
+    }
+}
+
+impl<M, N> PointsMut for WithNormals<M, N>
+where
+    M: PointsMut,
+{
+    fn points_mut(&mut self) -> &mut [Self::Point] {
+        self.mesh.points_mut()
+    }
+}
+
+impl<M, F> PointsMut for WithFaces<M, F>
+where
+    M: PointsMut,
+{
+    fn points_mut(&mut self) -> &mut [Self::Point] {
+        self.mesh.points_mut()
+    }
+}
+
 // **Indices** implementations.
 
 impl<M, I> Indices for WithIndices<M, I>
@@ -490,6 +854,17 @@ where
     }
 }
 
+impl<M, F> Indices for WithFaces<M, F>
+where
+    M: Indices,
+{
+    type Index = M::Index;
+    type Indices = M::Indices;
+    fn indices(&self) -> &Self::Indices {
+        self.mesh.indices()
+    }
+}
+
 // **Colors** implementations.
 
 impl<M, C> Colors for WithColors<M, C>
@@ -580,6 +955,17 @@ where
     }
 }
 
+impl<M, F> Colors for WithFaces<M, F>
+where
+    M: Colors,
+{
+    type Color = M::Color;
+    type Colors = M::Colors;
+    fn colors(&self) -> &Self::Colors {
+        self.mesh.colors()
+    }
+}
+
 // **TexCoords** implementations.
 
 impl<M, T> TexCoords for WithTexCoords<M, T>
@@ -670,6 +1056,17 @@ where
     }
 }
 
+impl<M, F> TexCoords for WithFaces<M, F>
+where
+    M: TexCoords,
+{
+    type TexCoord = M::TexCoord;
+    type TexCoords = M::TexCoords;
+    fn tex_coords(&self) -> &Self::TexCoords {
+        self.mesh.tex_coords()
+    }
+}
+
 // **Normals** implementations.
 
 impl<M, N> Normals for WithNormals<M, N>
@@ -761,126 +1158,437 @@ where
     }
 }
 
-// PushVertex implementations for each mesh type where the channels are **Vec**s.
+impl<M, F> Normals for WithFaces<M, F>
+where
+    M: Normals,
+{
+    type Normal = M::Normal;
+    type Normals = M::Normals;
+    fn normals(&self) -> &Self::Normals {
+        self.mesh.normals()
+    }
+}
 
-impl<'a, M, V> PushVertex<V> for &'a mut M
+// **NormalsMut** implementations.
+
+impl<M, N> NormalsMut for WithNormals<M, N>
 where
-    M: PushVertex<V>,
+    M: Points,
+    N: ChannelMut,
 {
-    fn push_vertex(&mut self, v: V) {
-        (**self).push_vertex(v)
+    fn normals_mut(&mut self) -> &mut [Self::Normal] {
+        self.normals.channel_mut()
     }
 }
 
-impl<'a, M, V> PushVertex<V> for RefMut<'a, M>
+impl<'a, M> NormalsMut for &'a mut M
 where
-    M: PushVertex<V>,
+    M: NormalsMut,
 {
-    fn push_vertex(&mut self, v: V) {
-        (**self).push_vertex(v)
+    fn normals_mut(&mut self) -> &mut [Self::Normal] {
+        (**self).normals_mut()
     }
 }
 
-impl<V> PushVertex<V> for MeshPoints<Vec<V>> {
-    fn push_vertex(&mut self, v: V) {
-        self.points.push(v);
+impl<'a, M> NormalsMut for RefMut<'a, M>
+where
+    M: NormalsMut,
+{
+    fn normals_mut(&mut self) -> &mut [Self::Normal] {
+        (**self).normals_mut()
     }
 }
 
-impl<M, I, V> PushVertex<V> for WithIndices<M, Vec<I>>
+impl<M, I> NormalsMut for WithIndices<M, I>
 where
-    M: PushVertex<V>,
+    M: NormalsMut,
 {
-    fn push_vertex(&mut self, v: V) {
-        self.mesh.push_vertex(v);
+    fn normals_mut(&mut self) -> &mut [Self::Normal] {
+        self.mesh.normals_mut()
     }
 }
 
-impl<M, V, C> PushVertex<vertex::WithColor<V, C>> for WithColors<M, Vec<C>>
+impl<M, C> NormalsMut for WithColors<M, C>
 where
-    M: PushVertex<V>,
+    M: NormalsMut,
 {
-    fn push_vertex(&mut self, v: vertex::WithColor<V, C>) {
-        let vertex::WithColor { vertex, color } = v;
-        self.colors.push(color);
-        self.mesh.push_vertex(vertex);
+    fn normals_mut(&mut self) -> &mut [Self::Normal] {
+        self.mesh.normals_mut()
     }
 }
 
-impl<M, V, T> PushVertex<vertex::WithTexCoords<V, T>> for WithTexCoords<M, Vec<T>>
+impl<M, T> NormalsMut for WithTexCoords<M, T>
 where
-    M: PushVertex<V>,
+    M: NormalsMut,
 {
-    fn push_vertex(&mut self, v: vertex::WithTexCoords<V, T>) {
-        let vertex::WithTexCoords { vertex, tex_coords } = v;
-        self.tex_coords.push(tex_coords);
-        self.mesh.push_vertex(vertex);
+    fn normals_mut(&mut self) -> &mut [Self::Normal] {
+        self.mesh.normals_mut()
     }
 }
 
-impl<M, V, N> PushVertex<vertex::WithNormal<V, N>> for WithNormals<M, Vec<N>>
+impl<M, F> NormalsMut for WithFaces<M, F>
 where
-    M: PushVertex<V>,
+    M: NormalsMut,
 {
-    fn push_vertex(&mut self, v: vertex::WithNormal<V, N>) {
-        let vertex::WithNormal { vertex, normal } = v;
-        self.normals.push(normal);
-        self.mesh.push_vertex(vertex);
+    fn normals_mut(&mut self) -> &mut [Self::Normal] {
+        self.mesh.normals_mut()
     }
 }
 
-// PushIndex implementations for meshes.
+// **Faces** implementations.
 
-impl<'a, M> PushIndex for &'a mut M
+impl<M, F> Faces for WithFaces<M, F>
 where
-    M: PushIndex,
+    F: Channel,
+    F::Element: Face,
 {
-    type Index = M::Index;
-    fn push_index(&mut self, index: Self::Index) {
-        (**self).push_index(index);
-    }
-    fn extend_indices<I>(&mut self, indices: I)
-    where
-        I: IntoIterator<Item = Self::Index>,
-    {
-        (**self).extend_indices(indices);
+    type Index = <F::Element as Face>::Index;
+    type Face = F::Element;
+    type Faces = F;
+    fn faces(&self) -> &Self::Faces {
+        &self.faces
     }
 }
 
-impl<'a, M> PushIndex for RefMut<'a, M>
+impl<M> Faces for &M
 where
-    M: PushIndex,
+    M: Faces,
 {
     type Index = M::Index;
-    fn push_index(&mut self, index: Self::Index) {
-        (**self).push_index(index);
-    }
-    fn extend_indices<I>(&mut self, indices: I)
-    where
-        I: IntoIterator<Item = Self::Index>,
-    {
-        (**self).extend_indices(indices);
+    type Face = M::Face;
+    type Faces = M::Faces;
+    fn faces(&self) -> &Self::Faces {
+        (**self).faces()
     }
 }
 
-impl<M, I> PushIndex for WithIndices<M, Vec<I>> {
-    type Index = I;
-
-    fn push_index(&mut self, index: Self::Index) {
-        self.indices.push(index);
+impl<M> Faces for &mut M
+where
+    M: Faces,
+{
+    type Index = M::Index;
+    type Face = M::Face;
+    type Faces = M::Faces;
+    fn faces(&self) -> &Self::Faces {
+        (**self).faces()
     }
+}
 
-    fn extend_indices<It>(&mut self, indices: It)
-    where
-        It: IntoIterator<Item = Self::Index>,
-    {
-        self.indices.extend(indices);
+impl<'a, M> Faces for Ref<'a, M>
+where
+    M: Faces,
+{
+    type Index = M::Index;
+    type Face = M::Face;
+    type Faces = M::Faces;
+    fn faces(&self) -> &Self::Faces {
+        (**self).faces()
     }
 }
 
-impl<M, C> PushIndex for WithColors<M, C>
+impl<'a, M> Faces for RefMut<'a, M>
 where
-    M: PushIndex,
+    M: Faces,
+{
+    type Index = M::Index;
+    type Face = M::Face;
+    type Faces = M::Faces;
+    fn faces(&self) -> &Self::Faces {
+        (**self).faces()
+    }
+}
+
+impl<M, I> Faces for WithIndices<M, I>
+where
+    M: Faces,
+{
+    type Index = M::Index;
+    type Face = M::Face;
+    type Faces = M::Faces;
+    fn faces(&self) -> &Self::Faces {
+        self.mesh.faces()
+    }
+}
+
+impl<M, C> Faces for WithColors<M, C>
+where
+    M: Faces,
+{
+    type Index = M::Index;
+    type Face = M::Face;
+    type Faces = M::Faces;
+    fn faces(&self) -> &Self::Faces {
+        self.mesh.faces()
+    }
+}
+
+impl<M, T> Faces for WithTexCoords<M, T>
+where
+    M: Faces,
+{
+    type Index = M::Index;
+    type Face = M::Face;
+    type Faces = M::Faces;
+    fn faces(&self) -> &Self::Faces {
+        self.mesh.faces()
+    }
+}
+
+impl<M, N> Faces for WithNormals<M, N>
+where
+    M: Faces,
+{
+    type Index = M::Index;
+    type Face = M::Face;
+    type Faces = M::Faces;
+    fn faces(&self) -> &Self::Faces {
+        self.mesh.faces()
+    }
+}
+
+// PushVertex implementations for each mesh type where the channels are **Vec**s.
+
+impl<'a, M, V> PushVertex<V> for &'a mut M
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: V) {
+        (**self).push_vertex(v)
+    }
+}
+
+impl<'a, M, V> PushVertex<V> for RefMut<'a, M>
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: V) {
+        (**self).push_vertex(v)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> PushVertex<V> for MeshPoints<Vec<V>> {
+    fn push_vertex(&mut self, v: V) {
+        self.points.push(v);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, I, V> PushVertex<V> for WithIndices<M, Vec<I>>
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: V) {
+        self.mesh.push_vertex(v);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, V, C> PushVertex<vertex::WithColor<V, C>> for WithColors<M, Vec<C>>
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: vertex::WithColor<V, C>) {
+        let vertex::WithColor { vertex, color } = v;
+        self.colors.push(color);
+        self.mesh.push_vertex(vertex);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, V, T> PushVertex<vertex::WithTexCoords<V, T>> for WithTexCoords<M, Vec<T>>
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: vertex::WithTexCoords<V, T>) {
+        let vertex::WithTexCoords { vertex, tex_coords } = v;
+        self.tex_coords.push(tex_coords);
+        self.mesh.push_vertex(vertex);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, V, N> PushVertex<vertex::WithNormal<V, N>> for WithNormals<M, Vec<N>>
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: vertex::WithNormal<V, N>) {
+        let vertex::WithNormal { vertex, normal } = v;
+        self.normals.push(normal);
+        self.mesh.push_vertex(vertex);
+    }
+}
+
+impl<M, F, V> PushVertex<V> for WithFaces<M, F>
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: V) {
+        self.mesh.push_vertex(v);
+    }
+}
+
+// PushVertex implementations for each mesh type where the channels are **VecDeque**s.
+
+#[cfg(feature = "alloc")]
+impl<V> PushVertex<V> for MeshPoints<VecDeque<V>> {
+    fn push_vertex(&mut self, v: V) {
+        self.points.push_back(v);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, V, C> PushVertex<vertex::WithColor<V, C>> for WithColors<M, VecDeque<C>>
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: vertex::WithColor<V, C>) {
+        let vertex::WithColor { vertex, color } = v;
+        self.colors.push_back(color);
+        self.mesh.push_vertex(vertex);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, V, T> PushVertex<vertex::WithTexCoords<V, T>> for WithTexCoords<M, VecDeque<T>>
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: vertex::WithTexCoords<V, T>) {
+        let vertex::WithTexCoords { vertex, tex_coords } = v;
+        self.tex_coords.push_back(tex_coords);
+        self.mesh.push_vertex(vertex);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, V, N> PushVertex<vertex::WithNormal<V, N>> for WithNormals<M, VecDeque<N>>
+where
+    M: PushVertex<V>,
+{
+    fn push_vertex(&mut self, v: vertex::WithNormal<V, N>) {
+        let vertex::WithNormal { vertex, normal } = v;
+        self.normals.push_back(normal);
+        self.mesh.push_vertex(vertex);
+    }
+}
+
+// PopFrontVertex implementations for each mesh type where the channels are **VecDeque**s.
+
+impl<'a, M, V> PopFrontVertex<V> for &'a mut M
+where
+    M: PopFrontVertex<V>,
+{
+    fn pop_front_vertex(&mut self) -> Option<V> {
+        (**self).pop_front_vertex()
+    }
+}
+
+impl<'a, M, V> PopFrontVertex<V> for RefMut<'a, M>
+where
+    M: PopFrontVertex<V>,
+{
+    fn pop_front_vertex(&mut self) -> Option<V> {
+        (**self).pop_front_vertex()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> PopFrontVertex<V> for MeshPoints<VecDeque<V>> {
+    fn pop_front_vertex(&mut self) -> Option<V> {
+        self.points.pop_front()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, V, C> PopFrontVertex<vertex::WithColor<V, C>> for WithColors<M, VecDeque<C>>
+where
+    M: PopFrontVertex<V>,
+{
+    fn pop_front_vertex(&mut self) -> Option<vertex::WithColor<V, C>> {
+        let vertex = self.mesh.pop_front_vertex()?;
+        let color = self.colors.pop_front()?;
+        Some(vertex::WithColor { vertex, color })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, V, T> PopFrontVertex<vertex::WithTexCoords<V, T>> for WithTexCoords<M, VecDeque<T>>
+where
+    M: PopFrontVertex<V>,
+{
+    fn pop_front_vertex(&mut self) -> Option<vertex::WithTexCoords<V, T>> {
+        let vertex = self.mesh.pop_front_vertex()?;
+        let tex_coords = self.tex_coords.pop_front()?;
+        Some(vertex::WithTexCoords { vertex, tex_coords })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, V, N> PopFrontVertex<vertex::WithNormal<V, N>> for WithNormals<M, VecDeque<N>>
+where
+    M: PopFrontVertex<V>,
+{
+    fn pop_front_vertex(&mut self) -> Option<vertex::WithNormal<V, N>> {
+        let vertex = self.mesh.pop_front_vertex()?;
+        let normal = self.normals.pop_front()?;
+        Some(vertex::WithNormal { vertex, normal })
+    }
+}
+
+// PushIndex implementations for meshes.
+
+impl<'a, M> PushIndex for &'a mut M
+where
+    M: PushIndex,
+{
+    type Index = M::Index;
+    fn push_index(&mut self, index: Self::Index) {
+        (**self).push_index(index);
+    }
+    fn extend_indices<I>(&mut self, indices: I)
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        (**self).extend_indices(indices);
+    }
+}
+
+impl<'a, M> PushIndex for RefMut<'a, M>
+where
+    M: PushIndex,
+{
+    type Index = M::Index;
+    fn push_index(&mut self, index: Self::Index) {
+        (**self).push_index(index);
+    }
+    fn extend_indices<I>(&mut self, indices: I)
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        (**self).extend_indices(indices);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, I> PushIndex for WithIndices<M, Vec<I>> {
+    type Index = I;
+
+    fn push_index(&mut self, index: Self::Index) {
+        self.indices.push(index);
+    }
+
+    fn extend_indices<It>(&mut self, indices: It)
+    where
+        It: IntoIterator<Item = Self::Index>,
+    {
+        self.indices.extend(indices);
+    }
+}
+
+impl<M, C> PushIndex for WithColors<M, C>
+where
+    M: PushIndex,
 {
     type Index = M::Index;
 
@@ -932,6 +1640,24 @@ where
     }
 }
 
+impl<M, F> PushIndex for WithFaces<M, F>
+where
+    M: PushIndex,
+{
+    type Index = M::Index;
+
+    fn push_index(&mut self, index: M::Index) {
+        self.mesh.push_index(index);
+    }
+
+    fn extend_indices<I>(&mut self, indices: I)
+    where
+        I: IntoIterator<Item = M::Index>,
+    {
+        self.mesh.extend_indices(indices);
+    }
+}
+
 // **ClearIndices** implementations
 
 impl<'a, M> ClearIndices for &'a mut M
@@ -952,6 +1678,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<M, I> ClearIndices for WithIndices<M, Vec<I>> {
     fn clear_indices(&mut self) {
         self.indices.clear();
@@ -985,74 +1712,255 @@ where
     }
 }
 
-// **ClearVertices** implementations
-
-impl<'a, M> ClearVertices for &'a mut M
+impl<M, F> ClearIndices for WithFaces<M, F>
+where
+    M: ClearIndices,
+{
+    fn clear_indices(&mut self) {
+        self.mesh.clear_indices();
+    }
+}
+
+// **ClearVertices** implementations
+
+impl<'a, M> ClearVertices for &'a mut M
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        (**self).clear_vertices()
+    }
+}
+
+impl<'a, M> ClearVertices for RefMut<'a, M>
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        (**self).clear_vertices()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> ClearVertices for MeshPoints<Vec<V>> {
+    fn clear_vertices(&mut self) {
+        self.points.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, I> ClearVertices for WithIndices<M, Vec<I>>
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        self.mesh.clear_vertices();
+        self.indices.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, C> ClearVertices for WithColors<M, Vec<C>>
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        self.mesh.clear_vertices();
+        self.colors.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, T> ClearVertices for WithTexCoords<M, Vec<T>>
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        self.mesh.clear_vertices();
+        self.tex_coords.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, N> ClearVertices for WithNormals<M, Vec<N>>
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        self.mesh.clear_vertices();
+        self.normals.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, F> ClearVertices for WithFaces<M, Vec<F>>
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        self.mesh.clear_vertices();
+        self.faces.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> ClearVertices for MeshPoints<VecDeque<V>> {
+    fn clear_vertices(&mut self) {
+        self.points.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, C> ClearVertices for WithColors<M, VecDeque<C>>
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        self.mesh.clear_vertices();
+        self.colors.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, T> ClearVertices for WithTexCoords<M, VecDeque<T>>
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        self.mesh.clear_vertices();
+        self.tex_coords.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, N> ClearVertices for WithNormals<M, VecDeque<N>>
+where
+    M: ClearVertices,
+{
+    fn clear_vertices(&mut self) {
+        self.mesh.clear_vertices();
+        self.normals.clear();
+    }
+}
+
+// **ChannelLengths** implementations
+
+impl<P> ChannelLengths for MeshPoints<P>
+where
+    P: Channel,
+{
+    fn channel_lens(&self) -> ChannelLens {
+        ChannelLens {
+            points: self.points.len(),
+            ..ChannelLens::default()
+        }
+    }
+}
+
+impl<M, I> ChannelLengths for WithIndices<M, I>
+where
+    M: ChannelLengths,
+    I: Channel,
+{
+    fn channel_lens(&self) -> ChannelLens {
+        ChannelLens {
+            indices: Some(self.indices.len()),
+            ..self.mesh.channel_lens()
+        }
+    }
+}
+
+impl<M, C> ChannelLengths for WithColors<M, C>
+where
+    M: ChannelLengths,
+    C: Channel,
+{
+    fn channel_lens(&self) -> ChannelLens {
+        ChannelLens {
+            colors: Some(self.colors.len()),
+            ..self.mesh.channel_lens()
+        }
+    }
+}
+
+impl<M, T> ChannelLengths for WithTexCoords<M, T>
 where
-    M: ClearVertices,
+    M: ChannelLengths,
+    T: Channel,
 {
-    fn clear_vertices(&mut self) {
-        (**self).clear_vertices()
+    fn channel_lens(&self) -> ChannelLens {
+        ChannelLens {
+            tex_coords: Some(self.tex_coords.len()),
+            ..self.mesh.channel_lens()
+        }
     }
 }
 
-impl<'a, M> ClearVertices for RefMut<'a, M>
+impl<M, N> ChannelLengths for WithNormals<M, N>
 where
-    M: ClearVertices,
+    M: ChannelLengths,
+    N: Channel,
 {
-    fn clear_vertices(&mut self) {
-        (**self).clear_vertices()
+    fn channel_lens(&self) -> ChannelLens {
+        ChannelLens {
+            normals: Some(self.normals.len()),
+            ..self.mesh.channel_lens()
+        }
     }
 }
 
-impl<V> ClearVertices for MeshPoints<Vec<V>> {
-    fn clear_vertices(&mut self) {
-        self.points.clear();
+impl<M, F> ChannelLengths for WithFaces<M, F>
+where
+    M: ChannelLengths,
+    F: Channel,
+{
+    fn channel_lens(&self) -> ChannelLens {
+        ChannelLens {
+            faces: Some(self.faces.len()),
+            ..self.mesh.channel_lens()
+        }
     }
 }
 
-impl<M, I> ClearVertices for WithIndices<M, Vec<I>>
+impl<'a, M> ChannelLengths for &'a M
 where
-    M: ClearVertices,
+    M: ChannelLengths,
 {
-    fn clear_vertices(&mut self) {
-        self.mesh.clear_vertices();
-        self.indices.clear();
+    fn channel_lens(&self) -> ChannelLens {
+        (**self).channel_lens()
     }
 }
 
-impl<M, C> ClearVertices for WithColors<M, Vec<C>>
+impl<'a, M> ChannelLengths for &'a mut M
 where
-    M: ClearVertices,
+    M: ChannelLengths,
 {
-    fn clear_vertices(&mut self) {
-        self.mesh.clear_vertices();
-        self.colors.clear();
+    fn channel_lens(&self) -> ChannelLens {
+        (**self).channel_lens()
     }
 }
 
-impl<M, T> ClearVertices for WithTexCoords<M, Vec<T>>
+impl<'a, M> ChannelLengths for Ref<'a, M>
 where
-    M: ClearVertices,
+    M: ChannelLengths,
 {
-    fn clear_vertices(&mut self) {
-        self.mesh.clear_vertices();
-        self.tex_coords.clear();
+    fn channel_lens(&self) -> ChannelLens {
+        (**self).channel_lens()
     }
 }
 
-impl<M, N> ClearVertices for WithNormals<M, Vec<N>>
+impl<'a, M> ChannelLengths for RefMut<'a, M>
 where
-    M: ClearVertices,
+    M: ChannelLengths,
 {
-    fn clear_vertices(&mut self) {
-        self.mesh.clear_vertices();
-        self.normals.clear();
+    fn channel_lens(&self) -> ChannelLens {
+        (**self).channel_lens()
     }
 }
 
 // **ExtendFromSlice** implementations
 
+#[cfg(feature = "alloc")]
 impl<'a, P> ExtendFromSlice<'a> for MeshPoints<Vec<P>>
 where
     P: 'a + Clone,
@@ -1063,6 +1971,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a, M, I> ExtendFromSlice<'a> for WithIndices<M, Vec<I>>
 where
     M: ExtendFromSlice<'a>,
@@ -1076,6 +1985,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a, M, C> ExtendFromSlice<'a> for WithColors<M, Vec<C>>
 where
     M: ExtendFromSlice<'a>,
@@ -1089,6 +1999,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a, M, T> ExtendFromSlice<'a> for WithTexCoords<M, Vec<T>>
 where
     M: ExtendFromSlice<'a>,
@@ -1102,6 +2013,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a, M, N> ExtendFromSlice<'a> for WithNormals<M, Vec<N>>
 where
     M: ExtendFromSlice<'a>,
@@ -1115,6 +2027,217 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<'a, M, F> ExtendFromSlice<'a> for WithFaces<M, Vec<F>>
+where
+    M: ExtendFromSlice<'a>,
+    F: 'a + Clone,
+{
+    type Slice = (&'a [F], M::Slice);
+    fn extend_from_slice(&mut self, slice: Self::Slice) {
+        let (slice, inner) = slice;
+        self.mesh.extend_from_slice(inner);
+        self.faces.extend_from_slice(slice);
+    }
+}
+
+// **ExtendFromChannels** implementations
+//
+// Each adaptor extends its own channel with its named slice and passes the remaining slices on
+// to its inner mesh. All lengths are checked on the way down before any channel is extended.
+
+#[cfg(feature = "alloc")]
+impl<'a, P> ExtendFromChannels<ChannelSlices<'a, P>> for MeshPoints<Vec<P>>
+where
+    P: Clone,
+{
+    fn extend_from_channels(
+        &mut self,
+        channels: ChannelSlices<'a, P>,
+    ) -> Result<(), ChannelLengthMismatch> {
+        self.points.extend_from_slice(channels.points);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, M, P, I, C, T, N, F> ExtendFromChannels<ChannelSlices<'a, P, I, C, T, N, F>>
+    for WithIndices<M, Vec<I>>
+where
+    M: ExtendFromChannels<ChannelSlices<'a, P, (), C, T, N, F>>,
+    I: Clone,
+{
+    fn extend_from_channels(
+        &mut self,
+        channels: ChannelSlices<'a, P, I, C, T, N, F>,
+    ) -> Result<(), ChannelLengthMismatch> {
+        let slice = channels.indices;
+        self.mesh.extend_from_channels(channels.indices(&[]))?;
+        self.indices.extend_from_slice(slice);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, M, P, I, C, T, N, F> ExtendFromChannels<ChannelSlices<'a, P, I, C, T, N, F>>
+    for WithColors<M, Vec<C>>
+where
+    M: ExtendFromChannels<ChannelSlices<'a, P, I, (), T, N, F>>,
+    C: Clone,
+{
+    fn extend_from_channels(
+        &mut self,
+        channels: ChannelSlices<'a, P, I, C, T, N, F>,
+    ) -> Result<(), ChannelLengthMismatch> {
+        let slice = channels.colors;
+        channels.check_len(slice.len())?;
+        self.mesh.extend_from_channels(channels.colors(&[]))?;
+        self.colors.extend_from_slice(slice);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, M, P, I, C, T, N, F> ExtendFromChannels<ChannelSlices<'a, P, I, C, T, N, F>>
+    for WithTexCoords<M, Vec<T>>
+where
+    M: ExtendFromChannels<ChannelSlices<'a, P, I, C, (), N, F>>,
+    T: Clone,
+{
+    fn extend_from_channels(
+        &mut self,
+        channels: ChannelSlices<'a, P, I, C, T, N, F>,
+    ) -> Result<(), ChannelLengthMismatch> {
+        let slice = channels.tex_coords;
+        channels.check_len(slice.len())?;
+        self.mesh.extend_from_channels(channels.tex_coords(&[]))?;
+        self.tex_coords.extend_from_slice(slice);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, M, P, I, C, T, N, F> ExtendFromChannels<ChannelSlices<'a, P, I, C, T, N, F>>
+    for WithNormals<M, Vec<N>>
+where
+    M: ExtendFromChannels<ChannelSlices<'a, P, I, C, T, (), F>>,
+    N: Clone,
+{
+    fn extend_from_channels(
+        &mut self,
+        channels: ChannelSlices<'a, P, I, C, T, N, F>,
+    ) -> Result<(), ChannelLengthMismatch> {
+        let slice = channels.normals;
+        channels.check_len(slice.len())?;
+        self.mesh.extend_from_channels(channels.normals(&[]))?;
+        self.normals.extend_from_slice(slice);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, M, P, I, C, T, N, F> ExtendFromChannels<ChannelSlices<'a, P, I, C, T, N, F>>
+    for WithFaces<M, Vec<F>>
+where
+    M: ExtendFromChannels<ChannelSlices<'a, P, I, C, T, N, ()>>,
+    F: Clone,
+{
+    fn extend_from_channels(
+        &mut self,
+        channels: ChannelSlices<'a, P, I, C, T, N, F>,
+    ) -> Result<(), ChannelLengthMismatch> {
+        let slice = channels.faces;
+        self.mesh.extend_from_channels(channels.faces(&[]))?;
+        self.faces.extend_from_slice(slice);
+        Ok(())
+    }
+}
+
+// **Append** implementations
+
+#[cfg(feature = "alloc")]
+impl<P> Append for MeshPoints<Vec<P>> {
+    fn append(&mut self, other: Self) -> Result<(), IndexOverflow> {
+        self.points.extend(other.points);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, I> Append for WithIndices<M, Vec<I>>
+where
+    M: Append + Points,
+    I: TryFrom<usize> + TryInto<usize>,
+{
+    fn append(&mut self, other: Self) -> Result<(), IndexOverflow> {
+        let WithIndices { mesh, indices } = other;
+        // Rebase the indices before mutating so that the mesh is unchanged upon error.
+        let offset = raw_vertex_count(&self.mesh);
+        let indices = indices
+            .into_iter()
+            .map(|i| {
+                let index = i
+                    .try_into()
+                    .unwrap_or_else(|_err| panic!("index out of range of valid `usize` values"))
+                    + offset;
+                I::try_from(index).map_err(|_err| IndexOverflow { index })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.mesh.append(mesh)?;
+        self.indices.extend(indices);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, C> Append for WithColors<M, Vec<C>>
+where
+    M: Append,
+{
+    fn append(&mut self, other: Self) -> Result<(), IndexOverflow> {
+        self.mesh.append(other.mesh)?;
+        self.colors.extend(other.colors);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, T> Append for WithTexCoords<M, Vec<T>>
+where
+    M: Append,
+{
+    fn append(&mut self, other: Self) -> Result<(), IndexOverflow> {
+        self.mesh.append(other.mesh)?;
+        self.tex_coords.extend(other.tex_coords);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, N> Append for WithNormals<M, Vec<N>>
+where
+    M: Append,
+{
+    fn append(&mut self, other: Self) -> Result<(), IndexOverflow> {
+        self.mesh.append(other.mesh)?;
+        self.normals.extend(other.normals);
+        Ok(())
+    }
+}
+
+impl fmt::Display for IndexOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "rebased index {} is out of range of the mesh's index type",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexOverflow {}
+
 // **Clear** implementation for all meshes.
 
 impl<T> Clear for T where T: ClearIndices + ClearVertices {}
@@ -1179,6 +2302,18 @@ where
     }
 }
 
+impl<M, F> Default for WithFaces<M, F>
+where
+    M: Default,
+    F: Default,
+{
+    fn default() -> Self {
+        let mesh = Default::default();
+        let faces = Default::default();
+        WithFaces { mesh, faces }
+    }
+}
+
 // Deref implementations for the mesh adaptor types to their inner mesh.
 
 impl<M, I> Deref for WithIndices<M, I> {
@@ -1233,6 +2368,222 @@ impl<M, N> DerefMut for WithNormals<M, N> {
     }
 }
 
+impl<M, F> Deref for WithFaces<M, F> {
+    type Target = M;
+    fn deref(&self) -> &Self::Target {
+        &self.mesh
+    }
+}
+
+impl<M, F> DerefMut for WithFaces<M, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.mesh
+    }
+}
+
+// Destructuring the mesh types into their channels.
+
+impl<P> MeshPoints<P> {
+    /// Consume the mesh and produce its points channel.
+    pub fn into_points(self) -> P {
+        self.points
+    }
+}
+
+impl<M, I> WithIndices<M, I> {
+    /// Consume the mesh and produce the inner mesh along with the indices channel.
+    pub fn into_parts(self) -> (M, I) {
+        let WithIndices { mesh, indices } = self;
+        (mesh, indices)
+    }
+
+    /// Produce an iterator yielding each unique edge of the mesh as a `(min, max)` pair of indices.
+    ///
+    /// See the `edges` function for details.
+    #[cfg(feature = "std")]
+    pub fn edges(&self) -> Edges<I::Element>
+    where
+        I: Channel,
+        I::Element: Copy + Ord + Hash,
+    {
+        edges(self)
+    }
+}
+
+impl<M, C> WithColors<M, C> {
+    /// Consume the mesh and produce the inner mesh along with the colors channel.
+    pub fn into_parts(self) -> (M, C) {
+        let WithColors { mesh, colors } = self;
+        (mesh, colors)
+    }
+}
+
+impl<M, T> WithTexCoords<M, T> {
+    /// Consume the mesh and produce the inner mesh along with the texture coordinates channel.
+    pub fn into_parts(self) -> (M, T) {
+        let WithTexCoords { mesh, tex_coords } = self;
+        (mesh, tex_coords)
+    }
+}
+
+impl<M, N> WithNormals<M, N> {
+    /// Consume the mesh and produce the inner mesh along with the normals channel.
+    pub fn into_parts(self) -> (M, N) {
+        let WithNormals { mesh, normals } = self;
+        (mesh, normals)
+    }
+}
+
+impl<M, F> WithFaces<M, F> {
+    /// Consume the mesh and produce the inner mesh along with the faces channel.
+    pub fn into_parts(self) -> (M, F) {
+        let WithFaces { mesh, faces } = self;
+        (mesh, faces)
+    }
+}
+
+// Naming the slices of a **ChannelSlices**.
+
+impl<'a, P> ChannelSlices<'a, P> {
+    /// Begin a set of channel slices with the slice of points.
+    pub fn new(points: &'a [P]) -> Self {
+        ChannelSlices {
+            points,
+            indices: &[],
+            colors: &[],
+            tex_coords: &[],
+            normals: &[],
+            faces: &[],
+        }
+    }
+}
+
+impl<'a, P, I, C, T, N, F> ChannelSlices<'a, P, I, C, T, N, F> {
+    /// Specify the slice used to extend the indices channel.
+    pub fn indices<I2>(self, indices: &'a [I2]) -> ChannelSlices<'a, P, I2, C, T, N, F> {
+        let ChannelSlices {
+            points,
+            colors,
+            tex_coords,
+            normals,
+            faces,
+            ..
+        } = self;
+        ChannelSlices {
+            points,
+            indices,
+            colors,
+            tex_coords,
+            normals,
+            faces,
+        }
+    }
+
+    /// Specify the slice used to extend the colors channel.
+    pub fn colors<C2>(self, colors: &'a [C2]) -> ChannelSlices<'a, P, I, C2, T, N, F> {
+        let ChannelSlices {
+            points,
+            indices,
+            tex_coords,
+            normals,
+            faces,
+            ..
+        } = self;
+        ChannelSlices {
+            points,
+            indices,
+            colors,
+            tex_coords,
+            normals,
+            faces,
+        }
+    }
+
+    /// Specify the slice used to extend the texture coordinates channel.
+    pub fn tex_coords<T2>(self, tex_coords: &'a [T2]) -> ChannelSlices<'a, P, I, C, T2, N, F> {
+        let ChannelSlices {
+            points,
+            indices,
+            colors,
+            normals,
+            faces,
+            ..
+        } = self;
+        ChannelSlices {
+            points,
+            indices,
+            colors,
+            tex_coords,
+            normals,
+            faces,
+        }
+    }
+
+    /// Specify the slice used to extend the normals channel.
+    pub fn normals<N2>(self, normals: &'a [N2]) -> ChannelSlices<'a, P, I, C, T, N2, F> {
+        let ChannelSlices {
+            points,
+            indices,
+            colors,
+            tex_coords,
+            faces,
+            ..
+        } = self;
+        ChannelSlices {
+            points,
+            indices,
+            colors,
+            tex_coords,
+            normals,
+            faces,
+        }
+    }
+
+    /// Specify the slice used to extend the faces channel.
+    pub fn faces<F2>(self, faces: &'a [F2]) -> ChannelSlices<'a, P, I, C, T, N, F2> {
+        let ChannelSlices {
+            points,
+            indices,
+            colors,
+            tex_coords,
+            normals,
+            ..
+        } = self;
+        ChannelSlices {
+            points,
+            indices,
+            colors,
+            tex_coords,
+            normals,
+            faces,
+        }
+    }
+
+    // Check that a vertex channel slice of the given length matches the slice of points.
+    #[cfg(feature = "alloc")]
+    fn check_len(&self, channel_len: usize) -> Result<(), ChannelLengthMismatch> {
+        let vertex_count = self.points.len();
+        if vertex_count != channel_len {
+            return Err(ChannelLengthMismatch {
+                vertex_count,
+                channel_len,
+            });
+        }
+        Ok(())
+    }
+}
+
+// **IntoIterator** implementations.
+
+#[cfg(feature = "alloc")]
+impl<P> IntoIterator for MeshPoints<Vec<P>> {
+    type Item = P;
+    type IntoIter = alloc::vec::IntoIter<P>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.points.into_iter()
+    }
+}
+
 // Mesh length functions.
 
 /// Get the number of vertices in the mesh.
@@ -1240,7 +2591,7 @@ pub fn raw_vertex_count<M>(mesh: M) -> usize
 where
     M: Points,
 {
-    mesh.points().channel().len()
+    mesh.points().len()
 }
 
 /// The number of vertices that would be yielded by a **Vertices** iterator for the given mesh.
@@ -1248,7 +2599,7 @@ pub fn vertex_count<M>(mesh: M) -> usize
 where
     M: Indices,
 {
-    mesh.indices().channel().len()
+    mesh.indices().len()
 }
 
 /// The number of triangles that would be yielded by a **Triangles** iterator for the given mesh.
@@ -1259,6 +2610,14 @@ where
     vertex_count(mesh) / geom::tri::NUM_VERTICES as usize
 }
 
+/// The number of faces within the mesh's **Faces** channel.
+pub fn face_count<M>(mesh: M) -> usize
+where
+    M: Faces,
+{
+    mesh.faces().len()
+}
+
 // Mesh constructors.
 
 /// Create a simple base mesh from the given channel of vertex points.
@@ -1280,38 +2639,107 @@ where
 
 /// Combine the given mesh with the given channel of vertex colors.
 ///
-/// **Panics** if the length of the **colors** channel differs from **points**.
+/// **Panics** if the length of the **colors** channel differs from **points**. See
+/// `try_with_colors` for a non-panicking alternative.
 pub fn with_colors<M, C>(mesh: M, colors: C) -> WithColors<M, C>
 where
     M: Points,
     C: Channel,
 {
-    assert_eq!(raw_vertex_count(&mesh), colors.channel().len());
-    WithColors { mesh, colors }
+    try_with_colors(mesh, colors).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Combine the given mesh with the given channel of vertex colors.
+///
+/// Returns an error describing both lengths if the length of the **colors** channel differs from
+/// **points**.
+pub fn try_with_colors<M, C>(mesh: M, colors: C) -> Result<WithColors<M, C>, ChannelLengthMismatch>
+where
+    M: Points,
+    C: Channel,
+{
+    check_channel_len(&mesh, colors.len())?;
+    Ok(WithColors { mesh, colors })
 }
 
 /// Combine the given mesh with the given channel of vertex texture coordinates.
 ///
-/// **Panics** if the length of the **tex_coords** channel differs from **points**.
+/// **Panics** if the length of the **tex_coords** channel differs from **points**. See
+/// `try_with_tex_coords` for a non-panicking alternative.
 pub fn with_tex_coords<M, T>(mesh: M, tex_coords: T) -> WithTexCoords<M, T>
 where
     M: Points,
     T: Channel,
 {
-    assert_eq!(raw_vertex_count(&mesh), tex_coords.channel().len());
-    WithTexCoords { mesh, tex_coords }
+    try_with_tex_coords(mesh, tex_coords).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Combine the given mesh with the given channel of vertex texture coordinates.
+///
+/// Returns an error describing both lengths if the length of the **tex_coords** channel differs
+/// from **points**.
+pub fn try_with_tex_coords<M, T>(
+    mesh: M,
+    tex_coords: T,
+) -> Result<WithTexCoords<M, T>, ChannelLengthMismatch>
+where
+    M: Points,
+    T: Channel,
+{
+    check_channel_len(&mesh, tex_coords.len())?;
+    Ok(WithTexCoords { mesh, tex_coords })
 }
 
 /// Combine the given mesh with the given **Normals** channel.
 ///
-/// **Panics** if the length of the **normals** channel differs from **points**.
+/// **Panics** if the length of the **normals** channel differs from **points**. See
+/// `try_with_normals` for a non-panicking alternative.
 pub fn with_normals<M, N>(mesh: M, normals: N) -> WithNormals<M, N>
 where
     M: Points,
     N: Channel,
 {
-    assert_eq!(raw_vertex_count(&mesh), normals.channel().len());
-    WithNormals { mesh, normals }
+    try_with_normals(mesh, normals).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Combine the given mesh with the given **Normals** channel.
+///
+/// Returns an error describing both lengths if the length of the **normals** channel differs from
+/// **points**.
+pub fn try_with_normals<M, N>(
+    mesh: M,
+    normals: N,
+) -> Result<WithNormals<M, N>, ChannelLengthMismatch>
+where
+    M: Points,
+    N: Channel,
+{
+    check_channel_len(&mesh, normals.len())?;
+    Ok(WithNormals { mesh, normals })
+}
+
+// Check that a channel of the given length may be combined with the given mesh.
+fn check_channel_len<M>(mesh: &M, channel_len: usize) -> Result<(), ChannelLengthMismatch>
+where
+    M: Points,
+{
+    let vertex_count = raw_vertex_count(mesh);
+    if vertex_count != channel_len {
+        return Err(ChannelLengthMismatch {
+            vertex_count,
+            channel_len,
+        });
+    }
+    Ok(())
+}
+
+/// Combine the given mesh with the given channel of polygonal faces.
+pub fn with_faces<M, F>(mesh: M, faces: F) -> WithFaces<M, F>
+where
+    M: Points,
+    F: Channel,
+{
+    WithFaces { mesh, faces }
 }
 
 // Mesh mutation functions.
@@ -1382,6 +2810,59 @@ where
     mesh.clear();
 }
 
+/// Concatenate the two meshes into one, e.g. to combine several generated sub-meshes into a single
+/// draw call.
+///
+/// All channels of `b` are appended to those of `a`, with the indices of `b` rebased by the number
+/// of vertices in `a`. Both meshes must be of the same type and so carry the same set of channels.
+///
+/// Returns an error if a rebased index cannot be represented by the mesh's index type.
+pub fn concat<M>(mut a: M, b: M) -> Result<M, IndexOverflow>
+where
+    M: Append,
+{
+    a.append(b)?;
+    Ok(a)
+}
+
+/// Triangulate the mesh's **Faces** channel into its **Indices** channel.
+///
+/// The **Indices** channel is cleared before each face `[a, b, c, d, ..]` is fanned into the
+/// triangles `[a, b, c]`, `[a, c, d]`, etc. As a result, only convex faces are triangulated
+/// correctly. Faces with fewer than three indices are skipped.
+pub fn triangulate<M, I>(mut mesh: M)
+where
+    M: Faces<Index = I> + PushIndex<Index = I> + ClearIndices,
+    I: Clone,
+{
+    mesh.clear_indices();
+    for f in 0..mesh.faces().len() {
+        for i in 1.. {
+            match mesh.faces().get(f).and_then(|face| fan_triangle(face, i)) {
+                Some(tri) => mesh.extend_indices(tri),
+                None => break,
+            }
+        }
+    }
+}
+
+// The `i`th triangle of the fan over the given face, starting from `1`.
+fn fan_triangle<F>(face: &F, i: usize) -> Option<[F::Index; 3]>
+where
+    F: Face,
+    F::Index: Clone,
+{
+    let indices = face.indices();
+    if i == 0 || i + 1 >= indices.len() {
+        return None;
+    }
+    Some([
+        indices[0].clone(),
+        indices[i].clone(),
+        indices[i + 1].clone(),
+    ])
+}
+
 // Mesh iterators.
 
 /// An iterator yielding the raw vertices (with combined channels) of a mesh.
@@ -1444,7 +2925,7 @@ where
     M: Indices<Index = I> + GetVertex<I>,
     I: TryFrom<usize>,
 {
-    let len = mesh.indices().channel().len();
+    let len = mesh.indices().len();
     let index_range = 0..len;
     Vertices { index_range, mesh }
 }
@@ -1469,6 +2950,14 @@ where
 // The error message produced when the `Vertices` iterator panics due to an out of bound index.
 const NO_VERTEX_FOR_INDEX: &str = "no vertex for the index produced by the mesh's indices channel";
 
+// Panic with a description of the out of bounds index produced by the mesh's indices channel.
+fn no_vertex_for_index(err: Option<OutOfBounds>) -> ! {
+    match err {
+        Some(err) => panic!("{}: {}", NO_VERTEX_FOR_INDEX, err),
+        None => panic!("{}", NO_VERTEX_FOR_INDEX),
+    }
+}
+
 impl<M> RawVertices<M> {
     /// Specify a range of raw vertices to yield.
     pub fn range(mut self, range: ops::Range<usize>) -> Self {
@@ -1519,8 +3008,11 @@ where
     type Item = M::Vertex;
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(i) = self.index_range.next() {
-            if let Some(&index) = self.mesh.indices().channel().get(i) {
-                let vertex = self.mesh.get_vertex(index).expect(NO_VERTEX_FOR_INDEX);
+            if let Some(&index) = self.mesh.indices().get(i) {
+                let vertex = match self.mesh.get_vertex_checked(index) {
+                    Ok(vertex) => vertex,
+                    Err(err) => no_vertex_for_index(err),
+                };
                 return Some(vertex);
             }
         }
@@ -1549,8 +3041,11 @@ where
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if let Some(i) = self.index_range.next_back() {
-            if let Some(&index) = self.mesh.indices().channel().get(i) {
-                let vertex = self.mesh.get_vertex(index).expect(NO_VERTEX_FOR_INDEX);
+            if let Some(&index) = self.mesh.indices().get(i) {
+                let vertex = match self.mesh.get_vertex_checked(index) {
+                    Ok(vertex) => vertex,
+                    Err(err) => no_vertex_for_index(err),
+                };
                 return Some(vertex);
             }
         }
@@ -1564,9 +3059,177 @@ where
     I: Copy,
 {
     fn len(&self) -> usize {
-        let indices_len = self.mesh.indices().channel().len();
+        let indices_len = self.mesh.indices().len();
         let remaining_indices = indices_len - self.index_range.start;
         let range_len = self.index_range.len();
         cmp::min(remaining_indices, range_len)
     }
 }
+
+#[test]
+fn test_triangulate_faces() {
+    use alloc::vec;
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [2.0, 0.5]];
+    let faces: Vec<Vec<u32>> = vec![vec![0, 1, 2, 3], vec![1, 4, 2], vec![0, 1]];
+    let mesh = with_faces(from_points(points), faces);
+    let mut mesh = with_indices(mesh, vec![99u32]);
+    triangulate(&mut mesh);
+    assert_eq!(face_count(&mesh), 3);
+    assert_eq!(triangle_count(&mesh), 3);
+    assert_eq!(mesh.indices(), &[0, 1, 2, 0, 2, 3, 1, 4, 2]);
+    let tris: Vec<_> = triangles(&mesh).collect();
+    assert_eq!(tris[1], geom::Tri([[0.0, 0.0], [1.0, 1.0], [0.0, 1.0]]));
+    // The original face structure is preserved.
+    assert_eq!(mesh.faces()[0], vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_strided_channel() {
+    // Interleaved `[point, color, point, color, ..]` data with a trailing incomplete vertex.
+    let data = [[0.0, 0.0], [1.0, 0.0], [2.0, 2.0], [0.0, 1.0], [4.0, 4.0]];
+    let points = StridedChannel::new(&data[..], 2, 0);
+    let colors = StridedChannel::new(&data[..], 2, 1);
+    assert_eq!(points.len(), 2);
+    assert_eq!(colors.get(1), Some(&[0.0, 1.0]));
+    assert_eq!(points.get(2), None);
+    let mesh = with_colors(from_points(points), colors);
+    let vertices: Vec<_> = raw_vertices(&mesh).collect();
+    assert_eq!(vertices.len(), 2);
+    assert_eq!(vertices[1].vertex, [2.0, 2.0]);
+    assert_eq!(vertices[1].color, [0.0, 1.0]);
+}
+
+#[test]
+fn test_into_parts() {
+    use alloc::vec;
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]];
+    let colors = vec![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    let mesh = with_indices(
+        with_colors(from_points(points.clone()), colors.clone()),
+        vec![0, 1, 2],
+    );
+    let (mesh, indices) = mesh.into_parts();
+    let (mesh, cols) = mesh.into_parts();
+    assert_eq!(indices, vec![0, 1, 2]);
+    assert_eq!(cols, colors);
+    assert_eq!(mesh.into_iter().collect::<Vec<_>>(), points);
+}
+
+#[test]
+fn test_channel_iter() {
+    use alloc::vec;
+    let mut mesh = from_points(vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]);
+    for p in mesh.points.iter_mut() {
+        p[1] += 1.0;
+    }
+    let ys: Vec<_> = mesh.points().iter().map(|p| p[1]).collect();
+    assert_eq!(ys, vec![1.0, 1.0, 2.0]);
+    // Non-contiguous channels may be iterated in either direction.
+    let data = [[0.0, 0.0], [1.0, 0.0], [2.0, 2.0], [0.0, 1.0]];
+    let points = StridedChannel::new(&data[..], 2, 0);
+    let rev: Vec<_> = points.iter().rev().collect();
+    assert_eq!(rev, vec![&[2.0, 2.0], &[0.0, 0.0]]);
+}
+
+#[test]
+fn test_get_checked() {
+    use alloc::vec;
+    let points = vec![[0.0, 0.0], [1.0, 0.0]];
+    assert_eq!(points.get_checked(1), Ok(&[1.0, 0.0]));
+    assert_eq!(points.get_checked(2), Err(OutOfBounds { index: 2, len: 2 }));
+}
+
+#[test]
+fn test_vec_deque_trail() {
+    // A trail keeping only the three most recent vertices.
+    let mut mesh = with_colors(from_points(VecDeque::new()), VecDeque::new());
+    for i in 0..5 {
+        let vertex = vertex::WithColor {
+            vertex: [i as f32, 0.0],
+            color: i,
+        };
+        push_vertex(&mut mesh, vertex);
+        if raw_vertex_count(&mesh) > 3 {
+            mesh.pop_front_vertex();
+        }
+    }
+    let xs: Vec<_> = raw_vertices(&mesh).map(|v| v.vertex[0]).collect();
+    assert_eq!(xs, [2.0, 3.0, 4.0]);
+    assert_eq!(mesh.colors().front(), Some(&2));
+    clear_vertices(&mut mesh);
+    assert_eq!(raw_vertex_count(&mesh), 0);
+}
+
+#[test]
+fn test_concat() {
+    use alloc::vec;
+    let tri = |x: f32| {
+        let points = vec![[x, 0.0], [x + 1.0, 0.0], [x, 1.0]];
+        let colors = vec![[x; 4]; 3];
+        with_colors(with_indices(from_points(points), vec![0u16, 1, 2]), colors)
+    };
+    let mesh = concat(tri(0.0), tri(2.0)).unwrap();
+    assert_eq!(raw_vertex_count(&mesh), 6);
+    assert_eq!(mesh.indices(), &[0, 1, 2, 3, 4, 5]);
+    assert_eq!(mesh.colors()[3], [2.0; 4]);
+    let tris: Vec<_> = triangles(&mesh).collect();
+    assert_eq!(tris[1].0[0].vertex, [2.0, 0.0]);
+
+    // The rebased indices must fit within the index type.
+    let big = with_indices(from_points(vec![[0.0, 0.0]; 256]), vec![0u8]);
+    let small = with_indices(from_points(vec![[0.0, 0.0]]), vec![0u8]);
+    assert_eq!(concat(big, small), Err(IndexOverflow { index: 256 }));
+}
+
+#[test]
+fn test_try_with_channels() {
+    let points = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]];
+    let err = try_with_colors(from_points(&points[..]), &[[1.0; 4]; 2][..]).unwrap_err();
+    let expected = ChannelLengthMismatch {
+        vertex_count: 3,
+        channel_len: 2,
+    };
+    assert_eq!(err, expected);
+    assert!(try_with_tex_coords(from_points(&points[..]), &[[0.0; 2]; 3][..]).is_ok());
+    assert!(try_with_normals(from_points(&points[..]), &[[0.0; 3]; 4][..]).is_err());
+}
+
+#[test]
+#[should_panic(expected = "index 3 is out of bounds of channel with length 3")]
+fn test_vertices_out_of_bounds() {
+    use alloc::vec;
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]];
+    let mesh = with_indices(from_points(points), vec![0u32, 1, 3]);
+    for _ in vertices(&mesh) {}
+}
+
+#[test]
+fn test_extend_from_channels() {
+    use alloc::vec;
+    let points = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]];
+    let normals = [[0.0, 0.0, 1.0]; 3];
+    let tex_coords = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]];
+    let indices = [0u32, 1, 2];
+    let mesh = with_tex_coords(from_points(vec![]), vec![]);
+    let mut mesh = with_indices(with_normals(mesh, vec![]), vec![]);
+    let channels = ChannelSlices::new(&points)
+        .indices(&indices)
+        .normals(&normals)
+        .tex_coords(&tex_coords);
+    mesh.extend_from_channels(channels).unwrap();
+    assert_eq!(mesh.points(), &points[..]);
+    assert_eq!(mesh.normals(), &normals[..]);
+    assert_eq!(mesh.tex_coords(), &tex_coords[..]);
+    assert_eq!(mesh.indices(), &indices[..]);
+
+    // A mismatched slice leaves every channel unchanged.
+    let channels = ChannelSlices::new(&points)
+        .indices(&indices)
+        .normals(&normals[..2])
+        .tex_coords(&tex_coords);
+    let err = mesh.extend_from_channels(channels).unwrap_err();
+    assert_eq!(err.channel_len, 2);
+    assert_eq!(raw_vertex_count(&mesh), 3);
+    assert_eq!(mesh.tex_coords().len(), 3);
+    assert_eq!(mesh.indices().len(), 3);
+}
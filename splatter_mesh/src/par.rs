@@ -0,0 +1,85 @@
+//! Parallel iteration over the vertices and triangles of a mesh via `rayon`.
+//!
+//! These mirror the `vertices` and `triangles` functions, splitting the mesh's index range across
+//! threads. This is useful for offline processing of large meshes, e.g. transforming every vertex
+//! or computing a bounding box.
+//!
+//! Only available with the `rayon` feature enabled.
+
+use crate::{no_vertex_for_index, Channel, GetVertex, Indices};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use splatter_core::geom;
+
+/// Produce a parallel iterator yielding vertices in the order specified via the mesh's
+/// **Indices** channel.
+///
+/// Requires that the mesh implements **Indices** and **GetVertex**. The mesh is shared between
+/// threads, so it must also be `Send` and `Sync`, e.g. `&mesh`.
+///
+/// **Panics** if the **Indices** channel produces an index that is out of bounds of the mesh's
+/// vertices.
+pub fn par_vertices<M, I>(mesh: M) -> impl IndexedParallelIterator<Item = M::Vertex>
+where
+    M: Send + Sync + Indices<Index = I> + GetVertex<I>,
+    M::Vertex: Send,
+    I: Copy,
+{
+    let len = mesh.indices().len();
+    (0..len).into_par_iter().map(move |i| vertex_at(&mesh, i))
+}
+
+/// Produce a parallel iterator yielding triangles for every three vertices in the order specified
+/// via the mesh's **Indices** channel.
+///
+/// Requires that the mesh implements **Indices** and **GetVertex**. The mesh is shared between
+/// threads, so it must also be `Send` and `Sync`, e.g. `&mesh`. Any trailing indices that do not
+/// form a complete triangle are ignored.
+///
+/// **Panics** if the **Indices** channel produces an index that is out of bounds of the mesh's
+/// vertices.
+pub fn par_triangles<M, I>(mesh: M) -> impl IndexedParallelIterator<Item = geom::Tri<M::Vertex>>
+where
+    M: Send + Sync + Indices<Index = I> + GetVertex<I>,
+    M::Vertex: Send,
+    I: Copy,
+{
+    let len = mesh.indices().len() / geom::tri::NUM_VERTICES as usize;
+    (0..len).into_par_iter().map(move |t| {
+        let i = t * geom::tri::NUM_VERTICES as usize;
+        geom::Tri([
+            vertex_at(&mesh, i),
+            vertex_at(&mesh, i + 1),
+            vertex_at(&mesh, i + 2),
+        ])
+    })
+}
+
+// Retrieve the vertex for the index at position `i` within the mesh's indices channel.
+fn vertex_at<M, I>(mesh: &M, i: usize) -> M::Vertex
+where
+    M: Indices<Index = I> + GetVertex<I>,
+    I: Copy,
+{
+    let index = *mesh
+        .indices()
+        .get(i)
+        .expect("index within the range of the indices channel");
+    match mesh.get_vertex_checked(index) {
+        Ok(vertex) => vertex,
+        Err(err) => no_vertex_for_index(err),
+    }
+}
+
+#[test]
+fn test_par_vertices_and_triangles() {
+    use alloc::{vec, vec::Vec};
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+    let indices = vec![0usize, 1, 2, 0, 2, 3, 1];
+    let mesh = crate::with_indices(crate::from_points(points), indices);
+    let expected: Vec<_> = crate::vertices(&mesh).collect();
+    assert_eq!(par_vertices(&mesh).len(), expected.len());
+    assert_eq!(par_vertices(&mesh).collect::<Vec<_>>(), expected);
+    let expected: Vec<_> = crate::triangles(&mesh).collect();
+    assert_eq!(par_triangles(&mesh).len(), 2);
+    assert_eq!(par_triangles(&mesh).collect::<Vec<_>>(), expected);
+}
@@ -0,0 +1,385 @@
+//! Generators for common parameterized meshes, e.g. planes for terrain and backgrounds.
+
+use crate::{
+    from_points, with_indices, with_normals, with_tex_coords, MeshPoints, WithIndices, WithNormals,
+    WithTexCoords,
+};
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+use core::f32::consts::{PI, TAU};
+use splatter_core::geom::{pt2, pt3, Point2, Point3, Vec2, Vec3};
+use splatter_core::math::num_traits::Float;
+
+/// The indexed mesh type produced by the generators within this module.
+///
+/// Every vertex has a position, a normal and texture coordinates.
+pub type Mesh = WithIndices<
+    WithNormals<WithTexCoords<MeshPoints<Vec<Point3>>, Vec<Point2>>, Vec<Vec3>>,
+    Vec<u32>,
+>;
+
+/// The mesh type produced by `icosphere`.
+pub type Icosphere = WithIndices<WithNormals<MeshPoints<Vec<Point3>>, Vec<Vec3>>, Vec<u32>>;
+
+/// The mesh type produced by `grid_lines`.
+pub type GridLines = WithIndices<MeshPoints<Vec<Point3>>, Vec<u32>>;
+
+/// A flat, indexed triangle mesh of `cols` by `rows` quads lying in the `xz` plane.
+///
+/// The plane is centred on the origin, spanning `size.x` along the `x` axis and `size.y` along the
+/// `z` axis. Each vertex has texture coordinates ranging from `[0.0, 0.0]` at the `-x`, `-z`
+/// corner to `[1.0, 1.0]` at the `+x`, `+z` corner, and a normal facing up the `y` axis. The
+/// triangles are wound counter-clockwise when viewed from above.
+///
+/// The vertices are ordered row by row along the `x` axis, so vertex `(col, row)` is found at
+/// index `row * (cols + 1) + col`. This is useful for displacing the points, e.g. by a height map.
+pub fn plane(cols: u32, rows: u32, size: Vec2) -> Mesh {
+    let mut builder = Builder::default();
+    builder.surface(cols, rows, |uv| (grid_point(uv, size), Vec3::Y, uv));
+    builder.build()
+}
+
+/// A UV sphere centred on the origin with `rings` divisions from pole to pole and `sectors`
+/// divisions around the `y` axis.
+///
+/// Texture coordinates are mapped equirectangularly, with `v` running from `0.0` at the top pole to
+/// `1.0` at the bottom pole. The vertices along the seam are duplicated so that `u` runs all the
+/// way from `0.0` to `1.0` without wrapping back across the texture.
+pub fn uv_sphere(rings: u32, sectors: u32, radius: f32) -> Mesh {
+    let mut builder = Builder::default();
+    builder.surface(sectors, rings, |uv| {
+        let (sin_p, cos_p) = sin_cos(uv.x * TAU);
+        // Snap the bottom pole so that its degenerate triangles are skipped.
+        let (sin_t, cos_t) = if uv.y >= 1.0 {
+            (0.0, -1.0)
+        } else {
+            sin_cos(uv.y * PI)
+        };
+        let normal = Vec3::new(sin_t * cos_p, cos_t, -sin_t * sin_p);
+        (normal * radius, normal, uv)
+    });
+    builder.build()
+}
+
+/// An axis-aligned box centred on the origin with the given side lengths.
+///
+/// Each face has its own four vertices so that normals remain flat, and each face maps the full
+/// `[0.0, 0.0]` to `[1.0, 1.0]` range of texture coordinates upright when viewed from outside.
+pub fn cube(size: Vec3) -> Mesh {
+    // The normal, then the right and down directions of each face when viewed from outside.
+    let faces = [
+        (Vec3::X, Vec3::NEG_Z, Vec3::NEG_Y),
+        (Vec3::NEG_X, Vec3::Z, Vec3::NEG_Y),
+        (Vec3::Y, Vec3::X, Vec3::Z),
+        (Vec3::NEG_Y, Vec3::X, Vec3::NEG_Z),
+        (Vec3::Z, Vec3::X, Vec3::NEG_Y),
+        (Vec3::NEG_Z, Vec3::NEG_X, Vec3::NEG_Y),
+    ];
+    let mut builder = Builder::default();
+    for &(normal, right, down) in faces.iter() {
+        builder.surface(1, 1, |uv| {
+            let p = normal * 0.5 + right * (uv.x - 0.5) + down * (uv.y - 0.5);
+            (p * size, normal, uv)
+        });
+    }
+    builder.build()
+}
+
+/// A capped cylinder centred on the origin, standing along the `y` axis.
+///
+/// The side wraps the full range of texture coordinates around the `y` axis from top to bottom,
+/// duplicating the vertices along the seam. Each cap maps a circle inscribed within the texture.
+pub fn cylinder(radius: f32, height: f32, segments: u32) -> Mesh {
+    let half = height * 0.5;
+    let mut builder = Builder::default();
+    builder.surface(segments, 1, |uv| {
+        let (sin, cos) = sin_cos(uv.x * TAU);
+        let normal = Vec3::new(cos, 0.0, -sin);
+        let p = normal * radius + Vec3::Y * (half - uv.y * height);
+        (p, normal, uv)
+    });
+    builder.cap(radius, half, segments, Vec3::Y);
+    builder.cap(radius, -half, segments, Vec3::NEG_Y);
+    builder.build()
+}
+
+/// A capped cone centred on the origin, standing along the `y` axis with its apex at the top.
+///
+/// Texture coordinates are mapped as for `cylinder`, with the top row collapsing to the apex.
+pub fn cone(radius: f32, height: f32, segments: u32) -> Mesh {
+    let half = height * 0.5;
+    let mut builder = Builder::default();
+    builder.surface(segments, 1, |uv| {
+        let (sin, cos) = sin_cos(uv.x * TAU);
+        let normal = Vec3::new(height * cos, radius, -height * sin).normalize_or_zero();
+        let p = pt3(
+            uv.y * radius * cos,
+            half - uv.y * height,
+            -uv.y * radius * sin,
+        );
+        (p, normal, uv)
+    });
+    builder.cap(radius, -half, segments, Vec3::NEG_Y);
+    builder.build()
+}
+
+/// A torus centred on the origin, lying in the `xz` plane.
+///
+/// `major` is the distance from the origin to the centre of the tube and `minor` is the radius of
+/// the tube. The torus is divided into `segments` both around the `y` axis and around the tube.
+/// Texture coordinates wrap once in each direction, duplicating the vertices along both seams.
+pub fn torus(major: f32, minor: f32, segments: u32) -> Mesh {
+    let mut builder = Builder::default();
+    builder.surface(segments, segments, |uv| {
+        let (sin_p, cos_p) = sin_cos(uv.x * TAU);
+        let (sin_t, cos_t) = sin_cos(uv.y * TAU);
+        let radial = Vec3::new(cos_p, 0.0, -sin_p);
+        let normal = radial * sin_t + Vec3::Y * cos_t;
+        (radial * major + normal * minor, normal, uv)
+    });
+    builder.build()
+}
+
+/// A sphere centred on the origin, tessellated evenly by subdividing a regular icosahedron.
+///
+/// Each subdivision splits every triangle into four, projecting the new vertices onto the sphere.
+/// Vertices are shared between neighbouring triangles, producing `20 * 4^subdivisions` triangles
+/// and `10 * 4^subdivisions + 2` vertices. Each normal is the direction of its vertex from the
+/// origin.
+///
+/// Unlike `uv_sphere`, the mesh has no seam and therefore no texture coordinates. They may be
+/// added with `uv::project_uvs_spherical`, though this will wrap back across the texture on the
+/// triangles that straddle the `-x` side of the `xz` plane.
+pub fn icosphere(subdivisions: u32, radius: f32) -> Icosphere {
+    // The golden ratio.
+    const T: f32 = 1.618_034;
+    #[rustfmt::skip]
+    let mut normals: Vec<Vec3> = [
+        [-1.0, T, 0.0], [1.0, T, 0.0], [-1.0, -T, 0.0], [1.0, -T, 0.0],
+        [0.0, -1.0, T], [0.0, 1.0, T], [0.0, -1.0, -T], [0.0, 1.0, -T],
+        [T, 0.0, -1.0], [T, 0.0, 1.0], [-T, 0.0, -1.0], [-T, 0.0, 1.0],
+    ]
+    .iter()
+    .map(|&p| Vec3::from(p).normalize())
+    .collect();
+    #[rustfmt::skip]
+    let mut indices: Vec<u32> = vec![
+        0, 11, 5, 0, 5, 1, 0, 1, 7, 0, 7, 10, 0, 10, 11,
+        1, 5, 9, 5, 11, 4, 11, 10, 2, 10, 7, 6, 7, 1, 8,
+        3, 9, 4, 3, 4, 2, 3, 2, 6, 3, 6, 8, 3, 8, 9,
+        4, 9, 5, 2, 4, 11, 6, 2, 10, 8, 6, 7, 9, 8, 1,
+    ];
+    for _ in 0..subdivisions {
+        // Maps each edge to the index of its midpoint so that neighbours share the new vertex.
+        let mut midpoints = BTreeMap::new();
+        let mut midpoint = |a: u32, b: u32| {
+            let key = (a.min(b), a.max(b));
+            *midpoints.entry(key).or_insert_with(|| {
+                let n = (normals[a as usize] + normals[b as usize]).normalize();
+                normals.push(n);
+                normals.len() as u32 - 1
+            })
+        };
+        let mut subdivided = Vec::with_capacity(indices.len() * 4);
+        for tri in indices.chunks_exact(3) {
+            let (a, b, c) = (tri[0], tri[1], tri[2]);
+            let (ab, bc, ca) = (midpoint(a, b), midpoint(b, c), midpoint(c, a));
+            subdivided.extend_from_slice(&[a, ab, ca, b, bc, ab, c, ca, bc, ab, bc, ca]);
+        }
+        indices = subdivided;
+    }
+    let points = normals.iter().map(|&n| n * radius).collect();
+    with_indices(with_normals(from_points(points), normals), indices)
+}
+
+/// A wireframe of `cols` by `rows` cells lying in the `xz` plane, described as a line list.
+///
+/// The points match those of a `plane` with the same arguments. Every two indices describe a
+/// single line segment between neighbouring points, e.g. for drawing via a line list topology.
+pub fn grid_lines(cols: u32, rows: u32, size: Vec2) -> GridLines {
+    let mut points = Vec::with_capacity(grid_vertex_count(cols, rows));
+    for row in 0..=rows {
+        for col in 0..=cols {
+            points.push(grid_point(grid_uv(col, cols, row, rows), size));
+        }
+    }
+    let mut indices = vec![];
+    for row in 0..=rows {
+        for col in 0..cols {
+            indices.push(grid_index(col, row, cols));
+            indices.push(grid_index(col + 1, row, cols));
+        }
+    }
+    for col in 0..=cols {
+        for row in 0..rows {
+            indices.push(grid_index(col, row, cols));
+            indices.push(grid_index(col, row + 1, cols));
+        }
+    }
+    with_indices(from_points(points), indices)
+}
+
+// Accumulates the channels of a `Mesh`.
+#[derive(Default)]
+struct Builder {
+    points: Vec<Point3>,
+    normals: Vec<Vec3>,
+    tex_coords: Vec<Point2>,
+    indices: Vec<u32>,
+}
+
+impl Builder {
+    // Append a parametric surface of `cols` by `rows` quads, where `f` maps a normalised grid
+    // position to a vertex's point, normal and texture coordinates.
+    //
+    // Triangles are wound counter-clockwise when the surface is viewed from the side in which the
+    // `v` direction faces down and the `u` direction faces right. Triangles with two coincident
+    // points, e.g. at the pole of a sphere, are skipped.
+    fn surface<F>(&mut self, cols: u32, rows: u32, mut f: F)
+    where
+        F: FnMut(Point2) -> (Point3, Vec3, Point2),
+    {
+        let start = self.points.len() as u32;
+        for row in 0..=rows {
+            for col in 0..=cols {
+                let (p, n, uv) = f(grid_uv(col, cols, row, rows));
+                self.points.push(p);
+                self.normals.push(n);
+                self.tex_coords.push(uv);
+            }
+        }
+        for row in 0..rows {
+            for col in 0..cols {
+                let a = start + grid_index(col, row, cols);
+                let b = start + grid_index(col, row + 1, cols);
+                let c = start + grid_index(col + 1, row, cols);
+                let d = start + grid_index(col + 1, row + 1, cols);
+                for &tri in [[a, b, c], [c, b, d]].iter() {
+                    let [a, b, c] = tri.map(|i| self.points[i as usize]);
+                    if a != b && b != c && c != a {
+                        self.indices.extend_from_slice(&tri);
+                    }
+                }
+            }
+        }
+    }
+
+    // Append a flat disc at height `y` facing along `normal`, which must be `Y` or `NEG_Y`.
+    fn cap(&mut self, radius: f32, y: f32, segments: u32, normal: Vec3) {
+        self.surface(segments, 1, |uv| {
+            let (sin, cos) = sin_cos(uv.x * TAU);
+            let (x, z) = (uv.y * cos, -uv.y * sin * normal.y);
+            let tex = pt2(0.5 + x * 0.5, 0.5 + z * 0.5 * normal.y);
+            (pt3(x * radius, y, z * radius), normal, tex)
+        });
+    }
+
+    fn build(self) -> Mesh {
+        let mesh = with_tex_coords(from_points(self.points), self.tex_coords);
+        let mesh = with_normals(mesh, self.normals);
+        with_indices(mesh, self.indices)
+    }
+}
+
+// Uses `Float` rather than the inherent method so that `libm` may be used in `no_std` builds.
+fn sin_cos(radians: f32) -> (f32, f32) {
+    Float::sin_cos(radians)
+}
+
+// The number of vertices within a grid of the given number of cells.
+fn grid_vertex_count(cols: u32, rows: u32) -> usize {
+    (cols as usize + 1) * (rows as usize + 1)
+}
+
+// The index of the vertex at the given column and row of the grid.
+fn grid_index(col: u32, row: u32, cols: u32) -> u32 {
+    row * (cols + 1) + col
+}
+
+// The normalised position of the vertex at the given column and row of the grid.
+fn grid_uv(col: u32, cols: u32, row: u32, rows: u32) -> Point2 {
+    let fract = |i: u32, n: u32| if n == 0 { 0.0 } else { i as f32 / n as f32 };
+    pt2(fract(col, cols), fract(row, rows))
+}
+
+// The point at the given normalised position within a plane of the given size.
+fn grid_point(uv: Point2, size: Vec2) -> Point3 {
+    let p = (uv - 0.5) * size;
+    pt3(p.x, 0.0, p.y)
+}
+
+#[test]
+fn test_plane_and_grid_lines() {
+    use crate::{raw_vertex_count, triangle_count, triangles, Indices, Points, TexCoords};
+    use splatter_core::geom::vec2;
+
+    let mesh = plane(2, 1, vec2(4.0, 2.0));
+    assert_eq!(raw_vertex_count(&mesh), 6);
+    assert_eq!(triangle_count(&mesh), 4);
+    assert_eq!(mesh.tex_coords()[5], pt2(1.0, 1.0));
+    // Each triangle faces up.
+    for tri in triangles(&mesh) {
+        let [a, b, c] = tri.0.map(|v| v.vertex.vertex);
+        let normal = (b - a).cross(c - a).normalize();
+        assert!((normal - Vec3::Y).length() < 1e-6);
+    }
+    let corners = [mesh.points()[0], mesh.points()[5]];
+    assert_eq!(corners, [pt3(-2.0, 0.0, -1.0), pt3(2.0, 0.0, 1.0)]);
+
+    // Two rows of two segments and three columns of one segment.
+    let lines = grid_lines(2, 1, vec2(4.0, 2.0));
+    assert_eq!(lines.points(), mesh.points());
+    assert_eq!(lines.indices().len(), 14);
+    assert_eq!(&lines.indices()[..4], &[0, 1, 1, 2]);
+}
+
+#[test]
+fn test_solids_wound_outward() {
+    use crate::{triangles, Points, TexCoords};
+
+    let meshes = [
+        uv_sphere(8, 16, 2.0),
+        cube(Vec3::new(1.0, 2.0, 3.0)),
+        cylinder(1.0, 2.0, 12),
+        cone(1.0, 2.0, 12),
+        torus(2.0, 0.5, 12),
+    ];
+    for mesh in meshes.iter() {
+        // Each triangle faces the same way as the normals of its vertices.
+        for tri in triangles(mesh) {
+            let [a, b, c] = tri.0.map(|v| v.vertex.vertex);
+            let face = (b - a).cross(c - a);
+            assert!(face.length() > 0.0);
+            for v in tri.0.iter() {
+                assert!(face.dot(v.normal) > 0.0);
+            }
+        }
+    }
+
+    // The seam of the sphere is duplicated with `u` at both `0.0` and `1.0`.
+    let sphere = &meshes[0];
+    let (p, uv) = (sphere.points()[17 * 4], sphere.tex_coords()[17 * 4]);
+    let (q, uv2) = (sphere.points()[17 * 5 - 1], sphere.tex_coords()[17 * 5 - 1]);
+    assert!((p - q).length() < 1e-6);
+    assert_eq!((uv.x, uv2.x), (0.0, 1.0));
+    assert_eq!(uv.y, uv2.y);
+}
+
+#[test]
+fn test_icosphere() {
+    use crate::{raw_vertex_count, triangle_count, triangles, Points};
+
+    for n in 0..4 {
+        let mesh = icosphere(n, 2.0);
+        assert_eq!(triangle_count(&mesh), 20 * 4usize.pow(n));
+        assert_eq!(raw_vertex_count(&mesh), 10 * 4usize.pow(n) + 2);
+        for p in mesh.points() {
+            assert!((p.length() - 2.0).abs() < 1e-5);
+        }
+        for tri in triangles(&mesh) {
+            let [a, b, c] = tri.0.map(|v| v.vertex);
+            let face = (b - a).cross(c - a);
+            assert!(face.dot(a + b + c) > 0.0);
+        }
+    }
+}
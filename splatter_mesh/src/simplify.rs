@@ -0,0 +1,416 @@
+//! Mesh simplification via quadric error metric edge collapse.
+
+use crate::{from_points, with_indices, Channel, Indices, MeshPoints, Points, WithIndices};
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::convert::TryInto;
+use splatter_core::geom::{DVec3, Point3};
+use std::collections::{BinaryHeap, HashMap};
+
+/// The mesh type produced by `simplify`.
+pub type Simplified = WithIndices<MeshPoints<Vec<Point3>>, Vec<u32>>;
+
+// A symmetric 4x4 matrix describing the sum of squared distances to a set of planes.
+//
+// Stores the upper triangle `[aa, ab, ac, ad, bb, bc, bd, cc, cd, dd]`.
+#[derive(Copy, Clone, Debug, Default)]
+struct Quadric([f64; 10]);
+
+// A candidate edge collapse within the queue, ordered by ascending cost.
+#[derive(Debug)]
+struct Collapse {
+    cost: f64,
+    position: DVec3,
+    vertices: [usize; 2],
+    versions: [u32; 2],
+}
+
+/// Reduce the number of triangles in the given indexed triangle mesh to `target_triangles`.
+///
+/// Edges are collapsed in order of their quadric error, i.e. the sum of squared distances from
+/// the collapsed point to the planes of the original surrounding triangles. Vertices on boundary
+/// edges are never moved or removed so that the outline of open meshes is preserved, though
+/// interior vertices may still be collapsed into them. Collapses that would flip the normal of a
+/// surrounding triangle or produce non-manifold geometry are rejected. As a result, the produced
+/// mesh may contain more than `target_triangles` triangles if no further valid collapses remain.
+///
+/// Triangles with repeated indices are skipped and points not referenced by any triangle are
+/// removed.
+///
+/// **Panics** if an index does not fit within a `usize` or is out of range of the mesh's points.
+pub fn simplify<M>(mesh: &M, target_triangles: usize) -> Simplified
+where
+    M: Points + Indices,
+    M::Point: Clone + Into<Point3>,
+    M::Index: Clone + TryInto<usize>,
+{
+    let mut points: Vec<DVec3> = mesh
+        .points()
+        .iter()
+        .map(|p| p.clone().into().as_dvec3())
+        .collect();
+    let indices: Vec<usize> = mesh
+        .indices()
+        .iter()
+        .map(|i| {
+            i.clone()
+                .try_into()
+                .unwrap_or_else(|_err| panic!("index out of range of valid usize values"))
+        })
+        .collect();
+    let mut triangles: Vec<Option<[usize; 3]>> = indices
+        .chunks_exact(3)
+        .map(|tri| [tri[0], tri[1], tri[2]])
+        .filter(|&[a, b, c]| a != b && b != c && c != a)
+        .map(Some)
+        .collect();
+
+    // The faces surrounding each vertex.
+    let mut faces: Vec<Vec<usize>> = vec![vec![]; points.len()];
+    for (f, tri) in triangles.iter().enumerate() {
+        for &v in tri.iter().flatten() {
+            faces[v].push(f);
+        }
+    }
+
+    // Lock vertices on boundary edges, i.e. edges with a single adjacent face.
+    let mut edge_faces: HashMap<(usize, usize), usize> = HashMap::new();
+    for &[a, b, c] in triangles.iter().flatten() {
+        for &(v0, v1) in &[(a, b), (b, c), (c, a)] {
+            *edge_faces.entry(edge_key(v0, v1)).or_insert(0) += 1;
+        }
+    }
+    let mut locked = vec![false; points.len()];
+    for (&(a, b), &count) in &edge_faces {
+        if count == 1 {
+            locked[a] = true;
+            locked[b] = true;
+        }
+    }
+
+    // Accumulate the quadric for each vertex from the planes of its surrounding faces.
+    let mut quadrics = vec![Quadric::default(); points.len()];
+    for &[a, b, c] in triangles.iter().flatten() {
+        let (pa, pb, pc) = (points[a], points[b], points[c]);
+        let normal = (pb - pa).cross(pc - pa);
+        if normal.length_squared() == 0.0 {
+            continue;
+        }
+        let normal = normal.normalize();
+        let q = Quadric::plane(normal, -normal.dot(pa));
+        for &v in &[a, b, c] {
+            quadrics[v] = quadrics[v].add(&q);
+        }
+    }
+
+    // Queue a candidate collapse for every edge.
+    let mut versions = vec![0u32; points.len()];
+    let mut queue = BinaryHeap::new();
+    for &(a, b) in edge_faces.keys() {
+        queue.extend(collapse(&points, &quadrics, &versions, &locked, a, b));
+    }
+
+    let mut triangle_count = triangles.iter().flatten().count();
+    while triangle_count > target_triangles {
+        let Collapse {
+            position,
+            vertices: [a, b],
+            versions: collapse_versions,
+            ..
+        } = match queue.pop() {
+            None => break,
+            Some(collapse) => collapse,
+        };
+        if collapse_versions != [versions[a], versions[b]] {
+            continue;
+        }
+        if !is_valid_collapse(&points, &triangles, &faces, a, b, position) {
+            continue;
+        }
+
+        // Remove the faces shared by the edge and move `b`'s faces over to `a`.
+        let b_faces = std::mem::take(&mut faces[b]);
+        for f in b_faces {
+            let tri = match triangles[f].as_mut() {
+                Some(tri) => tri,
+                None => continue,
+            };
+            if tri.contains(&a) {
+                triangles[f] = None;
+                triangle_count -= 1;
+            } else {
+                for v in tri.iter_mut().filter(|v| **v == b) {
+                    *v = a;
+                }
+                faces[a].push(f);
+            }
+        }
+        faces[a].retain(|&f| triangles[f].is_some());
+        points[a] = position;
+        quadrics[a] = quadrics[a].add(&quadrics[b]);
+        versions[a] += 1;
+        versions[b] += 1;
+
+        // Queue new candidate collapses for the edges surrounding the merged vertex.
+        for n in neighbours(&triangles, &faces, a) {
+            queue.extend(collapse(&points, &quadrics, &versions, &locked, a, n));
+        }
+    }
+
+    // Collect the remaining triangles, removing unreferenced points.
+    let mut remap: Vec<Option<u32>> = vec![None; points.len()];
+    let mut new_points = vec![];
+    let mut new_indices = vec![];
+    for tri in triangles.iter().flatten() {
+        for &v in tri {
+            let index = *remap[v].get_or_insert_with(|| {
+                new_points.push(points[v].as_vec3());
+                (new_points.len() - 1)
+                    .try_into()
+                    .unwrap_or_else(|_err| panic!("index out of range of valid u32 values"))
+            });
+            new_indices.push(index);
+        }
+    }
+    with_indices(from_points(new_points), new_indices)
+}
+
+impl Quadric {
+    // The quadric for the plane `ax + by + cz + d = 0`.
+    fn plane(n: DVec3, d: f64) -> Self {
+        let (a, b, c) = (n.x, n.y, n.z);
+        Quadric([
+            a * a,
+            a * b,
+            a * c,
+            a * d,
+            b * b,
+            b * c,
+            b * d,
+            c * c,
+            c * d,
+            d * d,
+        ])
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut q = *self;
+        for (a, b) in q.0.iter_mut().zip(other.0.iter()) {
+            *a += *b;
+        }
+        q
+    }
+
+    // The sum of squared distances from `p` to the planes of the quadric.
+    fn error(&self, p: DVec3) -> f64 {
+        let [aa, ab, ac, ad, bb, bc, bd, cc, cd, dd] = self.0;
+        let (x, y, z) = (p.x, p.y, p.z);
+        aa * x * x
+            + 2.0 * ab * x * y
+            + 2.0 * ac * x * z
+            + 2.0 * ad * x
+            + bb * y * y
+            + 2.0 * bc * y * z
+            + 2.0 * bd * y
+            + cc * z * z
+            + 2.0 * cd * z
+            + dd
+    }
+
+    // The point minimising the error of the quadric, if the quadric is not singular.
+    fn optimal_point(&self) -> Option<DVec3> {
+        let [aa, ab, ac, ad, bb, bc, bd, cc, cd, _] = self.0;
+        let det = aa * (bb * cc - bc * bc) - ab * (ab * cc - bc * ac) + ac * (ab * bc - bb * ac);
+        if det.abs() < 1e-12 {
+            return None;
+        }
+        let (rx, ry, rz) = (-ad, -bd, -cd);
+        let x = rx * (bb * cc - bc * bc) - ab * (ry * cc - bc * rz) + ac * (ry * bc - bb * rz);
+        let y = aa * (ry * cc - rz * bc) - rx * (ab * cc - bc * ac) + ac * (ab * rz - ry * ac);
+        let z = aa * (bb * rz - bc * ry) - ab * (ab * rz - ry * ac) + rx * (ab * bc - bb * ac);
+        Some(DVec3::new(x, y, z) / det)
+    }
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Collapse {
+    // Reversed so that the `BinaryHeap` yields the cheapest collapse first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+// The cheapest collapse of the edge `(a, b)`, or `None` if both vertices are locked.
+//
+// A locked vertex is always the one kept, and remains at its original position.
+fn collapse(
+    points: &[DVec3],
+    quadrics: &[Quadric],
+    versions: &[u32],
+    locked: &[bool],
+    a: usize,
+    b: usize,
+) -> Option<Collapse> {
+    let (a, b) = if locked[b] { (b, a) } else { (a, b) };
+    if locked[b] {
+        return None;
+    }
+    let q = quadrics[a].add(&quadrics[b]);
+    let (pa, pb) = (points[a], points[b]);
+    let candidates = match locked[a] {
+        true => [Some(pa), None, None, None],
+        false => [q.optimal_point(), Some(pa), Some(pb), Some((pa + pb) * 0.5)],
+    };
+    let (cost, position) = candidates
+        .iter()
+        .flatten()
+        .map(|&p| (q.error(p), p))
+        .min_by(|x, y| x.0.total_cmp(&y.0))?;
+    Some(Collapse {
+        cost,
+        position,
+        vertices: [a, b],
+        versions: [versions[a], versions[b]],
+    })
+}
+
+// Whether or not collapsing `b` into `a` at `position` keeps the mesh manifold and avoids
+// flipping the normals of the surrounding faces.
+fn is_valid_collapse(
+    points: &[DVec3],
+    triangles: &[Option<[usize; 3]>],
+    faces: &[Vec<usize>],
+    a: usize,
+    b: usize,
+    position: DVec3,
+) -> bool {
+    // The link condition: the only shared neighbours are those opposite the collapsed edge.
+    let na = neighbours(triangles, faces, a);
+    let nb = neighbours(triangles, faces, b);
+    let shared_neighbours = na.iter().filter(|v| nb.contains(v)).count();
+    let shared_faces = faces[a]
+        .iter()
+        .filter(|&&f| triangles[f].is_some_and(|tri| tri.contains(&b)))
+        .count();
+    if shared_neighbours != shared_faces {
+        return false;
+    }
+
+    // Reject collapses that would produce duplicate faces, e.g. when collapsing a tetrahedron.
+    let remaining = |v: usize, other: usize| {
+        faces[v]
+            .iter()
+            .filter_map(move |&f| triangles[f])
+            .filter(move |tri| !tri.contains(&other))
+            .map(move |tri| {
+                let mut tri = tri.map(|i| if i == b { a } else { i });
+                tri.sort_unstable();
+                tri
+            })
+    };
+    if remaining(b, a).any(|tb| remaining(a, b).any(|ta| ta == tb)) {
+        return false;
+    }
+
+    // Reject collapses that flip or degenerate any of the remaining faces.
+    for &v in &[a, b] {
+        for &f in &faces[v] {
+            let tri = match triangles[f] {
+                Some(tri) if !(tri.contains(&a) && tri.contains(&b)) => tri,
+                _ => continue,
+            };
+            let [p0, p1, p2] = tri.map(|i| points[i]);
+            let [q0, q1, q2] = tri.map(|i| if i == v { position } else { points[i] });
+            let before = (p1 - p0).cross(p2 - p0);
+            let after = (q1 - q0).cross(q2 - q0);
+            if before.dot(after) <= 0.0 {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+// The vertices sharing a live face with `v`.
+fn neighbours(triangles: &[Option<[usize; 3]>], faces: &[Vec<usize>], v: usize) -> Vec<usize> {
+    let mut ns = vec![];
+    for tri in faces[v].iter().filter_map(|&f| triangles[f]) {
+        for &n in tri.iter().filter(|&&n| n != v) {
+            if !ns.contains(&n) {
+                ns.push(n);
+            }
+        }
+    }
+    ns
+}
+
+// A key that is the same for both directions of an edge.
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+#[test]
+fn test_simplify() {
+    use crate::{raw_vertex_count, triangle_count};
+    use splatter_core::geom::pt3;
+
+    // A flat 5x5 grid of points, triangulated into 32 triangles.
+    let n = 5;
+    let mut points = vec![];
+    let mut indices = vec![];
+    for y in 0..n {
+        for x in 0..n {
+            points.push(pt3(x as f32, y as f32, 0.0));
+        }
+    }
+    for y in 0..n - 1 {
+        for x in 0..n - 1 {
+            let i = (y * n + x) as u32;
+            let n = n as u32;
+            indices.extend_from_slice(&[i, i + 1, i + n + 1, i, i + n + 1, i + n]);
+        }
+    }
+    let mesh = with_indices(from_points(points), indices);
+    let simplified = simplify(&mesh, 0);
+    // Only the 9 interior points may be collapsed, leaving the 16 boundary points.
+    assert!(triangle_count(&simplified) < 32);
+    assert_eq!(raw_vertex_count(&simplified), 16);
+    // The grid remains flat and its triangles keep their winding.
+    for tri in simplified.indices().chunks(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| simplified.points()[tri[i] as usize]);
+        assert_eq!(a.z, 0.0);
+        assert!((b - a).cross(c - a).z > 0.0);
+    }
+    // A target above the triangle count leaves the mesh unchanged.
+    let unchanged = simplify(&mesh, 32);
+    assert_eq!(triangle_count(&unchanged), 32);
+
+    // Closed meshes may be simplified down to a tetrahedron, but no further.
+    let points = vec![
+        pt3(1.0, 1.0, 1.0),
+        pt3(-1.0, -1.0, 1.0),
+        pt3(-1.0, 1.0, -1.0),
+        pt3(1.0, -1.0, -1.0),
+    ];
+    let indices = vec![0u32, 1, 2, 0, 3, 1, 0, 2, 3, 1, 3, 2];
+    let mesh = crate::loop_subdivide(&with_indices(from_points(points), indices), 2);
+    assert_eq!(triangle_count(simplify(&mesh, 20)), 20);
+    assert_eq!(triangle_count(simplify(&mesh, 0)), 4);
+}
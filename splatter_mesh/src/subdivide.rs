@@ -0,0 +1,202 @@
+//! Subdivision surface algorithms for smoothing indexed triangle meshes.
+
+use crate::{from_points, with_indices, Channel, Indices, MeshPoints, Points, WithIndices};
+use alloc::{vec, vec::Vec};
+use core::convert::TryInto;
+use splatter_core::geom::Point3;
+use std::collections::HashMap;
+
+/// The mesh type produced by `loop_subdivide`.
+pub type LoopSubdivided = WithIndices<MeshPoints<Vec<Point3>>, Vec<u32>>;
+
+// The vertices adjacent to an edge along with the index of its new edge point.
+struct Edge {
+    opposite: Vec<u32>,
+    index: u32,
+}
+
+/// Smooth the given indexed triangle mesh via the given number of iterations of Loop
+/// subdivision.
+///
+/// Each iteration splits every triangle into four, inserting a new point along each edge and
+/// repositioning the original points based on their valence. Edges that are only shared by a
+/// single triangle are treated as boundary edges and use the boundary stencil, so that open meshes
+/// do not shrink away from their boundaries.
+///
+/// Triangles with repeated indices are skipped. The winding order of the triangles is preserved.
+///
+/// **Panics** if an index does not fit within a `usize` or if the subdivided mesh contains more
+/// than `u32::MAX` points.
+pub fn loop_subdivide<M>(mesh: &M, iterations: u32) -> LoopSubdivided
+where
+    M: Points + Indices,
+    M::Point: Clone + Into<Point3>,
+    M::Index: Clone + TryInto<usize>,
+{
+    let mut points: Vec<Point3> = mesh.points().iter().cloned().map(Into::into).collect();
+    let indices: Vec<u32> = mesh
+        .indices()
+        .iter()
+        .map(|i| {
+            let i: usize = i
+                .clone()
+                .try_into()
+                .unwrap_or_else(|_err| panic!("index out of range of valid usize values"));
+            to_u32(i)
+        })
+        .collect();
+    let mut triangles: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|tri| [tri[0], tri[1], tri[2]])
+        .filter(|&[a, b, c]| a != b && b != c && c != a)
+        .collect();
+    for _ in 0..iterations {
+        let (new_points, new_triangles) = loop_subdivide_once(&points, &triangles);
+        points = new_points;
+        triangles = new_triangles;
+    }
+    let indices = triangles.into_iter().flatten().collect();
+    with_indices(from_points(points), indices)
+}
+
+// A single iteration of Loop subdivision.
+fn loop_subdivide_once(points: &[Point3], triangles: &[[u32; 3]]) -> (Vec<Point3>, Vec<[u32; 3]>) {
+    // Collect the edges along with the vertices opposite to them, assigning each a new index.
+    let mut edges: HashMap<(u32, u32), Edge> = HashMap::new();
+    let mut next_index = to_u32(points.len());
+    for &[a, b, c] in triangles {
+        for &(v0, v1, opposite) in &[(a, b, c), (b, c, a), (c, a, b)] {
+            let edge = edges.entry(edge_key(v0, v1)).or_insert_with(|| {
+                let index = next_index;
+                next_index = to_u32(index as usize + 1);
+                let opposite = vec![];
+                Edge { opposite, index }
+            });
+            edge.opposite.push(opposite);
+        }
+    }
+
+    // Find the neighbours of each vertex, along with its neighbours along boundary edges.
+    let mut neighbours: Vec<Vec<u32>> = vec![vec![]; points.len()];
+    let mut boundary_neighbours: Vec<Vec<u32>> = vec![vec![]; points.len()];
+    for (&(a, b), edge) in &edges {
+        neighbours[a as usize].push(b);
+        neighbours[b as usize].push(a);
+        if edge.opposite.len() == 1 {
+            boundary_neighbours[a as usize].push(b);
+            boundary_neighbours[b as usize].push(a);
+        }
+    }
+
+    // Reposition the original vertices.
+    let mut new_points = Vec::with_capacity(next_index as usize);
+    for (i, &p) in points.iter().enumerate() {
+        let boundary = &boundary_neighbours[i];
+        let ns = &neighbours[i];
+        let p = if !boundary.is_empty() {
+            // Boundary vertices only take the neighbouring boundary vertices into account.
+            match boundary[..] {
+                [b0, b1] => p * 0.75 + (points[b0 as usize] + points[b1 as usize]) * 0.125,
+                _ => p,
+            }
+        } else if ns.is_empty() {
+            p
+        } else {
+            let n = ns.len() as f32;
+            let beta = loop_beta(ns.len());
+            let sum = ns
+                .iter()
+                .fold(Point3::ZERO, |acc, &j| acc + points[j as usize]);
+            p * (1.0 - n * beta) + sum * beta
+        };
+        new_points.push(p);
+    }
+
+    // Position each new edge point, using the boundary stencil for edges with a single face.
+    new_points.resize(next_index as usize, Point3::ZERO);
+    for (&(a, b), edge) in &edges {
+        let (pa, pb) = (points[a as usize], points[b as usize]);
+        let p = match edge.opposite[..] {
+            [c, d] => (pa + pb) * 0.375 + (points[c as usize] + points[d as usize]) * 0.125,
+            _ => (pa + pb) * 0.5,
+        };
+        new_points[edge.index as usize] = p;
+    }
+
+    // Split each triangle into four.
+    let mut new_triangles = Vec::with_capacity(triangles.len() * 4);
+    for &[a, b, c] in triangles {
+        let ab = edges[&edge_key(a, b)].index;
+        let bc = edges[&edge_key(b, c)].index;
+        let ca = edges[&edge_key(c, a)].index;
+        new_triangles.push([a, ab, ca]);
+        new_triangles.push([ab, b, bc]);
+        new_triangles.push([ca, bc, c]);
+        new_triangles.push([ab, bc, ca]);
+    }
+
+    (new_points, new_triangles)
+}
+
+// The weight applied to each neighbour of an interior vertex with the given valence.
+fn loop_beta(valence: usize) -> f32 {
+    let n = valence as f32;
+    let x = 0.375 + 0.25 * (core::f32::consts::TAU / n).cos();
+    (0.625 - x * x) / n
+}
+
+// A key that is the same for both directions of an edge.
+fn edge_key(a: u32, b: u32) -> (u32, u32) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+// Convert the given index to a `u32`, panicking if it does not fit.
+fn to_u32(i: usize) -> u32 {
+    i.try_into()
+        .unwrap_or_else(|_err| panic!("subdivided mesh index out of range of valid u32 values"))
+}
+
+#[test]
+fn test_loop_subdivide() {
+    use crate::triangle_count;
+    use splatter_core::geom::pt3;
+
+    // A tetrahedron is closed, so all vertices are interior.
+    let points = vec![
+        pt3(1.0, 1.0, 1.0),
+        pt3(-1.0, -1.0, 1.0),
+        pt3(-1.0, 1.0, -1.0),
+        pt3(1.0, -1.0, -1.0),
+    ];
+    let indices = vec![0u32, 1, 2, 0, 3, 1, 0, 2, 3, 1, 3, 2];
+    let mesh = with_indices(from_points(points.clone()), indices);
+    let subdivided = loop_subdivide(&mesh, 2);
+    assert_eq!(triangle_count(&subdivided), 4 * 16);
+    // V - E + F = 2 for a closed genus-0 mesh.
+    assert_eq!(subdivided.points().len(), 2 + 64 / 2);
+    // Interior vertices are pulled toward the centroid.
+    let first = loop_subdivide(&mesh, 1);
+    assert!(first.points()[0].length() < points[0].length());
+
+    // The corners of an open quad lie on the boundary, so its boundary does not shrink.
+    let quad = vec![
+        pt3(0.0, 0.0, 0.0),
+        pt3(1.0, 0.0, 0.0),
+        pt3(1.0, 1.0, 0.0),
+        pt3(0.0, 1.0, 0.0),
+    ];
+    let mesh = with_indices(from_points(quad), vec![0u32, 1, 2, 0, 2, 3]);
+    let subdivided = loop_subdivide(&mesh, 1);
+    assert_eq!(subdivided.points().len(), 4 + 5);
+    // The boundary edge point at the midpoint of the bottom edge.
+    assert!(subdivided
+        .points()
+        .iter()
+        .any(|p| (*p - pt3(0.5, 0.0, 0.0)).length() < 1e-6));
+    // Corner `1` only neighbours boundary vertices `0` and `2`.
+    assert!((subdivided.points()[1] - pt3(0.875, 0.125, 0.0)).length() < 1e-6);
+}
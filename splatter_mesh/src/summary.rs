@@ -0,0 +1,154 @@
+//! Concise descriptions of meshes for logging and debugging.
+//!
+//! The `Debug` implementations of the mesh types print every element of every channel, which is
+//! rarely useful for meshes of any real size. The `summary` function instead reports the length
+//! of each channel along with the bounds of the mesh's points.
+
+use crate::{Channel, ChannelLengths, Points};
+use core::fmt;
+use splatter_core::geom::{self, Cuboid, Point3};
+
+/// The length of each channel within a mesh, or `None` for the channels the mesh does not have.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelLens {
+    /// The number of points, i.e. the raw vertex count.
+    pub points: usize,
+    pub indices: Option<usize>,
+    pub colors: Option<usize>,
+    pub tex_coords: Option<usize>,
+    pub normals: Option<usize>,
+    pub faces: Option<usize>,
+}
+
+/// A concise summary of a mesh produced via `summary`.
+///
+/// The `Display` implementation describes the mesh on a single line, e.g.
+/// `24 vertices, 36 indices (12 triangles), channels: [indices, tex_coords, normals], bounds:
+/// [-0.5, -0.5, -0.5]..[0.5, 0.5, 0.5]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MeshSummary {
+    /// The length of each channel within the mesh.
+    pub channels: ChannelLens,
+    /// The bounding box of the mesh's points, or `None` if the mesh has no points.
+    pub bounds: Option<Cuboid>,
+}
+
+/// Summarise the given mesh's channels and the bounds of its points.
+pub fn summary<M>(mesh: &M) -> MeshSummary
+where
+    M: Points + ChannelLengths,
+    M::Point: Clone + Into<Point3>,
+{
+    let points = mesh.points().iter().cloned().map(Into::into);
+    MeshSummary {
+        channels: mesh.channel_lens(),
+        bounds: geom::bounding_cuboid(points),
+    }
+}
+
+impl MeshSummary {
+    /// The number of vertices within the mesh's points channel.
+    pub fn vertex_count(&self) -> usize {
+        self.channels.points
+    }
+
+    /// The number of triangles described by the mesh's indices, or `None` if the mesh has no
+    /// indices channel.
+    pub fn triangle_count(&self) -> Option<usize> {
+        self.channels
+            .indices
+            .map(|len| len / geom::tri::NUM_VERTICES as usize)
+    }
+}
+
+impl fmt::Display for MeshSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ChannelLens {
+            points,
+            indices,
+            colors,
+            tex_coords,
+            normals,
+            faces,
+        } = self.channels;
+        write!(f, "{} vertices", points)?;
+        if let (Some(indices), Some(triangles)) = (indices, self.triangle_count()) {
+            write!(f, ", {} indices ({} triangles)", indices, triangles)?;
+        }
+        if let Some(faces) = faces {
+            write!(f, ", {} faces", faces)?;
+        }
+        write!(f, ", channels: [")?;
+        let present = [
+            ("indices", indices, false),
+            ("colors", colors, true),
+            ("tex_coords", tex_coords, true),
+            ("normals", normals, true),
+            ("faces", faces, false),
+        ];
+        let mut first = true;
+        for &(name, len, per_vertex) in present.iter() {
+            let len = match len {
+                None => continue,
+                Some(len) => len,
+            };
+            if !first {
+                write!(f, ", ")?;
+            }
+            first = false;
+            write!(f, "{}", name)?;
+            // Highlight vertex channels whose length differs from the number of points.
+            if per_vertex && len != points {
+                write!(f, " (len {})", len)?;
+            }
+        }
+        write!(f, "]")?;
+        match self.bounds {
+            None => write!(f, ", bounds: none"),
+            Some(b) => write!(
+                f,
+                ", bounds: [{}, {}, {}]..[{}, {}, {}]",
+                b.x.start, b.y.start, b.z.start, b.x.end, b.y.end, b.z.end
+            ),
+        }
+    }
+}
+
+#[test]
+fn test_summary_display() {
+    use crate::{from_points, with_colors, with_indices};
+    use alloc::{string::ToString, vec};
+
+    let points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(1.0, 2.0, 0.0),
+    ];
+    let colors = vec![[1.0, 0.0, 0.0, 1.0]; 3];
+    let mesh = with_indices(with_colors(from_points(points), colors), vec![0u32, 1, 2]);
+    let summary = summary(&mesh);
+    assert_eq!(summary.vertex_count(), 3);
+    assert_eq!(summary.triangle_count(), Some(1));
+    assert_eq!(
+        summary.to_string(),
+        "3 vertices, 3 indices (1 triangles), channels: [indices, colors], \
+         bounds: [0, 0, -1]..[1, 2, 0]"
+    );
+
+    // Vertex channels of the wrong length are highlighted.
+    let channels = ChannelLens {
+        points: 2,
+        colors: Some(1),
+        ..ChannelLens::default()
+    };
+    let summary = MeshSummary {
+        channels,
+        bounds: None,
+    };
+    assert_eq!(
+        summary.to_string(),
+        "2 vertices, channels: [colors (len 1)], bounds: none"
+    );
+    let empty = from_points(vec![Point3::ZERO; 0]);
+    assert_eq!(crate::summary(&empty).bounds, None);
+}
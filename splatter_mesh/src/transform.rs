@@ -0,0 +1,55 @@
+//! Baking transforms into the stored points and normals of a mesh.
+
+use crate::{NormalsMut, PointsMut};
+use splatter_core::geom::{Point3, Vec3};
+use splatter_core::glam::{Mat3, Mat4};
+
+/// Transform every point of the given mesh in place by the affine transform `m`.
+///
+/// Unlike mapping the points while iterating over the mesh, this permanently mutates the points
+/// stored within the mesh's vertex channel.
+///
+/// Meshes with normals should use `transform_mesh_with_normals` instead so that the normals are
+/// rotated along with the points.
+pub fn transform_mesh<M>(mesh: &mut M, m: Mat4)
+where
+    M: PointsMut<Point = Point3>,
+{
+    for p in mesh.points_mut() {
+        *p = m.transform_point3(*p);
+    }
+}
+
+/// Transform every point of the given mesh in place by the affine transform `m`, and every normal
+/// by its inverse-transpose.
+///
+/// Normals are renormalized after being transformed so that they remain unit length under scaling.
+/// If `m` is not invertible, the normals are set to zero.
+pub fn transform_mesh_with_normals<M>(mesh: &mut M, m: Mat4)
+where
+    M: PointsMut<Point = Point3> + NormalsMut<Normal = Vec3>,
+{
+    transform_mesh(mesh, m);
+    let normal_matrix = Mat3::from_mat4(m).inverse().transpose();
+    for n in mesh.normals_mut() {
+        *n = (normal_matrix * *n).normalize_or_zero();
+    }
+}
+
+#[test]
+fn test_transform_mesh_with_normals() {
+    use crate::{from_points, with_normals, Normals, Points};
+    use alloc::vec;
+    use splatter_core::geom::pt3;
+
+    let points = vec![pt3(1.0, 0.0, 0.0), pt3(0.0, 1.0, 0.0)];
+    let normals = vec![Vec3::X, Vec3::new(1.0, 1.0, 0.0).normalize()];
+    let mut mesh = with_normals(from_points(points), normals);
+    let m = Mat4::from_translation(Vec3::Z) * Mat4::from_scale(Vec3::new(2.0, 1.0, 1.0));
+    transform_mesh_with_normals(&mut mesh, m);
+    assert_eq!(mesh.points()[0], pt3(2.0, 0.0, 1.0));
+    assert_eq!(mesh.points()[1], pt3(0.0, 1.0, 1.0));
+    assert_eq!(mesh.normals()[0], Vec3::X);
+    let expected = Vec3::new(0.5, 1.0, 0.0).normalize();
+    assert!((mesh.normals()[1] - expected).length() < 1e-6);
+}
@@ -0,0 +1,77 @@
+//! Splitting shared vertices for rendering meshes with flat shading.
+
+use crate::{
+    from_points, with_indices, with_normals, Channel, Indices, MeshPoints, Points, WithIndices,
+    WithNormals,
+};
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use splatter_core::geom::{Point3, Vec3};
+
+/// The mesh type produced by `unweld`.
+pub type Unwelded = WithIndices<WithNormals<MeshPoints<Vec<Point3>>, Vec<Vec3>>, Vec<u32>>;
+
+/// Split the vertices of the given indexed triangle mesh so that no vertex is shared between
+/// triangles, assigning each vertex the geometric normal of its triangle.
+///
+/// This is useful for rendering crisp, flat-shaded faces. The produced mesh contains three unique
+/// vertices for every triangle in the given mesh, with indices in ascending order. The normal is
+/// derived from the counter-clockwise winding of each triangle. Degenerate triangles are assigned
+/// a zero normal.
+///
+/// **Panics** if an index does not fit within a `usize` or is out of range of the mesh's points.
+pub fn unweld<M>(mesh: &M) -> Unwelded
+where
+    M: Points + Indices,
+    M::Point: Clone + Into<Point3>,
+    M::Index: Clone + TryInto<usize>,
+{
+    let point = |i: &M::Index| -> Point3 {
+        let i: usize = i
+            .clone()
+            .try_into()
+            .unwrap_or_else(|_err| panic!("index out of range of valid usize values"));
+        match mesh.points().get(i) {
+            Some(p) => p.clone().into(),
+            None => panic!("no point for the index produced by the mesh's indices channel"),
+        }
+    };
+    let indices: Vec<_> = mesh.indices().iter().collect();
+    let mut points = Vec::with_capacity(indices.len());
+    let mut normals = Vec::with_capacity(indices.len());
+    for tri in indices.chunks_exact(3) {
+        let [a, b, c] = [point(tri[0]), point(tri[1]), point(tri[2])];
+        let normal = (b - a).cross(c - a).normalize_or_zero();
+        points.extend_from_slice(&[a, b, c]);
+        normals.extend_from_slice(&[normal; 3]);
+    }
+    let len: u32 = points
+        .len()
+        .try_into()
+        .unwrap_or_else(|_err| panic!("index out of range of valid u32 values"));
+    let mesh = with_normals(from_points(points), normals);
+    with_indices(mesh, (0..len).collect())
+}
+
+#[test]
+fn test_unweld() {
+    use crate::{raw_vertex_count, triangle_count, Normals};
+    use alloc::vec;
+    use splatter_core::geom::pt3;
+
+    // Two triangles sharing an edge, folded at a right angle.
+    let points = vec![
+        pt3(0.0, 0.0, 0.0),
+        pt3(1.0, 0.0, 0.0),
+        pt3(1.0, 1.0, 0.0),
+        pt3(1.0, 0.0, -1.0),
+    ];
+    let mesh = with_indices(from_points(points), vec![0u32, 1, 2, 0, 3, 1]);
+    let unwelded = unweld(&mesh);
+    assert_eq!(triangle_count(&unwelded), 2);
+    assert_eq!(raw_vertex_count(&unwelded), 6);
+    assert_eq!(unwelded.indices(), &[0, 1, 2, 3, 4, 5]);
+    assert_eq!(unwelded.points()[3], pt3(0.0, 0.0, 0.0));
+    assert_eq!(unwelded.normals()[..3], [Vec3::Z; 3]);
+    assert_eq!(unwelded.normals()[3..], [Vec3::NEG_Y; 3]);
+}
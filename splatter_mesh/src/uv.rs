@@ -0,0 +1,208 @@
+//! Generating texture coordinates for meshes by projecting their points.
+
+use crate::unweld::{unweld, Unwelded};
+use crate::{with_tex_coords, Channel, Indices, Normals, Points, WithTexCoords};
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::f32::consts::{PI, TAU};
+use splatter_core::geom::{Point2, Point3, Vec3};
+
+/// Produce texture coordinates for each point by projecting the mesh onto the plane perpendicular
+/// to the given `axis`.
+///
+/// The projected points are scaled uniformly so that the larger of the two extents spans the
+/// range `0.0..=1.0`, preserving the aspect ratio of the mesh.
+///
+/// **Panics** if `axis` has a length of zero.
+pub fn project_uvs_planar<M>(mesh: &M, axis: Vec3) -> WithTexCoords<M, Vec<Point2>>
+where
+    M: Clone + Points,
+    M::Point: Clone + Into<Point3>,
+{
+    let axis = axis
+        .try_normalize()
+        .expect("`axis` must have a non-zero length");
+    let (u, v) = axis.any_orthonormal_pair();
+    let points: Vec<Point3> = mesh.points().iter().cloned().map(Into::into).collect();
+    let projected: Vec<Point2> = points
+        .iter()
+        .map(|p| Point2::new(p.dot(u), p.dot(v)))
+        .collect();
+    let tex_coords = normalize(projected);
+    with_tex_coords(mesh.clone(), tex_coords)
+}
+
+/// Produce texture coordinates for each point by projecting the mesh onto a sphere surrounding
+/// the centre of its bounding box.
+///
+/// The `u` coordinate wraps around the `y` axis, while the `v` coordinate runs from `0.0` at the
+/// top of the sphere to `1.0` at the bottom. Points at the centre of the sphere are assigned the
+/// texture coordinate `[0.5, 0.5]`.
+pub fn project_uvs_spherical<M>(mesh: &M) -> WithTexCoords<M, Vec<Point2>>
+where
+    M: Clone + Points,
+    M::Point: Clone + Into<Point3>,
+{
+    let points: Vec<Point3> = mesh.points().iter().cloned().map(Into::into).collect();
+    let (min, max) = bounds(&points);
+    let centre = (min + max) * 0.5;
+    let tex_coords = points
+        .iter()
+        .map(|&p| match (p - centre).try_normalize() {
+            None => Point2::splat(0.5),
+            Some(d) => {
+                let u = 0.5 + d.z.atan2(d.x) / TAU;
+                let v = d.y.clamp(-1.0, 1.0).acos() / PI;
+                Point2::new(u, v)
+            }
+        })
+        .collect();
+    with_tex_coords(mesh.clone(), tex_coords)
+}
+
+/// Produce texture coordinates for each triangle by projecting the mesh onto the faces of its
+/// bounding box.
+///
+/// The projection plane for each triangle is chosen by the dominant axis of its normal. As
+/// triangles sharing a point may project onto different faces of the box, the mesh is first split
+/// via `unweld` so that each triangle has its own vertices. Points that are not referenced by any
+/// triangle are not included in the produced mesh, while degenerate triangles are projected along
+/// the `z` axis.
+///
+/// All coordinates are scaled uniformly by the largest extent of the bounding box so that they
+/// lie within the range `0.0..=1.0`.
+///
+/// **Panics** if an index does not fit within a `usize` or is out of range of the mesh's points.
+pub fn project_uvs_box<M>(mesh: &M) -> WithTexCoords<Unwelded, Vec<Point2>>
+where
+    M: Points + Indices,
+    M::Point: Clone + Into<Point3>,
+    M::Index: Clone + TryInto<usize>,
+{
+    let mesh = unweld(mesh);
+    let points = &mesh.points()[..];
+    let (min, max) = bounds(points);
+    let scale = scale((max - min).max_element());
+    let tex_coords = points
+        .iter()
+        .zip(mesh.normals())
+        .map(|(&p, n)| {
+            let p = (p - min) * scale;
+            let n = n.abs();
+            if n.x > n.y && n.x > n.z {
+                Point2::new(p.z, p.y)
+            } else if n.y > n.z {
+                Point2::new(p.x, p.z)
+            } else {
+                Point2::new(p.x, p.y)
+            }
+        })
+        .collect();
+    with_tex_coords(mesh, tex_coords)
+}
+
+// The minimum and maximum corners of the bounding box of the given points.
+fn bounds(points: &[Point3]) -> (Point3, Point3) {
+    if points.is_empty() {
+        return (Point3::ZERO, Point3::ZERO);
+    }
+    points.iter().fold(
+        (
+            Point3::splat(f32::INFINITY),
+            Point3::splat(f32::NEG_INFINITY),
+        ),
+        |(min, max), &p| (min.min(p), max.max(p)),
+    )
+}
+
+// The factor by which coordinates spanning the given extent are scaled to fit within `0.0..=1.0`.
+fn scale(extent: f32) -> f32 {
+    if extent > 0.0 {
+        1.0 / extent
+    } else {
+        0.0
+    }
+}
+
+// Translate and uniformly scale the given coordinates to fit within `0.0..=1.0`.
+fn normalize(coords: Vec<Point2>) -> Vec<Point2> {
+    let min = coords
+        .iter()
+        .fold(Point2::splat(f32::INFINITY), |min, &p| min.min(p));
+    let max = coords
+        .iter()
+        .fold(Point2::splat(f32::NEG_INFINITY), |max, &p| max.max(p));
+    let scale = scale((max - min).max_element());
+    coords.into_iter().map(|p| (p - min) * scale).collect()
+}
+
+#[test]
+fn test_project_uvs() {
+    use crate::{from_points, with_indices, TexCoords};
+    use alloc::vec;
+    use splatter_core::geom::{pt2, pt3};
+
+    // A 2x1 rectangle in the `xy` plane.
+    let points = vec![
+        pt3(-1.0, 0.0, 0.0),
+        pt3(1.0, 0.0, 0.0),
+        pt3(1.0, 1.0, 0.0),
+        pt3(-1.0, 1.0, 0.0),
+    ];
+    let mesh = with_indices(from_points(points), vec![0u32, 1, 2, 0, 2, 3]);
+    let planar = project_uvs_planar(&mesh, Vec3::Z);
+    let uvs = planar.tex_coords();
+    assert!(uvs
+        .iter()
+        .all(|uv| uv.min_element() >= 0.0 && uv.max_element() <= 1.0));
+    assert!((uvs[0].distance(uvs[1]) - 1.0).abs() < 1e-6);
+    assert!((uvs[1].distance(uvs[2]) - 0.5).abs() < 1e-6);
+
+    // Box projection of a flat mesh uses the plane of its triangles.
+    let boxed = project_uvs_box(&mesh);
+    assert_eq!(
+        boxed.tex_coords(),
+        &[
+            pt2(0.0, 0.0),
+            pt2(1.0, 0.0),
+            pt2(1.0, 0.5),
+            pt2(0.0, 0.0),
+            pt2(1.0, 0.5),
+            pt2(0.0, 0.5),
+        ]
+    );
+
+    // Triangles sharing an edge but facing different axes are each projected onto their own face,
+    // so the shared point at the origin receives a different coordinate in each.
+    let points = vec![
+        pt3(0.0, 0.0, 0.0),
+        pt3(1.0, 0.0, 0.0),
+        pt3(0.0, 1.0, 0.0),
+        pt3(0.0, 0.0, -1.0),
+    ];
+    let mesh = with_indices(from_points(points), vec![0u32, 1, 2, 0, 2, 3]);
+    let boxed = project_uvs_box(&mesh);
+    assert_eq!(
+        boxed.tex_coords(),
+        &[
+            pt2(0.0, 0.0),
+            pt2(1.0, 0.0),
+            pt2(0.0, 1.0),
+            pt2(1.0, 0.0),
+            pt2(1.0, 1.0),
+            pt2(0.0, 0.0),
+        ]
+    );
+
+    // Spherical projection maps the poles to the top and bottom of the texture.
+    let points = vec![
+        pt3(0.0, 1.0, 0.0),
+        pt3(0.0, -1.0, 0.0),
+        pt3(1.0, 0.0, 0.0),
+        pt3(-1.0, 0.0, 0.0),
+    ];
+    let spherical = project_uvs_spherical(&from_points(points));
+    assert_eq!(spherical.tex_coords()[0].y, 0.0);
+    assert_eq!(spherical.tex_coords()[1].y, 1.0);
+    assert_eq!(spherical.tex_coords()[2], pt2(0.5, 0.5));
+}
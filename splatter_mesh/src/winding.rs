@@ -0,0 +1,218 @@
+//! Checking and fixing the winding order of indexed triangle meshes.
+
+use crate::{Channel, ClearIndices, Indices, Points, PushIndex};
+use alloc::{vec, vec::Vec};
+use core::convert::TryInto;
+use splatter_core::geom::Point3;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Whether or not every pair of triangles sharing an edge traverse that edge in opposite
+/// directions.
+///
+/// Triangles with repeated indices are ignored. Edges shared by more than two triangles can never
+/// be consistently wound.
+///
+/// **Panics** if an index does not fit within a `usize`.
+pub fn is_consistently_wound<M>(mesh: M) -> bool
+where
+    M: Indices,
+    M::Index: Clone + TryInto<usize>,
+{
+    let triangles = triangles(&mesh);
+    let mut edges = HashSet::new();
+    triangles
+        .iter()
+        .flatten()
+        .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+        .all(|edge| edges.insert(edge))
+}
+
+/// Flip triangles within the mesh's **Indices** channel so that each connected component is
+/// consistently wound.
+///
+/// The first triangle of each component determines the winding of the rest of that component.
+/// If `outward` is `true`, each component is then flipped as necessary so that its triangles wind
+/// counter-clockwise when viewed from outside, relative to the component's centroid. This is only
+/// meaningful for closed meshes.
+///
+/// Triangles are flipped by swapping their last two indices. Triangles with repeated indices and
+/// any trailing indices that do not form a complete triangle are left unchanged.
+///
+/// **Panics** if an index does not fit within a `usize` or is out of range of the mesh's points.
+pub fn fix_winding<M, I>(mut mesh: M, outward: bool)
+where
+    M: Points + Indices<Index = I> + PushIndex<Index = I> + ClearIndices,
+    M::Point: Clone + Into<Point3>,
+    I: Clone + TryInto<usize>,
+{
+    let triangles = triangles(&mesh);
+
+    // The triangles adjacent to each edge.
+    let mut edge_triangles: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (t, tri) in triangles.iter().enumerate() {
+        if let Some([a, b, c]) = *tri {
+            for &(v0, v1) in &[(a, b), (b, c), (c, a)] {
+                edge_triangles.entry(edge_key(v0, v1)).or_default().push(t);
+            }
+        }
+    }
+
+    // Walk each component, flipping neighbours that traverse a shared edge in the same direction.
+    let mut flipped = vec![false; triangles.len()];
+    let mut visited = vec![false; triangles.len()];
+    let mut queue = VecDeque::new();
+    for start in 0..triangles.len() {
+        if visited[start] || triangles[start].is_none() {
+            continue;
+        }
+        visited[start] = true;
+        queue.push_back(start);
+        let mut component = vec![];
+        while let Some(t) = queue.pop_front() {
+            component.push(t);
+            let tri = oriented(triangles[t], flipped[t]);
+            for &(v0, v1) in &directed_edges(tri) {
+                for &n in &edge_triangles[&edge_key(v0, v1)] {
+                    if visited[n] {
+                        continue;
+                    }
+                    visited[n] = true;
+                    flipped[n] = directed_edges(oriented(triangles[n], false)).contains(&(v0, v1));
+                    queue.push_back(n);
+                }
+            }
+        }
+        if outward && signed_volume(&mesh, &triangles, &flipped, &component) < 0.0 {
+            for &t in &component {
+                flipped[t] = !flipped[t];
+            }
+        }
+    }
+
+    if !flipped.contains(&true) {
+        return;
+    }
+    let mut indices: Vec<I> = mesh.indices().iter().cloned().collect();
+    for (tri, &flip) in indices.chunks_exact_mut(3).zip(&flipped) {
+        if flip {
+            tri.swap(1, 2);
+        }
+    }
+    mesh.clear_indices();
+    mesh.extend_indices(indices);
+}
+
+// Collect the triangles of the mesh, substituting `None` for triangles with repeated indices.
+fn triangles<M>(mesh: &M) -> Vec<Option<[usize; 3]>>
+where
+    M: Indices,
+    M::Index: Clone + TryInto<usize>,
+{
+    let indices: Vec<usize> = mesh
+        .indices()
+        .iter()
+        .map(|i| {
+            i.clone()
+                .try_into()
+                .unwrap_or_else(|_err| panic!("index out of range of valid usize values"))
+        })
+        .collect();
+    indices
+        .chunks_exact(3)
+        .map(|tri| [tri[0], tri[1], tri[2]])
+        .map(|[a, b, c]| Some([a, b, c]).filter(|_| a != b && b != c && c != a))
+        .collect()
+}
+
+// The signed volume enclosed by the given component relative to its centroid.
+//
+// Positive when the component's triangles wind counter-clockwise when viewed from outside.
+fn signed_volume<M>(
+    mesh: &M,
+    triangles: &[Option<[usize; 3]>],
+    flipped: &[bool],
+    component: &[usize],
+) -> f32
+where
+    M: Points,
+    M::Point: Clone + Into<Point3>,
+{
+    let point = |i: usize| -> Point3 {
+        match mesh.points().get(i) {
+            Some(p) => p.clone().into(),
+            None => panic!("no point for the index produced by the mesh's indices channel"),
+        }
+    };
+    let vertices: HashSet<usize> = component
+        .iter()
+        .filter_map(|&t| triangles[t])
+        .flatten()
+        .collect();
+    let centroid = vertices.iter().map(|&v| point(v)).sum::<Point3>() / vertices.len() as f32;
+    component
+        .iter()
+        .filter_map(|&t| oriented(triangles[t], flipped[t]))
+        .map(|[a, b, c]| {
+            let [a, b, c] = [point(a), point(b), point(c)].map(|p| p - centroid);
+            a.dot(b.cross(c))
+        })
+        .sum()
+}
+
+// The given triangle with its last two indices swapped if `flip` is `true`.
+fn oriented(tri: Option<[usize; 3]>, flip: bool) -> Option<[usize; 3]> {
+    tri.map(|[a, b, c]| if flip { [a, c, b] } else { [a, b, c] })
+}
+
+// The directed edges of the given triangle in winding order.
+fn directed_edges(tri: Option<[usize; 3]>) -> Vec<(usize, usize)> {
+    tri.map(|[a, b, c]| vec![(a, b), (b, c), (c, a)])
+        .unwrap_or_default()
+}
+
+// A key that is the same for both directions of an edge.
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+#[test]
+fn test_fix_winding() {
+    use crate::{from_points, with_indices};
+    use splatter_core::geom::pt3;
+
+    // A tetrahedron wound counter-clockwise when viewed from outside.
+    let points = vec![
+        pt3(1.0, 1.0, 1.0),
+        pt3(-1.0, -1.0, 1.0),
+        pt3(-1.0, 1.0, -1.0),
+        pt3(1.0, -1.0, -1.0),
+    ];
+    let outward = vec![0u32, 2, 1, 0, 1, 3, 0, 3, 2, 1, 2, 3];
+    let mesh = with_indices(from_points(points.clone()), outward.clone());
+    assert!(is_consistently_wound(&mesh));
+
+    // Flipping a single triangle is detected and fixed.
+    let mut mesh = with_indices(
+        from_points(points.clone()),
+        vec![0u32, 2, 1, 0, 3, 1, 0, 3, 2, 1, 2, 3],
+    );
+    assert!(!is_consistently_wound(&mesh));
+    fix_winding(&mut mesh, false);
+    assert!(is_consistently_wound(&mesh));
+    assert_eq!(mesh.indices(), &outward[..]);
+
+    // Forcing outward winding flips an entirely inward facing mesh.
+    let inward: Vec<u32> = outward
+        .chunks(3)
+        .flat_map(|tri| [tri[0], tri[2], tri[1]])
+        .collect();
+    let mut mesh = with_indices(from_points(points), inward.clone());
+    fix_winding(&mut mesh, false);
+    assert_eq!(mesh.indices(), &inward[..]);
+    fix_winding(&mut mesh, true);
+    assert_eq!(mesh.indices(), &outward[..]);
+}
@@ -37,3 +37,61 @@ pub const LIGHTEST: wgpu::BlendComponent = wgpu::BlendComponent {
     dst_factor: wgpu::BlendFactor::One,
     operation: wgpu::BlendOperation::Max,
 };
+
+/// Common modes for blending the colours of drawn primitives with those already in the target.
+///
+/// Unlike the component descriptors above, each mode describes the blending of both the colour
+/// and alpha channels. The `Multiply` and `Screen` modes produce their exact results for opaque
+/// colours. Translucent colours are approximated, as the source colour is not premultiplied.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// Standard alpha blending, where the source is layered over the destination.
+    #[default]
+    Normal,
+    /// The source colour, scaled by its alpha, is added to the destination. Useful for glow and
+    /// particle effects.
+    Additive,
+    /// The source and destination colours are multiplied, darkening the result.
+    Multiply,
+    /// The inverse of the source and destination colours are multiplied, lightening the result.
+    Screen,
+    /// Alpha blending for source colours whose channels are already multiplied by their alpha.
+    AlphaPremultiplied,
+}
+
+impl BlendMode {
+    /// The blend state describing this mode.
+    pub const fn blend_state(self) -> wgpu::BlendState {
+        let over = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        };
+        let color = match self {
+            BlendMode::Normal => NORMAL,
+            BlendMode::Additive => wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::SrcAlpha,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+            BlendMode::Multiply => wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::Dst,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            BlendMode::Screen => wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrc,
+                operation: wgpu::BlendOperation::Add,
+            },
+            BlendMode::AlphaPremultiplied => over,
+        };
+        wgpu::BlendState { color, alpha: over }
+    }
+}
+
+impl From<BlendMode> for wgpu::BlendState {
+    fn from(mode: BlendMode) -> Self {
+        mode.blend_state()
+    }
+}
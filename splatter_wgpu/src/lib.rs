@@ -58,6 +58,10 @@ pub use self::texture::image::{
     format_from_image_color_type as texture_format_from_image_color_type, ImageHolder,
     ImageReadMapping, WithDeviceQueuePair,
 };
+pub use self::texture::mipmap::{
+    encode_generate as encode_generate_mipmaps, is_format_supported as is_mipmap_format_supported,
+    level_count as mip_level_count,
+};
 pub use self::texture::reshaper::Reshaper as TextureReshaper;
 pub use self::texture::row_padded_buffer::RowPaddedBuffer;
 pub use self::texture::{
@@ -147,6 +151,23 @@ pub fn default_device_descriptor() -> DeviceDescriptor<'static> {
     }
 }
 
+/// The sample counts that may be supported for multisampling by a WGPU adapter, in ascending order.
+pub const SAMPLE_COUNTS: [u32; 5] = [1, 2, 4, 8, 16];
+
+/// Find the supported sample count that is nearest to the requested `sample_count`.
+///
+/// The given `flags` should describe the texture format used as the multisampled render target,
+/// e.g. `adapter.get_texture_format_features(format).flags`. If two supported sample counts are
+/// equally near, the lower of the two is returned. A sample count of `1` is always supported.
+pub fn nearest_supported_sample_count(flags: TextureFormatFeatureFlags, sample_count: u32) -> u32 {
+    SAMPLE_COUNTS
+        .iter()
+        .copied()
+        .filter(|&count| flags.sample_count_supported(count))
+        .min_by_key(|&count| (count as i64 - sample_count as i64).abs())
+        .unwrap_or(1)
+}
+
 /// Adds a simple render pass command to the given encoder that resolves the given multisampled
 /// `src_texture` to the given non-multisampled `dst_texture`.
 ///
@@ -133,6 +133,35 @@ impl wgpu::Texture {
         })
     }
 
+    /// Load an image from the given path and upload it as a texture with a full mip chain.
+    ///
+    /// This is the same as `Texture::from_path`, but also generates every mip level of the
+    /// texture on the GPU so that the texture does not alias when it is drawn smaller than its
+    /// original size. See `Texture::load_from_image_with_mipmaps` for details.
+    pub fn from_path_with_mipmaps<T, P>(src: T, path: P) -> image::ImageResult<Self>
+    where
+        T: WithDeviceQueuePair,
+        P: AsRef<Path>,
+    {
+        let image = image::open(path)?;
+        Ok(Self::from_image_with_mipmaps(src, &image))
+    }
+
+    /// Load a texture with a full mip chain from the given image.
+    ///
+    /// This is the same as `Texture::from_image`, but also generates every mip level of the
+    /// texture on the GPU so that the texture does not alias when it is drawn smaller than its
+    /// original size. See `Texture::load_from_image_with_mipmaps` for details.
+    pub fn from_image_with_mipmaps<T>(src: T, image: &image::DynamicImage) -> Self
+    where
+        T: WithDeviceQueuePair,
+    {
+        let usage = wgpu::TextureBuilder::default_image_texture_usage();
+        src.with_device_queue_pair(|device, queue| {
+            wgpu::Texture::load_from_image_with_mipmaps(device, queue, usage, image)
+        })
+    }
+
     /// Read an image file from the given path and load it directly into a texture.
     ///
     /// This is short-hand for calling `image::open` and then `Texture::load_from_image`.
@@ -167,6 +196,23 @@ impl wgpu::Texture {
         load_texture_from_image(device, queue, usage, image)
     }
 
+    /// Load a texture with a full mip chain directly from a dynamic image.
+    ///
+    /// The first mip level is loaded from the image as with `Texture::load_from_image`, after
+    /// which each subsequent level is downsampled from the level before it on the GPU.
+    ///
+    /// As integer textures can not be filtered, images with 16-bit channels are converted to 8-bit
+    /// RGBA before being uploaded. The `TEXTURE_BINDING` and `RENDER_ATTACHMENT` usages are
+    /// required in order to generate the mipmaps and are always enabled.
+    pub fn load_from_image_with_mipmaps(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        usage: wgpu::TextureUsages,
+        image: &image::DynamicImage,
+    ) -> Self {
+        load_texture_from_image_with_mipmaps(device, queue, usage, image)
+    }
+
     /// Load a texture directly from an image buffer using the given device queue.
     ///
     /// No format or size conversions are performed - the given buffer is loaded directly into GPU
@@ -569,7 +615,84 @@ where
     let texture = wgpu::TextureBuilder::from_image_view(buffer)
         .usage(wgpu::TextureBuilder::REQUIRED_IMAGE_TEXTURE_USAGE | usage)
         .build(device);
+    write_texture_from_image_buffer(queue, &texture, buffer);
+    texture
+}
+
+/// Load a texture with a full mip chain directly from a dynamic image.
+///
+/// The first mip level is written via `Queue::write_texture`, after which the commands for
+/// generating the remaining levels are submitted to the given queue.
+///
+/// As integer textures can not be filtered, images with 16-bit channels are converted to 8-bit
+/// RGBA before being uploaded.
+pub fn load_texture_from_image_with_mipmaps(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    usage: wgpu::TextureUsages,
+    image: &image::DynamicImage,
+) -> wgpu::Texture {
+    use image::DynamicImage::*;
+    match image {
+        ImageLuma8(img) => load_texture_from_image_buffer_with_mipmaps(device, queue, usage, img),
+        ImageLumaA8(img) => load_texture_from_image_buffer_with_mipmaps(device, queue, usage, img),
+        ImageRgba8(img) => load_texture_from_image_buffer_with_mipmaps(device, queue, usage, img),
+        ImageBgra8(img) => load_texture_from_image_buffer_with_mipmaps(device, queue, usage, img),
+        ImageBgr8(_img) => {
+            let img = image.to_bgra8();
+            load_texture_from_image_buffer_with_mipmaps(device, queue, usage, &img)
+        }
+        _ => {
+            let img = image.to_rgba8();
+            load_texture_from_image_buffer_with_mipmaps(device, queue, usage, &img)
+        }
+    }
+}
+
+// Load the image buffer into the first mip level of a new texture and generate the rest.
+//
+// Falls back to a single mip level if the pixel format does not support mipmap generation.
+fn load_texture_from_image_buffer_with_mipmaps<P, Container>(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    usage: wgpu::TextureUsages,
+    buffer: &image::ImageBuffer<P, Container>,
+) -> wgpu::Texture
+where
+    P: 'static + Pixel,
+    Container: std::ops::Deref<Target = [P::Subpixel]>,
+{
+    let mip_level_count = match wgpu::is_mipmap_format_supported(device, P::TEXTURE_FORMAT) {
+        true => wgpu::mip_level_count([buffer.width(), buffer.height()]),
+        false => 1,
+    };
+    let mipmap_usage =
+        wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::RENDER_ATTACHMENT;
+    let texture = wgpu::TextureBuilder::from_image_view(buffer)
+        .mip_level_count(mip_level_count)
+        .usage(wgpu::TextureBuilder::REQUIRED_IMAGE_TEXTURE_USAGE | mipmap_usage | usage)
+        .build(device);
+    write_texture_from_image_buffer(queue, &texture, buffer);
 
+    // The write above is scheduled to happen before these commands are executed.
+    let cmd_encoder_desc = wgpu::CommandEncoderDescriptor {
+        label: Some("splatter_texture_generate_mipmaps"),
+    };
+    let mut encoder = device.create_command_encoder(&cmd_encoder_desc);
+    wgpu::encode_generate_mipmaps(device, &mut encoder, &texture);
+    queue.submit(std::iter::once(encoder.finish()));
+    texture
+}
+
+// Write the contents of the image buffer to the first mip level of the texture.
+fn write_texture_from_image_buffer<P, Container>(
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    buffer: &image::ImageBuffer<P, Container>,
+) where
+    P: 'static + Pixel,
+    Container: std::ops::Deref<Target = [P::Subpixel]>,
+{
     // Describe the layout of the data.
     let extent = texture.extent();
     let format = texture.format();
@@ -593,7 +716,6 @@ where
     let data = unsafe { wgpu::bytes::from_slice(buffer) };
 
     queue.write_texture(image_copy_texture, data, image_data_layout, extent);
-    texture
 }
 
 /// Load a 3d texture directly from a sequence of image buffers.
@@ -0,0 +1,182 @@
+//! Generation of the full mip chain of a texture on the GPU.
+//!
+//! Textures that are drawn smaller than their size on screen (i.e. minified) alias badly unless
+//! they have mipmaps to sample from. Each mip level is produced by downsampling the previous level
+//! within a render pass using a linear filter.
+
+use crate as wgpu;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+// The shader module and the pipelines built from it for each texture format on a single device.
+struct DevicePipelines {
+    shader_mod: wgpu::ShaderModule,
+    pipelines: HashMap<wgpu::TextureFormat, Arc<Pipeline>>,
+}
+
+// The resources required to downsample textures of a single format.
+struct Pipeline {
+    sampler: wgpu::Sampler,
+    bind_group_layout: wgpu::BindGroupLayout,
+    render_pipeline: wgpu::RenderPipeline,
+}
+
+/// The number of mip levels in a full mip chain for a texture of the given size.
+///
+/// This is the number of times the largest dimension may be halved until it reaches `1`, plus the
+/// level of the original size.
+pub fn level_count([width, height]: [u32; 2]) -> u32 {
+    32 - width.max(height).max(1).leading_zeros()
+}
+
+/// Whether or not mipmaps for textures of the given format can be generated on the given device.
+///
+/// The format must be both filterable and renderable, e.g. `Rgba8UnormSrgb` or `Rgba16Float`.
+/// Integer formats such as `Rgba16Uint` are not supported.
+pub fn is_format_supported(device: &wgpu::Device, format: wgpu::TextureFormat) -> bool {
+    let filterable = matches!(
+        format.sample_type(None),
+        Some(wgpu::TextureSampleType::Float { filterable: true })
+    );
+    let usages = format
+        .guaranteed_format_features(device.features())
+        .allowed_usages;
+    filterable && usages.contains(wgpu::TextureUsages::RENDER_ATTACHMENT)
+}
+
+/// Encode the render passes that fill every mip level of the given texture from its first level.
+///
+/// The texture must be a non-multisampled 2D texture with the `TEXTURE_BINDING` and
+/// `RENDER_ATTACHMENT` usages, and its format must be supported as described by
+/// [`is_format_supported`]. Does nothing if the texture has only one mip level.
+///
+/// The shader module and render pipeline are created on first use for each device and texture
+/// format, and are reused for all later calls.
+///
+/// NOTE: The mip levels will remain empty until the given `encoder` has its command buffer
+/// submitted to the given `device`'s queue.
+pub fn encode_generate(
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    texture: &wgpu::Texture,
+) {
+    let level_count = texture.mip_level_count();
+    if level_count <= 1 {
+        return;
+    }
+    assert_eq!(
+        texture.sample_count(),
+        1,
+        "cannot mipmap a multisampled texture"
+    );
+    assert_eq!(texture.dimension(), wgpu::TextureDimension::D2);
+    let required_usage =
+        wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::RENDER_ATTACHMENT;
+    assert!(
+        texture.usage().contains(required_usage),
+        "generating mipmaps requires the `TEXTURE_BINDING` and `RENDER_ATTACHMENT` usages"
+    );
+
+    let pipeline = pipeline(device, texture.format());
+
+    // A view of each level, each of which is rendered from the view of the level before it.
+    let views: Vec<_> = (0..level_count)
+        .map(|level| {
+            texture
+                .view()
+                .base_mip_level(level)
+                .level_count(Some(1))
+                .build()
+        })
+        .collect();
+    for pair in views.windows(2) {
+        let (src, dst) = (&pair[0], &pair[1]);
+        let bind_group = wgpu::BindGroupBuilder::new()
+            .texture_view(src)
+            .sampler(&pipeline.sampler)
+            .build(device, &pipeline.bind_group_layout);
+        let mut render_pass = wgpu::RenderPassBuilder::new()
+            .color_attachment(dst, |color| color)
+            .begin(encoder);
+        render_pass.set_pipeline(&pipeline.render_pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+// The cached pipeline for textures of the given format on the given device, created if necessary.
+//
+// NOTE: Cached resources are never removed, as there is no way to observe that a device has been
+// dropped. Only one entry is kept per device and format, so this is bounded in practice.
+fn pipeline(device: &wgpu::Device, format: wgpu::TextureFormat) -> Arc<Pipeline> {
+    static CACHE: OnceLock<Mutex<HashMap<wgpu_upstream::Id<wgpu::Device>, DevicePipelines>>> =
+        OnceLock::new();
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .expect("failed to acquire mipmap pipeline cache lock");
+    let device_pipelines = cache.entry(device.global_id()).or_insert_with(|| {
+        let shader_desc = wgpu::include_wgsl!("shaders/mipmap.wgsl");
+        DevicePipelines {
+            shader_mod: device.create_shader_module(shader_desc),
+            pipelines: HashMap::new(),
+        }
+    });
+    let shader_mod = &device_pipelines.shader_mod;
+    let pipeline = device_pipelines
+        .pipelines
+        .entry(format)
+        .or_insert_with(|| Arc::new(create_pipeline(device, shader_mod, format)));
+    pipeline.clone()
+}
+
+fn create_pipeline(
+    device: &wgpu::Device,
+    shader_mod: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+) -> Pipeline {
+    let sample_type = format
+        .sample_type(None)
+        .expect("Expected the format to have a sample type");
+    // The default sampler filters linearly, averaging the source texels.
+    let sampler_desc = wgpu::SamplerBuilder::new().into_descriptor();
+    let sampler = device.create_sampler(&sampler_desc);
+    let bind_group_layout = wgpu::BindGroupLayoutBuilder::new()
+        .texture(
+            wgpu::ShaderStages::FRAGMENT,
+            false,
+            wgpu::TextureViewDimension::D2,
+            sample_type,
+        )
+        .sampler(wgpu::ShaderStages::FRAGMENT, true)
+        .build(device);
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("splatter_mipmap"),
+        bind_group_layouts: &[&bind_group_layout],
+        push_constant_ranges: &[],
+    });
+    let render_pipeline = wgpu::RenderPipelineBuilder::from_layout(&pipeline_layout, shader_mod)
+        .vertex_entry_point("vs_main")
+        .fragment_shader(shader_mod)
+        .fragment_entry_point("fs_main")
+        .color_format(format)
+        .color_blend(wgpu::BlendComponent::REPLACE)
+        .alpha_blend(wgpu::BlendComponent::REPLACE)
+        .primitive_topology(wgpu::PrimitiveTopology::TriangleList)
+        .build(device);
+    Pipeline {
+        sampler,
+        bind_group_layout,
+        render_pipeline,
+    }
+}
+
+#[test]
+fn test_level_count() {
+    assert_eq!(level_count([1, 1]), 1);
+    assert_eq!(level_count([2, 1]), 2);
+    assert_eq!(level_count([256, 256]), 9);
+    assert_eq!(level_count([300, 100]), 9);
+    assert_eq!(level_count([1920, 1080]), 11);
+    assert_eq!(level_count([0, 0]), 1);
+}
@@ -6,6 +6,7 @@ use std::sync::Arc;
 pub mod capturer;
 #[cfg(feature = "image")]
 pub mod image;
+pub mod mipmap;
 pub mod reshaper;
 pub mod row_padded_buffer;
 
@@ -581,6 +582,14 @@ impl<'a> ViewBuilder<'a> {
         self
     }
 
+    /// Base mip level.
+    ///
+    /// By default, this is `0`.
+    pub fn base_mip_level(mut self, base_mip_level: u32) -> Self {
+        self.info.base_mip_level = base_mip_level;
+        self
+    }
+
     /// Mip level count.
     ///
     /// If `Some`, base_mip_level + count must be less or equal to underlying texture mip count.
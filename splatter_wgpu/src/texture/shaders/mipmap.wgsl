@@ -0,0 +1,31 @@
+struct VertexOutput {
+    @location(0) tex_coords: vec2<f32>,
+    @builtin(position) out_pos: vec4<f32>,
+};
+
+@group(0) @binding(0)
+var tex: texture_2d<f32>;
+@group(0) @binding(1)
+var tex_sampler: sampler;
+
+// A single triangle covering the whole target, so that no vertex buffer is required.
+@vertex
+fn vs_main(
+    @builtin(vertex_index) index: u32,
+) -> VertexOutput {
+    let x: f32 = f32(i32(index & 1u) * 4 - 1);
+    let y: f32 = f32(i32(index >> 1u) * 4 - 1);
+    let out_pos: vec4<f32> = vec4<f32>(x, y, 0.0, 1.0);
+    let tex_coords: vec2<f32> = vec2<f32>(x * 0.5 + 0.5, 1.0 - (y * 0.5 + 0.5));
+    return VertexOutput(tex_coords, out_pos);
+}
+
+// Each texel of the destination level lies at the corner of four texels of the source level, so a
+// linear sample averages them. The source view has a single level, so it is sampled explicitly
+// rather than by a level of detail derived from the minified destination.
+@fragment
+fn fs_main(
+    @location(0) tex_coords: vec2<f32>,
+) -> @location(0) vec4<f32> {
+    return textureSampleLevel(tex, tex_sampler, tex_coords, 0.0);
+}